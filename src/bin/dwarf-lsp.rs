@@ -0,0 +1,102 @@
+//! Dwarf Language Server
+//!
+//! Speaks LSP over stdin and stdout. Parsing is done by an external program,
+//! which is run as `<parser> [args...] <path>` each time a document changes.
+//! The source is written to it's stdin. If it parses, the program writes the
+//! lu_dog store to stdout, as bincode, and exits successfully. If it doesn't,
+//! the program writes a JSON array of syntax errors, each with a `message` and
+//! `start` and `end` byte offsets, to stderr, and exits with an error.
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use clap::Parser as _;
+use sarzak::{
+    dwarf::lsp::{Parser, Server, SyntaxError},
+    v2::lu_dog_vec::ObjectStore as LuDogStore,
+};
+
+#[derive(clap::Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The program that parses dwarf into a lu_dog store
+    #[arg(long)]
+    parser: String,
+    /// Arguments for the parser, before the path
+    #[arg(last = true)]
+    args: Vec<String>,
+}
+
+struct ExternalParser {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalParser {
+    fn fail(message: String) -> Vec<SyntaxError> {
+        vec![SyntaxError {
+            message,
+            start: 0,
+            end: 0,
+        }]
+    }
+}
+
+impl Parser for ExternalParser {
+    fn parse(&mut self, path: &str, source: &str) -> Result<LuDogStore, Vec<SyntaxError>> {
+        let child = Command::new(&self.program)
+            .args(&self.args)
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => return Err(Self::fail(format!("unable to run the parser: {}", e))),
+        };
+
+        // Feed it from another thread, so that a parser that writes a lot
+        // before it's done reading can't block us both. It may not read all
+        // of it's input if it fails early.
+        let writer = child.stdin.take().map(|mut stdin| {
+            let source = source.to_owned();
+            thread::spawn(move || {
+                let _ = stdin.write_all(source.as_bytes());
+            })
+        });
+        let output = child.wait_with_output();
+        if let Some(writer) = writer {
+            let _ = writer.join();
+        }
+        let output = match output {
+            Ok(output) => output,
+            Err(e) => return Err(Self::fail(format!("the parser failed: {}", e))),
+        };
+
+        if output.status.success() {
+            bincode::deserialize(&output.stdout)
+                .map_err(|e| Self::fail(format!("the parser wrote a bad store: {}", e)))
+        } else {
+            Err(serde_json::from_slice(&output.stderr).unwrap_or_else(|_| {
+                let message = String::from_utf8_lossy(&output.stderr).trim().to_owned();
+                if message.is_empty() {
+                    Self::fail(format!("the parser failed: {}", output.status))
+                } else {
+                    Self::fail(message)
+                }
+            }))
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let args = Args::parse();
+    let parser = ExternalParser {
+        program: args.parser,
+        args: args.args,
+    };
+
+    let stdin = io::stdin();
+    Server::new(parser).run(stdin.lock(), io::stdout())
+}
@@ -27,7 +27,10 @@ use crate::{
             types::{Attribute, Context as SarzakContext, Object, Relationship, Subtype, Type},
         },
     },
-    v2::domain::Domain as DomainV2,
+    v2::{
+        domain::Domain as DomainV2, merlin::store::ObjectStore as MerlinStore,
+        sarzak::store::ObjectStore as SarzakV2Store,
+    },
 };
 
 /// Domain Builder
@@ -87,15 +90,48 @@ use crate::{
 /// the system with instances that should exist at start-up. It's also a place
 /// where initialization events may be created and sent.
 ///
+/// # Version 2
+///
+/// The hooks above work on the version 1 stores, and only apply to cuckoo
+/// models. [`pre_load_v2`][Self::pre_load_v2] and
+/// [`post_load_v2`][Self::post_load_v2] are their version 2 counterparts, and
+/// work on the v2 sarzak and merlin stores. They apply no matter where the
+/// domain came from: a cuckoo model, a persisted v2 domain
+/// ([`v2_model`][Self::v2_model]), or a bincode file
+/// ([`bincode_model`][Self::bincode_model]).
+///
+/// ```
+/// # use sarzak::domain::DomainBuilder;
+/// # use sarzak::v2::sarzak::types::Object;
+/// # const SARZAK_MODEL:&str = "models/sarzak.json";
+/// let domain = DomainBuilder::new()
+///     .cuckoo_model(SARZAK_MODEL).unwrap()
+///     .post_load_v2(|sarzak, _merlin| {
+///         Object::new("".to_owned(), "WIP".to_owned(), "Work In Progress".to_owned(), sarzak);
+///     })
+///     .build_v2().unwrap();
+///
+/// assert!(domain.sarzak().exhume_object_id_by_name("WorkInProgress").is_some());
+/// ```
+///
 /// [os]: crate::sarzak::ObjectStore
 /// [s]: crate::sarzak::State
 /// [e]: crate::sarzak::Event
 pub struct DomainBuilder {
-    nut_model: Option<(PathBuf, FromModel)>,
+    source: Option<Source>,
     pre_load: Option<
         Box<dyn Fn(&FromSarzakStore, &FromDrawingStore, &mut SarzakV1Store, &mut DrawingV1Store)>,
     >,
     post_load: Option<Box<dyn Fn(&mut SarzakV1Store, &mut DrawingV1Store)>>,
+    pre_load_v2: Option<Box<dyn Fn(&mut SarzakV2Store, &mut MerlinStore)>>,
+    post_load_v2: Option<Box<dyn Fn(&mut SarzakV2Store, &mut MerlinStore)>>,
+}
+
+/// Where the domain comes from
+///
+enum Source {
+    Cuckoo(PathBuf, FromModel),
+    V2(DomainV2),
 }
 
 impl DomainBuilder {
@@ -103,22 +139,52 @@ impl DomainBuilder {
     ///
     pub fn new() -> Self {
         Self {
-            nut_model: None,
+            source: None,
             pre_load: None,
             post_load: None,
+            pre_load_v2: None,
+            post_load_v2: None,
         }
     }
 
     /// Specify an input model
     ///
-    /// Currently the only input we know how to process is a cuckoo model.
-    pub fn cuckoo_model<P: AsRef<Path>>(mut self, path: P) -> Result<Self> {
+    /// This one is a cuckoo model. See also [`v2_model`][Self::v2_model] and
+    /// [`bincode_model`][Self::bincode_model].
+    pub fn cuckoo_model<P: AsRef<Path>>(self, path: P) -> Result<Self> {
+        self.no_source()?;
+
         let nut_model = FromModel::load_cuckoo_model(&path).context(FileOpenSnafu {
             path: path.as_ref(),
         })?;
 
-        self.nut_model = Some((path.as_ref().to_path_buf(), nut_model));
-        Ok(self)
+        Ok(self.source(Source::Cuckoo(path.as_ref().to_path_buf(), nut_model)))
+    }
+
+    /// Specify an input model
+    ///
+    /// This one is a v2 domain, as persisted by [`DomainV2::persist`].
+    pub fn v2_model<P: AsRef<Path>>(self, path: P) -> Result<Self> {
+        self.no_source()?;
+
+        let domain = DomainV2::load(&path).context(FileOpenSnafu {
+            path: path.as_ref(),
+        })?;
+
+        Ok(self.source(Source::V2(domain)))
+    }
+
+    /// Specify an input model
+    ///
+    /// This one is a v2 domain, as persisted by [`DomainV2::persist_domain_bincode`].
+    pub fn bincode_model<P: AsRef<Path>>(self, path: P) -> Result<Self> {
+        self.no_source()?;
+
+        let domain = DomainV2::load_domain_bincode(&path).context(FileOpenSnafu {
+            path: path.as_ref(),
+        })?;
+
+        Ok(self.source(Source::V2(domain)))
     }
 
     /// Pre-load function
@@ -151,9 +217,49 @@ impl DomainBuilder {
         self
     }
 
-    fn _build_v1(self) -> DomainV1 {
-        let (path, model) = self.nut_model.unwrap();
+    /// Version 2 pre-load function
+    ///
+    /// The function is handed empty v2 stores, and the domain is loaded on top
+    /// of whatever it puts there.
+    pub fn pre_load_v2<F>(mut self, pre_load: F) -> Self
+    where
+        F: Fn(&mut SarzakV2Store, &mut MerlinStore) + 'static,
+    {
+        self.pre_load_v2 = Some(Box::new(pre_load));
 
+        self
+    }
+
+    /// Version 2 post-load function
+    ///
+    /// The function is handed the stores of the loaded domain.
+    pub fn post_load_v2<F>(mut self, post_load: F) -> Self
+    where
+        F: Fn(&mut SarzakV2Store, &mut MerlinStore) + 'static,
+    {
+        self.post_load_v2 = Some(Box::new(post_load));
+
+        self
+    }
+
+    fn no_source(&self) -> Result<()> {
+        ensure!(
+            self.source.is_none(),
+            DomainBuilderSnafu {
+                message: "only one input model may be specified"
+            }
+        );
+
+        Ok(())
+    }
+
+    fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+
+        self
+    }
+
+    fn _build_v1(&self, path: PathBuf, model: FromModel) -> DomainV1 {
         let mut sarzak = SarzakV1Store::new();
         let mut drawing = DrawingV1Store::new();
 
@@ -179,29 +285,68 @@ impl DomainBuilder {
     /// The final step
     ///
     /// Return the newly packaged domain
-    pub fn build_v1(self) -> Result<DomainV1> {
-        ensure!(
-            self.nut_model.is_some(),
-            DomainBuilderSnafu {
-                message: "you must specify a cuckoo model using `DomainBuilder::cuckoo_model`"
+    pub fn build_v1(mut self) -> Result<DomainV1> {
+        match self.source.take() {
+            Some(Source::Cuckoo(path, model)) => Ok(self._build_v1(path, model)),
+            Some(Source::V2(_)) => DomainBuilderSnafu {
+                message: "a v1 domain may only be built from a cuckoo model",
             }
-        );
-
-        Ok(self._build_v1())
+            .fail()
+            .map_err(|e| e.into()),
+            None => DomainBuilderSnafu {
+                message: "you must specify a cuckoo model using `DomainBuilder::cuckoo_model`",
+            }
+            .fail()
+            .map_err(|e| e.into()),
+        }
     }
 
     /// The final step
     ///
     /// Return the newly packaged domain
-    pub fn build_v2(self) -> Result<DomainV2> {
-        ensure!(
-            self.nut_model.is_some(),
-            DomainBuilderSnafu {
-                message: "you must specify a cuckoo model using `DomainBuilder::cuckoo_model`"
+    pub fn build_v2(mut self) -> Result<DomainV2> {
+        let mut domain = match self.source.take() {
+            Some(Source::Cuckoo(path, model)) => self._build_v1(path, model).into(),
+            Some(Source::V2(domain)) => {
+                ensure!(
+                    self.pre_load.is_none() && self.post_load.is_none(),
+                    DomainBuilderSnafu {
+                        message: "`pre_load` and `post_load` only apply to cuckoo models, use \
+                                  `pre_load_v2` and `post_load_v2` instead"
+                    }
+                );
+                domain
             }
-        );
+            None => {
+                return DomainBuilderSnafu {
+                    message:
+                        "you must specify an input model using `DomainBuilder::cuckoo_model`, \
+                              `DomainBuilder::v2_model`, or `DomainBuilder::bincode_model`",
+                }
+                .fail()
+                .map_err(|e| e.into())
+            }
+        };
+
+        if let Some(ref func) = self.pre_load_v2 {
+            log::debug!("executing v2 preload function");
+            let mut sarzak = SarzakV2Store::new();
+            let mut merlin = MerlinStore::new();
+            func(&mut sarzak, &mut merlin);
+
+            sarzak.merge(domain.sarzak());
+            merlin.merge(domain.merlin());
+            *domain.sarzak_mut() = sarzak;
+            *domain.merlin_mut() = merlin;
+        }
+
+        if let Some(ref func) = self.post_load_v2 {
+            log::debug!("executing v2 postload function");
+            let (sarzak, merlin) = domain.stores_mut();
+            func(sarzak, merlin);
+        }
 
-        Ok(self._build_v1().into())
+        Ok(domain)
     }
 }
 
@@ -362,4 +507,79 @@ mod tests {
             .build_v1();
         assert!(ok.is_ok());
     }
+
+    fn pets() -> DomainV2 {
+        use crate::v2::domain::builder::{ModelBuilder, Multiplicity::*};
+
+        ModelBuilder::new("pets", "")
+            .object("Dog")
+            .object("Owner")
+            .binary(1, "Dog", Many, "Owner", One, "is owned by")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_v2() {
+        use crate::v2::sarzak::types::Object;
+
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let err = DomainBuilder::new().build_v2();
+        assert!(err.is_err());
+
+        let dir = std::env::temp_dir().join(format!("sarzak-builder-{}", std::process::id()));
+        let path = dir.join("pets");
+        pets().persist(&path).unwrap();
+        let bin = dir.join("pets.bin");
+        pets().persist_domain_bincode(&bin).unwrap();
+
+        let domain = DomainBuilder::new()
+            .v2_model(&path)
+            .unwrap()
+            .pre_load_v2(|sarzak, _| {
+                Object::new("".to_owned(), "C".to_owned(), "Cat".to_owned(), sarzak);
+            })
+            .post_load_v2(|sarzak, _| {
+                Object::new("".to_owned(), "V".to_owned(), "Vet".to_owned(), sarzak);
+            })
+            .build_v2()
+            .unwrap();
+        let sarzak = domain.sarzak();
+        for name in ["Cat", "Dog", "Owner", "Vet"] {
+            assert!(sarzak.exhume_object_id_by_name(name).is_some(), "{}", name);
+        }
+        assert_eq!(sarzak.iter_binary().count(), 1);
+        assert_eq!(domain.merlin().iter_line().count(), 1);
+
+        let domain = DomainBuilder::new()
+            .bincode_model(&bin)
+            .unwrap()
+            .build_v2()
+            .unwrap();
+        assert_eq!(domain.sarzak().iter_object().count(), 2);
+        assert_eq!(domain.merlin().iter_x_box().count(), 2);
+
+        // The v1 hooks don't apply to v2 models, and a v2 model can't be
+        // built as v1.
+        let err = DomainBuilder::new()
+            .bincode_model(&bin)
+            .unwrap()
+            .post_load(|_, _| {})
+            .build_v2();
+        assert!(err.is_err());
+        let err = DomainBuilder::new().v2_model(&path).unwrap().build_v1();
+        assert!(err.is_err());
+
+        // One model at a time.
+        let err = DomainBuilder::new()
+            .v2_model(&path)
+            .unwrap()
+            .bincode_model(&bin);
+        assert!(err.is_err());
+
+        assert!(DomainBuilder::new().v2_model(dir.join("nope")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
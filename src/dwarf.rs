@@ -0,0 +1,25 @@
+//! Dwarf
+//!
+//! Dwarf programs live in a lu_dog store. These are the tools that work on
+//! them.
+pub mod checker;
+pub mod diagnostic;
+pub mod index;
+pub mod interpreter;
+pub mod lsp;
+pub mod printer;
+pub mod resolver;
+pub mod source;
+pub mod visitor;
+
+#[cfg(test)]
+mod sample;
+
+pub use checker::check;
+pub use diagnostic::{Diagnostic, Problem};
+pub use index::{Hover, Index, Occurrence};
+pub use interpreter::{Interpreter, RuntimeError, Value};
+pub use printer::Printer;
+pub use resolver::resolve;
+pub use source::{location, snippet, Location};
+pub use visitor::{walk, walk_mut, Node, Visitor, VisitorMut, Walk};
@@ -0,0 +1,1910 @@
+//! Dwarf Type Checker
+//!
+//! This checks that a program in a lu_dog store is well typed. It checks
+//!
+//!  - the arguments of calls against the parameters of the function
+//!  - the operands of operators
+//!  - that the test of an `if` is a boolean
+//!  - that `for` loops iterate over something that they can, and that the
+//!    test of a `while` is a boolean
+//!  - that `break` and `continue` are inside a loop with the label, and that
+//!    the values a `loop` breaks with agree
+//!  - returned values against the return type of the function
+//!
+//! along with a few other things, like let annotations, struct fields, and the
+//! elements of lists and the arms of a match all agreeing.
+//!
+//! Types that are `Unknown` are holes in the program, and they are compatible
+//! with everything. The checker fills them in where it can: the types of let
+//! bindings and expressions are inferred from their values, and the types of
+//! spans from their expressions.
+//!
+//! Problems are reported as [`Diagnostic`]s, with the span of the offending
+//! expression, if the store has one for it. Unresolved names are not reported;
+//! the types involved are simply unknown. Finding them is the job of
+//! [`resolve`](crate::dwarf::resolve).
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::dwarf::{diagnostic::jump, printer::compound, Diagnostic, Printer, Problem};
+use crate::v2::{
+    lu_dog_vec::{
+        BinaryEnum, BodyEnum, CallEnum, ComparisonEnum, DataStructureEnum, EnumFieldEnum,
+        ExpressionEnum, FieldAccessTargetEnum, FieldExpressionEnum, FormatBitEnum, List,
+        LiteralEnum, ObjectStore as LuDogStore, OperatorEnum, StatementEnum, UnaryEnum, ValueType,
+        ValueTypeEnum, VariableEnum, XValueEnum,
+    },
+    sarzak::types::{BOOLEAN, FLOAT, INTEGER, Z_STRING},
+};
+
+/// Type check the program in the store
+///
+/// Unknown types are filled in where they can be inferred.
+pub fn check(store: &mut LuDogStore) -> Vec<Diagnostic> {
+    let mut checker = Checker::new(store);
+
+    let functions: Vec<_> = checker
+        .store
+        .iter_function()
+        .map(|func| func.borrow().id)
+        .collect();
+    for func in functions {
+        checker.function(func);
+    }
+
+    checker.diagnostics
+}
+
+struct Checker<'a> {
+    store: &'a mut LuDogStore,
+    diagnostics: Vec<Diagnostic>,
+    /// Span ids, by expression id
+    spans: HashMap<usize, usize>,
+    /// XValue ids, by expression id
+    values: HashMap<usize, usize>,
+    /// XValue ids, by local variable id
+    locals: HashMap<usize, usize>,
+    /// Variable names, by local variable id
+    local_names: HashMap<usize, String>,
+    /// Parameter names, by parameter id
+    params: HashMap<usize, String>,
+    /// Lambda parameter names, by lambda parameter id
+    lambda_params: HashMap<usize, String>,
+    /// Free functions, by name
+    functions: HashMap<String, usize>,
+    /// Functions in implementation blocks, by type and function name
+    methods: HashMap<(String, String), usize>,
+    /// Variable types, innermost scope last
+    scopes: Vec<HashMap<String, usize>>,
+    /// Return types of the enclosing functions and lambdas
+    returns: Vec<usize>,
+    /// Labels of the enclosing loops, innermost last, with the type of their
+    /// value, once it's known
+    loops: Vec<(String, Option<usize>)>,
+    unknown: usize,
+    empty: usize,
+    boolean: usize,
+    char: usize,
+    float: usize,
+    integer: usize,
+    range: usize,
+    string: usize,
+}
+
+impl<'a> Checker<'a> {
+    fn new(store: &'a mut LuDogStore) -> Self {
+        let mut spans = HashMap::new();
+        let mut values = HashMap::new();
+        let mut locals = HashMap::new();
+        let mut local_names = HashMap::new();
+        let mut params = HashMap::new();
+        let mut lambda_params = HashMap::new();
+
+        for value in store.iter_x_value() {
+            let value = value.borrow();
+            match value.subtype {
+                XValueEnum::Expression(expr) => {
+                    values.insert(expr, value.id);
+                }
+                XValueEnum::Variable(ref var) => {
+                    let var = store.exhume_variable(var).unwrap();
+                    let var = var.borrow();
+                    if let VariableEnum::LocalVariable(local) = var.subtype {
+                        locals.insert(local, value.id);
+                    }
+                }
+            }
+        }
+        for span in store.iter_span() {
+            let span = span.borrow();
+            if let Some(ref value) = span.x_value {
+                if let XValueEnum::Expression(expr) =
+                    store.exhume_x_value(value).unwrap().borrow().subtype
+                {
+                    spans.insert(expr, span.id);
+                }
+            }
+        }
+        for var in store.iter_variable() {
+            let var = var.borrow();
+            let (names, id) = match var.subtype {
+                VariableEnum::LambdaParameter(id) => (&mut lambda_params, id),
+                VariableEnum::LocalVariable(id) => (&mut local_names, id),
+                VariableEnum::Parameter(id) => (&mut params, id),
+            };
+            names.insert(id, var.name.clone());
+        }
+
+        let mut functions = HashMap::new();
+        let mut methods = HashMap::new();
+        for func in store.iter_function() {
+            let func = func.borrow();
+            match func.impl_block {
+                Some(ref id) => {
+                    let block = store.exhume_implementation_block(id).unwrap();
+                    let block = block.borrow();
+                    let ty = match (block.model_type, block.enumeration) {
+                        (Some(ref id), _) => {
+                            store.exhume_woog_struct(id).unwrap().borrow().name.clone()
+                        }
+                        (None, Some(ref id)) => {
+                            store.exhume_enumeration(id).unwrap().borrow().name.clone()
+                        }
+                        (None, None) => continue,
+                    };
+                    methods.insert((ty, func.name.clone()), func.id);
+                }
+                None => {
+                    functions.insert(func.name.clone(), func.id);
+                }
+            }
+        }
+
+        let unknown = ValueType::new_unknown(false, store).borrow().id;
+        let empty = ValueType::new_empty(false, store).borrow().id;
+        let char = ValueType::new_char(false, store).borrow().id;
+        let range = ValueType::new_range(false, store).borrow().id;
+        let boolean = ty(BOOLEAN, store);
+        let float = ty(FLOAT, store);
+        let integer = ty(INTEGER, store);
+        let string = ty(Z_STRING, store);
+
+        Self {
+            store,
+            diagnostics: Vec::new(),
+            spans,
+            values,
+            locals,
+            local_names,
+            params,
+            lambda_params,
+            functions,
+            methods,
+            scopes: Vec::new(),
+            returns: Vec::new(),
+            loops: Vec::new(),
+            unknown,
+            empty,
+            boolean,
+            char,
+            float,
+            integer,
+            range,
+            string,
+        }
+    }
+
+    fn report(&mut self, problem: Problem, expr: usize) {
+        let span = self
+            .spans
+            .get(&expr)
+            .map(|span| self.store.exhume_span(span).unwrap().borrow().clone());
+        self.diagnostics.push(Diagnostic { problem, span });
+    }
+
+    fn mismatch(&mut self, expected: usize, found: usize, expr: usize) {
+        let problem = Problem::Mismatch {
+            expected: self.name(expected),
+            found: self.name(found),
+        };
+        self.report(problem, expr);
+    }
+
+    /// Report a mismatch unless the types are compatible
+    fn expect(&mut self, expected: usize, found: usize, expr: usize) {
+        if !self.compatible(expected, found) {
+            self.mismatch(expected, found, expr);
+        }
+    }
+
+    //
+    // Types
+    //
+
+    fn subtype(&self, ty: usize) -> ValueTypeEnum {
+        self.store
+            .exhume_value_type(&ty)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone()
+    }
+
+    fn name(&self, ty: usize) -> String {
+        let ty = self.store.exhume_value_type(&ty).unwrap();
+        let name = Printer::new(self.store).print_type(&ty.borrow());
+        name
+    }
+
+    fn is_unknown(&self, ty: usize) -> bool {
+        matches!(self.subtype(ty), ValueTypeEnum::Unknown(_))
+    }
+
+    fn is_numeric(&self, ty: usize) -> bool {
+        ty == self.integer || ty == self.float
+    }
+
+    /// The first of the types that isn't unknown
+    fn known(&self, a: usize, b: usize) -> usize {
+        if self.is_unknown(a) {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// Could a value of type `b` be used where one of type `a` is expected?
+    ///
+    /// Anything unknown, generic, or imported is compatible with everything,
+    /// as are the signatures of functions and lambdas.
+    fn compatible(&self, a: usize, b: usize) -> bool {
+        if a == b {
+            return true;
+        }
+
+        use ValueTypeEnum::*;
+        match (self.subtype(a), self.subtype(b)) {
+            (Unknown(_), _) | (_, Unknown(_)) => true,
+            (EnumGeneric(_) | FuncGeneric(_) | StructGeneric(_) | Import(_), _)
+            | (_, EnumGeneric(_) | FuncGeneric(_) | StructGeneric(_) | Import(_)) => true,
+            (AnyList(_), AnyList(_) | List(_)) | (List(_), AnyList(_)) => true,
+            (List(a), List(b)) => {
+                let a = self.store.exhume_list(&a).unwrap().borrow().ty;
+                let b = self.store.exhume_list(&b).unwrap().borrow().ty;
+                self.compatible(a, b)
+            }
+            (Map(a), Map(b)) => {
+                let a = self.store.exhume_map(&a).unwrap();
+                let b = self.store.exhume_map(&b).unwrap();
+                let (a, b) = (a.borrow(), b.borrow());
+                self.compatible(a.key_type, b.key_type)
+                    && self.compatible(a.value_type, b.value_type)
+            }
+            (XFuture(a), XFuture(b)) => {
+                let a = self.store.exhume_x_future(&a).unwrap().borrow().x_value;
+                let b = self.store.exhume_x_future(&b).unwrap().borrow().x_value;
+                self.compatible(a, b)
+            }
+            (Function(_) | Lambda(_), Function(_) | Lambda(_)) => true,
+            (Char(_), Char(_))
+            | (Empty(_), Empty(_))
+            | (Range(_), Range(_))
+            | (Task(_), Task(_)) => true,
+            (Ty(a), Ty(b)) => a == b,
+            (WoogStruct(a), WoogStruct(b))
+            | (Enumeration(a), Enumeration(b))
+            | (ZObjectStore(a), ZObjectStore(b))
+            | (XPlugin(a), XPlugin(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    fn list_of(&mut self, element: usize) -> usize {
+        let element = self.store.exhume_value_type(&element).unwrap();
+        let list = List::new(&element, self.store);
+        let ty = ValueType::new_list(false, &list, self.store);
+        let id = ty.borrow().id;
+        id
+    }
+
+    /// The element type of a list, if it's a list
+    fn element_of(&self, ty: usize) -> Option<usize> {
+        match self.subtype(ty) {
+            ValueTypeEnum::List(list) => Some(self.store.exhume_list(&list).unwrap().borrow().ty),
+            ValueTypeEnum::AnyList(_) => Some(self.unknown),
+            _ => None,
+        }
+    }
+
+    fn function_type(&mut self, func: usize) -> usize {
+        let func = self.store.exhume_function(&func).unwrap();
+        let ty = ValueType::new_function(false, &func, self.store);
+        let id = ty.borrow().id;
+        id
+    }
+
+    /// The parameter names and types, and return type, of a function
+    fn signature(&self, func: usize) -> (Vec<(String, usize)>, usize) {
+        let func = self.store.exhume_function(&func).unwrap();
+        let func = func.borrow();
+
+        let mut params = Vec::new();
+        let mut next = func.first_param;
+        while let Some(id) = next {
+            let param = self.store.exhume_parameter(&id).unwrap();
+            let param = param.borrow();
+            next = param.next;
+            params.push((self.params[&id].clone(), param.ty));
+        }
+
+        (params, func.return_type)
+    }
+
+    fn lambda_signature(&self, lambda: usize) -> (Vec<(String, usize)>, usize) {
+        let lambda = self.store.exhume_lambda(&lambda).unwrap();
+        let lambda = lambda.borrow();
+
+        let mut params = Vec::new();
+        let mut next = lambda.first_param;
+        while let Some(id) = next {
+            let param = self.store.exhume_lambda_parameter(&id).unwrap();
+            let param = param.borrow();
+            next = param.next;
+            params.push((
+                self.lambda_params[&id].clone(),
+                param.ty.unwrap_or(self.unknown),
+            ));
+        }
+
+        (params, lambda.return_type)
+    }
+
+    /// Record the type of an expression, if it was unknown
+    fn fill(&mut self, expr: usize, ty: usize) {
+        if self.is_unknown(ty) {
+            return;
+        }
+
+        if let Some(value) = self.values.get(&expr) {
+            let value = self.store.exhume_x_value(value).unwrap();
+            let current = value.borrow().ty;
+            if self.is_unknown(current) {
+                value.borrow_mut().ty = ty;
+            }
+        }
+        if let Some(span) = self.spans.get(&expr) {
+            let span = self.store.exhume_span(span).unwrap();
+            let current = span.borrow().ty;
+            if current.map_or(true, |current| self.is_unknown(current)) {
+                span.borrow_mut().ty = Some(ty);
+            }
+        }
+    }
+
+    //
+    // Scopes
+    //
+
+    fn define(&mut self, name: &str, ty: usize) {
+        self.scopes
+            .last_mut()
+            .expect("no scope")
+            .insert(name.to_owned(), ty);
+    }
+
+    fn lookup(&mut self, name: &str) -> usize {
+        if let Some(ty) = self.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            return *ty;
+        }
+        match self.functions.get(name) {
+            Some(func) => self.function_type(*func),
+            None => self.unknown,
+        }
+    }
+
+    //
+    // Functions and blocks
+    //
+
+    fn function(&mut self, func: usize) {
+        let body = self.store.exhume_function(&func).unwrap().borrow().body;
+        let block = match self.store.exhume_body(&body).unwrap().borrow().subtype {
+            BodyEnum::Block(block) => block,
+            BodyEnum::ExternalImplementation(_) => return,
+        };
+        let (params, return_type) = self.signature(func);
+
+        // Functions don't see the scopes around them.
+        let scopes = std::mem::take(&mut self.scopes);
+        self.body(params, return_type, block);
+        self.scopes = scopes;
+    }
+
+    /// Check the body of a function or lambda, including the type of it's
+    /// value
+    fn body(&mut self, params: Vec<(String, usize)>, return_type: usize, block: usize) {
+        // Neither functions nor lambdas can break out of the loops around them.
+        let loops = std::mem::take(&mut self.loops);
+        self.scopes.push(params.into_iter().collect());
+        self.returns.push(return_type);
+        let (ty, result) = self.statements(block);
+        self.returns.pop();
+        self.scopes.pop();
+        self.loops = loops;
+
+        if let Some(result) = result {
+            self.expect(return_type, ty, result);
+        }
+    }
+
+    fn block(&mut self, block: usize) -> usize {
+        self.scopes.push(HashMap::new());
+        let (ty, _) = self.statements(block);
+        self.scopes.pop();
+
+        ty
+    }
+
+    /// The type of the block, and the expression that is it's value, if it
+    /// ends with one
+    fn statements(&mut self, block: usize) -> (usize, Option<usize>) {
+        let mut result = (self.empty, None);
+        let mut next = self.store.exhume_block(&block).unwrap().borrow().statement;
+        while let Some(id) = next {
+            let statement = self.store.exhume_statement(&id).unwrap();
+            let subtype = statement.borrow().subtype.clone();
+            next = statement.borrow().next;
+
+            result = (self.empty, None);
+            match subtype {
+                StatementEnum::ExpressionStatement(ref id) => {
+                    let expr = self
+                        .store
+                        .exhume_expression_statement(id)
+                        .unwrap()
+                        .borrow()
+                        .expression;
+                    let ty = self.expression(expr);
+                    let block_like = matches!(
+                        self.store
+                            .exhume_expression(&expr)
+                            .unwrap()
+                            .borrow()
+                            .subtype,
+                        ExpressionEnum::Block(_)
+                            | ExpressionEnum::XIf(_)
+                            | ExpressionEnum::XLoop(_)
+                            | ExpressionEnum::XMatch(_)
+                            | ExpressionEnum::XWhile(_)
+                    );
+                    if block_like {
+                        result = (ty, Some(expr));
+                    }
+                }
+                StatementEnum::ItemStatement(_) => {}
+                StatementEnum::LetStatement(ref id) => {
+                    let stmt = self.store.exhume_let_statement(id).unwrap();
+                    let (expr, local) = {
+                        let stmt = stmt.borrow();
+                        (stmt.expression, stmt.variable)
+                    };
+                    let ty = self.expression(expr);
+
+                    let ty = match self.locals.get(&local) {
+                        Some(value) => {
+                            let value = self.store.exhume_x_value(value).unwrap();
+                            let declared = value.borrow().ty;
+                            if self.is_unknown(declared) {
+                                value.borrow_mut().ty = ty;
+                                ty
+                            } else {
+                                self.expect(declared, ty, expr);
+                                declared
+                            }
+                        }
+                        None => ty,
+                    };
+                    let name = self.local_names[&local].clone();
+                    self.define(&name, ty);
+                }
+                StatementEnum::ResultStatement(ref id) => {
+                    let expr = self
+                        .store
+                        .exhume_result_statement(id)
+                        .unwrap()
+                        .borrow()
+                        .expression;
+                    result = (self.expression(expr), Some(expr));
+                }
+            }
+        }
+
+        result
+    }
+
+    //
+    // Expressions
+    //
+
+    fn expression(&mut self, id: usize) -> usize {
+        let expr = self.store.exhume_expression(&id).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        let ty = match subtype {
+            ExpressionEnum::AWait(ref a_wait) => {
+                let future = self.store.exhume_a_wait(a_wait).unwrap().borrow().x_future;
+                let ty = self.expression(future);
+                match self.subtype(ty) {
+                    ValueTypeEnum::XFuture(ref future) => {
+                        self.store.exhume_x_future(future).unwrap().borrow().x_value
+                    }
+                    _ => self.unknown,
+                }
+            }
+            ExpressionEnum::Block(ref block) => self.block(*block),
+            ExpressionEnum::XBreak(ref x_break) => self.x_break(*x_break, id),
+            ExpressionEnum::Call(ref call) => self.call(*call, id),
+            ExpressionEnum::XContinue(ref x_continue) => {
+                let label = self
+                    .store
+                    .exhume_x_continue(x_continue)
+                    .unwrap()
+                    .borrow()
+                    .label
+                    .clone();
+                if self.target(&label).is_none() {
+                    let problem = Problem::OutsideLoop {
+                        what: jump("continue", &label),
+                    };
+                    self.report(problem, id);
+                }
+                self.unknown
+            }
+            ExpressionEnum::XDebugger(_) | ExpressionEnum::EmptyExpression(_) => self.empty,
+            ExpressionEnum::FieldAccess(ref access) => self.field_access(*access),
+            ExpressionEnum::FieldExpression(ref field) => {
+                let expr = self
+                    .store
+                    .exhume_field_expression(field)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr)
+            }
+            ExpressionEnum::ForLoop(ref for_loop) => self.for_loop(*for_loop),
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr)
+            }
+            ExpressionEnum::HaltAndCatchFire(ref hcf) => {
+                let expr = self
+                    .store
+                    .exhume_halt_and_catch_fire(hcf)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+                self.unknown
+            }
+            ExpressionEnum::XIf(ref x_if) => self.x_if(*x_if),
+            ExpressionEnum::Index(ref index) => self.index(*index),
+            ExpressionEnum::Lambda(ref lambda) => self.lambda(*lambda),
+            ExpressionEnum::ListElement(ref element) => {
+                let expr = self
+                    .store
+                    .exhume_list_element(element)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr)
+            }
+            ExpressionEnum::ListExpression(ref list) => self.list(*list),
+            ExpressionEnum::Literal(ref literal) => self.literal(*literal),
+            ExpressionEnum::XLoop(ref x_loop) => self.x_loop(*x_loop),
+            ExpressionEnum::XMatch(ref x_match) => self.x_match(*x_match),
+            ExpressionEnum::Operator(ref operator) => self.operator(*operator, id),
+            ExpressionEnum::XPath(ref path) => self.path(*path),
+            ExpressionEnum::XPrint(ref print) => {
+                let expr = self
+                    .store
+                    .exhume_x_print(print)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+                self.empty
+            }
+            ExpressionEnum::RangeExpression(ref range) => {
+                let (lhs, rhs) = {
+                    let range = self.store.exhume_range_expression(range).unwrap();
+                    let range = range.borrow();
+                    (range.lhs, range.rhs)
+                };
+                for bound in lhs.into_iter().chain(rhs) {
+                    let ty = self.expression(bound);
+                    self.expect(self.integer, ty, bound);
+                }
+                self.range
+            }
+            ExpressionEnum::XReturn(ref x_return) => {
+                let expr = self
+                    .store
+                    .exhume_x_return(x_return)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                let ty = self.expression(expr);
+                if let Some(expected) = self.returns.last() {
+                    self.expect(*expected, ty, expr);
+                }
+                // It doesn't produce a value, so it fits anywhere.
+                self.unknown
+            }
+            ExpressionEnum::StructExpression(ref expr) => self.struct_expression(*expr),
+            ExpressionEnum::TypeCast(ref cast) => {
+                let (lhs, ty) = {
+                    let cast = self.store.exhume_type_cast(cast).unwrap();
+                    let cast = cast.borrow();
+                    (cast.lhs, cast.ty)
+                };
+                self.expression(lhs);
+                ty
+            }
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                self.lookup(&name)
+            }
+            ExpressionEnum::XWhile(ref x_while) => self.x_while(*x_while),
+        };
+
+        self.fill(id, ty);
+        ty
+    }
+
+    fn literal(&mut self, id: usize) -> usize {
+        let literal = self.store.exhume_literal(&id).unwrap();
+        let subtype = literal.borrow().subtype.clone();
+
+        match subtype {
+            LiteralEnum::BooleanLiteral(_) => self.boolean,
+            LiteralEnum::CharLiteral(_) => self.char,
+            LiteralEnum::FloatLiteral(_) => self.float,
+            LiteralEnum::FormatString(ref id) => {
+                let mut next = self
+                    .store
+                    .exhume_format_string(id)
+                    .unwrap()
+                    .borrow()
+                    .first_format_bit;
+                while let Some(id) = next {
+                    let bit = self.store.exhume_format_bit(&id).unwrap();
+                    let subtype = bit.borrow().subtype.clone();
+                    next = bit.borrow().next;
+                    if let FormatBitEnum::ExpressionBit(ref id) = subtype {
+                        let expr = self
+                            .store
+                            .exhume_expression_bit(id)
+                            .unwrap()
+                            .borrow()
+                            .expression;
+                        self.expression(expr);
+                    }
+                }
+                self.string
+            }
+            LiteralEnum::IntegerLiteral(_) => self.integer,
+            LiteralEnum::MapExpression(ref id) => {
+                let elements: Vec<_> = self
+                    .store
+                    .iter_map_element()
+                    .filter(|element| element.borrow().map == *id)
+                    .map(|element| {
+                        let element = element.borrow();
+                        (element.key, element.x_value)
+                    })
+                    .collect();
+                for (key, value) in elements {
+                    self.expression(key);
+                    self.expression(value);
+                }
+                self.unknown
+            }
+            LiteralEnum::StringLiteral(_) => self.string,
+        }
+    }
+
+    fn list(&mut self, id: usize) -> usize {
+        let list = self.store.exhume_list_expression(&id).unwrap();
+        let (mut next, declared) = {
+            let list = list.borrow();
+            (list.elements, list.ty)
+        };
+
+        let mut element = self.element_of(declared).unwrap_or(self.unknown);
+        while let Some(id) = next {
+            let (expr, following) = {
+                let el = self.store.exhume_list_element(&id).unwrap();
+                let el = el.borrow();
+                (el.expression, el.next)
+            };
+            next = following;
+
+            let ty = self.expression(expr);
+            self.expect(element, ty, expr);
+            element = self.known(element, ty);
+        }
+
+        if self.is_unknown(element) {
+            return if self.is_unknown(declared) {
+                self.unknown
+            } else {
+                declared
+            };
+        }
+        let ty = self.list_of(element);
+        if self.is_unknown(declared) {
+            list.borrow_mut().ty = ty;
+        }
+
+        ty
+    }
+
+    fn path(&mut self, path: usize) -> usize {
+        let mut names = Vec::new();
+        let mut next = self.store.exhume_x_path(&path).unwrap().borrow().first;
+        while let Some(id) = next {
+            let element = self.store.exhume_path_element(&id).unwrap();
+            let element = element.borrow();
+            next = element.next;
+            names.push(element.name.clone());
+        }
+
+        match names.as_slice() {
+            [name] => self.lookup(name),
+            [.., ty, name] => {
+                if let Some(id) = self.store.exhume_enumeration_id_by_name(ty) {
+                    let woog_enum = self.store.exhume_enumeration(&id).unwrap();
+                    let ty = ValueType::new_enumeration(false, &woog_enum, self.store);
+                    let id = ty.borrow().id;
+                    return id;
+                }
+                match self.methods.get(&(ty.clone(), name.clone())) {
+                    Some(func) => self.function_type(*func),
+                    None => self.unknown,
+                }
+            }
+            [] => self.unknown,
+        }
+    }
+
+    fn arguments(&mut self, first: Option<usize>) -> Vec<(usize, usize)> {
+        let mut args = Vec::new();
+        let mut next = first;
+        while let Some(id) = next {
+            let (expr, following) = {
+                let arg = self.store.exhume_argument(&id).unwrap();
+                let arg = arg.borrow();
+                (arg.expression, arg.next)
+            };
+            next = following;
+            args.push((expr, self.expression(expr)));
+        }
+
+        args
+    }
+
+    /// Check arguments, as expressions and their types, against parameters
+    fn check_arguments(
+        &mut self,
+        name: &str,
+        params: &[(String, usize)],
+        args: &[(usize, usize)],
+        expr: usize,
+    ) {
+        if params.len() != args.len() {
+            let problem = Problem::Arity {
+                name: name.to_owned(),
+                expected: params.len(),
+                found: args.len(),
+            };
+            self.report(problem, expr);
+            return;
+        }
+        for ((_, param), (arg, ty)) in params.iter().zip(args) {
+            self.expect(*param, *ty, *arg);
+        }
+    }
+
+    fn call(&mut self, id: usize, expr: usize) -> usize {
+        let call = self.store.exhume_call(&id).unwrap();
+        let call = call.borrow().clone();
+
+        match call.subtype {
+            CallEnum::FunctionCall(ref id) => {
+                let name = self
+                    .store
+                    .exhume_function_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                let callee = match call.expression {
+                    Some(callee) => self.expression(callee),
+                    None => self.lookup(&name),
+                };
+                let args = self.arguments(call.argument);
+
+                let (params, return_type) = match self.subtype(callee) {
+                    ValueTypeEnum::Function(func) => self.signature(func),
+                    ValueTypeEnum::Lambda(lambda) => self.lambda_signature(lambda),
+                    ValueTypeEnum::Unknown(_)
+                    | ValueTypeEnum::FuncGeneric(_)
+                    | ValueTypeEnum::Import(_) => return self.unknown,
+                    _ => {
+                        let problem = Problem::NotCallable {
+                            found: self.name(callee),
+                        };
+                        self.report(problem, expr);
+                        return self.unknown;
+                    }
+                };
+                self.check_arguments(&name, &params, &args, expr);
+
+                return_type
+            }
+            CallEnum::MacroCall(_) => {
+                self.arguments(call.argument);
+                self.unknown
+            }
+            CallEnum::MethodCall(ref id) => {
+                let name = self
+                    .store
+                    .exhume_method_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                let receiver = match call.expression {
+                    Some(receiver) => self.expression(receiver),
+                    None => self.unknown,
+                };
+                let args = self.arguments(call.argument);
+                self.method(receiver, &name, &args, expr)
+            }
+            CallEnum::StaticMethodCall(ref id) => {
+                let key = {
+                    let method = self.store.exhume_static_method_call(id).unwrap();
+                    let method = method.borrow();
+                    (method.ty.clone(), method.func.clone())
+                };
+                let args = self.arguments(call.argument);
+                match self.methods.get(&key) {
+                    Some(func) => {
+                        let (params, return_type) = self.signature(*func);
+                        let name = format!("{}::{}", key.0, key.1);
+                        self.check_arguments(&name, &params, &args, expr);
+                        return_type
+                    }
+                    None => self.unknown,
+                }
+            }
+        }
+    }
+
+    /// Methods are defined on structs and enums, and lists, maps and strings
+    /// have a few built in, courtesy of the interpreter.
+    fn method(
+        &mut self,
+        receiver: usize,
+        name: &str,
+        args: &[(usize, usize)],
+        expr: usize,
+    ) -> usize {
+        let ty = match self.subtype(receiver) {
+            ValueTypeEnum::WoogStruct(ref id) => Some(
+                self.store
+                    .exhume_woog_struct(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone(),
+            ),
+            ValueTypeEnum::Enumeration(ref id) => Some(
+                self.store
+                    .exhume_enumeration(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone(),
+            ),
+            _ => None,
+        };
+        if let Some(ty) = ty {
+            return match self.methods.get(&(ty, name.to_owned())) {
+                Some(func) => {
+                    let (mut params, return_type) = self.signature(*func);
+                    if params.first().map_or(false, |(name, _)| name == "self") {
+                        params.remove(0);
+                    }
+                    self.check_arguments(name, &params, args, expr);
+                    return_type
+                }
+                None => self.unknown,
+            };
+        }
+
+        if let Some(element) = self.element_of(receiver) {
+            return match name {
+                "len" => self.integer,
+                "is_empty" => self.boolean,
+                "pop" => element,
+                "push" => {
+                    let params = [("value".to_owned(), element)];
+                    self.check_arguments(name, &params, args, expr);
+                    self.empty
+                }
+                _ => self.unknown,
+            };
+        }
+
+        match (self.subtype(receiver), name) {
+            (ValueTypeEnum::Map(_), "len") => self.integer,
+            (ValueTypeEnum::Map(_), "contains_key") => self.boolean,
+            (_, "len") if receiver == self.string => self.integer,
+            (_, "is_empty") if receiver == self.string => self.boolean,
+            _ => self.unknown,
+        }
+    }
+
+    fn field_access(&mut self, id: usize) -> usize {
+        let (expr, target) = {
+            let access = self.store.exhume_field_access(&id).unwrap();
+            let access = access.borrow();
+            (access.expression, access.field)
+        };
+        let target = self
+            .store
+            .exhume_field_access_target(&target)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone();
+
+        match target {
+            FieldAccessTargetEnum::EnumField(ref field) => {
+                let woog_enum = self
+                    .store
+                    .exhume_enum_field(field)
+                    .unwrap()
+                    .borrow()
+                    .woog_enum;
+                let woog_enum = self.store.exhume_enumeration(&woog_enum).unwrap();
+                let ty = ValueType::new_enumeration(false, &woog_enum, self.store);
+                let id = ty.borrow().id;
+                id
+            }
+            FieldAccessTargetEnum::Field(ref field) => {
+                self.expression(expr);
+                self.store.exhume_field(field).unwrap().borrow().ty
+            }
+            FieldAccessTargetEnum::Function(func) => {
+                self.expression(expr);
+                self.function_type(func)
+            }
+        }
+    }
+
+    fn for_loop(&mut self, id: usize) -> usize {
+        let (ident, label, block, expr) = {
+            let for_loop = self.store.exhume_for_loop(&id).unwrap();
+            let for_loop = for_loop.borrow();
+            (
+                for_loop.ident.clone(),
+                for_loop.label.clone(),
+                for_loop.block,
+                for_loop.expression,
+            )
+        };
+
+        let iterable = self.expression(expr);
+        let item = if iterable == self.range {
+            self.integer
+        } else if iterable == self.string {
+            self.char
+        } else if let Some(element) = self.element_of(iterable) {
+            element
+        } else if self.is_unknown(iterable) {
+            self.unknown
+        } else {
+            let problem = Problem::NotIterable {
+                found: self.name(iterable),
+            };
+            self.report(problem, expr);
+            self.unknown
+        };
+
+        self.scopes.push(HashMap::new());
+        self.define(&ident, item);
+        self.loops.push((label, Some(self.empty)));
+        self.expression(block);
+        self.loops.pop();
+        self.scopes.pop();
+
+        self.empty
+    }
+
+    /// The index of the loop that a `break` or `continue` with the label
+    /// refers to
+    fn target(&self, label: &str) -> Option<usize> {
+        self.loops
+            .iter()
+            .rposition(|(l, _)| label.is_empty() || l == label)
+    }
+
+    fn x_break(&mut self, id: usize, expr: usize) -> usize {
+        let (label, value) = {
+            let x_break = self.store.exhume_x_break(&id).unwrap();
+            let x_break = x_break.borrow();
+            (x_break.label.clone(), x_break.expression)
+        };
+
+        let ty = match value {
+            Some(value) => self.expression(value),
+            None => self.empty,
+        };
+        match self.target(&label) {
+            Some(target) => match self.loops[target].1 {
+                Some(expected) => {
+                    self.expect(expected, ty, value.unwrap_or(expr));
+                    self.loops[target].1 = Some(self.known(expected, ty));
+                }
+                None => self.loops[target].1 = Some(ty),
+            },
+            None => {
+                let problem = Problem::OutsideLoop {
+                    what: jump("break", &label),
+                };
+                self.report(problem, expr);
+            }
+        }
+
+        // Like return, it doesn't produce a value.
+        self.unknown
+    }
+
+    /// A `loop` has the type of the values that it's broken out with.
+    fn x_loop(&mut self, id: usize) -> usize {
+        let (label, block) = {
+            let x_loop = self.store.exhume_x_loop(&id).unwrap();
+            let x_loop = x_loop.borrow();
+            (x_loop.label.clone(), x_loop.block)
+        };
+
+        self.loops.push((label, None));
+        self.expression(block);
+        let (_, ty) = self.loops.pop().unwrap();
+
+        ty.unwrap_or(self.unknown)
+    }
+
+    fn x_while(&mut self, id: usize) -> usize {
+        let (label, test, block) = {
+            let x_while = self.store.exhume_x_while(&id).unwrap();
+            let x_while = x_while.borrow();
+            (x_while.label.clone(), x_while.test, x_while.block)
+        };
+
+        let ty = self.expression(test);
+        self.expect(self.boolean, ty, test);
+
+        self.loops.push((label, Some(self.empty)));
+        self.expression(block);
+        self.loops.pop();
+
+        self.empty
+    }
+
+    fn x_if(&mut self, id: usize) -> usize {
+        let (test, true_block, false_block) = {
+            let x_if = self.store.exhume_x_if(&id).unwrap();
+            let x_if = x_if.borrow();
+            (x_if.test, x_if.true_block, x_if.false_block)
+        };
+
+        let ty = self.expression(test);
+        self.expect(self.boolean, ty, test);
+
+        let ty = self.block(true_block);
+        match false_block {
+            Some(expr) => {
+                let other = self.expression(expr);
+                self.expect(ty, other, expr);
+                self.known(ty, other)
+            }
+            None => self.empty,
+        }
+    }
+
+    fn index(&mut self, id: usize) -> usize {
+        let (index, target) = {
+            let index = self.store.exhume_index(&id).unwrap();
+            let index = index.borrow();
+            (index.index, index.target)
+        };
+
+        let target_ty = self.expression(target);
+        let index_ty = self.expression(index);
+
+        if let Some(element) = self.element_of(target_ty) {
+            self.expect(self.integer, index_ty, index);
+            element
+        } else if target_ty == self.string {
+            self.expect(self.integer, index_ty, index);
+            self.char
+        } else if let ValueTypeEnum::Map(ref map) = self.subtype(target_ty) {
+            let (key, value) = {
+                let map = self.store.exhume_map(map).unwrap();
+                let map = map.borrow();
+                (map.key_type, map.value_type)
+            };
+            self.expect(key, index_ty, index);
+            value
+        } else if self.is_unknown(target_ty) {
+            self.unknown
+        } else {
+            let problem = Problem::Mismatch {
+                expected: "a list, map, or string".to_owned(),
+                found: self.name(target_ty),
+            };
+            self.report(problem, target);
+            self.unknown
+        }
+    }
+
+    fn lambda(&mut self, id: usize) -> usize {
+        let (params, return_type) = self.lambda_signature(id);
+        let lambda = self.store.exhume_lambda(&id).unwrap();
+
+        let body = lambda.borrow().body;
+        if let Some(ref body) = body {
+            if let BodyEnum::Block(block) = self.store.exhume_body(body).unwrap().borrow().subtype {
+                // Lambdas see the scopes around them.
+                self.body(params, return_type, block);
+            }
+        }
+
+        let ty = ValueType::new_lambda(false, &lambda, self.store);
+        let id = ty.borrow().id;
+        id
+    }
+
+    fn x_match(&mut self, id: usize) -> usize {
+        let scrutinee = self.store.exhume_x_match(&id).unwrap().borrow().scrutinee;
+        let scrutinee = self.expression(scrutinee);
+
+        let patterns: Vec<_> = self
+            .store
+            .iter_pattern()
+            .filter(|pattern| pattern.borrow().x_match == id)
+            .map(|pattern| {
+                let pattern = pattern.borrow();
+                (
+                    pattern.id,
+                    pattern.next,
+                    pattern.match_expr,
+                    pattern.expression,
+                )
+            })
+            .collect();
+        let mut next = patterns
+            .iter()
+            .find(|(id, ..)| !patterns.iter().any(|(_, next, ..)| *next == Some(*id)))
+            .map(|(id, ..)| *id);
+
+        let mut ty = self.unknown;
+        while let Some(id) = next {
+            let (_, following, pattern, expr) =
+                *patterns.iter().find(|(this, ..)| *this == id).unwrap();
+            next = following;
+
+            self.scopes.push(HashMap::new());
+            self.pattern(pattern, scrutinee);
+            let arm = self.expression(expr);
+            self.scopes.pop();
+
+            self.expect(ty, arm, expr);
+            ty = self.known(ty, arm);
+        }
+
+        ty
+    }
+
+    /// Bind the variables in a pattern, and check it against the type of the
+    /// scrutinee
+    fn pattern(&mut self, pattern: usize, ty: usize) {
+        let expr = self.store.exhume_expression(&pattern).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                if name != "_" {
+                    self.define(&name, ty);
+                }
+            }
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.pattern(expr, ty);
+            }
+            ExpressionEnum::StructExpression(ref id) => {
+                let (data, path) = {
+                    let expr = self.store.exhume_struct_expression(id).unwrap();
+                    let expr = expr.borrow();
+                    (expr.data, expr.x_path)
+                };
+                let data = self
+                    .store
+                    .exhume_data_structure(&data)
+                    .unwrap()
+                    .borrow()
+                    .subtype
+                    .clone();
+                let fields = self.field_expressions(*id);
+
+                let this = match data {
+                    DataStructureEnum::Enumeration(ref woog_enum) => {
+                        let variant = self.path_last(path);
+                        let field_ty = self
+                            .store
+                            .iter_enum_field()
+                            .find(|field| {
+                                let field = field.borrow();
+                                field.woog_enum == *woog_enum
+                                    && Some(&field.name) == variant.as_ref()
+                            })
+                            .and_then(|field| match field.borrow().subtype {
+                                EnumFieldEnum::TupleField(ref tuple) => {
+                                    Some(self.store.exhume_tuple_field(tuple).unwrap().borrow().ty)
+                                }
+                                _ => None,
+                            })
+                            .unwrap_or(self.unknown);
+                        for (_, expr) in fields {
+                            self.pattern(expr, field_ty);
+                        }
+
+                        let woog_enum = self.store.exhume_enumeration(woog_enum).unwrap();
+                        let ty = ValueType::new_enumeration(false, &woog_enum, self.store);
+                        let id = ty.borrow().id;
+                        id
+                    }
+                    DataStructureEnum::WoogStruct(ref woog_struct) => {
+                        for (name, expr) in fields {
+                            let field_ty = self.field_type(*woog_struct, &name);
+                            self.pattern(expr, field_ty);
+                        }
+
+                        let woog_struct = self.store.exhume_woog_struct(woog_struct).unwrap();
+                        let ty = ValueType::new_woog_struct(false, &woog_struct, self.store);
+                        let id = ty.borrow().id;
+                        id
+                    }
+                };
+                self.expect(ty, this, pattern);
+            }
+            // Anything else is a value to compare against.
+            _ => {
+                let this = self.expression(pattern);
+                self.expect(ty, this, pattern);
+            }
+        }
+    }
+
+    fn path_last(&self, path: usize) -> Option<String> {
+        let mut last = None;
+        let mut next = self.store.exhume_x_path(&path).unwrap().borrow().first;
+        while let Some(id) = next {
+            let element = self.store.exhume_path_element(&id).unwrap();
+            let element = element.borrow();
+            next = element.next;
+            last = Some(element.name.clone());
+        }
+
+        last
+    }
+
+    /// The type of a struct's field, by name
+    fn field_type(&self, woog_struct: usize, name: &str) -> usize {
+        self.store
+            .iter_field()
+            .find(|field| {
+                let field = field.borrow();
+                field.x_model == woog_struct && field.name == name
+            })
+            .map_or(self.unknown, |field| field.borrow().ty)
+    }
+
+    /// The fields of a struct expression, as names and expressions, in the
+    /// order in which they were written. Unnamed fields are named by position.
+    fn field_expressions(&self, id: usize) -> Vec<(String, usize)> {
+        let mut fields: Vec<_> = self
+            .store
+            .iter_field_expression()
+            .filter(|field| field.borrow().woog_struct == id)
+            .map(|field| {
+                let field = field.borrow();
+                let name = match field.subtype {
+                    FieldExpressionEnum::NamedFieldExpression(ref id) => self
+                        .store
+                        .exhume_named_field_expression(id)
+                        .unwrap()
+                        .borrow()
+                        .name
+                        .clone(),
+                    FieldExpressionEnum::UnnamedFieldExpression(ref id) => self
+                        .store
+                        .exhume_unnamed_field_expression(id)
+                        .unwrap()
+                        .borrow()
+                        .position
+                        .to_string(),
+                };
+                (name, field.expression)
+            })
+            .collect();
+        fields.sort_by_key(|(name, _)| name.parse::<usize>().ok());
+
+        fields
+    }
+
+    fn struct_expression(&mut self, id: usize) -> usize {
+        let (data, path) = {
+            let expr = self.store.exhume_struct_expression(&id).unwrap();
+            let expr = expr.borrow();
+            (expr.data, expr.x_path)
+        };
+        let data = self
+            .store
+            .exhume_data_structure(&data)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone();
+
+        match data {
+            DataStructureEnum::Enumeration(ref woog_enum) => {
+                let variant = self.path_last(path);
+                let field_ty = self
+                    .store
+                    .iter_enum_field()
+                    .find(|field| {
+                        let field = field.borrow();
+                        field.woog_enum == *woog_enum && Some(&field.name) == variant.as_ref()
+                    })
+                    .and_then(|field| match field.borrow().subtype {
+                        EnumFieldEnum::TupleField(ref tuple) => {
+                            Some(self.store.exhume_tuple_field(tuple).unwrap().borrow().ty)
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or(self.unknown);
+                for (_, expr) in self.field_expressions(id) {
+                    let ty = self.expression(expr);
+                    self.expect(field_ty, ty, expr);
+                }
+
+                let woog_enum = self.store.exhume_enumeration(woog_enum).unwrap();
+                let ty = ValueType::new_enumeration(false, &woog_enum, self.store);
+                let id = ty.borrow().id;
+                id
+            }
+            DataStructureEnum::WoogStruct(ref woog_struct) => {
+                for (name, expr) in self.field_expressions(id) {
+                    let ty = self.expression(expr);
+                    let field_ty = self.field_type(*woog_struct, &name);
+                    self.expect(field_ty, ty, expr);
+                }
+
+                let woog_struct = self.store.exhume_woog_struct(woog_struct).unwrap();
+                let ty = ValueType::new_woog_struct(false, &woog_struct, self.store);
+                let id = ty.borrow().id;
+                id
+            }
+        }
+    }
+
+    //
+    // Operators
+    //
+
+    fn operator(&mut self, id: usize, expr: usize) -> usize {
+        let operator = self.store.exhume_operator(&id).unwrap();
+        let operator = operator.borrow().clone();
+
+        match operator.subtype {
+            OperatorEnum::Binary(ref id) => {
+                let binary = self
+                    .store
+                    .exhume_binary(id)
+                    .unwrap()
+                    .borrow()
+                    .subtype
+                    .clone();
+                let lhs = self.expression(operator.lhs);
+                let rhs = match operator.rhs {
+                    Some(rhs) => (rhs, self.expression(rhs)),
+                    None => return self.unknown,
+                };
+
+                match binary {
+                    BinaryEnum::Assignment(_) => {
+                        self.expect(lhs, rhs.1, rhs.0);
+                        self.empty
+                    }
+                    BinaryEnum::BooleanOperator(_) => {
+                        self.expect(self.boolean, lhs, operator.lhs);
+                        self.expect(self.boolean, rhs.1, rhs.0);
+                        self.boolean
+                    }
+                    binary => match compound(&binary) {
+                        // The result of the operation has to fit back into the lhs.
+                        Some(binary) => {
+                            let ty = self.arithmetic(&binary, lhs, rhs.1, expr);
+                            self.expect(lhs, ty, rhs.0);
+                            self.empty
+                        }
+                        None => self.arithmetic(&binary, lhs, rhs.1, expr),
+                    },
+                }
+            }
+            OperatorEnum::Comparison(ref id) => {
+                let comparison = self
+                    .store
+                    .exhume_comparison(id)
+                    .unwrap()
+                    .borrow()
+                    .subtype
+                    .clone();
+                let lhs = self.expression(operator.lhs);
+                let rhs = match operator.rhs {
+                    Some(rhs) => self.expression(rhs),
+                    None => return self.boolean,
+                };
+
+                let (symbol, ordered) = match comparison {
+                    ComparisonEnum::Equal(_) => ("==", false),
+                    ComparisonEnum::NotEqual(_) => ("!=", false),
+                    ComparisonEnum::GreaterThan(_) => (">", true),
+                    ComparisonEnum::GreaterThanOrEqual(_) => (">=", true),
+                    ComparisonEnum::LessThan(_) => ("<", true),
+                    ComparisonEnum::LessThanOrEqual(_) => ("<=", true),
+                };
+                let ty = self.known(lhs, rhs);
+                let applies = !ordered
+                    || self.is_numeric(ty)
+                    || ty == self.string
+                    || ty == self.char
+                    || self.is_unknown(ty);
+                if !applies || !self.compatible(lhs, rhs) {
+                    let problem = Problem::Operands {
+                        operator: symbol.to_owned(),
+                        lhs: self.name(lhs),
+                        rhs: self.name(rhs),
+                    };
+                    self.report(problem, expr);
+                }
+
+                self.boolean
+            }
+            OperatorEnum::Unary(ref id) => {
+                let unary = self
+                    .store
+                    .exhume_unary(id)
+                    .unwrap()
+                    .borrow()
+                    .subtype
+                    .clone();
+                let ty = self.expression(operator.lhs);
+
+                match unary {
+                    UnaryEnum::Negation(_) => {
+                        if !self.numeric_or_unknown(ty, ty) {
+                            let problem = Problem::Mismatch {
+                                expected: "a number".to_owned(),
+                                found: self.name(ty),
+                            };
+                            self.report(problem, operator.lhs);
+                        }
+                        ty
+                    }
+                    // On integers it's bitwise.
+                    UnaryEnum::Not(_) if ty == self.integer => self.integer,
+                    UnaryEnum::Not(_) => {
+                        self.expect(self.boolean, ty, operator.lhs);
+                        self.boolean
+                    }
+                }
+            }
+        }
+    }
+
+    /// The type of the result of an arithmetic, bitwise, or shift operator
+    fn arithmetic(&mut self, binary: &BinaryEnum, lhs: usize, rhs: usize, expr: usize) -> usize {
+        let ty = self.known(lhs, rhs);
+        let numeric = self.numeric_or_unknown(lhs, rhs);
+        // Bitwise operators apply to booleans too, as their logical
+        // counterparts without the short circuit.
+        let bitwise = ty == self.integer || ty == self.boolean || self.is_unknown(ty);
+        let integer = ty == self.integer || self.is_unknown(ty);
+        let (symbol, applies) = match binary {
+            BinaryEnum::Addition(_) => (
+                "+",
+                numeric || ty == self.string || self.element_of(ty).is_some(),
+            ),
+            BinaryEnum::BitwiseAnd(_) => ("&", bitwise),
+            BinaryEnum::BitwiseOr(_) => ("|", bitwise),
+            BinaryEnum::BitwiseXor(_) => ("^", bitwise),
+            BinaryEnum::Division(_) => ("/", numeric),
+            BinaryEnum::Multiplication(_) => ("*", numeric),
+            BinaryEnum::Remainder(_) => ("%", numeric),
+            BinaryEnum::ShiftLeft(_) => ("<<", integer),
+            BinaryEnum::ShiftRight(_) => (">>", integer),
+            _ => ("-", numeric),
+        };
+
+        if applies && self.compatible(lhs, rhs) {
+            ty
+        } else {
+            let problem = Problem::Operands {
+                operator: symbol.to_owned(),
+                lhs: self.name(lhs),
+                rhs: self.name(rhs),
+            };
+            self.report(problem, expr);
+            self.unknown
+        }
+    }
+
+    fn numeric_or_unknown(&self, lhs: usize, rhs: usize) -> bool {
+        let ty = self.known(lhs, rhs);
+        self.is_numeric(ty) || self.is_unknown(ty)
+    }
+}
+
+/// Intern one of the sarzak types
+///
+fn ty(ty: Uuid, store: &mut LuDogStore) -> usize {
+    let ty = store.inter_value_type(|id| {
+        Rc::new(RefCell::new(ValueType {
+            subtype: ValueTypeEnum::Ty(ty),
+            bogus: false,
+            id,
+        }))
+    });
+    let id = ty.borrow().id;
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::sample::{Sample, Stmt};
+
+    #[test]
+    fn test_inference() {
+        let mut s = Sample::new("inference");
+
+        // fn main() {
+        //     let x = 1;
+        //     let xs = [x, 2];
+        //     let y = xs[0] * 2;
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let two = s.int(2);
+        let zero = s.int(0);
+        let x = s.var("x");
+        let xs = s.list(vec![x, two.clone()]);
+        let xs_var = s.var("xs");
+        let index = s.index(&xs_var, &zero);
+        let y = s.binary("*", &index, &two);
+        s.span(&y, &main, 40, 49);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, one),
+                Stmt::Let("xs", None, xs),
+                Stmt::Let("y", None, y),
+            ],
+        );
+
+        let diagnostics = check(&mut s.store);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+        let store = &s.store;
+        let type_of = |name: &str| {
+            let var = store
+                .iter_variable()
+                .find(|var| var.borrow().name == name)
+                .unwrap();
+            let var = var.borrow().id;
+            let value = store
+                .iter_x_value()
+                .find(|value| value.borrow().subtype == XValueEnum::Variable(var))
+                .unwrap();
+            let ty = store.exhume_value_type(&value.borrow().ty).unwrap();
+            let ty = Printer::new(store).print_type(&ty.borrow());
+            ty
+        };
+        assert_eq!(type_of("x"), "int");
+        assert_eq!(type_of("xs"), "[int]");
+        assert_eq!(type_of("y"), "int");
+
+        let span = store.iter_span().next().unwrap();
+        let ty = store.exhume_value_type(&span.borrow().ty.unwrap()).unwrap();
+        assert_eq!(ty.borrow().subtype, ValueTypeEnum::Ty(INTEGER));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let mut s = Sample::new("diagnostics");
+
+        // fn add(a: int, b: int) -> int {
+        //     if a {
+        //         return "nope";
+        //     }
+        //     a + 1.5
+        // }
+        let int = s.int_ty();
+        let add = s.function("add", &[("a", int.clone()), ("b", int.clone())], &int, None);
+        let a = s.var("a");
+        let nope = s.string("nope");
+        let ret = s.ret(&nope);
+        let early = s.block(&add);
+        s.statements(&early, vec![Stmt::Expr(ret)]);
+        let x_if = s.if_else(&a, &early, None);
+        let half = s.float(1.5);
+        let sum = s.binary("+", &a, &half);
+        s.span(&sum, &add, 70, 77);
+        s.statements(&add, vec![Stmt::Expr(x_if), Stmt::Result(sum)]);
+
+        // fn main() {
+        //     add(1);
+        //     add(1, true);
+        //     for c in 5 {}
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let yes = s.boolean(true);
+        let five = s.int(5);
+        let short = s.call("add", vec![one.clone()]);
+        let wrong = s.call("add", vec![one, yes]);
+        let body = s.block(&main);
+        let for_loop = s.for_loop("c", &five, &body);
+        s.statements(
+            &main,
+            vec![Stmt::Expr(short), Stmt::Expr(wrong), Stmt::Expr(for_loop)],
+        );
+
+        let diagnostics = check(&mut s.store);
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.problem.clone())
+            .collect();
+        let mismatch = |expected: &str, found: &str| Problem::Mismatch {
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        };
+        assert_eq!(
+            problems,
+            vec![
+                mismatch("bool", "int"),
+                mismatch("int", "string"),
+                Problem::Operands {
+                    operator: "+".to_owned(),
+                    lhs: "int".to_owned(),
+                    rhs: "float".to_owned(),
+                },
+                Problem::Arity {
+                    name: "add".to_owned(),
+                    expected: 2,
+                    found: 1,
+                },
+                mismatch("int", "bool"),
+                Problem::NotIterable {
+                    found: "int".to_owned(),
+                },
+            ]
+        );
+
+        let span = diagnostics[2].span.as_ref().unwrap();
+        assert_eq!((span.start, span.end), (70, 77));
+        assert!(diagnostics[0].span.is_none());
+        assert_eq!(
+            diagnostics[2].to_string(),
+            "cannot apply `+` to int and float"
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut s = Sample::new("operators");
+
+        // fn main() {
+        //     let x = !(1 << 3) & 7;
+        //     let s = "a";
+        //     s += 1;
+        //     x %= 2.5;
+        //     let b = true ^ false;
+        //     b << 1;
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let three = s.int(3);
+        let seven = s.int(7);
+        let half = s.float(2.5);
+        let a = s.string("a");
+        let yes = s.boolean(true);
+        let no = s.boolean(false);
+        let shl = s.binary("<<", &one, &three);
+        let shl = s.group(&shl);
+        let not = s.unary("!", &shl);
+        let x = s.binary("&", &not, &seven);
+        let s_var = s.var("s");
+        let append = s.binary("+=", &s_var, &one);
+        let x_var = s.var("x");
+        let rem = s.binary("%=", &x_var, &half);
+        let b = s.binary("^", &yes, &no);
+        let b_var = s.var("b");
+        let shift = s.binary("<<", &b_var, &one);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, x),
+                Stmt::Let("s", None, a),
+                Stmt::Expr(append),
+                Stmt::Expr(rem),
+                Stmt::Let("b", None, b),
+                Stmt::Expr(shift),
+            ],
+        );
+
+        let diagnostics = check(&mut s.store);
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.problem.clone())
+            .collect();
+        let operands = |operator: &str, lhs: &str, rhs: &str| Problem::Operands {
+            operator: operator.to_owned(),
+            lhs: lhs.to_owned(),
+            rhs: rhs.to_owned(),
+        };
+        assert_eq!(
+            problems,
+            vec![
+                operands("+", "string", "int"),
+                operands("%", "int", "float"),
+                operands("<<", "bool", "int"),
+            ]
+        );
+
+        let type_of = |name: &str| {
+            let var = s
+                .store
+                .iter_variable()
+                .find(|var| var.borrow().name == name)
+                .unwrap();
+            let var = var.borrow().id;
+            let value = s
+                .store
+                .iter_x_value()
+                .find(|value| value.borrow().subtype == XValueEnum::Variable(var))
+                .unwrap();
+            let ty = value.borrow().ty;
+            ty
+        };
+        let (x, b) = (type_of("x"), type_of("b"));
+        assert_eq!(x, ty(INTEGER, &mut s.store));
+        assert_eq!(b, ty(BOOLEAN, &mut s.store));
+    }
+
+    #[test]
+    fn test_loops() {
+        let mut s = Sample::new("loops");
+
+        // fn main() {
+        //     let x = loop {
+        //         break 1;
+        //     };
+        //     let y = loop {
+        //         break 1;
+        //         break "no";
+        //     };
+        //     while 1 {}
+        //     break 'out;
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let no = s.string("no");
+        let first = s.x_break("", Some(&one));
+        let second = s.x_break("", Some(&no));
+        let body = s.block(&main);
+        s.statements(&body, vec![Stmt::Expr(first.clone())]);
+        let x = s.x_loop("", &body);
+        let body = s.block(&main);
+        s.statements(&body, vec![Stmt::Expr(first), Stmt::Expr(second)]);
+        let y = s.x_loop("", &body);
+        let body = s.block(&main);
+        let while_loop = s.while_loop("", &one, &body);
+        let stray = s.x_break("out", None);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, x),
+                Stmt::Let("y", None, y),
+                Stmt::Expr(while_loop),
+                Stmt::Expr(stray),
+            ],
+        );
+
+        let diagnostics = check(&mut s.store);
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.problem.clone())
+            .collect();
+        let mismatch = |expected: &str, found: &str| Problem::Mismatch {
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        };
+        assert_eq!(
+            problems,
+            vec![
+                mismatch("int", "string"),
+                mismatch("bool", "int"),
+                Problem::OutsideLoop {
+                    what: "break 'out".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(diagnostics[2].to_string(), "`break 'out` outside of a loop");
+
+        let x = s.store.iter_variable().find(|var| var.borrow().name == "x");
+        let x = x.unwrap().borrow().id;
+        let value = s
+            .store
+            .iter_x_value()
+            .find(|value| value.borrow().subtype == XValueEnum::Variable(x))
+            .unwrap();
+        assert_eq!(value.borrow().ty, ty(INTEGER, &mut s.store));
+    }
+}
@@ -0,0 +1,124 @@
+//! Diagnostics
+//!
+//! The problems that the passes over a program find, and where they found
+//! them.
+use std::fmt;
+
+use crate::dwarf::source;
+use crate::v2::lu_dog_vec::{ObjectStore as LuDogStore, Span};
+
+/// A problem found in a program
+///
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub problem: Problem,
+    /// The span of the offending expression
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// Render the diagnostic for people, with the source that it's about, if
+    /// there's a span
+    ///
+    pub fn render(&self, store: &LuDogStore) -> String {
+        match self.span {
+            Some(ref span) => format!("error: {}\n{}", self, source::snippet(span, store)),
+            None => format!("error: {}\n", self),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.problem.fmt(f)
+    }
+}
+
+/// What's wrong
+///
+/// Types are given as dwarf source.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    /// A call with the wrong number of arguments
+    Arity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    Mismatch {
+        expected: String,
+        found: String,
+    },
+    NotCallable {
+        found: String,
+    },
+    NotIterable {
+        found: String,
+    },
+    /// A `break` or `continue` with no loop to refer to
+    OutsideLoop {
+        what: String,
+    },
+    /// Operands that the operator doesn't apply to
+    Operands {
+        operator: String,
+        lhs: String,
+        rhs: String,
+    },
+    /// A name that could refer to more than one thing
+    Ambiguous {
+        name: String,
+        candidates: usize,
+    },
+    /// A name that doesn't refer to anything
+    Unresolved {
+        name: String,
+    },
+}
+
+/// A `break` or `continue`, as written, with its label if it has one
+///
+pub(crate) fn jump(keyword: &str, label: &str) -> String {
+    if label.is_empty() {
+        keyword.to_owned()
+    } else {
+        format!("{} '{}", keyword, label)
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arity {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{}` takes {} arguments, but {} were given",
+                name, expected, found
+            ),
+            Self::Mismatch { expected, found } => {
+                write!(
+                    f,
+                    "mismatched types: expected {}, found {}",
+                    expected, found
+                )
+            }
+            Self::NotCallable { found } => write!(f, "{} is not callable", found),
+            Self::NotIterable { found } => write!(f, "{} is not iterable", found),
+            Self::OutsideLoop { what } => write!(f, "`{}` outside of a loop", what),
+            Self::Operands { operator, lhs, rhs } => {
+                write!(f, "cannot apply `{}` to {} and {}", operator, lhs, rhs)
+            }
+            Self::Ambiguous { name, candidates } => {
+                write!(
+                    f,
+                    "`{}` is ambiguous: it could be any of {} things",
+                    name, candidates
+                )
+            }
+            Self::Unresolved { name } => write!(f, "cannot find `{}`", name),
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Semantic Index
+//!
+//! The queries that an editor asks of a program: what is at this offset in
+//! this file, where is the thing under the cursor defined, where else is it
+//! used, and what is it's type. They are answered from the [`Span`]s in a
+//! lu_dog store, together with the resolutions recorded by
+//! [`resolve`](crate::dwarf::resolve), which should be run first.
+//!
+//! Spans of expressions are uses of whatever the expression resolves to. Spans
+//! of variables, and spans of the types of functions, are where those are
+//! defined. The model doesn't give struct fields and enum variants spans of
+//! their own, so they can be found by their references, but they don't have a
+//! definition.
+//!
+//! When the items of a source file are replaced, resolve the program again and
+//! [`update`](Index::update) the index for that file.
+use std::collections::HashMap;
+
+use crate::dwarf::Printer;
+use crate::v2::lu_dog_vec::{
+    ObjectStore as LuDogStore, Resolution, Span, ValueTypeEnum, XValueEnum,
+};
+
+/// A span in a source file, and what's there
+///
+#[derive(Clone, Debug)]
+pub struct Occurrence {
+    pub span: Span,
+    /// The value that the span is of, if it's of one
+    pub value: Option<usize>,
+    /// What the name there refers to, if it's a name
+    pub symbol: Option<Resolution>,
+    /// Is this where the symbol is defined?
+    pub definition: bool,
+}
+
+/// What to show for the thing under the cursor
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hover {
+    pub span: Span,
+    /// The type, as dwarf source, if it's known
+    pub ty: Option<String>,
+    pub symbol: Option<Resolution>,
+}
+
+/// An index of the occurrences in each of the source files in a store
+///
+#[derive(Debug, Default)]
+pub struct Index {
+    /// Occurrences, by source file id
+    occurrences: HashMap<usize, Vec<Occurrence>>,
+    /// Where symbols are defined, as source file id and occurrence
+    definitions: HashMap<Resolution, (usize, usize)>,
+    /// Where symbols are used, as source file ids and occurrences
+    references: HashMap<Resolution, Vec<(usize, usize)>>,
+}
+
+impl Index {
+    /// Index every source file in the store
+    ///
+    pub fn new(store: &LuDogStore) -> Self {
+        let mut index = Self::default();
+        for span in store.iter_span() {
+            let span = span.borrow();
+            let occurrence = occurrence(&span, store);
+            index
+                .occurrences
+                .entry(span.source)
+                .or_default()
+                .push(occurrence);
+        }
+        index.link();
+
+        index
+    }
+
+    /// Index a source file again, after it's items have been replaced
+    ///
+    pub fn update(&mut self, store: &LuDogStore, source: usize) {
+        let occurrences = store
+            .iter_span()
+            .filter(|span| span.borrow().source == source)
+            .map(|span| occurrence(&span.borrow(), store))
+            .collect::<Vec<_>>();
+        if occurrences.is_empty() {
+            self.occurrences.remove(&source);
+        } else {
+            self.occurrences.insert(source, occurrences);
+        }
+        self.link();
+    }
+
+    /// Rebuild the definitions and references from the occurrences
+    fn link(&mut self) {
+        self.definitions.clear();
+        self.references.clear();
+
+        for (source, occurrences) in &mut self.occurrences {
+            occurrences.sort_by_key(|occurrence| (occurrence.span.start, occurrence.span.end));
+            for (n, occurrence) in occurrences.iter().enumerate() {
+                let symbol = match occurrence.symbol {
+                    Some(symbol) => symbol,
+                    None => continue,
+                };
+                if occurrence.definition {
+                    self.definitions.insert(symbol, (*source, n));
+                } else {
+                    self.references
+                        .entry(symbol)
+                        .or_default()
+                        .push((*source, n));
+                }
+            }
+        }
+        for references in self.references.values_mut() {
+            references.sort_unstable();
+        }
+    }
+
+    fn get(&self, (source, n): (usize, usize)) -> &Occurrence {
+        &self.occurrences[&source][n]
+    }
+
+    /// The innermost occurrence that covers a byte offset in a source file
+    ///
+    pub fn at(&self, source: usize, offset: usize) -> Option<&Occurrence> {
+        let offset = offset as i64;
+        self.occurrences
+            .get(&source)?
+            .iter()
+            .filter(|occurrence| {
+                let span = &occurrence.span;
+                span.start <= offset && (offset < span.end || span.start == span.end)
+            })
+            .min_by_key(|occurrence| occurrence.span.end - occurrence.span.start)
+    }
+
+    /// Where the symbol at a byte offset in a source file is defined
+    ///
+    pub fn definition_at(&self, source: usize, offset: usize) -> Option<&Span> {
+        self.definition(self.at(source, offset)?.symbol?)
+    }
+
+    /// Where a symbol is defined
+    ///
+    pub fn definition(&self, symbol: Resolution) -> Option<&Span> {
+        let definition = self.definitions.get(&symbol)?;
+        Some(&self.get(*definition).span)
+    }
+
+    /// Everywhere that the symbol at a byte offset in a source file is used
+    ///
+    pub fn references_at(&self, source: usize, offset: usize) -> Vec<&Span> {
+        match self
+            .at(source, offset)
+            .and_then(|occurrence| occurrence.symbol)
+        {
+            Some(symbol) => self.references(symbol),
+            None => Vec::new(),
+        }
+    }
+
+    /// Everywhere that a symbol is used, in order of file and offset
+    ///
+    pub fn references(&self, symbol: Resolution) -> Vec<&Span> {
+        self.references
+            .get(&symbol)
+            .map(|references| {
+                references
+                    .iter()
+                    .map(|reference| &self.get(*reference).span)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The type and symbol of the occurrence at a byte offset in a source file
+    ///
+    pub fn hover(&self, store: &LuDogStore, source: usize, offset: usize) -> Option<Hover> {
+        let occurrence = self.at(source, offset)?;
+        let value = occurrence
+            .value
+            .and_then(|value| store.exhume_x_value(&value))
+            .map(|value| value.borrow().ty);
+        let ty = value
+            .into_iter()
+            .chain(occurrence.span.ty)
+            .map(|ty| store.exhume_value_type(&ty).unwrap())
+            .find(|ty| !matches!(ty.borrow().subtype, ValueTypeEnum::Unknown(_)))
+            .map(|ty| Printer::new(store).print_type(&ty.borrow()));
+
+        Some(Hover {
+            span: occurrence.span.clone(),
+            ty,
+            symbol: occurrence.symbol,
+        })
+    }
+}
+
+/// What's at a span
+fn occurrence(span: &Span, store: &LuDogStore) -> Occurrence {
+    let value = span
+        .x_value
+        .map(|value| store.exhume_x_value(&value).unwrap());
+    let (symbol, definition) = match value {
+        Some(ref value) => {
+            let value = value.borrow();
+            match value.subtype {
+                XValueEnum::Expression(expr) => {
+                    (store.exhume_resolution(&value.block, &expr), false)
+                }
+                XValueEnum::Variable(var) => (Some(Resolution::Variable(var)), true),
+            }
+        }
+        None => match span.ty.map(|ty| store.exhume_value_type(&ty).unwrap()) {
+            Some(ty) => match ty.borrow().subtype {
+                ValueTypeEnum::Function(func) => (Some(Resolution::Function(func)), true),
+                _ => (None, false),
+            },
+            None => (None, false),
+        },
+    };
+
+    Occurrence {
+        span: span.clone(),
+        value: value.map(|value| value.borrow().id),
+        symbol,
+        definition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::sample::{Sample, Stmt};
+    use crate::dwarf::{check, resolve};
+    use crate::v2::lu_dog_vec::ValueType;
+
+    #[test]
+    fn test_queries() {
+        let mut s = Sample::new("index");
+        let text = "fn main() {\n    let x = 1;\n    x + x\n}\n";
+        let source = s.source();
+        source.borrow_mut().set_source(text.to_owned());
+        let at = |needle: &str, nth: usize| text.match_indices(needle).nth(nth).unwrap().0 as i64;
+
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let x = s.var("x");
+        let sum = s.binary("+", &x, &x);
+        s.statements(
+            &main,
+            vec![Stmt::Let("x", None, one), Stmt::Result(sum.clone())],
+        );
+
+        // fn main
+        let func = s.store.iter_function().next().unwrap();
+        let func_ty = ValueType::new_function(false, &func, &mut s.store);
+        let start = at("main", 0);
+        Span::new(
+            start + 4,
+            start,
+            &source,
+            Some(&func_ty),
+            None,
+            &mut s.store,
+        );
+        // let x
+        let var = s.store.iter_variable().next().unwrap().borrow().id;
+        let value = s
+            .store
+            .iter_x_value()
+            .find(|value| value.borrow().subtype == XValueEnum::Variable(var))
+            .unwrap();
+        let start = at("x", 0);
+        Span::new(start + 1, start, &source, None, Some(&value), &mut s.store);
+        // x + x
+        for nth in 1..3 {
+            let start = at("x", nth);
+            s.span(&x, &main, start, start + 1);
+        }
+        let start = at("x", 1);
+        s.span(&sum, &main, start, start + 5);
+
+        resolve(&mut s.store);
+        check(&mut s.store);
+        let index = Index::new(&s.store);
+        let source = source.borrow().id;
+        let second = at("x", 2) as usize;
+
+        let occurrence = index.at(source, second).unwrap();
+        assert_eq!(occurrence.symbol, Some(Resolution::Variable(var)));
+        assert!(!occurrence.definition);
+
+        let definition = index.definition_at(source, second).unwrap();
+        assert_eq!(definition.start, at("x", 0));
+
+        let references: Vec<_> = index
+            .references_at(source, second)
+            .iter()
+            .map(|span| span.start)
+            .collect();
+        assert_eq!(references, vec![at("x", 1), at("x", 2)]);
+
+        let func = Resolution::Function(func.borrow().id);
+        assert_eq!(index.definition(func).unwrap().start, at("main", 0));
+        assert!(index.references(func).is_empty());
+
+        let hover = index.hover(&s.store, source, second).unwrap();
+        assert_eq!(hover.ty.as_deref(), Some("int"));
+        // Between the operands is the sum.
+        let hover = index.hover(&s.store, source, second - 2).unwrap();
+        assert_eq!(hover.symbol, None);
+        assert_eq!(hover.ty.as_deref(), Some("int"));
+        assert!(index.at(source, 0).is_none());
+    }
+
+    #[test]
+    fn test_update() {
+        let mut s = Sample::new("update");
+        let source = s.source();
+        source.borrow_mut().set_source("fn main() {}\n".to_owned());
+        let empty = s.empty_ty();
+        s.function("main", &[], &empty, None);
+
+        let mut index = Index::new(&s.store);
+        let id = source.borrow().id;
+        assert!(index.at(id, 3).is_none());
+
+        let func = s.store.iter_function().next().unwrap();
+        let func_ty = ValueType::new_function(false, &func, &mut s.store);
+        Span::new(7, 3, &source, Some(&func_ty), None, &mut s.store);
+        index.update(&s.store, id);
+
+        let occurrence = index.at(id, 3).unwrap();
+        assert_eq!(
+            occurrence.symbol,
+            Some(Resolution::Function(func.borrow().id))
+        );
+        assert!(occurrence.definition);
+    }
+}
@@ -0,0 +1,2467 @@
+//! Dwarf Interpreter
+//!
+//! A tree-walking interpreter that runs dwarf programs straight out of a
+//! lu_dog store. It's not fast, and it isn't meant to be. It's the reference
+//! semantics for the language, against which the output of the model compilers
+//! may be checked.
+//!
+//! Scopes follow the blocks. A block gets a new scope when it's entered, and the
+//! parent of that scope is the scope of the block that encloses it in the
+//! source, per `Block.parent`. Functions see their parameters and the items in
+//! the program, and nothing else. Lambdas capture the scope in which they are
+//! created.
+//!
+//! When something goes wrong the result is a [`RuntimeError`], which carries
+//! the [`Span`] of the expression that failed, if the store has one for it.
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+use snafu::prelude::*;
+use uuid::Uuid;
+
+use crate::dwarf::{diagnostic::jump, printer::compound, Printer};
+use crate::v2::{
+    lu_dog_vec::{
+        BinaryEnum, BodyEnum, BooleanLiteralEnum, BooleanOperatorEnum, CallEnum, ComparisonEnum,
+        DataStructureEnum, ExpressionEnum, FieldAccessTargetEnum, FieldExpressionEnum,
+        FormatBitEnum, LiteralEnum, ObjectStore as LuDogStore, OperatorEnum, RangeExpressionEnum,
+        Span, StatementEnum, UnaryEnum, ValueTypeEnum, VariableEnum, XValueEnum,
+    },
+    sarzak::types::{BOOLEAN, FLOAT, INTEGER, Z_STRING},
+};
+
+/// How deeply calls may nest before we call it a stack overflow
+///
+const MAX_DEPTH: usize = 256;
+
+/// Something that went wrong while running a program
+///
+/// The span is that of the expression being evaluated when the error occurred.
+/// It's `None` if the store has no span for the expression, or if the error
+/// didn't come from an expression, e.g. calling a function that doesn't exist.
+#[derive(Debug, Snafu)]
+pub enum RuntimeError {
+    #[snafu(display("wrong number of arguments to `{name}`: expected {expected}, found {found}"))]
+    Arity {
+        name: String,
+        expected: usize,
+        found: usize,
+        span: Option<Span>,
+    },
+    #[snafu(display("division by zero"))]
+    DivisionByZero { span: Option<Span> },
+    #[snafu(display("halted: {message}"))]
+    Halted { message: String, span: Option<Span> },
+    #[snafu(display("index {index} is out of bounds for length {len}"))]
+    IndexOutOfBounds {
+        index: i64,
+        len: usize,
+        span: Option<Span>,
+    },
+    #[snafu(display("cannot cast {from} to {to}"))]
+    InvalidCast {
+        from: String,
+        to: String,
+        span: Option<Span>,
+    },
+    #[snafu(display("key {key} is not in the map"))]
+    MissingKey { key: String, span: Option<Span> },
+    #[snafu(display("no pattern matched {value}"))]
+    NoMatch { value: String, span: Option<Span> },
+    #[snafu(display("{ty} is not callable"))]
+    NotCallable { ty: String, span: Option<Span> },
+    #[snafu(display("`{what}` outside of a loop"))]
+    OutsideLoop { what: String, span: Option<Span> },
+    #[snafu(display("error writing output: {source}"))]
+    Output { source: io::Error },
+    #[snafu(display("integer overflow"))]
+    Overflow { span: Option<Span> },
+    #[snafu(display("stack overflow"))]
+    StackOverflow { span: Option<Span> },
+    #[snafu(display("expected {expected}, found {found}"))]
+    TypeMismatch {
+        expected: String,
+        found: String,
+        span: Option<Span>,
+    },
+    #[snafu(display("no field `{name}` on {ty}"))]
+    UnknownField {
+        name: String,
+        ty: String,
+        span: Option<Span>,
+    },
+    #[snafu(display("unknown function `{name}`"))]
+    UnknownFunction { name: String, span: Option<Span> },
+    #[snafu(display("no method `{name}` on {ty}"))]
+    UnknownMethod {
+        name: String,
+        ty: String,
+        span: Option<Span>,
+    },
+    #[snafu(display("unknown variable `{name}`"))]
+    UnknownVariable { name: String, span: Option<Span> },
+    #[snafu(display("{what} is not supported"))]
+    Unsupported { what: String, span: Option<Span> },
+}
+
+impl RuntimeError {
+    /// The span of the expression that failed
+    ///
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::Arity { span, .. }
+            | Self::DivisionByZero { span }
+            | Self::Halted { span, .. }
+            | Self::IndexOutOfBounds { span, .. }
+            | Self::InvalidCast { span, .. }
+            | Self::MissingKey { span, .. }
+            | Self::NoMatch { span, .. }
+            | Self::NotCallable { span, .. }
+            | Self::OutsideLoop { span, .. }
+            | Self::Overflow { span }
+            | Self::StackOverflow { span }
+            | Self::TypeMismatch { span, .. }
+            | Self::UnknownField { span, .. }
+            | Self::UnknownFunction { span, .. }
+            | Self::UnknownMethod { span, .. }
+            | Self::UnknownVariable { span, .. }
+            | Self::Unsupported { span, .. } => span.as_ref(),
+            Self::Output { .. } => None,
+        }
+    }
+}
+
+/// A runtime value
+///
+/// These mirror `ValueTypeEnum`. The primitive types, that are a `Ty` there,
+/// each get their own variant here.
+///
+/// Lists, maps and structs are shared, rather than copied, when they are
+/// assigned or passed to a function.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Boolean(bool),
+    Char(char),
+    Empty,
+    Enumeration {
+        ty: String,
+        variant: String,
+        fields: Vec<Value>,
+    },
+    Float(f64),
+    /// A function, by it's id in the store
+    Function(usize),
+    Integer(i64),
+    Lambda(Closure),
+    List(Rc<RefCell<Vec<Value>>>),
+    /// A map, which keeps it's entries in insertion order
+    Map(Rc<RefCell<Vec<(Value, Value)>>>),
+    /// A half-open range, `start..end`, with either end possibly missing
+    Range {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+    String(String),
+    Struct(Rc<RefCell<Struct>>),
+    Uuid(Uuid),
+}
+
+/// The value of a struct
+///
+/// The fields are in the order in which they are declared.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Struct {
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.fields
+            .iter_mut()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// A lambda, and the scope that it captured
+///
+#[derive(Clone)]
+pub struct Closure {
+    lambda: usize,
+    scope: Rc<RefCell<Scope>>,
+}
+
+impl Closure {
+    /// The id of the lambda in the store
+    ///
+    pub fn lambda(&self) -> usize {
+        self.lambda
+    }
+}
+
+// The captured scope is elided, since it very likely contains the closure.
+impl fmt::Debug for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Closure")
+            .field("lambda", &self.lambda)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Value {
+    /// The name of the value's type, for error messages
+    ///
+    pub fn type_name(&self) -> String {
+        match self {
+            Self::Boolean(_) => "bool".to_owned(),
+            Self::Char(_) => "char".to_owned(),
+            Self::Empty => "()".to_owned(),
+            Self::Enumeration { ty, .. } => format!("enum `{}`", ty),
+            Self::Float(_) => "float".to_owned(),
+            Self::Function(_) => "function".to_owned(),
+            Self::Integer(_) => "int".to_owned(),
+            Self::Lambda(_) => "lambda".to_owned(),
+            Self::List(_) => "list".to_owned(),
+            Self::Map(_) => "map".to_owned(),
+            Self::Range { .. } => "range".to_owned(),
+            Self::String(_) => "string".to_owned(),
+            Self::Struct(value) => format!("struct `{}`", value.borrow().name),
+            Self::Uuid(_) => "Uuid".to_owned(),
+        }
+    }
+
+    /// Strings are quoted when they are inside of another value.
+    fn nested(&self) -> String {
+        match self {
+            Self::String(s) => format!("{:?}", s),
+            Self::Char(c) => format!("{:?}", c),
+            value => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(b) => write!(f, "{}", b),
+            Self::Char(c) => write!(f, "{}", c),
+            Self::Empty => write!(f, "()"),
+            Self::Enumeration {
+                ty,
+                variant,
+                fields,
+            } => {
+                write!(f, "{}::{}", ty, variant)?;
+                if !fields.is_empty() {
+                    let fields: Vec<_> = fields.iter().map(Value::nested).collect();
+                    write!(f, "({})", fields.join(", "))?;
+                }
+                Ok(())
+            }
+            Self::Float(x) => write!(f, "{}", x),
+            Self::Function(_) => write!(f, "<function>"),
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Lambda(_) => write!(f, "<lambda>"),
+            Self::List(list) => {
+                let elements: Vec<_> = list.borrow().iter().map(Value::nested).collect();
+                write!(f, "[{}]", elements.join(", "))
+            }
+            Self::Map(map) => {
+                let entries: Vec<_> = map
+                    .borrow()
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key.nested(), value.nested()))
+                    .collect();
+                write!(f, "{{{}}}", entries.join(", "))
+            }
+            Self::Range { start, end } => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, "..")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                Ok(())
+            }
+            Self::String(s) => write!(f, "{}", s),
+            Self::Struct(value) => {
+                let value = value.borrow();
+                let fields: Vec<_> = value
+                    .fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value.nested()))
+                    .collect();
+                write!(f, "{} {{ {} }}", value.name, fields.join(", "))
+            }
+            Self::Uuid(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Boolean(a), Self::Boolean(b)) => a == b,
+            (Self::Char(a), Self::Char(b)) => a == b,
+            (Self::Empty, Self::Empty) => true,
+            (
+                Self::Enumeration {
+                    ty: a_ty,
+                    variant: a_variant,
+                    fields: a_fields,
+                },
+                Self::Enumeration {
+                    ty: b_ty,
+                    variant: b_variant,
+                    fields: b_fields,
+                },
+            ) => a_ty == b_ty && a_variant == b_variant && a_fields == b_fields,
+            (Self::Float(a), Self::Float(b)) => a == b,
+            (Self::Function(a), Self::Function(b)) => a == b,
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Lambda(a), Self::Lambda(b)) => {
+                a.lambda == b.lambda && Rc::ptr_eq(&a.scope, &b.scope)
+            }
+            (Self::List(a), Self::List(b)) => *a.borrow() == *b.borrow(),
+            (Self::Map(a), Self::Map(b)) => *a.borrow() == *b.borrow(),
+            (
+                Self::Range {
+                    start: a_start,
+                    end: a_end,
+                },
+                Self::Range {
+                    start: b_start,
+                    end: b_end,
+                },
+            ) => a_start == b_start && a_end == b_end,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Struct(a), Self::Struct(b)) => *a.borrow() == *b.borrow(),
+            (Self::Uuid(a), Self::Uuid(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Char(a), Self::Char(b)) => a.partial_cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.partial_cmp(b),
+            (Self::String(a), Self::String(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+/// The variables that are visible in a block
+///
+#[derive(Default)]
+struct Scope {
+    block: Option<usize>,
+    vars: HashMap<String, Value>,
+    parent: Option<Rc<RefCell<Scope>>>,
+}
+
+impl Scope {
+    fn new(block: Option<usize>, parent: Option<&Rc<RefCell<Scope>>>) -> Rc<RefCell<Scope>> {
+        Rc::new(RefCell::new(Scope {
+            block,
+            vars: HashMap::new(),
+            parent: parent.cloned(),
+        }))
+    }
+
+    /// A scope for bindings that aren't introduced by a block, like the
+    /// variable of a for loop. It stands in for the block that it's in.
+    fn nested(parent: &Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
+        let block = parent.borrow().block;
+        Scope::new(block, Some(parent))
+    }
+
+    /// Find the innermost scope that belongs to `block`
+    fn find(scope: &Rc<RefCell<Scope>>, block: usize) -> Option<Rc<RefCell<Scope>>> {
+        let mut scope = Some(scope.clone());
+        while let Some(this) = scope {
+            if this.borrow().block == Some(block) {
+                return Some(this);
+            }
+            scope = this.borrow().parent.clone();
+        }
+        None
+    }
+
+    fn get(&self, name: &str) -> Option<Value> {
+        match self.vars.get(name) {
+            Some(value) => Some(value.clone()),
+            None => self.parent.as_ref()?.borrow().get(name),
+        }
+    }
+
+    fn define(&mut self, name: &str, value: Value) {
+        self.vars.insert(name.to_owned(), value);
+    }
+
+    /// Assign to an existing variable, returning false if there isn't one
+    fn assign(&mut self, name: &str, value: Value) -> bool {
+        match self.vars.get_mut(name) {
+            Some(var) => {
+                *var = value;
+                true
+            }
+            None => match self.parent {
+                Some(ref parent) => parent.borrow_mut().assign(name, value),
+                None => false,
+            },
+        }
+    }
+}
+
+/// Why evaluation stopped before reaching the end of an expression
+///
+enum Unwind {
+    Error(RuntimeError),
+    Return(Value),
+    /// A `break`, on its way to the loop with the label, or the innermost
+    /// loop if the label is empty
+    Break {
+        label: String,
+        value: Value,
+        span: Option<Span>,
+    },
+    /// A `continue`, likewise
+    Continue {
+        label: String,
+        span: Option<Span>,
+    },
+}
+
+impl From<RuntimeError> for Unwind {
+    fn from(error: RuntimeError) -> Self {
+        Unwind::Error(error)
+    }
+}
+
+type Eval<T = Value> = Result<T, Unwind>;
+
+/// Run dwarf programs from a lu_dog store
+///
+/// `print` writes to stdout, unless the interpreter is created with some other
+/// output.
+///
+/// ```
+/// # use sarzak::dwarf::interpreter::{Interpreter, RuntimeError};
+/// # use sarzak::v2::lu_dog_vec::ObjectStore;
+/// let store = ObjectStore::new();
+/// let mut output = Vec::new();
+/// let mut interpreter = Interpreter::with_output(&store, &mut output);
+///
+/// assert!(matches!(
+///     interpreter.run(),
+///     Err(RuntimeError::UnknownFunction { .. })
+/// ));
+/// ```
+pub struct Interpreter<'a> {
+    store: &'a LuDogStore,
+    out: Box<dyn Write + 'a>,
+    /// Spans, by expression id
+    spans: HashMap<usize, Span>,
+    /// Free functions, by name
+    functions: HashMap<String, usize>,
+    /// Functions in implementation blocks, by type and function name
+    methods: HashMap<(String, String), usize>,
+    /// Variable names, by the id of the parameter, lambda parameter, or local
+    /// variable
+    params: HashMap<usize, String>,
+    lambda_params: HashMap<usize, String>,
+    locals: HashMap<usize, String>,
+    scope: Rc<RefCell<Scope>>,
+    depth: usize,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(store: &'a LuDogStore) -> Self {
+        Self::with_output(store, io::stdout())
+    }
+
+    pub fn with_output(store: &'a LuDogStore, out: impl Write + 'a) -> Self {
+        let spans = store
+            .iter_span()
+            .filter_map(|span| {
+                let span = span.borrow();
+                let value = store.exhume_x_value(&span.x_value?)?;
+                let value = value.borrow();
+                match value.subtype {
+                    XValueEnum::Expression(expr) => Some((expr, span.clone())),
+                    XValueEnum::Variable(_) => None,
+                }
+            })
+            .collect();
+
+        let mut functions = HashMap::new();
+        let mut methods = HashMap::new();
+        for func in store.iter_function() {
+            let func = func.borrow();
+            match func.impl_block {
+                Some(ref id) => {
+                    let block = store.exhume_implementation_block(id).unwrap();
+                    let block = block.borrow();
+                    let ty = match (block.model_type, block.enumeration) {
+                        (Some(ref id), _) => {
+                            store.exhume_woog_struct(id).unwrap().borrow().name.clone()
+                        }
+                        (None, Some(ref id)) => {
+                            store.exhume_enumeration(id).unwrap().borrow().name.clone()
+                        }
+                        // Object store impls are backed by external code.
+                        (None, None) => continue,
+                    };
+                    methods.insert((ty, func.name.clone()), func.id);
+                }
+                None => {
+                    functions.insert(func.name.clone(), func.id);
+                }
+            }
+        }
+
+        let mut params = HashMap::new();
+        let mut lambda_params = HashMap::new();
+        let mut locals = HashMap::new();
+        for var in store.iter_variable() {
+            let var = var.borrow();
+            let (names, id) = match var.subtype {
+                VariableEnum::LambdaParameter(id) => (&mut lambda_params, id),
+                VariableEnum::LocalVariable(id) => (&mut locals, id),
+                VariableEnum::Parameter(id) => (&mut params, id),
+            };
+            names.insert(id, var.name.clone());
+        }
+
+        Self {
+            store,
+            out: Box::new(out),
+            spans,
+            functions,
+            methods,
+            params,
+            lambda_params,
+            locals,
+            scope: Scope::new(None, None),
+            depth: 0,
+        }
+    }
+
+    /// Run the program, by calling `main`
+    ///
+    pub fn run(&mut self) -> Result<Value, RuntimeError> {
+        self.call("main", Vec::new())
+    }
+
+    /// Call a function, by name
+    ///
+    pub fn call(&mut self, name: &str, args: Vec<Value>) -> Result<Value, RuntimeError> {
+        let func = *self
+            .functions
+            .get(name)
+            .context(UnknownFunctionSnafu { name, span: None })?;
+
+        finish(self.call_function(func, args, None))
+    }
+
+    /// Evaluate an expression, outside of any function
+    ///
+    /// The expression may refer to functions in the program, but there are no
+    /// variables in scope.
+    pub fn evaluate(&mut self, expr: usize) -> Result<Value, RuntimeError> {
+        finish(self.eval(expr))
+    }
+
+    fn span(&self, expr: Option<usize>) -> Option<Span> {
+        self.spans.get(&expr?).cloned()
+    }
+
+    fn mismatch<T>(&self, expected: &str, found: &Value, expr: usize) -> Eval<T> {
+        Err(RuntimeError::TypeMismatch {
+            expected: expected.to_owned(),
+            found: found.type_name(),
+            span: self.span(Some(expr)),
+        }
+        .into())
+    }
+
+    fn unsupported<T>(&self, what: &str, expr: usize) -> Eval<T> {
+        Err(RuntimeError::Unsupported {
+            what: what.to_owned(),
+            span: self.span(Some(expr)),
+        }
+        .into())
+    }
+
+    /// Run `f` in `scope`, restoring the current scope afterwards
+    fn in_scope<T>(&mut self, scope: Rc<RefCell<Scope>>, f: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = std::mem::replace(&mut self.scope, scope);
+        let result = f(self);
+        self.scope = outer;
+
+        result
+    }
+
+    //
+    // Blocks and functions
+    //
+
+    fn block(&mut self, id: usize) -> Eval {
+        let parent = self.store.exhume_block(&id).unwrap().borrow().parent;
+        let outer = parent
+            .and_then(|parent| Scope::find(&self.scope, parent))
+            .unwrap_or_else(|| self.scope.clone());
+
+        self.in_scope(Scope::new(Some(id), Some(&outer)), |this| {
+            this.statements(id)
+        })
+    }
+
+    /// Run the statements of a block, in the current scope
+    fn statements(&mut self, block: usize) -> Eval {
+        let mut value = Value::Empty;
+        let mut next = self.store.exhume_block(&block).unwrap().borrow().statement;
+        while let Some(id) = next {
+            let statement = self.store.exhume_statement(&id).unwrap();
+            let statement = statement.borrow();
+            next = statement.next;
+
+            value = match statement.subtype {
+                StatementEnum::ExpressionStatement(ref id) => {
+                    let stmt = self.store.exhume_expression_statement(id).unwrap();
+                    let expr = stmt.borrow().expression;
+                    let value = self.eval(expr)?;
+                    // A block-like expression at the end of a block is it's
+                    // value, semicolon or no.
+                    if next.is_none() && self.is_block_like(expr) {
+                        value
+                    } else {
+                        Value::Empty
+                    }
+                }
+                StatementEnum::ItemStatement(_) => Value::Empty,
+                StatementEnum::LetStatement(ref id) => {
+                    let stmt = self.store.exhume_let_statement(id).unwrap();
+                    let stmt = stmt.borrow();
+                    let value = self.eval(stmt.expression)?;
+                    let name = &self.locals[&stmt.variable];
+                    self.scope.borrow_mut().define(name, value);
+                    Value::Empty
+                }
+                StatementEnum::ResultStatement(ref id) => {
+                    let stmt = self.store.exhume_result_statement(id).unwrap();
+                    let expr = stmt.borrow().expression;
+                    self.eval(expr)?
+                }
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn is_block_like(&self, expr: usize) -> bool {
+        matches!(
+            self.store
+                .exhume_expression(&expr)
+                .unwrap()
+                .borrow()
+                .subtype,
+            ExpressionEnum::Block(_) | ExpressionEnum::XIf(_) | ExpressionEnum::XMatch(_)
+        )
+    }
+
+    fn call_function(&mut self, id: usize, args: Vec<Value>, expr: Option<usize>) -> Eval {
+        let func = self.store.exhume_function(&id).unwrap();
+        let func = func.borrow();
+
+        let mut params = Vec::new();
+        let mut next = func.first_param;
+        while let Some(id) = next {
+            next = self.store.exhume_parameter(&id).unwrap().borrow().next;
+            params.push(self.params[&id].clone());
+        }
+
+        let body = self.store.exhume_body(&func.body).unwrap();
+        let block = match body.borrow().subtype {
+            BodyEnum::Block(block) => block,
+            BodyEnum::ExternalImplementation(_) => {
+                return Err(RuntimeError::Unsupported {
+                    what: format!("calling external function `{}`", func.name),
+                    span: self.span(expr),
+                }
+                .into())
+            }
+        };
+
+        self.invoke(&func.name, params, args, block, None, expr)
+    }
+
+    fn call_lambda(&mut self, closure: &Closure, args: Vec<Value>, expr: usize) -> Eval {
+        let lambda = self.store.exhume_lambda(&closure.lambda).unwrap();
+        let lambda = lambda.borrow();
+
+        let mut params = Vec::new();
+        let mut next = lambda.first_param;
+        while let Some(id) = next {
+            next = self
+                .store
+                .exhume_lambda_parameter(&id)
+                .unwrap()
+                .borrow()
+                .next;
+            params.push(self.lambda_params[&id].clone());
+        }
+
+        let block = match lambda.body {
+            Some(ref body) => match self.store.exhume_body(body).unwrap().borrow().subtype {
+                BodyEnum::Block(block) => block,
+                BodyEnum::ExternalImplementation(_) => {
+                    return self.unsupported("an external lambda body", expr)
+                }
+            },
+            None => return Ok(Value::Empty),
+        };
+
+        self.invoke(
+            "<lambda>",
+            params,
+            args,
+            block,
+            Some(&closure.scope),
+            Some(expr),
+        )
+    }
+
+    /// Bind the arguments to the parameters, and run the body
+    fn invoke(
+        &mut self,
+        name: &str,
+        params: Vec<String>,
+        args: Vec<Value>,
+        block: usize,
+        captured: Option<&Rc<RefCell<Scope>>>,
+        expr: Option<usize>,
+    ) -> Eval {
+        ensure!(
+            params.len() == args.len(),
+            AritySnafu {
+                name,
+                expected: params.len(),
+                found: args.len(),
+                span: self.span(expr),
+            }
+        );
+        ensure!(
+            self.depth < MAX_DEPTH,
+            StackOverflowSnafu {
+                span: self.span(expr)
+            }
+        );
+
+        let scope = Scope::new(Some(block), captured);
+        for (param, arg) in params.iter().zip(args) {
+            scope.borrow_mut().define(param, arg);
+        }
+
+        self.depth += 1;
+        let result = self.in_scope(scope, |this| this.statements(block));
+        self.depth -= 1;
+
+        finish(result).map_err(Unwind::from)
+    }
+
+    //
+    // Expressions
+    //
+
+    fn eval(&mut self, id: usize) -> Eval {
+        let expr = self.store.exhume_expression(&id).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::AWait(_) => self.unsupported("await", id),
+            ExpressionEnum::Block(ref block) => self.block(*block),
+            ExpressionEnum::XBreak(ref x_break) => {
+                let (label, expr) = {
+                    let x_break = self.store.exhume_x_break(x_break).unwrap();
+                    let x_break = x_break.borrow();
+                    (x_break.label.clone(), x_break.expression)
+                };
+                let value = match expr {
+                    Some(expr) => self.eval(expr)?,
+                    None => Value::Empty,
+                };
+                Err(Unwind::Break {
+                    label,
+                    value,
+                    span: self.span(Some(id)),
+                })
+            }
+            ExpressionEnum::Call(ref call) => self.call_expression(*call, id),
+            ExpressionEnum::XContinue(ref x_continue) => {
+                let label = self
+                    .store
+                    .exhume_x_continue(x_continue)
+                    .unwrap()
+                    .borrow()
+                    .label
+                    .clone();
+                Err(Unwind::Continue {
+                    label,
+                    span: self.span(Some(id)),
+                })
+            }
+            ExpressionEnum::XDebugger(_) | ExpressionEnum::EmptyExpression(_) => Ok(Value::Empty),
+            ExpressionEnum::FieldAccess(ref access) => self.field_access(*access, id),
+            ExpressionEnum::FieldExpression(ref field) => {
+                let expr = self
+                    .store
+                    .exhume_field_expression(field)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.eval(expr)
+            }
+            ExpressionEnum::ForLoop(ref for_loop) => self.for_loop(*for_loop, id),
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.eval(expr)
+            }
+            ExpressionEnum::HaltAndCatchFire(ref hcf) => {
+                let expr = self
+                    .store
+                    .exhume_halt_and_catch_fire(hcf)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                let message = self.eval(expr)?.to_string();
+                Err(RuntimeError::Halted {
+                    message,
+                    span: self.span(Some(id)),
+                }
+                .into())
+            }
+            ExpressionEnum::XIf(ref x_if) => self.x_if(*x_if, id),
+            ExpressionEnum::Index(ref index) => self.index(*index, id),
+            ExpressionEnum::Lambda(ref lambda) => Ok(Value::Lambda(Closure {
+                lambda: *lambda,
+                scope: self.scope.clone(),
+            })),
+            ExpressionEnum::ListElement(ref element) => {
+                let expr = self
+                    .store
+                    .exhume_list_element(element)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.eval(expr)
+            }
+            ExpressionEnum::ListExpression(ref list) => {
+                let mut elements = Vec::new();
+                let mut next = self
+                    .store
+                    .exhume_list_expression(list)
+                    .unwrap()
+                    .borrow()
+                    .elements;
+                while let Some(id) = next {
+                    let element = self.store.exhume_list_element(&id).unwrap();
+                    let element = element.borrow();
+                    next = element.next;
+                    elements.push(self.eval(element.expression)?);
+                }
+                Ok(Value::List(Rc::new(RefCell::new(elements))))
+            }
+            ExpressionEnum::Literal(ref literal) => self.literal(*literal),
+            ExpressionEnum::XLoop(ref x_loop) => self.x_loop(*x_loop),
+            ExpressionEnum::XMatch(ref x_match) => self.x_match(*x_match, id),
+            ExpressionEnum::Operator(ref operator) => self.operator(*operator, id),
+            ExpressionEnum::XPath(ref path) => self.path(*path, id),
+            ExpressionEnum::XPrint(ref print) => {
+                let expr = self
+                    .store
+                    .exhume_x_print(print)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                let value = self.eval(expr)?;
+                write!(self.out, "{}", value).context(OutputSnafu)?;
+                Ok(Value::Empty)
+            }
+            ExpressionEnum::RangeExpression(ref range) => self.range(*range, id),
+            ExpressionEnum::XReturn(ref x_return) => {
+                let expr = self
+                    .store
+                    .exhume_x_return(x_return)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                let value = self.eval(expr)?;
+                Err(Unwind::Return(value))
+            }
+            ExpressionEnum::StructExpression(ref expr) => self.struct_expression(*expr, id),
+            ExpressionEnum::TypeCast(ref cast) => self.type_cast(*cast, id),
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                self.lookup(&name, id)
+            }
+            ExpressionEnum::XWhile(ref x_while) => self.x_while(*x_while, id),
+        }
+    }
+
+    /// Look up a name, as a variable and then as a function
+    fn lookup(&self, name: &str, expr: usize) -> Eval {
+        if let Some(value) = self.scope.borrow().get(name) {
+            return Ok(value);
+        }
+        match self.functions.get(name) {
+            Some(func) => Ok(Value::Function(*func)),
+            None => Err(RuntimeError::UnknownVariable {
+                name: name.to_owned(),
+                span: self.span(Some(expr)),
+            }
+            .into()),
+        }
+    }
+
+    fn path_names(&self, path: usize) -> Vec<String> {
+        let mut names = Vec::new();
+        let mut next = self.store.exhume_x_path(&path).unwrap().borrow().first;
+        while let Some(id) = next {
+            let element = self.store.exhume_path_element(&id).unwrap();
+            let element = element.borrow();
+            next = element.next;
+            names.push(element.name.clone());
+        }
+
+        names
+    }
+
+    /// A path is a unit variant, `Enum::Variant`, a function, or a variable.
+    fn path(&mut self, path: usize, expr: usize) -> Eval {
+        let names = self.path_names(path);
+        match names.as_slice() {
+            [name] => self.lookup(name, expr),
+            [.., ty, name] => {
+                if let Some(woog_enum) = self.store.exhume_enumeration_id_by_name(ty) {
+                    let is_variant = self.store.iter_enum_field().any(|field| {
+                        let field = field.borrow();
+                        field.woog_enum == woog_enum && field.name == *name
+                    });
+                    if is_variant {
+                        return Ok(Value::Enumeration {
+                            ty: ty.clone(),
+                            variant: name.clone(),
+                            fields: Vec::new(),
+                        });
+                    }
+                }
+                match self.methods.get(&(ty.clone(), name.clone())) {
+                    Some(func) => Ok(Value::Function(*func)),
+                    None => Err(RuntimeError::UnknownVariable {
+                        name: names.join("::"),
+                        span: self.span(Some(expr)),
+                    }
+                    .into()),
+                }
+            }
+            [] => self.unsupported("an empty path", expr),
+        }
+    }
+
+    fn arguments(&mut self, first: Option<usize>) -> Eval<Vec<Value>> {
+        let mut args = Vec::new();
+        let mut next = first;
+        while let Some(id) = next {
+            let arg = self.store.exhume_argument(&id).unwrap();
+            let arg = arg.borrow();
+            next = arg.next;
+            args.push(self.eval(arg.expression)?);
+        }
+
+        Ok(args)
+    }
+
+    fn call_expression(&mut self, id: usize, expr: usize) -> Eval {
+        let call = self.store.exhume_call(&id).unwrap();
+        let call = call.borrow().clone();
+
+        match call.subtype {
+            CallEnum::FunctionCall(ref id) => {
+                let callee = match call.expression {
+                    Some(callee) => self.eval(callee)?,
+                    None => {
+                        let name = self
+                            .store
+                            .exhume_function_call(id)
+                            .unwrap()
+                            .borrow()
+                            .name
+                            .clone();
+                        self.lookup(&name, expr)?
+                    }
+                };
+                let args = self.arguments(call.argument)?;
+                match callee {
+                    Value::Function(func) => self.call_function(func, args, Some(expr)),
+                    Value::Lambda(ref closure) => self.call_lambda(closure, args, expr),
+                    callee => Err(RuntimeError::NotCallable {
+                        ty: callee.type_name(),
+                        span: self.span(Some(expr)),
+                    }
+                    .into()),
+                }
+            }
+            CallEnum::MacroCall(_) => self.unsupported("calling a macro", expr),
+            CallEnum::MethodCall(ref id) => {
+                let name = self
+                    .store
+                    .exhume_method_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                let receiver = match call.expression {
+                    Some(receiver) => self.eval(receiver)?,
+                    None => return self.unsupported("a method call without a receiver", expr),
+                };
+                let args = self.arguments(call.argument)?;
+                self.method(receiver, &name, args, expr)
+            }
+            CallEnum::StaticMethodCall(ref id) => {
+                let method = self.store.exhume_static_method_call(id).unwrap();
+                let key = {
+                    let method = method.borrow();
+                    (method.ty.clone(), method.func.clone())
+                };
+                let args = self.arguments(call.argument)?;
+                match self.methods.get(&key) {
+                    Some(func) => self.call_function(*func, args, Some(expr)),
+                    None => Err(RuntimeError::UnknownFunction {
+                        name: format!("{}::{}", key.0, key.1),
+                        span: self.span(Some(expr)),
+                    }
+                    .into()),
+                }
+            }
+        }
+    }
+
+    /// Call a method
+    ///
+    /// Methods on structs and enums are defined in the program. Lists, maps
+    /// and strings have a few built in.
+    fn method(&mut self, receiver: Value, name: &str, mut args: Vec<Value>, expr: usize) -> Eval {
+        let ty = match receiver {
+            Value::Struct(ref value) => Some(value.borrow().name.clone()),
+            Value::Enumeration { ref ty, .. } => Some(ty.clone()),
+            _ => None,
+        };
+        if let Some(ty) = ty {
+            if let Some(func) = self.methods.get(&(ty, name.to_owned())) {
+                let func = *func;
+                args.insert(0, receiver);
+                return self.call_function(func, args, Some(expr));
+            }
+        }
+
+        let arity = |expected: usize, this: &Self| -> Eval<()> {
+            ensure!(
+                args.len() == expected,
+                AritySnafu {
+                    name,
+                    expected,
+                    found: args.len(),
+                    span: this.span(Some(expr)),
+                }
+            );
+            Ok(())
+        };
+
+        match (&receiver, name) {
+            (Value::List(list), "len") => {
+                arity(0, self)?;
+                Ok(Value::Integer(list.borrow().len() as i64))
+            }
+            (Value::List(list), "is_empty") => {
+                arity(0, self)?;
+                Ok(Value::Boolean(list.borrow().is_empty()))
+            }
+            (Value::List(list), "push") => {
+                arity(1, self)?;
+                list.borrow_mut().extend(args);
+                Ok(Value::Empty)
+            }
+            (Value::List(list), "pop") => {
+                arity(0, self)?;
+                // There's no Option in the interpreter, so popping an empty
+                // list is an error.
+                let len = list.borrow().len();
+                match list.borrow_mut().pop() {
+                    Some(value) => Ok(value),
+                    None => Err(RuntimeError::IndexOutOfBounds {
+                        index: len as i64,
+                        len,
+                        span: self.span(Some(expr)),
+                    }
+                    .into()),
+                }
+            }
+            (Value::Map(map), "len") => {
+                arity(0, self)?;
+                Ok(Value::Integer(map.borrow().len() as i64))
+            }
+            (Value::Map(map), "contains_key") => {
+                arity(1, self)?;
+                Ok(Value::Boolean(
+                    map.borrow().iter().any(|(key, _)| *key == args[0]),
+                ))
+            }
+            (Value::String(s), "len") => {
+                arity(0, self)?;
+                Ok(Value::Integer(s.chars().count() as i64))
+            }
+            (Value::String(s), "is_empty") => {
+                arity(0, self)?;
+                Ok(Value::Boolean(s.is_empty()))
+            }
+            _ => Err(RuntimeError::UnknownMethod {
+                name: name.to_owned(),
+                ty: receiver.type_name(),
+                span: self.span(Some(expr)),
+            }
+            .into()),
+        }
+    }
+
+    fn field_access(&mut self, id: usize, expr: usize) -> Eval {
+        let access = self.store.exhume_field_access(&id).unwrap();
+        let access = access.borrow();
+        let target = self
+            .store
+            .exhume_field_access_target(&access.field)
+            .unwrap();
+        let target = target.borrow().subtype.clone();
+
+        match target {
+            FieldAccessTargetEnum::EnumField(ref field) => {
+                let field = self.store.exhume_enum_field(field).unwrap();
+                let field = field.borrow();
+                let woog_enum = self.store.exhume_enumeration(&field.woog_enum).unwrap();
+                let ty = woog_enum.borrow().name.clone();
+                Ok(Value::Enumeration {
+                    ty,
+                    variant: field.name.clone(),
+                    fields: Vec::new(),
+                })
+            }
+            FieldAccessTargetEnum::Field(ref field) => {
+                let name = self
+                    .store
+                    .exhume_field(field)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                match self.eval(access.expression)? {
+                    Value::Struct(value) => match value.borrow().field(&name) {
+                        Some(value) => Ok(value.clone()),
+                        None => Err(RuntimeError::UnknownField {
+                            name,
+                            ty: format!("struct `{}`", value.borrow().name),
+                            span: self.span(Some(expr)),
+                        }
+                        .into()),
+                    },
+                    value => self.mismatch("a struct", &value, expr),
+                }
+            }
+            FieldAccessTargetEnum::Function(ref func) => Ok(Value::Function(*func)),
+        }
+    }
+
+    fn for_loop(&mut self, id: usize, expr: usize) -> Eval {
+        let for_loop = self.store.exhume_for_loop(&id).unwrap();
+        let for_loop = for_loop.borrow();
+
+        let iterable = self.eval(for_loop.expression)?;
+        let items: Box<dyn Iterator<Item = Value>> = match iterable {
+            Value::Range {
+                start: Some(start),
+                end,
+            } => Box::new(
+                (start..)
+                    .take_while(move |i| end.map_or(true, |end| *i < end))
+                    .map(Value::Integer),
+            ),
+            // Iterate over a snapshot, so that the body may change the list.
+            Value::List(ref list) => Box::new(list.borrow().clone().into_iter()),
+            Value::String(ref s) => {
+                Box::new(s.chars().collect::<Vec<_>>().into_iter().map(Value::Char))
+            }
+            ref value => {
+                return self.mismatch("a list, string, or range with a start", value, expr)
+            }
+        };
+
+        for item in items {
+            let scope = Scope::nested(&self.scope);
+            scope.borrow_mut().define(&for_loop.ident, item);
+            let broken = self.in_scope(scope, |this| {
+                this.iteration(&for_loop.label, for_loop.block)
+            })?;
+            if broken.is_some() {
+                break;
+            }
+        }
+
+        Ok(Value::Empty)
+    }
+
+    /// Evaluate the block of a loop once
+    ///
+    /// The result is the value it was broken out of the loop with, if it was.
+    /// Breaks and continues that are meant for an outer loop carry on
+    /// unwinding.
+    fn iteration(&mut self, label: &str, block: usize) -> Eval<Option<Value>> {
+        let ours = |l: &str| l.is_empty() || l == label;
+        match self.eval(block) {
+            Ok(_) => Ok(None),
+            Err(Unwind::Break { label, value, .. }) if ours(&label) => Ok(Some(value)),
+            Err(Unwind::Continue { label, .. }) if ours(&label) => Ok(None),
+            Err(unwind) => Err(unwind),
+        }
+    }
+
+    fn x_loop(&mut self, id: usize) -> Eval {
+        let x_loop = self.store.exhume_x_loop(&id).unwrap();
+        let x_loop = x_loop.borrow();
+
+        loop {
+            if let Some(value) = self.iteration(&x_loop.label, x_loop.block)? {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn x_while(&mut self, id: usize, expr: usize) -> Eval {
+        let x_while = self.store.exhume_x_while(&id).unwrap();
+        let x_while = x_while.borrow();
+
+        loop {
+            match self.eval(x_while.test)? {
+                Value::Boolean(true) => {
+                    if self.iteration(&x_while.label, x_while.block)?.is_some() {
+                        break;
+                    }
+                }
+                Value::Boolean(false) => break,
+                value => return self.mismatch("bool", &value, expr),
+            }
+        }
+
+        Ok(Value::Empty)
+    }
+
+    fn x_if(&mut self, id: usize, expr: usize) -> Eval {
+        let x_if = self.store.exhume_x_if(&id).unwrap();
+        let x_if = x_if.borrow();
+
+        match self.eval(x_if.test)? {
+            Value::Boolean(true) => self.block(x_if.true_block),
+            Value::Boolean(false) => match x_if.false_block {
+                Some(expr) => self.eval(expr),
+                None => Ok(Value::Empty),
+            },
+            value => self.mismatch("bool", &value, expr),
+        }
+    }
+
+    fn index(&mut self, id: usize, expr: usize) -> Eval {
+        let index = self.store.exhume_index(&id).unwrap();
+        let index = index.borrow();
+
+        let target = self.eval(index.target)?;
+        let key = self.eval(index.index)?;
+        match (&target, &key) {
+            (Value::List(list), Value::Integer(i)) => {
+                let list = list.borrow();
+                self.element(&list, *i, expr).cloned()
+            }
+            (Value::String(s), Value::Integer(i)) => {
+                let chars: Vec<_> = s.chars().map(Value::Char).collect();
+                self.element(&chars, *i, expr).cloned()
+            }
+            (Value::Map(map), key) => match map.borrow().iter().find(|(k, _)| k == key) {
+                Some((_, value)) => Ok(value.clone()),
+                None => Err(RuntimeError::MissingKey {
+                    key: key.nested(),
+                    span: self.span(Some(expr)),
+                }
+                .into()),
+            },
+            (Value::List(_) | Value::String(_), key) => self.mismatch("int", key, expr),
+            (target, _) => self.mismatch("a list, map, or string", target, expr),
+        }
+    }
+
+    fn element<'v>(&self, values: &'v [Value], index: i64, expr: usize) -> Eval<&'v Value> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| values.get(i))
+            .ok_or_else(|| {
+                RuntimeError::IndexOutOfBounds {
+                    index,
+                    len: values.len(),
+                    span: self.span(Some(expr)),
+                }
+                .into()
+            })
+    }
+
+    fn literal(&mut self, id: usize) -> Eval {
+        let literal = self.store.exhume_literal(&id).unwrap();
+        let subtype = literal.borrow().subtype.clone();
+
+        Ok(match subtype {
+            LiteralEnum::BooleanLiteral(ref id) => {
+                let boolean = self.store.exhume_boolean_literal(id).unwrap();
+                let value = matches!(boolean.borrow().subtype, BooleanLiteralEnum::TrueLiteral(_));
+                Value::Boolean(value)
+            }
+            LiteralEnum::CharLiteral(ref id) => {
+                let value = self.store.exhume_char_literal(id).unwrap().borrow().x_value;
+                Value::Char(
+                    u32::try_from(value)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER),
+                )
+            }
+            LiteralEnum::FloatLiteral(ref id) => Value::Float(
+                self.store
+                    .exhume_float_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .x_value,
+            ),
+            LiteralEnum::FormatString(ref id) => {
+                let mut result = String::new();
+                let mut next = self
+                    .store
+                    .exhume_format_string(id)
+                    .unwrap()
+                    .borrow()
+                    .first_format_bit;
+                while let Some(id) = next {
+                    let bit = self.store.exhume_format_bit(&id).unwrap();
+                    let bit = bit.borrow();
+                    next = bit.next;
+                    match bit.subtype {
+                        FormatBitEnum::ExpressionBit(ref id) => {
+                            let expr = self
+                                .store
+                                .exhume_expression_bit(id)
+                                .unwrap()
+                                .borrow()
+                                .expression;
+                            result.push_str(&self.eval(expr)?.to_string());
+                        }
+                        FormatBitEnum::StringBit(ref id) => {
+                            let string =
+                                self.store.exhume_string_bit(id).unwrap().borrow().z_string;
+                            let string = self.store.exhume_string_literal(&string).unwrap();
+                            result.push_str(&string.borrow().x_value);
+                        }
+                    }
+                }
+                Value::String(result)
+            }
+            LiteralEnum::IntegerLiteral(ref id) => Value::Integer(
+                self.store
+                    .exhume_integer_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .x_value,
+            ),
+            LiteralEnum::MapExpression(ref id) => {
+                let elements: Vec<_> = self
+                    .store
+                    .iter_map_element()
+                    .filter(|element| element.borrow().map == *id)
+                    .collect();
+                let mut entries = Vec::new();
+                for element in elements {
+                    let element = element.borrow();
+                    let key = self.eval(element.key)?;
+                    let value = self.eval(element.x_value)?;
+                    entries.push((key, value));
+                }
+                Value::Map(Rc::new(RefCell::new(entries)))
+            }
+            LiteralEnum::StringLiteral(ref id) => Value::String(
+                self.store
+                    .exhume_string_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .x_value
+                    .clone(),
+            ),
+        })
+    }
+
+    fn x_match(&mut self, id: usize, expr: usize) -> Eval {
+        let scrutinee = self.store.exhume_x_match(&id).unwrap().borrow().scrutinee;
+        let scrutinee = self.eval(scrutinee)?;
+
+        let patterns: Vec<_> = self
+            .store
+            .iter_pattern()
+            .filter(|pattern| pattern.borrow().x_match == id)
+            .collect();
+        let mut next = patterns
+            .iter()
+            .find(|pattern| {
+                let id = pattern.borrow().id;
+                !patterns.iter().any(|other| other.borrow().next == Some(id))
+            })
+            .map(|pattern| pattern.borrow().id);
+
+        while let Some(id) = next {
+            let pattern = self.store.exhume_pattern(&id).unwrap();
+            let pattern = pattern.borrow();
+            next = pattern.next;
+
+            let mut bindings = Vec::new();
+            if self.matches(pattern.match_expr, &scrutinee, &mut bindings)? {
+                let scope = Scope::nested(&self.scope);
+                for (name, value) in bindings {
+                    scope.borrow_mut().define(&name, value);
+                }
+                return self.in_scope(scope, |this| this.eval(pattern.expression));
+            }
+        }
+
+        Err(RuntimeError::NoMatch {
+            value: scrutinee.nested(),
+            span: self.span(Some(expr)),
+        }
+        .into())
+    }
+
+    /// Match a value against a pattern, collecting the variables it binds
+    fn matches(
+        &mut self,
+        pattern: usize,
+        value: &Value,
+        bindings: &mut Vec<(String, Value)>,
+    ) -> Eval<bool> {
+        let expr = self.store.exhume_expression(&pattern).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                if name != "_" {
+                    bindings.push((name, value.clone()));
+                }
+                Ok(true)
+            }
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.matches(expr, value, bindings)
+            }
+            ExpressionEnum::StructExpression(ref id) => {
+                let struct_expr = self.store.exhume_struct_expression(id).unwrap();
+                let struct_expr = struct_expr.borrow();
+                let names = self.path_names(struct_expr.x_path);
+                let fields = self.field_expressions(*id);
+                let data = self.store.exhume_data_structure(&struct_expr.data).unwrap();
+                let data = data.borrow().subtype.clone();
+
+                match (data, value) {
+                    (
+                        DataStructureEnum::Enumeration(ref woog_enum),
+                        Value::Enumeration {
+                            ty,
+                            variant,
+                            fields: values,
+                        },
+                    ) => {
+                        let woog_enum = self.store.exhume_enumeration(woog_enum).unwrap();
+                        if *ty != woog_enum.borrow().name
+                            || Some(variant) != names.last()
+                            || fields.len() != values.len()
+                        {
+                            return Ok(false);
+                        }
+                        for ((_, pattern), value) in fields.iter().zip(values) {
+                            if !self.matches(*pattern, value, bindings)? {
+                                return Ok(false);
+                            }
+                        }
+                        Ok(true)
+                    }
+                    (DataStructureEnum::WoogStruct(ref woog_struct), Value::Struct(value)) => {
+                        let woog_struct = self.store.exhume_woog_struct(woog_struct).unwrap();
+                        if value.borrow().name != woog_struct.borrow().name {
+                            return Ok(false);
+                        }
+                        for (name, pattern) in fields {
+                            let field = value.borrow().field(&name).cloned();
+                            match field {
+                                Some(field) => {
+                                    if !self.matches(pattern, &field, bindings)? {
+                                        return Ok(false);
+                                    }
+                                }
+                                None => return Ok(false),
+                            }
+                        }
+                        Ok(true)
+                    }
+                    _ => Ok(false),
+                }
+            }
+            ExpressionEnum::RangeExpression(_) => match (self.eval(pattern)?, value) {
+                (Value::Range { start, end }, Value::Integer(i)) => {
+                    Ok(start.map_or(true, |start| start <= *i) && end.map_or(true, |end| *i < end))
+                }
+                _ => Ok(false),
+            },
+            // Anything else is a value to compare against.
+            _ => Ok(self.eval(pattern)? == *value),
+        }
+    }
+
+    /// The fields of a struct expression, as names and expressions, in the
+    /// order in which they were written. Unnamed fields are named by position.
+    fn field_expressions(&self, id: usize) -> Vec<(String, usize)> {
+        let mut fields: Vec<_> = self
+            .store
+            .iter_field_expression()
+            .filter(|field| field.borrow().woog_struct == id)
+            .map(|field| {
+                let field = field.borrow();
+                let name = match field.subtype {
+                    FieldExpressionEnum::NamedFieldExpression(ref id) => self
+                        .store
+                        .exhume_named_field_expression(id)
+                        .unwrap()
+                        .borrow()
+                        .name
+                        .clone(),
+                    FieldExpressionEnum::UnnamedFieldExpression(ref id) => self
+                        .store
+                        .exhume_unnamed_field_expression(id)
+                        .unwrap()
+                        .borrow()
+                        .position
+                        .to_string(),
+                };
+                (name, field.expression)
+            })
+            .collect();
+        // Positions sort numerically, and named fields keep their order.
+        fields.sort_by_key(|(name, _)| name.parse::<usize>().ok());
+
+        fields
+    }
+
+    fn struct_expression(&mut self, id: usize, expr: usize) -> Eval {
+        let struct_expr = self.store.exhume_struct_expression(&id).unwrap();
+        let struct_expr = struct_expr.borrow();
+        let data = self.store.exhume_data_structure(&struct_expr.data).unwrap();
+        let data = data.borrow().subtype.clone();
+
+        let mut values = Vec::new();
+        for (name, expr) in self.field_expressions(id) {
+            values.push((name, self.eval(expr)?));
+        }
+
+        match data {
+            DataStructureEnum::Enumeration(ref woog_enum) => {
+                let ty = self
+                    .store
+                    .exhume_enumeration(woog_enum)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                let variant = match self.path_names(struct_expr.x_path).pop() {
+                    Some(variant) => variant,
+                    None => return self.unsupported("an enum variant without a name", expr),
+                };
+                Ok(Value::Enumeration {
+                    ty,
+                    variant,
+                    fields: values.into_iter().map(|(_, value)| value).collect(),
+                })
+            }
+            DataStructureEnum::WoogStruct(ref woog_struct) => {
+                let name = self
+                    .store
+                    .exhume_woog_struct(woog_struct)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                // Put the fields in declaration order.
+                let declared: Vec<_> = self
+                    .store
+                    .iter_field()
+                    .filter(|field| field.borrow().x_model == *woog_struct)
+                    .map(|field| field.borrow().name.clone())
+                    .collect();
+                values.sort_by_key(|(name, _)| declared.iter().position(|field| field == name));
+
+                Ok(Value::Struct(Rc::new(RefCell::new(Struct {
+                    name,
+                    fields: values,
+                }))))
+            }
+        }
+    }
+
+    fn range(&mut self, id: usize, expr: usize) -> Eval {
+        let range = self.store.exhume_range_expression(&id).unwrap();
+        let range = range.borrow();
+
+        let mut bound = |this: &mut Self, side: Option<usize>| -> Eval<Option<i64>> {
+            match side {
+                Some(side) => match this.eval(side)? {
+                    Value::Integer(i) => Ok(Some(i)),
+                    value => this.mismatch("int", &value, expr),
+                },
+                None => Ok(None),
+            }
+        };
+        let start = bound(self, range.lhs)?;
+        let mut end = bound(self, range.rhs)?;
+
+        if let RangeExpressionEnum::Inclusive(_) | RangeExpressionEnum::ToInclusive(_) =
+            range.subtype
+        {
+            end = match end {
+                Some(end) => Some(end.checked_add(1).context(OverflowSnafu {
+                    span: self.span(Some(expr)),
+                })?),
+                None => None,
+            };
+        }
+
+        Ok(Value::Range { start, end })
+    }
+
+    fn type_cast(&mut self, id: usize, expr: usize) -> Eval {
+        let cast = self.store.exhume_type_cast(&id).unwrap();
+        let cast = cast.borrow();
+        let ty = self.store.exhume_value_type(&cast.ty).unwrap();
+        let ty = ty.borrow();
+
+        let value = self.eval(cast.lhs)?;
+        let result = match (&value, &ty.subtype) {
+            (Value::Integer(i), ValueTypeEnum::Ty(FLOAT)) => Some(Value::Float(*i as f64)),
+            (Value::Float(x), ValueTypeEnum::Ty(INTEGER)) => Some(Value::Integer(*x as i64)),
+            (Value::Char(c), ValueTypeEnum::Ty(INTEGER)) => Some(Value::Integer(*c as i64)),
+            (Value::Boolean(b), ValueTypeEnum::Ty(INTEGER)) => Some(Value::Integer(*b as i64)),
+            (Value::Integer(i), ValueTypeEnum::Char(_)) => u32::try_from(*i)
+                .ok()
+                .and_then(char::from_u32)
+                .map(Value::Char),
+            (value, ValueTypeEnum::Ty(Z_STRING)) => Some(Value::String(value.to_string())),
+            (Value::Boolean(_), ValueTypeEnum::Ty(BOOLEAN))
+            | (Value::Float(_), ValueTypeEnum::Ty(FLOAT))
+            | (Value::Integer(_), ValueTypeEnum::Ty(INTEGER))
+            | (Value::Char(_), ValueTypeEnum::Char(_)) => Some(value.clone()),
+            _ => None,
+        };
+
+        match result {
+            Some(value) => Ok(value),
+            None => Err(RuntimeError::InvalidCast {
+                from: value.type_name(),
+                to: Printer::new(self.store).print_type(&ty),
+                span: self.span(Some(expr)),
+            }
+            .into()),
+        }
+    }
+
+    //
+    // Operators
+    //
+
+    fn operator(&mut self, id: usize, expr: usize) -> Eval {
+        let operator = self.store.exhume_operator(&id).unwrap();
+        let operator = operator.borrow().clone();
+
+        match operator.subtype {
+            OperatorEnum::Binary(ref id) => {
+                let binary = self.store.exhume_binary(id).unwrap();
+                let binary = binary.borrow().subtype.clone();
+                let rhs = match operator.rhs {
+                    Some(rhs) => rhs,
+                    None => return self.unsupported("a binary operator without a rhs", expr),
+                };
+
+                match binary {
+                    BinaryEnum::Assignment(_) => {
+                        let value = self.eval(rhs)?;
+                        self.assign(operator.lhs, value, expr)?;
+                        Ok(Value::Empty)
+                    }
+                    BinaryEnum::BooleanOperator(ref id) => {
+                        let boolean = self.store.exhume_boolean_operator(id).unwrap();
+                        let is_and =
+                            matches!(boolean.borrow().subtype, BooleanOperatorEnum::And(_));
+                        // `false && _` and `true || _` short circuit.
+                        match self.eval(operator.lhs)? {
+                            Value::Boolean(lhs) if lhs != is_and => Ok(Value::Boolean(lhs)),
+                            Value::Boolean(_) => match self.eval(rhs)? {
+                                Value::Boolean(rhs) => Ok(Value::Boolean(rhs)),
+                                value => self.mismatch("bool", &value, expr),
+                            },
+                            value => self.mismatch("bool", &value, expr),
+                        }
+                    }
+                    binary => {
+                        let lhs = self.eval(operator.lhs)?;
+                        let rhs = self.eval(rhs)?;
+                        match compound(&binary) {
+                            Some(binary) => {
+                                let value = self.arithmetic(&binary, lhs, rhs, expr)?;
+                                self.assign(operator.lhs, value, expr)?;
+                                Ok(Value::Empty)
+                            }
+                            None => self.arithmetic(&binary, lhs, rhs, expr),
+                        }
+                    }
+                }
+            }
+            OperatorEnum::Comparison(ref id) => {
+                let comparison = self.store.exhume_comparison(id).unwrap();
+                let comparison = comparison.borrow().subtype.clone();
+                let lhs = self.eval(operator.lhs)?;
+                let rhs = match operator.rhs {
+                    Some(rhs) => self.eval(rhs)?,
+                    None => return self.unsupported("a comparison without a rhs", expr),
+                };
+
+                let ordering = || lhs.partial_cmp(&rhs);
+                let result = match comparison {
+                    ComparisonEnum::Equal(_) => Some(lhs == rhs),
+                    ComparisonEnum::NotEqual(_) => Some(lhs != rhs),
+                    ComparisonEnum::GreaterThan(_) => ordering().map(Ordering::is_gt),
+                    ComparisonEnum::GreaterThanOrEqual(_) => ordering().map(Ordering::is_ge),
+                    ComparisonEnum::LessThan(_) => ordering().map(Ordering::is_lt),
+                    ComparisonEnum::LessThanOrEqual(_) => ordering().map(Ordering::is_le),
+                };
+
+                match result {
+                    Some(result) => Ok(Value::Boolean(result)),
+                    None if lhs.partial_cmp(&lhs).is_some() => {
+                        self.mismatch(&lhs.type_name(), &rhs, expr)
+                    }
+                    None => self.mismatch("a number, char, or string", &lhs, expr),
+                }
+            }
+            OperatorEnum::Unary(ref id) => {
+                let unary = self.store.exhume_unary(id).unwrap();
+                let unary = unary.borrow().subtype.clone();
+                let value = self.eval(operator.lhs)?;
+
+                match (unary, value) {
+                    (UnaryEnum::Negation(_), Value::Integer(i)) => match i.checked_neg() {
+                        Some(i) => Ok(Value::Integer(i)),
+                        None => Err(RuntimeError::Overflow {
+                            span: self.span(Some(expr)),
+                        }
+                        .into()),
+                    },
+                    (UnaryEnum::Negation(_), Value::Float(x)) => Ok(Value::Float(-x)),
+                    (UnaryEnum::Negation(_), value) => self.mismatch("a number", &value, expr),
+                    (UnaryEnum::Not(_), Value::Boolean(b)) => Ok(Value::Boolean(!b)),
+                    (UnaryEnum::Not(_), Value::Integer(i)) => Ok(Value::Integer(!i)),
+                    (UnaryEnum::Not(_), value) => self.mismatch("bool or int", &value, expr),
+                }
+            }
+        }
+    }
+
+    /// Arithmetic, bitwise, and shift operators
+    ///
+    /// Integer arithmetic is checked: overflow is an error rather than wrapping,
+    /// as is dividing by zero, or taking the remainder of it. Shifts are by 0 to
+    /// 63 bits, and right shifts are arithmetic, keeping the sign. Bits shifted
+    /// out of the left are lost. The bitwise operators apply to booleans too,
+    /// without short circuiting. Lists and strings may be added, which
+    /// concatenates them.
+    fn arithmetic(&self, binary: &BinaryEnum, lhs: Value, rhs: Value, expr: usize) -> Eval {
+        let overflow = || -> Unwind {
+            RuntimeError::Overflow {
+                span: self.span(Some(expr)),
+            }
+            .into()
+        };
+        let bitwise = matches!(
+            binary,
+            BinaryEnum::BitwiseAnd(_) | BinaryEnum::BitwiseOr(_) | BinaryEnum::BitwiseXor(_)
+        );
+        let shift = matches!(binary, BinaryEnum::ShiftLeft(_) | BinaryEnum::ShiftRight(_));
+
+        match (binary, &lhs, &rhs) {
+            (
+                BinaryEnum::Division(_) | BinaryEnum::Remainder(_),
+                Value::Integer(_),
+                Value::Integer(0),
+            ) => Err(RuntimeError::DivisionByZero {
+                span: self.span(Some(expr)),
+            }
+            .into()),
+            (_, Value::Integer(a), Value::Integer(b)) => {
+                let bits = u32::try_from(*b).ok();
+                let result = match binary {
+                    BinaryEnum::Addition(_) => a.checked_add(*b),
+                    BinaryEnum::BitwiseAnd(_) => Some(a & b),
+                    BinaryEnum::BitwiseOr(_) => Some(a | b),
+                    BinaryEnum::BitwiseXor(_) => Some(a ^ b),
+                    BinaryEnum::Division(_) => a.checked_div(*b),
+                    BinaryEnum::Multiplication(_) => a.checked_mul(*b),
+                    BinaryEnum::Remainder(_) => a.checked_rem(*b),
+                    BinaryEnum::ShiftLeft(_) => bits.and_then(|bits| a.checked_shl(bits)),
+                    BinaryEnum::ShiftRight(_) => bits.and_then(|bits| a.checked_shr(bits)),
+                    BinaryEnum::Subtraction(_) => a.checked_sub(*b),
+                    _ => unreachable!(),
+                };
+                result.map(Value::Integer).ok_or_else(overflow)
+            }
+            (_, Value::Float(a), Value::Float(b)) if !bitwise && !shift => {
+                Ok(Value::Float(match binary {
+                    BinaryEnum::Addition(_) => a + b,
+                    BinaryEnum::Division(_) => a / b,
+                    BinaryEnum::Multiplication(_) => a * b,
+                    BinaryEnum::Remainder(_) => a % b,
+                    BinaryEnum::Subtraction(_) => a - b,
+                    _ => unreachable!(),
+                }))
+            }
+            (_, Value::Boolean(a), Value::Boolean(b)) if bitwise => {
+                Ok(Value::Boolean(match binary {
+                    BinaryEnum::BitwiseAnd(_) => a & b,
+                    BinaryEnum::BitwiseOr(_) => a | b,
+                    _ => a ^ b,
+                }))
+            }
+            (BinaryEnum::Addition(_), Value::String(a), Value::String(b)) => {
+                Ok(Value::String(format!("{}{}", a, b)))
+            }
+            (BinaryEnum::Addition(_), Value::List(a), Value::List(b)) => {
+                let mut list = a.borrow().clone();
+                list.extend(b.borrow().iter().cloned());
+                Ok(Value::List(Rc::new(RefCell::new(list))))
+            }
+            (_, Value::Integer(_), _) => self.mismatch(&lhs.type_name(), &rhs, expr),
+            (_, Value::Float(_), _) if !bitwise && !shift => {
+                self.mismatch(&lhs.type_name(), &rhs, expr)
+            }
+            (_, Value::Boolean(_), _) if bitwise => self.mismatch(&lhs.type_name(), &rhs, expr),
+            (BinaryEnum::Addition(_), Value::String(_) | Value::List(_), _) => {
+                self.mismatch(&lhs.type_name(), &rhs, expr)
+            }
+            _ if bitwise => self.mismatch("bool or int", &lhs, expr),
+            _ if shift => self.mismatch("int", &lhs, expr),
+            _ => self.mismatch("a number", &lhs, expr),
+        }
+    }
+
+    /// Assign to a variable, a field of a struct, or an element of a list or
+    /// map
+    fn assign(&mut self, place: usize, value: Value, expr: usize) -> Eval<()> {
+        let place_expr = self.store.exhume_expression(&place).unwrap();
+        let subtype = place_expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                ensure!(
+                    self.scope.borrow_mut().assign(&name, value),
+                    UnknownVariableSnafu {
+                        name,
+                        span: self.span(Some(place)),
+                    }
+                );
+                Ok(())
+            }
+            ExpressionEnum::FieldAccess(ref access) => {
+                let access = self.store.exhume_field_access(access).unwrap();
+                let access = access.borrow();
+                let target = self
+                    .store
+                    .exhume_field_access_target(&access.field)
+                    .unwrap();
+                let name = match target.borrow().subtype {
+                    FieldAccessTargetEnum::Field(ref field) => self
+                        .store
+                        .exhume_field(field)
+                        .unwrap()
+                        .borrow()
+                        .name
+                        .clone(),
+                    _ => return self.unsupported("assigning to this field", expr),
+                };
+
+                match self.eval(access.expression)? {
+                    Value::Struct(target) => {
+                        let mut target = target.borrow_mut();
+                        let ty = format!("struct `{}`", target.name);
+                        match target.field_mut(&name) {
+                            Some(field) => {
+                                *field = value;
+                                Ok(())
+                            }
+                            None => Err(RuntimeError::UnknownField {
+                                name,
+                                ty,
+                                span: self.span(Some(place)),
+                            }
+                            .into()),
+                        }
+                    }
+                    target => self.mismatch("a struct", &target, place),
+                }
+            }
+            ExpressionEnum::Index(ref index) => {
+                let index = self.store.exhume_index(index).unwrap();
+                let index = index.borrow();
+                let target = self.eval(index.target)?;
+                let key = self.eval(index.index)?;
+
+                match (&target, key) {
+                    (Value::List(list), Value::Integer(i)) => {
+                        let mut list = list.borrow_mut();
+                        let len = list.len();
+                        match usize::try_from(i).ok().and_then(|i| list.get_mut(i)) {
+                            Some(element) => {
+                                *element = value;
+                                Ok(())
+                            }
+                            None => Err(RuntimeError::IndexOutOfBounds {
+                                index: i,
+                                len,
+                                span: self.span(Some(place)),
+                            }
+                            .into()),
+                        }
+                    }
+                    (Value::List(_), key) => self.mismatch("int", &key, place),
+                    (Value::Map(map), key) => {
+                        let mut map = map.borrow_mut();
+                        match map.iter_mut().find(|(k, _)| *k == key) {
+                            Some((_, entry)) => *entry = value,
+                            None => map.push((key, value)),
+                        }
+                        Ok(())
+                    }
+                    (target, _) => self.mismatch("a list or map", target, place),
+                }
+            }
+            _ => self.unsupported("assigning to this expression", expr),
+        }
+    }
+}
+
+/// Turn the result of evaluation into the result of running the program
+///
+fn finish(result: Eval) -> Result<Value, RuntimeError> {
+    match result {
+        Ok(value) | Err(Unwind::Return(value)) => Ok(value),
+        Err(Unwind::Error(error)) => Err(error),
+        Err(Unwind::Break { label, span, .. }) => Err(RuntimeError::OutsideLoop {
+            what: jump("break", &label),
+            span,
+        }),
+        Err(Unwind::Continue { label, span }) => Err(RuntimeError::OutsideLoop {
+            what: jump("continue", &label),
+            span,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::sample::{Bit, Sample, Stmt};
+
+    #[test]
+    fn test_recursion() {
+        let mut s = Sample::new("fib");
+
+        let int = s.int_ty();
+        let body = s.function("fib", &[("n", int.clone())], &int, None);
+        let n = s.var("n");
+        let one = s.int(1);
+        let two = s.int(2);
+        let test = s.binary("<", &n, &two);
+        let early = s.block(&body);
+        let ret = s.ret(&n);
+        s.statements(&early, vec![Stmt::Expr(ret)]);
+        let x_if = s.if_else(&test, &early, None);
+        let n_1 = s.binary("-", &n, &one);
+        let n_2 = s.binary("-", &n, &two);
+        let fib_1 = s.call("fib", vec![n_1]);
+        let fib_2 = s.call("fib", vec![n_2]);
+        let sum = s.binary("+", &fib_1, &fib_2);
+        s.statements(&body, vec![Stmt::Expr(x_if), Stmt::Result(sum)]);
+
+        let mut interpreter = Interpreter::with_output(&s.store, io::sink());
+        assert_eq!(
+            interpreter.call("fib", vec![Value::Integer(20)]).unwrap(),
+            Value::Integer(6765)
+        );
+        assert!(matches!(
+            interpreter.call("fib", vec![]),
+            Err(RuntimeError::Arity {
+                expected: 1,
+                found: 0,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_points() {
+        let mut s = Sample::new("points");
+
+        let int = s.int_ty();
+        let point = s.structure("Point", &[("x", int.clone()), ("y", int.clone())]);
+        let point_ty = s.struct_ty(&point);
+        let imp = s.implementation(&point);
+
+        let body = s.function("norm", &[("self", point_ty)], &int, Some(&imp));
+        let me = s.var("self");
+        let x = s.field(&me, &point, "x");
+        let y = s.field(&me, &point, "y");
+        let xx = s.binary("*", &x, &x);
+        let yy = s.binary("*", &y, &y);
+        let sum = s.binary("+", &xx, &yy);
+        s.statements(&body, vec![Stmt::Result(sum)]);
+
+        // fn main() {
+        //     let p = Point { y: 4, x: 3 };
+        //     let total = 0;
+        //     for i in 0..=3 {
+        //         total = total + i;
+        //     }
+        //     p.x = total;
+        //     print("{p} {p.norm()}\n");
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let three = s.int(3);
+        let four = s.int(4);
+        let p = s.struct_expr(&point, vec![("y", four), ("x", three.clone())]);
+        let zero = s.int(0);
+        let range = s.range(Some(&zero), Some(&three), true);
+        let body = s.block(&main);
+        let total = s.var("total");
+        let i = s.var("i");
+        let add = s.binary("+", &total, &i);
+        let assign = s.binary("=", &total, &add);
+        s.statements(&body, vec![Stmt::Expr(assign)]);
+        let for_loop = s.for_loop("i", &range, &body);
+        let pv = s.var("p");
+        let px = s.field(&pv, &point, "x");
+        let assign = s.binary("=", &px, &total);
+        let norm = s.method(&pv, "norm", vec![]);
+        let message = s.format(vec![
+            Bit::Expr(pv),
+            Bit::Str(" "),
+            Bit::Expr(norm),
+            Bit::Str("\n"),
+        ]);
+        let print = s.print(&message);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("p", None, p),
+                Stmt::Let("total", None, zero),
+                Stmt::Expr(for_loop),
+                Stmt::Expr(assign),
+                Stmt::Expr(print),
+            ],
+        );
+
+        let mut output = Vec::new();
+        let mut interpreter = Interpreter::with_output(&s.store, &mut output);
+        assert_eq!(interpreter.run().unwrap(), Value::Empty);
+        drop(interpreter);
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Point { x: 6, y: 4 } 52\n"
+        );
+    }
+
+    #[test]
+    fn test_closures() {
+        let mut s = Sample::new("closures");
+
+        let int = s.int_ty();
+        let shape = s.enumeration("Shape", &[("Circle", Some(int.clone())), ("Empty", None)]);
+
+        // fn main() -> [int] {
+        //     let k = 10;
+        //     let add = |x| x + k;
+        //     let shape = Shape::Circle(3);
+        //     let xs = [1, 2, 3];
+        //     xs.push(4);
+        //     [
+        //         add(5),
+        //         match shape { Shape::Circle(r) => r * 2, Shape::Empty => 0 },
+        //         match 7 { 1 => 10, _ => 20 },
+        //         xs[3] + xs.len(),
+        //     ]
+        // }
+        let list = s.list_ty(&int);
+        let main = s.function("main", &[], &list, None);
+        let (lambda, body) = s.lambda(&[("x", None)], &int, &main);
+        let x = s.var("x");
+        let k = s.var("k");
+        let sum = s.binary("+", &x, &k);
+        s.statements(&body, vec![Stmt::Result(sum)]);
+
+        let ten = s.int(10);
+        let three = s.int(3);
+        let circle = s.variant(&shape, "Circle", vec![three.clone()]);
+        let one = s.int(1);
+        let two = s.int(2);
+        let four = s.int(4);
+        let xs = s.list(vec![one.clone(), two.clone(), three.clone()]);
+        let xs_var = s.var("xs");
+        let push = s.method(&xs_var, "push", vec![four]);
+
+        let five = s.int(5);
+        let add = s.call("add", vec![five]);
+
+        let shape_var = s.var("shape");
+        let r = s.var("r");
+        let circle_pattern = s.variant(&shape, "Circle", vec![r.clone()]);
+        let double = s.binary("*", &r, &two);
+        let empty_pattern = s.path(&["Shape", "Empty"]);
+        let zero = s.int(0);
+        let match_shape = s.x_match(
+            &shape_var,
+            vec![(circle_pattern, double), (empty_pattern, zero)],
+        );
+
+        let seven = s.int(7);
+        let wild = s.var("_");
+        let twenty = s.int(20);
+        let match_seven = s.x_match(&seven, vec![(one, ten.clone()), (wild, twenty)]);
+
+        let index = s.index(&xs_var, &three);
+        let len = s.method(&xs_var, "len", vec![]);
+        let sum = s.binary("+", &index, &len);
+
+        let result = s.list(vec![add, match_shape, match_seven, sum]);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("k", None, ten),
+                Stmt::Let("add", None, lambda),
+                Stmt::Let("shape", None, circle),
+                Stmt::Let("xs", None, xs),
+                Stmt::Expr(push),
+                Stmt::Result(result),
+            ],
+        );
+
+        let mut interpreter = Interpreter::with_output(&s.store, io::sink());
+        assert_eq!(interpreter.run().unwrap().to_string(), "[15, 6, 20, 8]");
+    }
+
+    #[test]
+    fn test_loops() {
+        let mut s = Sample::new("loops");
+
+        // fn main() -> [int] {
+        //     let n = 0;
+        //     let total = 0;
+        //     while n < 10 {
+        //         n = n + 1;
+        //         if n == 3 {
+        //             continue;
+        //         }
+        //         total = total + n;
+        //     }
+        //     let found = 'outer: loop {
+        //         for i in 0..5 {
+        //             if i == 3 {
+        //                 break 'outer i * 10;
+        //             }
+        //         }
+        //     };
+        //     [total, found]
+        // }
+        let int = s.int_ty();
+        let list = s.list_ty(&int);
+        let main = s.function("main", &[], &list, None);
+        let zero = s.int(0);
+        let one = s.int(1);
+        let three = s.int(3);
+        let five = s.int(5);
+        let ten = s.int(10);
+
+        let n = s.var("n");
+        let total = s.var("total");
+        let test = s.binary("<", &n, &ten);
+        let body = s.block(&main);
+        let inc = s.binary("+", &n, &one);
+        let inc = s.binary("=", &n, &inc);
+        let skip = s.block(&body);
+        let cont = s.x_continue("");
+        s.statements(&skip, vec![Stmt::Expr(cont)]);
+        let is_three = s.binary("==", &n, &three);
+        let skip = s.if_else(&is_three, &skip, None);
+        let add = s.binary("+", &total, &n);
+        let add = s.binary("=", &total, &add);
+        s.statements(
+            &body,
+            vec![Stmt::Expr(inc), Stmt::Expr(skip), Stmt::Expr(add)],
+        );
+        let while_loop = s.while_loop("", &test, &body);
+
+        let outer = s.block(&main);
+        let inner = s.block(&outer);
+        let found = s.block(&inner);
+        let i = s.var("i");
+        let value = s.binary("*", &i, &ten);
+        let x_break = s.x_break("outer", Some(&value));
+        s.statements(&found, vec![Stmt::Expr(x_break)]);
+        let is_three = s.binary("==", &i, &three);
+        let found = s.if_else(&is_three, &found, None);
+        s.statements(&inner, vec![Stmt::Expr(found)]);
+        let range = s.range(Some(&zero), Some(&five), false);
+        let for_loop = s.for_loop("i", &range, &inner);
+        s.statements(&outer, vec![Stmt::Expr(for_loop)]);
+        let x_loop = s.x_loop("outer", &outer);
+
+        let found = s.var("found");
+        let result = s.list(vec![total, found]);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("n", None, zero.clone()),
+                Stmt::Let("total", None, zero),
+                Stmt::Expr(while_loop),
+                Stmt::Let("found", None, x_loop),
+                Stmt::Result(result),
+            ],
+        );
+
+        // fn stray() {
+        //     continue 'nowhere;
+        // }
+        let empty = s.empty_ty();
+        let stray = s.function("stray", &[], &empty, None);
+        let cont = s.x_continue("nowhere");
+        s.statements(&stray, vec![Stmt::Expr(cont)]);
+
+        let mut interpreter = Interpreter::with_output(&s.store, io::sink());
+        assert_eq!(interpreter.run().unwrap().to_string(), "[52, 30]");
+
+        let error = interpreter.call("stray", vec![]).unwrap_err();
+        assert!(
+            matches!(error, RuntimeError::OutsideLoop { ref what, .. } if what == "continue 'nowhere")
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut s = Sample::new("operators");
+
+        // fn main() -> [int] {
+        //     let x = 7;
+        //     x += 3;
+        //     x <<= 2;
+        //     x %= 7;
+        //     [x, 12 & 10, 12 | 3, 12 ^ 10, -16 >> 2, !0, -7 % 3]
+        // }
+        let int = s.int_ty();
+        let list = s.list_ty(&int);
+        let main = s.function("main", &[], &list, None);
+        let x = s.var("x");
+        let [zero, two, three, seven, ten, twelve, sixteen] =
+            [0, 2, 3, 7, 10, 12, 16].map(|i| s.int(i));
+        let add = s.binary("+=", &x, &three);
+        let shift = s.binary("<<=", &x, &two);
+        let rem = s.binary("%=", &x, &seven);
+        let and = s.binary("&", &twelve, &ten);
+        let or = s.binary("|", &twelve, &three);
+        let xor = s.binary("^", &twelve, &ten);
+        let minus_sixteen = s.unary("-", &sixteen);
+        let shr = s.binary(">>", &minus_sixteen, &two);
+        let not = s.unary("!", &zero);
+        let minus_seven = s.unary("-", &seven);
+        let modulo = s.binary("%", &minus_seven, &three);
+        let result = s.list(vec![x, and, or, xor, shr, not, modulo]);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, seven.clone()),
+                Stmt::Expr(add),
+                Stmt::Expr(shift),
+                Stmt::Expr(rem),
+                Stmt::Result(result),
+            ],
+        );
+
+        // fn flags() -> bool {
+        //     true ^ true | false & true
+        // }
+        let boolean = s.bool_ty();
+        let flags = s.function("flags", &[], &boolean, None);
+        let yes = s.boolean(true);
+        let no = s.boolean(false);
+        let xor = s.binary("^", &yes, &yes);
+        let and = s.binary("&", &no, &yes);
+        let or = s.binary("|", &xor, &and);
+        s.statements(&flags, vec![Stmt::Result(or)]);
+
+        // fn wide() -> int { 1 << 64 }, and so on
+        let one = s.int(1);
+        let sixty_four = s.int(64);
+        let min = s.int(i64::MIN);
+        let minus_one = s.unary("-", &one);
+        for (name, op, lhs, rhs) in [
+            ("wide", "<<", &one, &sixty_four),
+            ("negative", ">>", &one, &minus_one),
+            ("zero", "%", &one, &zero),
+            ("min", "%", &min, &minus_one),
+        ] {
+            let func = s.function(name, &[], &int, None);
+            let expr = s.binary(op, lhs, rhs);
+            s.statements(&func, vec![Stmt::Result(expr)]);
+        }
+
+        let mut interpreter = Interpreter::with_output(&s.store, io::sink());
+        assert_eq!(
+            interpreter.run().unwrap().to_string(),
+            "[5, 8, 15, 6, -4, -1, -1]"
+        );
+        assert_eq!(
+            interpreter.call("flags", vec![]).unwrap(),
+            Value::Boolean(false)
+        );
+
+        for (name, overflow) in [
+            ("wide", true),
+            ("negative", true),
+            ("zero", false),
+            ("min", true),
+        ] {
+            match interpreter.call(name, vec![]) {
+                Err(RuntimeError::Overflow { .. }) => assert!(overflow, "{}", name),
+                Err(RuntimeError::DivisionByZero { .. }) => assert!(!overflow, "{}", name),
+                result => panic!("{}: {:?}", name, result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut s = Sample::new("errors");
+
+        // fn main() -> int {
+        //     let a = 1;
+        //     a / 0
+        // }
+        let int = s.int_ty();
+        let main = s.function("main", &[], &int, None);
+        let a = s.var("a");
+        let one = s.int(1);
+        let zero = s.int(0);
+        let div = s.binary("/", &a, &zero);
+        s.span(&div, &main, 36, 41);
+        s.statements(
+            &main,
+            vec![Stmt::Let("a", None, one.clone()), Stmt::Result(div)],
+        );
+
+        // fn leak() -> int {
+        //     {
+        //         let b = 1;
+        //     }
+        //     b
+        // }
+        let leak = s.function("leak", &[], &int, None);
+        let inner = s.block(&leak);
+        s.statements(&inner, vec![Stmt::Let("b", None, one)]);
+        let inner = s.block_expr(&inner);
+        let b = s.var("b");
+        s.statements(&leak, vec![Stmt::Expr(inner), Stmt::Result(b)]);
+
+        let mut interpreter = Interpreter::with_output(&s.store, io::sink());
+
+        let error = interpreter.run().unwrap_err();
+        assert!(matches!(error, RuntimeError::DivisionByZero { .. }));
+        let span = error.span().unwrap();
+        assert_eq!((span.start, span.end), (36, 41));
+
+        let error = interpreter.call("leak", vec![]).unwrap_err();
+        assert!(matches!(error, RuntimeError::UnknownVariable { ref name, .. } if name == "b"));
+        assert!(error.span().is_none());
+
+        assert!(matches!(
+            interpreter.call("missing", vec![]),
+            Err(RuntimeError::UnknownFunction { .. })
+        ));
+    }
+}
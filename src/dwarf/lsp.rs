@@ -0,0 +1,1014 @@
+//! Dwarf Language Server
+//!
+//! A [Language Server Protocol](https://microsoft.github.io/language-server-protocol/)
+//! server for dwarf, that speaks JSON-RPC over a pair of streams, usually
+//! stdin and stdout. The parser lives elsewhere, so it's behind the [`Parser`]
+//! trait. Each time that a document changes it's parsed into a store of it's
+//! own, and then
+//!
+//!  - names are [resolved](crate::dwarf::resolve) and the program is
+//!    [checked](crate::dwarf::check), and the problems published as diagnostics
+//!  - an [`Index`] is built to answer hover, go-to-definition and references
+//!  - the items in the store are the document's symbols
+//!  - the fields of structs and the variants of enums are offered as
+//!    completions after `.` and `::`
+//!
+//! Documents are synced in full. When a document doesn't parse, the syntax
+//! errors are published, and the last store that did parse is kept for the
+//! other queries, along with the text it was parsed from.
+//!
+//! Positions in the protocol are zero-based lines, and columns counted in
+//! UTF-16 code units.
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::dwarf::{check, resolve, source, Index, Printer};
+use crate::v2::lu_dog_vec::{ItemEnum, ObjectStore as LuDogStore, Span, ValueTypeEnum};
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+// LSP symbol and completion item kinds
+const SYMBOL_CLASS: u32 = 5;
+const SYMBOL_METHOD: u32 = 6;
+const SYMBOL_ENUM: u32 = 10;
+const SYMBOL_FUNCTION: u32 = 12;
+const SYMBOL_STRUCT: u32 = 23;
+const COMPLETION_METHOD: u32 = 2;
+const COMPLETION_FIELD: u32 = 5;
+const COMPLETION_ENUM_MEMBER: u32 = 20;
+
+/// Turns dwarf source into a lu_dog store
+///
+/// The store should have a [`DwarfSourceFile`](crate::v2::lu_dog_vec::DwarfSourceFile)
+/// with the path that was given, and spans into it.
+pub trait Parser {
+    fn parse(&mut self, path: &str, source: &str) -> Result<LuDogStore, Vec<SyntaxError>>;
+}
+
+impl<F> Parser for F
+where
+    F: FnMut(&str, &str) -> Result<LuDogStore, Vec<SyntaxError>>,
+{
+    fn parse(&mut self, path: &str, source: &str) -> Result<LuDogStore, Vec<SyntaxError>> {
+        self(path, source)
+    }
+}
+
+/// Something that the parser didn't like, between two byte offsets
+///
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SyntaxError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An open document
+struct Document {
+    parsed: Option<Parsed>,
+}
+
+/// The result of the last successful parse of a document
+struct Parsed {
+    /// The text that was parsed, which positions are relative to
+    text: String,
+    store: LuDogStore,
+    /// The id of the document's source file
+    source: usize,
+    index: Index,
+}
+
+/// The language server
+///
+pub struct Server<P: Parser> {
+    parser: P,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+impl<P: Parser> Server<P> {
+    pub fn new(parser: P) -> Self {
+        Self {
+            parser,
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    /// Serve requests until the client says to exit
+    ///
+    /// A message that isn't JSON is answered with a parse error. Only a broken
+    /// stream, or a message without a `Content-Length` header, ends the loop
+    /// early.
+    ///
+    pub fn run<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> io::Result<()> {
+        while let Some(message) = read_message(&mut input)? {
+            let message = match message {
+                Ok(message) => message,
+                Err(e) => {
+                    let reply = error(Value::Null, PARSE_ERROR, &e.to_string());
+                    write_message(&mut output, &reply)?;
+                    continue;
+                }
+            };
+            let exit = message["method"] == "exit";
+            for message in self.handle(message) {
+                write_message(&mut output, &message)?;
+            }
+            if exit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle one message from the client, returning the messages to send
+    /// back
+    ///
+    pub fn handle(&mut self, message: Value) -> Vec<Value> {
+        let method = match message["method"].as_str() {
+            Some(method) => method.to_owned(),
+            // A response to something that we didn't ask.
+            None => return Vec::new(),
+        };
+        let params = &message["params"];
+
+        let id = match message.get("id") {
+            Some(id) => id.clone(),
+            None => return self.notification(&method, params),
+        };
+        if self.shutdown && method != "shutdown" {
+            return vec![error(id, INVALID_REQUEST, "the server is shutting down")];
+        }
+
+        let result = match method.as_str() {
+            "initialize" => Some(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "referencesProvider": true,
+                    "documentSymbolProvider": true,
+                    "completionProvider": { "triggerCharacters": [".", ":"] },
+                },
+                "serverInfo": { "name": "dwarf-lsp", "version": crate::VERSION },
+            })),
+            "shutdown" => {
+                self.shutdown = true;
+                Some(Value::Null)
+            }
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/references" => self.references(params),
+            "textDocument/documentSymbol" => self.symbols(params),
+            "textDocument/completion" => self.completion(params),
+            _ => {
+                let message = format!("unknown method `{}`", method);
+                return vec![error(id, METHOD_NOT_FOUND, &message)];
+            }
+        };
+
+        match result {
+            Some(result) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            None => vec![error(id, INVALID_PARAMS, "no such document or position")],
+        }
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = match params["textDocument"]["uri"].as_str() {
+            Some(uri) => uri.to_owned(),
+            None => return Vec::new(),
+        };
+
+        match method {
+            "textDocument/didOpen" => match params["textDocument"]["text"].as_str() {
+                Some(text) => vec![self.update(uri, text.to_owned())],
+                None => Vec::new(),
+            },
+            // Documents are synced in full, so the last change is the text.
+            "textDocument/didChange" => {
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                match text {
+                    Some(text) => vec![self.update(uri, text.to_owned())],
+                    None => Vec::new(),
+                }
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                vec![publish(&uri, Vec::new())]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Parse, resolve, check, and index a document, returning it's diagnostics
+    fn update(&mut self, uri: String, text: String) -> Value {
+        let path = uri_to_path(&uri);
+        let parsed = self.parser.parse(&path, &text);
+        let document = self
+            .documents
+            .entry(uri.clone())
+            .or_insert(Document { parsed: None });
+
+        let mut store = match parsed {
+            Ok(store) => store,
+            Err(errors) => {
+                let diagnostics = errors
+                    .iter()
+                    .map(|error| {
+                        json!({
+                            "range": {
+                                "start": position(&text, error.start),
+                                "end": position(&text, error.end),
+                            },
+                            "severity": 1,
+                            "source": "dwarf",
+                            "message": error.message,
+                        })
+                    })
+                    .collect();
+                return publish(&uri, diagnostics);
+            }
+        };
+
+        let source = source::source_file(&store, &path)
+            .or_else(|| store.iter_dwarf_source_file().next())
+            .map(|source| source.borrow().id);
+        let source = match source {
+            Some(source) => source,
+            None => {
+                document.parsed = None;
+                return publish(&uri, Vec::new());
+            }
+        };
+
+        let mut problems = resolve(&mut store);
+        problems.extend(check(&mut store));
+        let diagnostics = problems
+            .iter()
+            .filter(|problem| {
+                problem
+                    .span
+                    .as_ref()
+                    .map_or(true, |span| span.source == source)
+            })
+            .map(|problem| {
+                let range = match problem.span {
+                    Some(ref span) => range(&store, span),
+                    None => json!({
+                        "start": { "line": 0, "character": 0 },
+                        "end": { "line": 0, "character": 0 },
+                    }),
+                };
+                json!({
+                    "range": range,
+                    "severity": 1,
+                    "source": "dwarf",
+                    "message": problem.to_string(),
+                })
+            })
+            .collect();
+
+        let index = Index::new(&store);
+        document.parsed = Some(Parsed {
+            text,
+            store,
+            source,
+            index,
+        });
+
+        publish(&uri, diagnostics)
+    }
+
+    /// The last parse of the document, and the byte offset of the position,
+    /// of a request
+    ///
+    /// The position is taken against the text that was parsed, so that the
+    /// spans still line up after an edit that doesn't parse.
+    fn locate(&self, params: &Value) -> Option<(&Parsed, usize)> {
+        let document = self
+            .documents
+            .get(params["textDocument"]["uri"].as_str()?)?;
+        let parsed = document.parsed.as_ref()?;
+        let offset = offset(&parsed.text, &params["position"])?;
+
+        Some((parsed, offset))
+    }
+
+    fn hover(&self, params: &Value) -> Option<Value> {
+        let (parsed, offset) = self.locate(params)?;
+        let hover = match parsed.index.hover(&parsed.store, parsed.source, offset) {
+            Some(hover) => hover,
+            None => return Some(Value::Null),
+        };
+
+        Some(match hover.ty {
+            Some(ty) => json!({
+                "contents": { "kind": "markdown", "value": format!("```dwarf\n{}\n```", ty) },
+                "range": range(&parsed.store, &hover.span),
+            }),
+            None => Value::Null,
+        })
+    }
+
+    fn definition(&self, params: &Value) -> Option<Value> {
+        let (parsed, offset) = self.locate(params)?;
+        let uri = params["textDocument"]["uri"].as_str()?;
+
+        Some(match parsed.index.definition_at(parsed.source, offset) {
+            Some(span) => location(&parsed.store, span, uri),
+            None => Value::Null,
+        })
+    }
+
+    fn references(&self, params: &Value) -> Option<Value> {
+        let (parsed, offset) = self.locate(params)?;
+        let uri = params["textDocument"]["uri"].as_str()?;
+
+        let mut spans = parsed.index.references_at(parsed.source, offset);
+        if params["context"]["includeDeclaration"] == true {
+            let symbol = parsed
+                .index
+                .at(parsed.source, offset)
+                .and_then(|occurrence| occurrence.symbol);
+            if let Some(span) = symbol.and_then(|symbol| parsed.index.definition(symbol)) {
+                spans.insert(0, span);
+            }
+        }
+
+        Some(Value::Array(
+            spans
+                .into_iter()
+                .map(|span| location(&parsed.store, span, uri))
+                .collect(),
+        ))
+    }
+
+    /// The items in the document, with the functions of implementation blocks
+    /// inside of them
+    fn symbols(&self, params: &Value) -> Option<Value> {
+        let document = self
+            .documents
+            .get(params["textDocument"]["uri"].as_str()?)?;
+        let parsed = match document.parsed {
+            Some(ref parsed) => parsed,
+            None => return Some(json!([])),
+        };
+        let store = &parsed.store;
+
+        let mut symbols = Vec::new();
+        for item in store.iter_item() {
+            let item = item.borrow();
+            if item.source != parsed.source {
+                continue;
+            }
+
+            let symbol = match item.subtype {
+                ItemEnum::Enumeration(ref id) => {
+                    let name = store.exhume_enumeration(id).unwrap().borrow().name.clone();
+                    let span = type_span(store, &ValueTypeEnum::Enumeration(*id));
+                    symbol_at(store, name, span, SYMBOL_ENUM, Vec::new())
+                }
+                ItemEnum::Function(ref id) => {
+                    let func = store.exhume_function(id).unwrap();
+                    let func = func.borrow();
+                    // Functions in implementation blocks go in their block.
+                    if func.impl_block.is_some() {
+                        continue;
+                    }
+                    let span = type_span(store, &ValueTypeEnum::Function(*id));
+                    symbol_at(store, func.name.clone(), span, SYMBOL_FUNCTION, Vec::new())
+                }
+                ItemEnum::ImplementationBlock(ref id) => {
+                    let block = store.exhume_implementation_block(id).unwrap();
+                    let block = block.borrow();
+                    let (name, ty) = match (block.model_type, block.enumeration) {
+                        (Some(ref id), _) => (
+                            store.exhume_woog_struct(id).unwrap().borrow().name.clone(),
+                            ValueTypeEnum::WoogStruct(*id),
+                        ),
+                        (None, Some(ref id)) => (
+                            store.exhume_enumeration(id).unwrap().borrow().name.clone(),
+                            ValueTypeEnum::Enumeration(*id),
+                        ),
+                        (None, None) => continue,
+                    };
+                    let children = store
+                        .iter_function()
+                        .filter(|func| func.borrow().impl_block == Some(*id))
+                        .map(|func| {
+                            let func = func.borrow();
+                            let span = type_span(store, &ValueTypeEnum::Function(func.id));
+                            symbol_at(store, func.name.clone(), span, SYMBOL_METHOD, Vec::new())
+                        })
+                        .collect();
+                    let span = type_span(store, &ty);
+                    symbol_at(
+                        store,
+                        format!("impl {}", name),
+                        span,
+                        SYMBOL_CLASS,
+                        children,
+                    )
+                }
+                ItemEnum::WoogStruct(ref id) => {
+                    let name = store.exhume_woog_struct(id).unwrap().borrow().name.clone();
+                    let span = type_span(store, &ValueTypeEnum::WoogStruct(*id));
+                    symbol_at(store, name, span, SYMBOL_STRUCT, Vec::new())
+                }
+                ItemEnum::Import(_) | ItemEnum::XMacro(_) => continue,
+            };
+            symbols.push(symbol);
+        }
+
+        Some(Value::Array(symbols))
+    }
+
+    /// The fields and methods of the receiver before a `.`, or the variants
+    /// and functions of the type before a `::`
+    fn completion(&self, params: &Value) -> Option<Value> {
+        let (parsed, offset) = self.locate(params)?;
+        let store = &parsed.store;
+
+        // Skip over whatever has been typed of the name so far.
+        let before = &parsed.text[..offset];
+        let stem = before.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_');
+
+        let ty = if let Some(receiver) = stem.strip_suffix('.') {
+            let end = receiver.trim_end().len();
+            let occurrence = parsed
+                .index
+                .at(parsed.source, end.saturating_sub(1))
+                .filter(|occurrence| occurrence.span.end as usize == end);
+            occurrence.and_then(|occurrence| {
+                let value = occurrence
+                    .value
+                    .and_then(|value| store.exhume_x_value(&value))
+                    .map(|value| value.borrow().ty);
+                value
+                    .into_iter()
+                    .chain(occurrence.span.ty)
+                    .map(|ty| {
+                        store
+                            .exhume_value_type(&ty)
+                            .unwrap()
+                            .borrow()
+                            .subtype
+                            .clone()
+                    })
+                    .find(|ty| {
+                        matches!(
+                            ty,
+                            ValueTypeEnum::WoogStruct(_) | ValueTypeEnum::Enumeration(_)
+                        )
+                    })
+            })
+        } else if let Some(path) = stem.strip_suffix("::") {
+            let name = &path[path
+                .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map_or(0, |index| index + 1)..];
+            match (
+                store.exhume_woog_struct_id_by_name(name),
+                store.exhume_enumeration_id_by_name(name),
+            ) {
+                (Some(id), _) => Some(ValueTypeEnum::WoogStruct(id)),
+                (None, Some(id)) => Some(ValueTypeEnum::Enumeration(id)),
+                (None, None) => None,
+            }
+        } else {
+            None
+        };
+
+        let printer = Printer::new(store);
+        let mut items = Vec::new();
+        let functions = match ty {
+            Some(ValueTypeEnum::WoogStruct(id)) => {
+                for field in store
+                    .iter_field()
+                    .filter(|field| field.borrow().x_model == id)
+                {
+                    let field = field.borrow();
+                    let ty = store.exhume_value_type(&field.ty).unwrap();
+                    items.push(json!({
+                        "label": field.name,
+                        "kind": COMPLETION_FIELD,
+                        "detail": printer.print_type(&ty.borrow()),
+                    }));
+                }
+                store
+                    .iter_implementation_block()
+                    .filter(|block| block.borrow().model_type == Some(id))
+                    .map(|block| block.borrow().id)
+                    .collect::<Vec<_>>()
+            }
+            Some(ValueTypeEnum::Enumeration(id)) => {
+                for field in store
+                    .iter_enum_field()
+                    .filter(|field| field.borrow().woog_enum == id)
+                {
+                    items.push(json!({
+                        "label": field.borrow().name,
+                        "kind": COMPLETION_ENUM_MEMBER,
+                    }));
+                }
+                store
+                    .iter_implementation_block()
+                    .filter(|block| block.borrow().enumeration == Some(id))
+                    .map(|block| block.borrow().id)
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        for func in store.iter_function().filter(|func| {
+            func.borrow()
+                .impl_block
+                .map_or(false, |id| functions.contains(&id))
+        }) {
+            items.push(json!({
+                "label": func.borrow().name,
+                "kind": COMPLETION_METHOD,
+            }));
+        }
+
+        Some(json!({ "isIncomplete": false, "items": items }))
+    }
+}
+
+/// The span of the definition of a type, if there is one
+///
+/// Definitions are spans of their type that aren't spans of a value.
+fn type_span(store: &LuDogStore, ty: &ValueTypeEnum) -> Option<Span> {
+    store
+        .iter_span()
+        .find(|span| {
+            let span = span.borrow();
+            span.x_value.is_none()
+                && span.ty.map_or(false, |id| {
+                    store.exhume_value_type(&id).unwrap().borrow().subtype == *ty
+                })
+        })
+        .map(|span| span.borrow().clone())
+}
+
+fn symbol_at(
+    store: &LuDogStore,
+    name: String,
+    span: Option<Span>,
+    kind: u32,
+    children: Vec<Value>,
+) -> Value {
+    let range = match span {
+        Some(ref span) => range(store, span),
+        None => json!({
+            "start": { "line": 0, "character": 0 },
+            "end": { "line": 0, "character": 0 },
+        }),
+    };
+
+    json!({
+        "name": name,
+        "kind": kind,
+        "range": range,
+        "selectionRange": range,
+        "children": children,
+    })
+}
+
+fn publish(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+/// The range of a span, in it's own source file
+fn range(store: &LuDogStore, span: &Span) -> Value {
+    let source = store.exhume_dwarf_source_file(&span.source).unwrap();
+    let text = &source.borrow().source;
+    let start = span.start.max(0) as usize;
+    let end = (span.end.max(0) as usize).max(start);
+
+    json!({ "start": position(text, start), "end": position(text, end) })
+}
+
+/// The location of a span, in the document with the uri, unless the span is in
+/// some other file
+fn location(store: &LuDogStore, span: &Span, uri: &str) -> Value {
+    let source = store.exhume_dwarf_source_file(&span.source).unwrap();
+    let path = source.borrow().path.clone();
+    let uri = if path.is_empty() || path == uri_to_path(uri) {
+        uri.to_owned()
+    } else {
+        path_to_uri(&path)
+    };
+
+    json!({ "uri": uri, "range": range(store, span) })
+}
+
+/// The LSP position of a byte offset
+fn position(text: &str, offset: usize) -> Value {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let start = before.rfind('\n').map_or(0, |index| index + 1);
+    let character: usize = before[start..].chars().map(char::len_utf16).sum();
+
+    json!({ "line": line, "character": character })
+}
+
+/// The byte offset of an LSP position
+fn offset(text: &str, position: &Value) -> Option<usize> {
+    let line = position["line"].as_u64()? as usize;
+    let character = position["character"].as_u64()? as usize;
+
+    let start = if line == 0 {
+        0
+    } else {
+        text.match_indices('\n').nth(line - 1)?.0 + 1
+    };
+    let mut units = 0;
+    for (index, c) in text[start..].char_indices() {
+        if units >= character || c == '\n' {
+            return Some(start + index);
+        }
+        units += c.len_utf16();
+    }
+
+    Some(text.len())
+}
+
+fn uri_to_path(uri: &str) -> String {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut chars = path.bytes();
+    while let Some(b) = chars.next() {
+        if b == b'%' {
+            let hex: Vec<u8> = chars.by_ref().take(2).collect();
+            match std::str::from_utf8(&hex)
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                Some(b) => bytes.push(b),
+                None => {
+                    bytes.push(b'%');
+                    bytes.extend(hex);
+                }
+            }
+        } else {
+            bytes.push(b);
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn path_to_uri(path: &str) -> String {
+    let mut uri = String::from("file://");
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                uri.push(b as char)
+            }
+            _ => uri += &format!("%{:02X}", b),
+        }
+    }
+
+    uri
+}
+
+/// Read a message, framed by a `Content-Length` header
+///
+/// The outer error is for the stream, the inner one for a body that isn't
+/// JSON.
+fn read_message<R: BufRead>(input: &mut R) -> io::Result<Option<serde_json::Result<Value>>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let length = length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length header")
+    })?;
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+
+    Ok(Some(serde_json::from_slice(&body)))
+}
+
+fn write_message<W: Write>(output: &mut W, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::sample::{Sample, Stmt};
+    use crate::v2::lu_dog_vec::{ValueType, XValueEnum};
+
+    const URI: &str = "file:///work/main.tao";
+    const TEXT: &str = "struct Point {\n    x: int,\n}\n\nfn main() {\n    let p = Point { x: 1 };\n    p.x + missing;\n}\n";
+
+    /// A parser that only knows the one program, and doesn't like dollars
+    fn parse(path: &str, text: &str) -> Result<LuDogStore, Vec<SyntaxError>> {
+        if let Some(start) = text.find('$') {
+            return Err(vec![SyntaxError {
+                message: "unexpected `$`".to_owned(),
+                start,
+                end: start + 1,
+            }]);
+        }
+
+        let mut s = Sample::new("main");
+        let source = s.source();
+        source.borrow_mut().path = path.to_owned();
+        source.borrow_mut().set_source(TEXT.to_owned());
+        let at = |needle: &str| TEXT.find(needle).unwrap() as i64;
+
+        let int = s.int_ty();
+        let point = s.structure("Point", &[("x", int)]);
+        let point_ty = s.struct_ty(&point);
+        Span::new(
+            at("Point") + 5,
+            at("Point"),
+            &source,
+            Some(&point_ty),
+            None,
+            &mut s.store,
+        );
+
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let origin = s.struct_expr(&point, vec![("x", one)]);
+        let p = s.var("p");
+        let x = s.field(&p, &point, "x");
+        let missing = s.var("missing");
+        let sum = s.binary("+", &x, &missing);
+        s.statements(
+            &main,
+            vec![Stmt::Let("p", None, origin), Stmt::Expr(sum.clone())],
+        );
+
+        let func = s.store.iter_function().next().unwrap();
+        let func_ty = ValueType::new_function(false, &func, &mut s.store);
+        Span::new(
+            at("main") + 4,
+            at("main"),
+            &source,
+            Some(&func_ty),
+            None,
+            &mut s.store,
+        );
+        let var = s.store.iter_variable().next().unwrap().borrow().id;
+        let value = s
+            .store
+            .iter_x_value()
+            .find(|value| value.borrow().subtype == XValueEnum::Variable(var))
+            .unwrap();
+        Span::new(
+            at("p =") + 1,
+            at("p ="),
+            &source,
+            None,
+            Some(&value),
+            &mut s.store,
+        );
+        s.span(&p, &main, at("p.x"), at("p.x") + 1);
+        s.span(&x, &main, at("p.x"), at("p.x") + 3);
+        s.span(&missing, &main, at("missing"), at("missing") + 7);
+        s.span(&sum, &main, at("p.x"), at("missing") + 7);
+
+        Ok(s.store)
+    }
+
+    fn request(id: i64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    fn at(line: u32, character: u32) -> Value {
+        json!({
+            "textDocument": { "uri": URI },
+            "position": { "line": line, "character": character },
+        })
+    }
+
+    #[test]
+    fn test_queries() {
+        let mut server = Server::new(parse);
+        let response = server.handle(request(1, "initialize", json!({})));
+        assert_eq!(response[0]["result"]["capabilities"]["hoverProvider"], true);
+
+        let opened = server.handle(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": URI, "languageId": "dwarf", "version": 1, "text": TEXT } },
+        }));
+        let diagnostics = &opened[0]["params"]["diagnostics"];
+        assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+        assert_eq!(diagnostics[0]["message"], "cannot find `missing`");
+        assert_eq!(
+            diagnostics[0]["range"],
+            json!({ "start": { "line": 6, "character": 10 }, "end": { "line": 6, "character": 17 } })
+        );
+
+        let hover = &server.handle(request(2, "textDocument/hover", at(6, 4)))[0]["result"];
+        assert_eq!(hover["contents"]["value"], "```dwarf\nPoint\n```");
+
+        let definition =
+            &server.handle(request(3, "textDocument/definition", at(6, 4)))[0]["result"];
+        assert_eq!(definition["uri"], URI);
+        assert_eq!(
+            definition["range"]["start"],
+            json!({ "line": 5, "character": 8 })
+        );
+
+        let symbols = &server.handle(request(
+            4,
+            "textDocument/documentSymbol",
+            json!({ "textDocument": { "uri": URI } }),
+        ))[0]["result"];
+        let names: Vec<_> = symbols
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|symbol| symbol["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Point", "main"]);
+        assert_eq!(
+            symbols[1]["range"]["start"],
+            json!({ "line": 4, "character": 3 })
+        );
+
+        let completion =
+            &server.handle(request(5, "textDocument/completion", at(6, 6)))[0]["result"];
+        assert_eq!(
+            completion["items"],
+            json!([{ "label": "x", "kind": COMPLETION_FIELD, "detail": "int" }])
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let mut server = Server::new(parse);
+        let open = |text: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didChange",
+                "params": {
+                    "textDocument": { "uri": URI, "version": 2 },
+                    "contentChanges": [{ "text": text }],
+                },
+            })
+        };
+        server.handle(open(TEXT));
+
+        let broken = TEXT.replace("missing", "miss$ing");
+        let changed = server.handle(open(&broken));
+        let diagnostics = &changed[0]["params"]["diagnostics"];
+        assert_eq!(diagnostics[0]["message"], "unexpected `$`");
+        assert_eq!(
+            diagnostics[0]["range"]["start"],
+            json!({ "line": 6, "character": 14 })
+        );
+
+        // The last program that parsed is still there.
+        let hover = &server.handle(request(1, "textDocument/hover", at(6, 4)))[0]["result"];
+        assert_eq!(hover["contents"]["value"], "```dwarf\nPoint\n```");
+
+        let unknown = server.handle(request(2, "textDocument/rename", at(6, 4)));
+        assert_eq!(unknown[0]["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_queries_after_broken_edit() {
+        let mut server = Server::new(parse);
+        let change = |text: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didChange",
+                "params": {
+                    "textDocument": { "uri": URI, "version": 2 },
+                    "contentChanges": [{ "text": text }],
+                },
+            })
+        };
+        server.handle(change(TEXT));
+
+        // Push everything down a line, and cut the end off.
+        let broken = format!("$\n{}", &TEXT[..TEXT.find("missing").unwrap()]);
+        server.handle(change(&broken));
+
+        // Positions are in the text that parsed, not the broken one.
+        let hover = &server.handle(request(1, "textDocument/hover", at(6, 4)))[0]["result"];
+        assert_eq!(hover["contents"]["value"], "```dwarf\nPoint\n```");
+
+        let definition =
+            &server.handle(request(2, "textDocument/definition", at(6, 4)))[0]["result"];
+        assert_eq!(
+            definition["range"]["start"],
+            json!({ "line": 5, "character": 8 })
+        );
+
+        let completion =
+            &server.handle(request(3, "textDocument/completion", at(6, 6)))[0]["result"];
+        assert_eq!(
+            completion["items"],
+            json!([{ "label": "x", "kind": COMPLETION_FIELD, "detail": "int" }])
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut input = Vec::new();
+        for message in [
+            request(1, "initialize", json!({})),
+            request(2, "shutdown", Value::Null),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ] {
+            write_message(&mut input, &message).unwrap();
+        }
+
+        let mut output = Vec::new();
+        Server::new(parse).run(&input[..], &mut output).unwrap();
+
+        let mut output = &output[..];
+        let first = read_message(&mut output).unwrap().unwrap().unwrap();
+        assert_eq!(first["id"], 1);
+        let second = read_message(&mut output).unwrap().unwrap().unwrap();
+        assert_eq!(second, json!({ "jsonrpc": "2.0", "id": 2, "result": null }));
+        assert!(read_message(&mut output).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_run_after_bad_json() {
+        let mut input = b"Content-Length: 5\r\n\r\n{oops".to_vec();
+        write_message(&mut input, &request(1, "initialize", json!({}))).unwrap();
+
+        let mut output = Vec::new();
+        Server::new(parse).run(&input[..], &mut output).unwrap();
+
+        let mut output = &output[..];
+        let first = read_message(&mut output).unwrap().unwrap().unwrap();
+        assert_eq!(first["id"], Value::Null);
+        assert_eq!(first["error"]["code"], PARSE_ERROR);
+        let second = read_message(&mut output).unwrap().unwrap().unwrap();
+        assert_eq!(second["id"], 1);
+        assert!(read_message(&mut output).unwrap().is_none());
+
+        // No header at all is still the end of the line.
+        let mut output = Vec::new();
+        let input = b"\r\n{}";
+        assert!(Server::new(parse).run(&input[..], &mut output).is_err());
+    }
+
+    #[test]
+    fn test_uris() {
+        assert_eq!(
+            uri_to_path("file:///my%20work/main.tao"),
+            "/my work/main.tao"
+        );
+        assert_eq!(
+            path_to_uri("/my work/main.tao"),
+            "file:///my%20work/main.tao"
+        );
+    }
+}
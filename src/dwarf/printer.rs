@@ -0,0 +1,1749 @@
+//! Dwarf Pretty Printer
+//!
+//! The lu_dog store holds a complete dwarf program, and this turns it back
+//! into source. The output is canonical: it depends only on what's in the
+//! store, and not on how the original source was written. Comments and blank
+//! lines are lost, parentheses are added only where precedence requires them
+//! (or where the program was written with them), and lists are wrapped one
+//! element per line when they don't fit on one.
+//!
+//! Here's what it looks like:
+//!
+//! ```text
+//! use std::option::Option;
+//!
+//! struct Point {
+//!     x: int,
+//!     y: int,
+//! }
+//!
+//! impl Point {
+//!     fn norm(self) -> int {
+//!         self.x * self.x + self.y * self.y
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let p = Point { x: 3, y: 4 };
+//!     for i in 0..p.norm() {
+//!         print("{i}\n");
+//!     }
+//! }
+//! ```
+//!
+//! Items are printed in the order in which they were added to the store, which
+//! is the order in which they appear in the source.
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::v2::{
+    lu_dog_vec::{
+        BinaryEnum, Block, BodyEnum, BooleanLiteralEnum, BooleanOperatorEnum, CallEnum,
+        ComparisonEnum, DwarfSourceFile, EnumFieldEnum, Enumeration, Expression, ExpressionEnum,
+        FieldAccessTargetEnum, FieldExpressionEnum, FormatBitEnum, Function, ImplementationBlock,
+        Import, Item, ItemEnum, Lambda, LiteralEnum, ObjectStore as LuDogStore, Operator,
+        OperatorEnum, RangeExpressionEnum, Statement, StatementEnum, UnaryEnum, ValueType,
+        ValueTypeEnum, VariableEnum, WoogStruct, XValueEnum, ADDITION, BITWISE_AND, BITWISE_OR,
+        BITWISE_XOR, DIVISION, MULTIPLICATION, REMAINDER, SHIFT_LEFT, SHIFT_RIGHT, SUBTRACTION,
+    },
+    sarzak::types::{BOOLEAN, FLOAT, INTEGER, Z_STRING, Z_UUID},
+};
+
+const DEFAULT_INDENT: usize = 4;
+const DEFAULT_WIDTH: usize = 100;
+
+/// Operator precedence, from loosest to tightest
+///
+/// Prefix operators that extend as far to the right as they can, like `return`
+/// and lambdas, are the loosest of all.
+mod precedence {
+    pub const OPEN: u8 = 0;
+    pub const ASSIGNMENT: u8 = 1;
+    pub const RANGE: u8 = 2;
+    pub const OR: u8 = 3;
+    pub const AND: u8 = 4;
+    pub const COMPARISON: u8 = 5;
+    pub const BIT_OR: u8 = 6;
+    pub const BIT_XOR: u8 = 7;
+    pub const BIT_AND: u8 = 8;
+    pub const SHIFT: u8 = 9;
+    pub const ADDITIVE: u8 = 10;
+    pub const MULTIPLICATIVE: u8 = 11;
+    pub const CAST: u8 = 12;
+    pub const UNARY: u8 = 13;
+    pub const POSTFIX: u8 = 14;
+    pub const PRIMARY: u8 = 15;
+}
+
+/// The operator that a compound assignment applies, e.g. `+` for `+=`
+///
+/// `None` if the operator isn't a compound assignment.
+pub(crate) fn compound(binary: &BinaryEnum) -> Option<BinaryEnum> {
+    match binary {
+        BinaryEnum::AdditionAssignment(_) => Some(BinaryEnum::Addition(ADDITION)),
+        BinaryEnum::BitwiseAndAssignment(_) => Some(BinaryEnum::BitwiseAnd(BITWISE_AND)),
+        BinaryEnum::BitwiseOrAssignment(_) => Some(BinaryEnum::BitwiseOr(BITWISE_OR)),
+        BinaryEnum::BitwiseXorAssignment(_) => Some(BinaryEnum::BitwiseXor(BITWISE_XOR)),
+        BinaryEnum::DivisionAssignment(_) => Some(BinaryEnum::Division(DIVISION)),
+        BinaryEnum::MultiplicationAssignment(_) => Some(BinaryEnum::Multiplication(MULTIPLICATION)),
+        BinaryEnum::RemainderAssignment(_) => Some(BinaryEnum::Remainder(REMAINDER)),
+        BinaryEnum::ShiftLeftAssignment(_) => Some(BinaryEnum::ShiftLeft(SHIFT_LEFT)),
+        BinaryEnum::ShiftRightAssignment(_) => Some(BinaryEnum::ShiftRight(SHIFT_RIGHT)),
+        BinaryEnum::SubtractionAssignment(_) => Some(BinaryEnum::Subtraction(SUBTRACTION)),
+        _ => None,
+    }
+}
+
+/// Print dwarf source from a lu_dog store
+///
+/// ```
+/// # use sarzak::dwarf::Printer;
+/// # use sarzak::v2::lu_dog_vec::ObjectStore;
+/// let store = ObjectStore::new();
+/// let printer = Printer::new(&store).indent(2).width(80);
+///
+/// for source in store.iter_dwarf_source_file() {
+///     print!("{}", printer.print_source(&source.borrow()));
+/// }
+/// ```
+pub struct Printer<'a> {
+    store: &'a LuDogStore,
+    indent: usize,
+    width: usize,
+}
+
+impl<'a> Printer<'a> {
+    /// Create a printer with four space indentation, and lines of at most 100
+    /// characters
+    ///
+    pub fn new(store: &'a LuDogStore) -> Self {
+        Self {
+            store,
+            indent: DEFAULT_INDENT,
+            width: DEFAULT_WIDTH,
+        }
+    }
+
+    /// Set the number of spaces per level of indentation
+    ///
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the line width
+    ///
+    /// Lines may still run over if there's nowhere to break them.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Print every item in a source file
+    ///
+    pub fn print_source(&self, source: &DwarfSourceFile) -> String {
+        let items = self
+            .store
+            .iter_item()
+            .filter(|item| item.borrow().source == source.id)
+            .filter(|item| match item.borrow().subtype {
+                // Functions in impl blocks are printed by their impl block.
+                ItemEnum::Function(ref id) => self.function(id).borrow().impl_block.is_none(),
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+
+        let mut writer = Writer::new(self);
+        let mut previous: Option<bool> = None;
+        for item in items {
+            let item = item.borrow();
+            let is_import = matches!(item.subtype, ItemEnum::Import(_));
+            match previous {
+                Some(true) if is_import => writer.newline(),
+                Some(_) => {
+                    writer.newline();
+                    writer.newline();
+                }
+                None => {}
+            }
+            writer.item(&item);
+            previous = Some(is_import);
+        }
+        if previous.is_some() {
+            writer.newline();
+        }
+
+        writer.out
+    }
+
+    /// Print a single item
+    ///
+    pub fn print_item(&self, item: &Item) -> String {
+        let mut writer = Writer::new(self);
+        writer.item(item);
+        writer.out
+    }
+
+    /// Print an expression, as though it started at the beginning of a line
+    ///
+    pub fn print_expression(&self, expr: &Expression) -> String {
+        let mut writer = Writer::new(self);
+        writer.expression(expr, precedence::OPEN);
+        writer.out
+    }
+
+    /// Print a type
+    ///
+    pub fn print_type(&self, ty: &ValueType) -> String {
+        let mut writer = Writer::new(self);
+        writer.ty(ty);
+        writer.out
+    }
+
+    fn expr(&self, id: &usize) -> Rc<RefCell<Expression>> {
+        self.store.exhume_expression(id).unwrap()
+    }
+
+    fn function(&self, id: &usize) -> Rc<RefCell<Function>> {
+        self.store.exhume_function(id).unwrap()
+    }
+
+    fn value_type(&self, id: &usize) -> Rc<RefCell<ValueType>> {
+        self.store.exhume_value_type(id).unwrap()
+    }
+
+    /// The name of a variable, by the id of it's subtype
+    ///
+    fn variable_name(&self, is: impl Fn(&VariableEnum) -> bool) -> String {
+        self.store
+            .iter_variable()
+            .find(|var| is(&var.borrow().subtype))
+            .map(|var| var.borrow().name.clone())
+            .unwrap_or_else(|| "_".to_owned())
+    }
+}
+
+/// Where the printer is writing to
+///
+/// The printer itself is immutable, and just holds the configuration. All the
+/// state is here.
+struct Writer<'a, 'b> {
+    printer: &'b Printer<'a>,
+    store: &'a LuDogStore,
+    out: String,
+    depth: usize,
+    /// The column at which `out` starts
+    offset: usize,
+}
+
+impl<'a, 'b> Writer<'a, 'b> {
+    fn new(printer: &'b Printer<'a>) -> Self {
+        Self {
+            printer,
+            store: printer.store,
+            out: String::new(),
+            depth: 0,
+            offset: 0,
+        }
+    }
+
+    /// A writer that picks up where this one is, but writes to it's own buffer
+    ///
+    fn fork(&self) -> Self {
+        Self {
+            printer: self.printer,
+            store: self.store,
+            out: String::new(),
+            depth: self.depth,
+            offset: self.column(),
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self.out.rfind('\n') {
+            Some(n) => self.out[n + 1..].chars().count(),
+            None => self.offset + self.out.chars().count(),
+        }
+    }
+
+    fn push(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn newline(&mut self) {
+        // Blank lines don't get indented.
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        self.out.push('\n');
+        self.out
+            .extend(std::iter::repeat(' ').take(self.depth * self.printer.indent));
+    }
+
+    /// Write a comma separated list
+    ///
+    /// The list is written on one line if it fits, and otherwise one element
+    /// per line, each followed by a comma. `padded` puts spaces inside the
+    /// delimiters when the list is on one line, as in `Point { x: 1 }`.
+    fn list<T>(
+        &mut self,
+        open: &str,
+        items: &[T],
+        close: &str,
+        padded: bool,
+        mut f: impl FnMut(&mut Self, &T),
+    ) {
+        if items.is_empty() {
+            self.push(open);
+            self.push(close);
+            return;
+        }
+
+        let mut flat = self.fork();
+        flat.push(open);
+        if padded {
+            flat.push(" ");
+        }
+        for (n, item) in items.iter().enumerate() {
+            if n > 0 {
+                flat.push(", ");
+            }
+            f(&mut flat, item);
+        }
+        if padded {
+            flat.push(" ");
+        }
+        flat.push(close);
+
+        if !flat.out.contains('\n') && flat.column() <= self.printer.width {
+            self.push(&flat.out);
+            return;
+        }
+
+        self.push(open);
+        self.depth += 1;
+        for item in items {
+            self.newline();
+            f(self, item);
+            self.push(",");
+        }
+        self.depth -= 1;
+        self.newline();
+        self.push(close);
+    }
+
+    //
+    // Items
+    //
+
+    fn item(&mut self, item: &Item) {
+        match item.subtype {
+            ItemEnum::Enumeration(ref id) => {
+                self.enumeration(&self.store.exhume_enumeration(id).unwrap().borrow())
+            }
+            ItemEnum::Function(ref id) => self.function(&self.printer.function(id).borrow()),
+            ItemEnum::ImplementationBlock(ref id) => {
+                self.implementation(&self.store.exhume_implementation_block(id).unwrap().borrow())
+            }
+            ItemEnum::Import(ref id) => {
+                self.import(&self.store.exhume_import(id).unwrap().borrow())
+            }
+            ItemEnum::XMacro(ref id) => {
+                let name = self.store.exhume_x_macro(id).unwrap().borrow().name.clone();
+                self.push(&format!("macro {};", name));
+            }
+            ItemEnum::WoogStruct(ref id) => {
+                self.structure(&self.store.exhume_woog_struct(id).unwrap().borrow())
+            }
+        }
+    }
+
+    fn import(&mut self, import: &Import) {
+        self.push("use ");
+        if import.x_path.ends_with(&format!("::{}", import.name)) || import.x_path == import.name {
+            self.push(&import.x_path);
+        } else {
+            self.push(&format!("{}::{}", import.x_path, import.name));
+        }
+        if import.has_alias {
+            self.push(&format!(" as {}", import.alias));
+        }
+        self.push(";");
+    }
+
+    fn structure(&mut self, woog_struct: &WoogStruct) {
+        self.push(&format!("struct {}", woog_struct.name));
+
+        let mut generics = Vec::new();
+        let mut next = woog_struct.first_generic;
+        while let Some(id) = next {
+            let generic = self.store.exhume_struct_generic(&id).unwrap();
+            let generic = generic.borrow();
+            generics.push(generic.name.clone());
+            next = generic.next;
+        }
+        self.generics(&generics);
+
+        let fields = self
+            .store
+            .iter_field()
+            .filter(|field| field.borrow().x_model == woog_struct.id)
+            .collect::<Vec<_>>();
+        self.push(" ");
+        self.block_of(&fields, |w, field| {
+            let field = field.borrow();
+            w.push(&format!("{}: ", field.name));
+            w.ty(&w.printer.value_type(&field.ty).borrow());
+            w.push(",");
+        });
+    }
+
+    fn enumeration(&mut self, woog_enum: &Enumeration) {
+        self.push(&format!("enum {}", woog_enum.name));
+
+        let mut generics = Vec::new();
+        let mut next = woog_enum.first_generic;
+        while let Some(id) = next {
+            let generic = self.store.exhume_enum_generic(&id).unwrap();
+            let generic = generic.borrow();
+            generics.push(generic.name.clone());
+            next = generic.next;
+        }
+        self.generics(&generics);
+
+        let fields = self
+            .store
+            .iter_enum_field()
+            .filter(|field| field.borrow().woog_enum == woog_enum.id)
+            .collect::<Vec<_>>();
+        self.push(" ");
+        self.block_of(&fields, |w, field| {
+            let field = field.borrow();
+            w.push(&field.name);
+            match field.subtype {
+                EnumFieldEnum::StructField(_) => w.push(" {}"),
+                EnumFieldEnum::TupleField(ref id) => {
+                    let tuple = w.store.exhume_tuple_field(id).unwrap();
+                    w.push("(");
+                    w.ty(&w.printer.value_type(&tuple.borrow().ty).borrow());
+                    w.push(")");
+                }
+                EnumFieldEnum::Unit(_) => {}
+            }
+            w.push(",");
+        });
+    }
+
+    fn implementation(&mut self, block: &ImplementationBlock) {
+        let name = if let Some(ref id) = block.model_type {
+            self.store
+                .exhume_woog_struct(id)
+                .unwrap()
+                .borrow()
+                .name
+                .clone()
+        } else if let Some(ref id) = block.enumeration {
+            self.store
+                .exhume_enumeration(id)
+                .unwrap()
+                .borrow()
+                .name
+                .clone()
+        } else if let Some(ref id) = block.object_store {
+            let store = self.store.exhume_z_object_store(id).unwrap();
+            let store = store.borrow();
+            self.push(&format!("#[store(model = \"{}\")]", store.domain));
+            self.newline();
+            store.name.clone()
+        } else {
+            "_".to_owned()
+        };
+
+        let functions = self
+            .store
+            .iter_function()
+            .filter(|func| func.borrow().impl_block == Some(block.id))
+            .collect::<Vec<_>>();
+
+        self.push(&format!("impl {} {{", name));
+        self.depth += 1;
+        for (n, func) in functions.iter().enumerate() {
+            if n > 0 {
+                self.newline();
+            }
+            self.newline();
+            self.function(&func.borrow());
+        }
+        self.depth -= 1;
+        if !functions.is_empty() {
+            self.newline();
+        }
+        self.push("}");
+    }
+
+    fn function(&mut self, func: &Function) {
+        let body = self.store.exhume_body(&func.body).unwrap();
+        let body = body.borrow();
+        if let BodyEnum::ExternalImplementation(ref id) = body.subtype {
+            let external = self.store.exhume_external_implementation(id).unwrap();
+            let external = external.borrow();
+            self.push(&format!(
+                "#[proxy(model = \"{}\", object = \"{}\", func = \"{}\")]",
+                external.x_model, external.object, external.function
+            ));
+            self.newline();
+        }
+
+        self.push(&format!("fn {}", func.name));
+
+        let mut generics = Vec::new();
+        let mut next = func.first_generic;
+        while let Some(id) = next {
+            let generic = self.store.exhume_func_generic(&id).unwrap();
+            let generic = generic.borrow();
+            generics.push(generic.name.clone());
+            next = generic.next;
+        }
+        self.generics(&generics);
+
+        let mut params = Vec::new();
+        let mut next = func.first_param;
+        while let Some(id) = next {
+            let param = self.store.exhume_parameter(&id).unwrap();
+            next = param.borrow().next;
+            params.push(param);
+        }
+        self.list("(", &params, ")", false, |w, param| {
+            let param = param.borrow();
+            let name = w
+                .printer
+                .variable_name(|var| *var == VariableEnum::Parameter(param.id));
+            w.push(&name);
+            if name != "self" {
+                w.push(": ");
+                w.ty(&w.printer.value_type(&param.ty).borrow());
+            }
+        });
+
+        self.return_type(&func.return_type);
+
+        match body.subtype {
+            BodyEnum::Block(ref id) => {
+                self.push(" ");
+                self.block(&self.store.exhume_block(id).unwrap().borrow());
+            }
+            BodyEnum::ExternalImplementation(_) => self.push(";"),
+        }
+    }
+
+    fn generics(&mut self, generics: &[String]) {
+        if !generics.is_empty() {
+            self.push(&format!("<{}>", generics.join(", ")));
+        }
+    }
+
+    /// The return type of a function or lambda, if it's worth mentioning
+    ///
+    fn return_type(&mut self, id: &usize) {
+        let ty = self.printer.value_type(id);
+        let ty = ty.borrow();
+        match ty.subtype {
+            ValueTypeEnum::Empty(_) | ValueTypeEnum::Unknown(_) => {}
+            _ => {
+                self.push(" -> ");
+                self.ty(&ty);
+            }
+        }
+    }
+
+    /// Write things between braces, one per line
+    ///
+    fn block_of<T>(&mut self, things: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        if things.is_empty() {
+            self.push("{}");
+            return;
+        }
+
+        self.push("{");
+        self.depth += 1;
+        for thing in things {
+            self.newline();
+            f(self, thing);
+        }
+        self.depth -= 1;
+        self.newline();
+        self.push("}");
+    }
+
+    //
+    // Statements
+    //
+
+    fn block(&mut self, block: &Block) {
+        let mut statements = Vec::new();
+        let mut next = block.statement;
+        while let Some(id) = next {
+            let statement = self.store.exhume_statement(&id).unwrap();
+            next = statement.borrow().next;
+            // Items declared in a block are printed where they are declared.
+            if !matches!(statement.borrow().subtype, StatementEnum::ItemStatement(_)) {
+                statements.push(statement);
+            }
+        }
+
+        self.block_of(&statements, |w, statement| w.statement(&statement.borrow()));
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement.subtype {
+            StatementEnum::ExpressionStatement(ref id) => {
+                let stmt = self.store.exhume_expression_statement(id).unwrap();
+                let expr = self.printer.expr(&stmt.borrow().expression);
+                let expr = expr.borrow();
+                self.expression(&expr, precedence::OPEN);
+                if !is_block_like(&expr) {
+                    self.push(";");
+                }
+            }
+            StatementEnum::ItemStatement(_) => {}
+            StatementEnum::LetStatement(ref id) => {
+                let stmt = self.store.exhume_let_statement(id).unwrap();
+                let stmt = stmt.borrow();
+                let var = self
+                    .store
+                    .iter_variable()
+                    .find(|var| var.borrow().subtype == VariableEnum::LocalVariable(stmt.variable))
+                    .unwrap();
+                let var = var.borrow();
+                self.push(&format!("let {}", var.name));
+
+                let ty = self
+                    .store
+                    .iter_x_value()
+                    .find(|value| value.borrow().subtype == XValueEnum::Variable(var.id))
+                    .map(|value| self.printer.value_type(&value.borrow().ty));
+                if let Some(ty) = ty {
+                    let ty = ty.borrow();
+                    if !matches!(ty.subtype, ValueTypeEnum::Unknown(_)) {
+                        self.push(": ");
+                        self.ty(&ty);
+                    }
+                }
+
+                self.push(" = ");
+                self.expression(
+                    &self.printer.expr(&stmt.expression).borrow(),
+                    precedence::OPEN,
+                );
+                self.push(";");
+            }
+            StatementEnum::ResultStatement(ref id) => {
+                let stmt = self.store.exhume_result_statement(id).unwrap();
+                self.expression(
+                    &self.printer.expr(&stmt.borrow().expression).borrow(),
+                    precedence::OPEN,
+                );
+            }
+        }
+    }
+
+    //
+    // Expressions
+    //
+
+    /// Write an expression, in parentheses if it binds more loosely than `min`
+    ///
+    fn expression(&mut self, expr: &Expression, min: u8) {
+        if self.precedence(expr) < min {
+            self.push("(");
+            self.expression(expr, precedence::OPEN);
+            self.push(")");
+            return;
+        }
+
+        match expr.subtype {
+            ExpressionEnum::AWait(ref id) => {
+                let a_wait = self.store.exhume_a_wait(id).unwrap();
+                self.operand(&a_wait.borrow().x_future, precedence::POSTFIX);
+                self.push(".await");
+            }
+            ExpressionEnum::Block(ref id) => {
+                self.block(&self.store.exhume_block(id).unwrap().borrow())
+            }
+            ExpressionEnum::XBreak(ref id) => {
+                let x_break = self.store.exhume_x_break(id).unwrap();
+                let x_break = x_break.borrow();
+                self.push("break");
+                self.label(&x_break.label);
+                if let Some(ref expr) = x_break.expression {
+                    self.push(" ");
+                    self.operand(expr, precedence::OPEN);
+                }
+            }
+            ExpressionEnum::Call(ref id) => self.call(id),
+            ExpressionEnum::XContinue(ref id) => {
+                let x_continue = self.store.exhume_x_continue(id).unwrap();
+                self.push("continue");
+                self.label(&x_continue.borrow().label);
+            }
+            ExpressionEnum::XDebugger(_) => self.push("debugger"),
+            ExpressionEnum::EmptyExpression(_) => {}
+            ExpressionEnum::FieldAccess(ref id) => {
+                let access = self.store.exhume_field_access(id).unwrap();
+                let access = access.borrow();
+                self.operand(&access.expression, precedence::POSTFIX);
+                let target = self
+                    .store
+                    .exhume_field_access_target(&access.field)
+                    .unwrap();
+                let target = match target.borrow().subtype {
+                    FieldAccessTargetEnum::EnumField(ref id) => format!(
+                        "::{}",
+                        self.store.exhume_enum_field(id).unwrap().borrow().name
+                    ),
+                    FieldAccessTargetEnum::Field(ref id) => {
+                        format!(".{}", self.store.exhume_field(id).unwrap().borrow().name)
+                    }
+                    FieldAccessTargetEnum::Function(ref id) => {
+                        format!(".{}", self.printer.function(id).borrow().name)
+                    }
+                };
+                self.push(&target);
+            }
+            ExpressionEnum::FieldExpression(ref id) => {
+                let field = self.store.exhume_field_expression(id).unwrap();
+                self.operand(&field.borrow().expression, precedence::OPEN);
+            }
+            ExpressionEnum::ForLoop(ref id) => {
+                let for_loop = self.store.exhume_for_loop(id).unwrap();
+                let for_loop = for_loop.borrow();
+                self.loop_label(&for_loop.label);
+                self.push(&format!("for {} in ", for_loop.ident));
+                self.operand(&for_loop.expression, precedence::OPEN);
+                self.push(" ");
+                self.operand(&for_loop.block, precedence::OPEN);
+            }
+            ExpressionEnum::Grouped(ref id) => {
+                let grouped = self.store.exhume_grouped(id).unwrap();
+                self.push("(");
+                self.operand(&grouped.borrow().expression, precedence::OPEN);
+                self.push(")");
+            }
+            ExpressionEnum::HaltAndCatchFire(ref id) => {
+                let hcf = self.store.exhume_halt_and_catch_fire(id).unwrap();
+                self.push("hcf(");
+                self.operand(&hcf.borrow().expression, precedence::OPEN);
+                self.push(")");
+            }
+            ExpressionEnum::XIf(ref id) => {
+                let x_if = self.store.exhume_x_if(id).unwrap();
+                let x_if = x_if.borrow();
+                self.push("if ");
+                self.operand(&x_if.test, precedence::OPEN);
+                self.push(" ");
+                self.block(&self.store.exhume_block(&x_if.true_block).unwrap().borrow());
+                if let Some(ref false_block) = x_if.false_block {
+                    self.push(" else ");
+                    self.operand(false_block, precedence::OPEN);
+                }
+            }
+            ExpressionEnum::Index(ref id) => {
+                let index = self.store.exhume_index(id).unwrap();
+                let index = index.borrow();
+                self.operand(&index.target, precedence::POSTFIX);
+                self.push("[");
+                self.operand(&index.index, precedence::OPEN);
+                self.push("]");
+            }
+            ExpressionEnum::Lambda(ref id) => {
+                self.lambda(&self.store.exhume_lambda(id).unwrap().borrow())
+            }
+            ExpressionEnum::ListElement(ref id) => {
+                let element = self.store.exhume_list_element(id).unwrap();
+                self.operand(&element.borrow().expression, precedence::OPEN);
+            }
+            ExpressionEnum::ListExpression(ref id) => {
+                let list = self.store.exhume_list_expression(id).unwrap();
+                let mut elements = Vec::new();
+                let mut next = list.borrow().elements;
+                while let Some(id) = next {
+                    let element = self.store.exhume_list_element(&id).unwrap();
+                    next = element.borrow().next;
+                    elements.push(element.borrow().expression);
+                }
+                self.list("[", &elements, "]", false, |w, expr| {
+                    w.operand(expr, precedence::OPEN)
+                });
+            }
+            ExpressionEnum::Literal(ref id) => self.literal(id),
+            ExpressionEnum::XLoop(ref id) => {
+                let x_loop = self.store.exhume_x_loop(id).unwrap();
+                let x_loop = x_loop.borrow();
+                self.loop_label(&x_loop.label);
+                self.push("loop ");
+                self.operand(&x_loop.block, precedence::OPEN);
+            }
+            ExpressionEnum::XMatch(ref id) => self.x_match(id),
+            ExpressionEnum::Operator(ref id) => self.operator(id),
+            ExpressionEnum::XPath(ref id) => {
+                let path = self.path(id);
+                self.push(&path);
+            }
+            ExpressionEnum::XPrint(ref id) => {
+                let print = self.store.exhume_x_print(id).unwrap();
+                self.push("print(");
+                self.operand(&print.borrow().expression, precedence::OPEN);
+                self.push(")");
+            }
+            ExpressionEnum::RangeExpression(ref id) => {
+                let range = self.store.exhume_range_expression(id).unwrap();
+                let range = range.borrow();
+                if let Some(ref lhs) = range.lhs {
+                    self.operand(lhs, precedence::RANGE + 1);
+                }
+                self.push(match range.subtype {
+                    RangeExpressionEnum::Inclusive(_) | RangeExpressionEnum::ToInclusive(_) => {
+                        "..="
+                    }
+                    _ => "..",
+                });
+                if let Some(ref rhs) = range.rhs {
+                    self.operand(rhs, precedence::RANGE + 1);
+                }
+            }
+            ExpressionEnum::XReturn(ref id) => {
+                let x_return = self.store.exhume_x_return(id).unwrap();
+                let expr = self.printer.expr(&x_return.borrow().expression);
+                let expr = expr.borrow();
+                self.push("return");
+                if !matches!(expr.subtype, ExpressionEnum::EmptyExpression(_)) {
+                    self.push(" ");
+                    self.expression(&expr, precedence::OPEN);
+                }
+            }
+            ExpressionEnum::StructExpression(ref id) => self.struct_expression(id),
+            ExpressionEnum::TypeCast(ref id) => {
+                let cast = self.store.exhume_type_cast(id).unwrap();
+                let cast = cast.borrow();
+                self.operand(&cast.lhs, precedence::CAST);
+                self.push(" as ");
+                self.ty(&self.printer.value_type(&cast.ty).borrow());
+            }
+            ExpressionEnum::VariableExpression(ref id) => {
+                let var = self.store.exhume_variable_expression(id).unwrap();
+                self.push(&var.borrow().name);
+            }
+            ExpressionEnum::XWhile(ref id) => {
+                let x_while = self.store.exhume_x_while(id).unwrap();
+                let x_while = x_while.borrow();
+                self.loop_label(&x_while.label);
+                self.push("while ");
+                self.operand(&x_while.test, precedence::OPEN);
+                self.push(" ");
+                self.operand(&x_while.block, precedence::OPEN);
+            }
+        }
+    }
+
+    /// Write the label that a loop is named by, if it has one
+    ///
+    fn loop_label(&mut self, label: &str) {
+        if !label.is_empty() {
+            self.push(&format!("'{}: ", label));
+        }
+    }
+
+    /// Write the label that `break` or `continue` refers to, if there is one
+    ///
+    fn label(&mut self, label: &str) {
+        if !label.is_empty() {
+            self.push(&format!(" '{}", label));
+        }
+    }
+
+    /// Write the expression with the given id
+    ///
+    fn operand(&mut self, id: &usize, min: u8) {
+        self.expression(&self.printer.expr(id).borrow(), min);
+    }
+
+    fn precedence(&self, expr: &Expression) -> u8 {
+        match expr.subtype {
+            ExpressionEnum::AWait(_)
+            | ExpressionEnum::Call(_)
+            | ExpressionEnum::FieldAccess(_)
+            | ExpressionEnum::Index(_) => precedence::POSTFIX,
+            ExpressionEnum::Lambda(_) | ExpressionEnum::XBreak(_) | ExpressionEnum::XReturn(_) => {
+                precedence::OPEN
+            }
+            ExpressionEnum::Operator(ref id) => {
+                self.operator_precedence(&self.store.exhume_operator(id).unwrap().borrow())
+            }
+            ExpressionEnum::RangeExpression(_) => precedence::RANGE,
+            ExpressionEnum::TypeCast(_) => precedence::CAST,
+            _ => precedence::PRIMARY,
+        }
+    }
+
+    fn operator_precedence(&self, operator: &Operator) -> u8 {
+        match operator.subtype {
+            OperatorEnum::Binary(ref id) => {
+                let binary = self.store.exhume_binary(id).unwrap();
+                let binary = binary.borrow();
+                match binary.subtype {
+                    BinaryEnum::Addition(_) | BinaryEnum::Subtraction(_) => precedence::ADDITIVE,
+                    BinaryEnum::Assignment(_)
+                    | BinaryEnum::AdditionAssignment(_)
+                    | BinaryEnum::BitwiseAndAssignment(_)
+                    | BinaryEnum::BitwiseOrAssignment(_)
+                    | BinaryEnum::BitwiseXorAssignment(_)
+                    | BinaryEnum::DivisionAssignment(_)
+                    | BinaryEnum::MultiplicationAssignment(_)
+                    | BinaryEnum::RemainderAssignment(_)
+                    | BinaryEnum::ShiftLeftAssignment(_)
+                    | BinaryEnum::ShiftRightAssignment(_)
+                    | BinaryEnum::SubtractionAssignment(_) => precedence::ASSIGNMENT,
+                    BinaryEnum::BitwiseAnd(_) => precedence::BIT_AND,
+                    BinaryEnum::BitwiseOr(_) => precedence::BIT_OR,
+                    BinaryEnum::BitwiseXor(_) => precedence::BIT_XOR,
+                    BinaryEnum::BooleanOperator(ref id) => {
+                        match self
+                            .store
+                            .exhume_boolean_operator(id)
+                            .unwrap()
+                            .borrow()
+                            .subtype
+                        {
+                            BooleanOperatorEnum::And(_) => precedence::AND,
+                            BooleanOperatorEnum::Or(_) => precedence::OR,
+                        }
+                    }
+                    BinaryEnum::Division(_)
+                    | BinaryEnum::Multiplication(_)
+                    | BinaryEnum::Remainder(_) => precedence::MULTIPLICATIVE,
+                    BinaryEnum::ShiftLeft(_) | BinaryEnum::ShiftRight(_) => precedence::SHIFT,
+                }
+            }
+            OperatorEnum::Comparison(_) => precedence::COMPARISON,
+            OperatorEnum::Unary(_) => precedence::UNARY,
+        }
+    }
+
+    fn operator(&mut self, id: &usize) {
+        let operator = self.store.exhume_operator(id).unwrap();
+        let operator = operator.borrow();
+
+        let (symbol, left, right) = match operator.subtype {
+            OperatorEnum::Binary(ref id) => {
+                let binary = self.store.exhume_binary(id).unwrap();
+                let binary = binary.borrow();
+                match binary.subtype {
+                    BinaryEnum::Addition(_) => ("+", 0, 1),
+                    BinaryEnum::AdditionAssignment(_) => ("+=", 1, 0),
+                    BinaryEnum::Assignment(_) => ("=", 1, 0),
+                    BinaryEnum::BitwiseAnd(_) => ("&", 0, 1),
+                    BinaryEnum::BitwiseAndAssignment(_) => ("&=", 1, 0),
+                    BinaryEnum::BitwiseOr(_) => ("|", 0, 1),
+                    BinaryEnum::BitwiseOrAssignment(_) => ("|=", 1, 0),
+                    BinaryEnum::BitwiseXor(_) => ("^", 0, 1),
+                    BinaryEnum::BitwiseXorAssignment(_) => ("^=", 1, 0),
+                    BinaryEnum::BooleanOperator(ref id) => {
+                        match self
+                            .store
+                            .exhume_boolean_operator(id)
+                            .unwrap()
+                            .borrow()
+                            .subtype
+                        {
+                            BooleanOperatorEnum::And(_) => ("&&", 0, 1),
+                            BooleanOperatorEnum::Or(_) => ("||", 0, 1),
+                        }
+                    }
+                    BinaryEnum::Division(_) => ("/", 0, 1),
+                    BinaryEnum::DivisionAssignment(_) => ("/=", 1, 0),
+                    BinaryEnum::Multiplication(_) => ("*", 0, 1),
+                    BinaryEnum::MultiplicationAssignment(_) => ("*=", 1, 0),
+                    BinaryEnum::Remainder(_) => ("%", 0, 1),
+                    BinaryEnum::RemainderAssignment(_) => ("%=", 1, 0),
+                    BinaryEnum::ShiftLeft(_) => ("<<", 0, 1),
+                    BinaryEnum::ShiftLeftAssignment(_) => ("<<=", 1, 0),
+                    BinaryEnum::ShiftRight(_) => (">>", 0, 1),
+                    BinaryEnum::ShiftRightAssignment(_) => (">>=", 1, 0),
+                    BinaryEnum::Subtraction(_) => ("-", 0, 1),
+                    BinaryEnum::SubtractionAssignment(_) => ("-=", 1, 0),
+                }
+            }
+            OperatorEnum::Comparison(ref id) => {
+                let comparison = self.store.exhume_comparison(id).unwrap();
+                let symbol = match comparison.borrow().subtype {
+                    ComparisonEnum::Equal(_) => "==",
+                    ComparisonEnum::GreaterThan(_) => ">",
+                    ComparisonEnum::GreaterThanOrEqual(_) => ">=",
+                    ComparisonEnum::LessThan(_) => "<",
+                    ComparisonEnum::LessThanOrEqual(_) => "<=",
+                    ComparisonEnum::NotEqual(_) => "!=",
+                };
+                // Comparisons don't chain.
+                (symbol, 1, 1)
+            }
+            OperatorEnum::Unary(ref id) => {
+                let unary = self.store.exhume_unary(id).unwrap();
+                let symbol = match unary.borrow().subtype {
+                    UnaryEnum::Negation(_) => "-",
+                    UnaryEnum::Not(_) => "!",
+                };
+                self.push(symbol);
+                self.operand(&operator.lhs, precedence::UNARY);
+                return;
+            }
+        };
+
+        let this = self.operator_precedence(&operator);
+        // Operands on the side that doesn't associate need to bind tighter.
+        self.operand(&operator.lhs, this + left);
+        self.push(&format!(" {} ", symbol));
+        if let Some(ref rhs) = operator.rhs {
+            self.operand(rhs, this + right);
+        }
+    }
+
+    fn call(&mut self, id: &usize) {
+        let call = self.store.exhume_call(id).unwrap();
+        let call = call.borrow();
+
+        match call.subtype {
+            CallEnum::FunctionCall(ref id) => match call.expression {
+                Some(ref callee) => self.operand(callee, precedence::POSTFIX),
+                None => {
+                    let name = self
+                        .store
+                        .exhume_function_call(id)
+                        .unwrap()
+                        .borrow()
+                        .name
+                        .clone();
+                    self.push(&name);
+                }
+            },
+            CallEnum::MacroCall(_) => {
+                if let Some(ref callee) = call.expression {
+                    self.operand(callee, precedence::POSTFIX);
+                }
+                self.push("!");
+            }
+            CallEnum::MethodCall(ref id) => {
+                if let Some(ref receiver) = call.expression {
+                    self.operand(receiver, precedence::POSTFIX);
+                }
+                let name = self
+                    .store
+                    .exhume_method_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                self.push(&format!(".{}", name));
+            }
+            CallEnum::StaticMethodCall(ref id) => {
+                let call = self.store.exhume_static_method_call(id).unwrap();
+                let call = call.borrow();
+                self.push(&format!("{}::{}", call.ty, call.func));
+            }
+        }
+
+        let mut args = Vec::new();
+        let mut next = call.argument;
+        while let Some(id) = next {
+            let arg = self.store.exhume_argument(&id).unwrap();
+            next = arg.borrow().next;
+            args.push(arg.borrow().expression);
+        }
+        self.list("(", &args, ")", false, |w, arg| {
+            w.operand(arg, precedence::OPEN)
+        });
+    }
+
+    fn lambda(&mut self, lambda: &Lambda) {
+        let mut params = Vec::new();
+        let mut next = lambda.first_param;
+        while let Some(id) = next {
+            let param = self.store.exhume_lambda_parameter(&id).unwrap();
+            next = param.borrow().next;
+            params.push(param);
+        }
+        self.list("|", &params, "|", false, |w, param| {
+            let param = param.borrow();
+            let name = w
+                .printer
+                .variable_name(|var| *var == VariableEnum::LambdaParameter(param.id));
+            w.push(&name);
+            if let Some(ref ty) = param.ty {
+                let ty = w.printer.value_type(ty);
+                let ty = ty.borrow();
+                if !matches!(ty.subtype, ValueTypeEnum::Unknown(_)) {
+                    w.push(": ");
+                    w.ty(&ty);
+                }
+            }
+        });
+
+        self.return_type(&lambda.return_type);
+
+        self.push(" ");
+        match lambda.body {
+            Some(ref id) => match self.store.exhume_body(id).unwrap().borrow().subtype {
+                BodyEnum::Block(ref id) => {
+                    self.block(&self.store.exhume_block(id).unwrap().borrow())
+                }
+                BodyEnum::ExternalImplementation(_) => self.push("{}"),
+            },
+            None => self.push("{}"),
+        }
+    }
+
+    fn literal(&mut self, id: &usize) {
+        let literal = self.store.exhume_literal(id).unwrap();
+        let literal = literal.borrow();
+
+        match literal.subtype {
+            LiteralEnum::BooleanLiteral(ref id) => {
+                match self
+                    .store
+                    .exhume_boolean_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .subtype
+                {
+                    BooleanLiteralEnum::FalseLiteral(_) => self.push("false"),
+                    BooleanLiteralEnum::TrueLiteral(_) => self.push("true"),
+                }
+            }
+            LiteralEnum::CharLiteral(ref id) => {
+                let value = self.store.exhume_char_literal(id).unwrap().borrow().x_value;
+                let value = char::from_u32(value as u32).unwrap_or(char::REPLACEMENT_CHARACTER);
+                self.push(&format!("{:?}", value));
+            }
+            LiteralEnum::FloatLiteral(ref id) => {
+                let value = self
+                    .store
+                    .exhume_float_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .x_value;
+                // Debug always includes a decimal point, or an exponent.
+                self.push(&format!("{:?}", value));
+            }
+            LiteralEnum::FormatString(ref id) => {
+                let format = self.store.exhume_format_string(id).unwrap();
+                self.push("\"");
+                let mut next = format.borrow().first_format_bit;
+                while let Some(id) = next {
+                    let bit = self.store.exhume_format_bit(&id).unwrap();
+                    let bit = bit.borrow();
+                    match bit.subtype {
+                        FormatBitEnum::ExpressionBit(ref id) => {
+                            let bit = self.store.exhume_expression_bit(id).unwrap();
+                            self.push("{");
+                            self.operand(&bit.borrow().expression, precedence::OPEN);
+                            self.push("}");
+                        }
+                        FormatBitEnum::StringBit(ref id) => {
+                            let bit = self.store.exhume_string_bit(id).unwrap();
+                            let string = self
+                                .store
+                                .exhume_string_literal(&bit.borrow().z_string)
+                                .unwrap();
+                            let escaped = string
+                                .borrow()
+                                .x_value
+                                .escape_debug()
+                                .to_string()
+                                .replace('{', "{{")
+                                .replace('}', "}}");
+                            self.push(&escaped);
+                        }
+                    }
+                    next = bit.next;
+                }
+                self.push("\"");
+            }
+            LiteralEnum::IntegerLiteral(ref id) => {
+                let value = self
+                    .store
+                    .exhume_integer_literal(id)
+                    .unwrap()
+                    .borrow()
+                    .x_value;
+                self.push(&value.to_string());
+            }
+            LiteralEnum::MapExpression(ref id) => {
+                let elements = self
+                    .store
+                    .iter_map_element()
+                    .filter(|element| element.borrow().map == *id)
+                    .collect::<Vec<_>>();
+                self.list("{", &elements, "}", true, |w, element| {
+                    let element = element.borrow();
+                    w.operand(&element.key, precedence::OPEN);
+                    w.push(": ");
+                    w.operand(&element.x_value, precedence::OPEN);
+                });
+            }
+            LiteralEnum::StringLiteral(ref id) => {
+                let value = self.store.exhume_string_literal(id).unwrap();
+                self.push(&format!("{:?}", value.borrow().x_value));
+            }
+        }
+    }
+
+    fn x_match(&mut self, id: &usize) {
+        let x_match = self.store.exhume_x_match(id).unwrap();
+        let x_match = x_match.borrow();
+
+        let patterns = self
+            .store
+            .iter_pattern()
+            .filter(|pattern| pattern.borrow().x_match == x_match.id)
+            .collect::<Vec<_>>();
+        // The first pattern is the one that doesn't follow any other.
+        let mut next = patterns
+            .iter()
+            .find(|pattern| {
+                !patterns
+                    .iter()
+                    .any(|other| other.borrow().next == Some(pattern.borrow().id))
+            })
+            .map(|pattern| pattern.borrow().id);
+        let mut arms = Vec::new();
+        while let Some(id) = next {
+            let pattern = self.store.exhume_pattern(&id).unwrap();
+            next = pattern.borrow().next;
+            arms.push(pattern);
+        }
+
+        self.push("match ");
+        self.operand(&x_match.scrutinee, precedence::OPEN);
+        self.push(" ");
+        self.block_of(&arms, |w, arm| {
+            let arm = arm.borrow();
+            w.operand(&arm.match_expr, precedence::OPEN);
+            w.push(" => ");
+            let expr = w.printer.expr(&arm.expression);
+            let expr = expr.borrow();
+            w.expression(&expr, precedence::OPEN);
+            if !matches!(expr.subtype, ExpressionEnum::Block(_)) {
+                w.push(",");
+            }
+        });
+    }
+
+    fn path(&self, id: &usize) -> String {
+        let path = self.store.exhume_x_path(id).unwrap();
+        let mut elements = Vec::new();
+        let mut next = path.borrow().first;
+        while let Some(id) = next {
+            let element = self.store.exhume_path_element(&id).unwrap();
+            let element = element.borrow();
+            elements.push(element.name.clone());
+            next = element.next;
+        }
+
+        elements.join("::")
+    }
+
+    fn struct_expression(&mut self, id: &usize) {
+        let expr = self.store.exhume_struct_expression(id).unwrap();
+        let expr = expr.borrow();
+
+        let path = self.path(&expr.x_path);
+        self.push(&path);
+
+        let mut fields = self
+            .store
+            .iter_field_expression()
+            .filter(|field| field.borrow().woog_struct == expr.id)
+            .collect::<Vec<_>>();
+
+        let named = fields.iter().all(|field| {
+            matches!(
+                field.borrow().subtype,
+                FieldExpressionEnum::NamedFieldExpression(_)
+            )
+        });
+        if named {
+            self.push(" ");
+            self.list("{", &fields, "}", true, |w, field| {
+                let field = field.borrow();
+                if let FieldExpressionEnum::NamedFieldExpression(ref id) = field.subtype {
+                    let name = w.store.exhume_named_field_expression(id).unwrap();
+                    w.push(&format!("{}: ", name.borrow().name));
+                }
+                w.operand(&field.expression, precedence::OPEN);
+            });
+        } else {
+            fields.sort_by_key(|field| match field.borrow().subtype {
+                FieldExpressionEnum::UnnamedFieldExpression(ref id) => {
+                    self.store
+                        .exhume_unnamed_field_expression(id)
+                        .unwrap()
+                        .borrow()
+                        .position
+                }
+                FieldExpressionEnum::NamedFieldExpression(_) => i64::MAX,
+            });
+            self.list("(", &fields, ")", false, |w, field| {
+                w.operand(&field.borrow().expression, precedence::OPEN)
+            });
+        }
+    }
+
+    //
+    // Types
+    //
+
+    fn ty(&mut self, ty: &ValueType) {
+        match ty.subtype {
+            ValueTypeEnum::AnyList(_) => self.push("[_]"),
+            ValueTypeEnum::Char(_) => self.push("char"),
+            ValueTypeEnum::Empty(_) => self.push("()"),
+            ValueTypeEnum::EnumGeneric(ref id) => {
+                let generic = self.store.exhume_enum_generic(id).unwrap();
+                self.push(&generic.borrow().name);
+            }
+            ValueTypeEnum::Enumeration(ref id) => {
+                let woog_enum = self.store.exhume_enumeration(id).unwrap();
+                self.push(&woog_enum.borrow().name);
+            }
+            ValueTypeEnum::FuncGeneric(ref id) => {
+                let generic = self.store.exhume_func_generic(id).unwrap();
+                self.push(&generic.borrow().name);
+            }
+            ValueTypeEnum::Function(ref id) => {
+                let func = self.printer.function(id);
+                let func = func.borrow();
+                let mut params = Vec::new();
+                let mut next = func.first_param;
+                while let Some(id) = next {
+                    let param = self.store.exhume_parameter(&id).unwrap();
+                    next = param.borrow().next;
+                    params.push(Some(param.borrow().ty));
+                }
+                self.fn_type(&params, &func.return_type);
+            }
+            ValueTypeEnum::XFuture(ref id) => {
+                let future = self.store.exhume_x_future(id).unwrap();
+                self.push("Future<");
+                self.ty(&self.printer.value_type(&future.borrow().x_value).borrow());
+                self.push(">");
+            }
+            ValueTypeEnum::Import(ref id) => {
+                let import = self.store.exhume_import(id).unwrap();
+                let import = import.borrow();
+                self.push(if import.has_alias {
+                    &import.alias
+                } else {
+                    &import.name
+                });
+            }
+            ValueTypeEnum::Lambda(ref id) => {
+                let lambda = self.store.exhume_lambda(id).unwrap();
+                let lambda = lambda.borrow();
+                let mut params = Vec::new();
+                let mut next = lambda.first_param;
+                while let Some(id) = next {
+                    let param = self.store.exhume_lambda_parameter(&id).unwrap();
+                    next = param.borrow().next;
+                    params.push(param.borrow().ty);
+                }
+                self.fn_type(&params, &lambda.return_type);
+            }
+            ValueTypeEnum::List(ref id) => {
+                let list = self.store.exhume_list(id).unwrap();
+                self.push("[");
+                self.ty(&self.printer.value_type(&list.borrow().ty).borrow());
+                self.push("]");
+            }
+            ValueTypeEnum::Map(ref id) => {
+                let map = self.store.exhume_map(id).unwrap();
+                let map = map.borrow();
+                self.push("Map<");
+                self.ty(&self.printer.value_type(&map.key_type).borrow());
+                self.push(", ");
+                self.ty(&self.printer.value_type(&map.value_type).borrow());
+                self.push(">");
+            }
+            ValueTypeEnum::ZObjectStore(ref id) => {
+                let store = self.store.exhume_z_object_store(id).unwrap();
+                self.push(&store.borrow().name);
+            }
+            ValueTypeEnum::XPlugin(ref id) => {
+                let plugin = self.store.exhume_x_plugin(id).unwrap();
+                self.push(&plugin.borrow().name);
+            }
+            ValueTypeEnum::Range(_) => self.push("Range"),
+            ValueTypeEnum::WoogStruct(ref id) => {
+                let woog_struct = self.store.exhume_woog_struct(id).unwrap();
+                self.push(&woog_struct.borrow().name);
+            }
+            ValueTypeEnum::StructGeneric(ref id) => {
+                let generic = self.store.exhume_struct_generic(id).unwrap();
+                self.push(&generic.borrow().name);
+            }
+            ValueTypeEnum::Task(_) => self.push("Task"),
+            ValueTypeEnum::Ty(ref id) => {
+                let name = match *id {
+                    BOOLEAN => "bool".to_owned(),
+                    FLOAT => "float".to_owned(),
+                    INTEGER => "int".to_owned(),
+                    Z_STRING => "string".to_owned(),
+                    Z_UUID => "Uuid".to_owned(),
+                    // Anything else is an object, and we only know about it if
+                    // there's a struct for it.
+                    id => self
+                        .store
+                        .iter_woog_struct()
+                        .find(|woog_struct| woog_struct.borrow().object == Some(id))
+                        .map(|woog_struct| woog_struct.borrow().name.clone())
+                        .unwrap_or_else(|| "_".to_owned()),
+                };
+                self.push(&name);
+            }
+            ValueTypeEnum::Unknown(_) => self.push("_"),
+        }
+    }
+
+    fn fn_type(&mut self, params: &[Option<usize>], return_type: &usize) {
+        self.push("fn");
+        self.list("(", params, ")", false, |w, ty| match ty {
+            Some(ty) => w.ty(&w.printer.value_type(ty).borrow()),
+            None => w.push("_"),
+        });
+        self.return_type(return_type);
+    }
+}
+
+/// Expressions that end in a block, and don't need a semicolon as a statement
+///
+fn is_block_like(expr: &Expression) -> bool {
+    matches!(
+        expr.subtype,
+        ExpressionEnum::Block(_)
+            | ExpressionEnum::ForLoop(_)
+            | ExpressionEnum::XIf(_)
+            | ExpressionEnum::XLoop(_)
+            | ExpressionEnum::XMatch(_)
+            | ExpressionEnum::XWhile(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::sample::{Bit, Sample, Stmt};
+
+    fn print(sample: &Sample, printer: Printer) -> String {
+        printer.print_source(&sample.source().borrow())
+    }
+
+    #[test]
+    fn test_points() {
+        let mut s = Sample::new("points");
+        s.import("std::option", "Option", None);
+
+        let int = s.int_ty();
+        let point = s.structure("Point", &[("x", int.clone()), ("y", int.clone())]);
+        let point_ty = s.struct_ty(&point);
+        let imp = s.implementation(&point);
+
+        let body = s.function("norm", &[("self", point_ty)], &int, Some(&imp));
+        let me = s.var("self");
+        let x = s.field(&me, &point, "x");
+        let y = s.field(&me, &point, "y");
+        let xx = s.binary("*", &x, &x);
+        let yy = s.binary("*", &y, &y);
+        let sum = s.binary("+", &xx, &yy);
+        s.statements(&body, vec![Stmt::Result(sum)]);
+
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let three = s.int(3);
+        let four = s.int(4);
+        let p = s.struct_expr(&point, vec![("x", three), ("y", four)]);
+        let zero = s.int(0);
+        let pv = s.var("p");
+        let norm = s.method(&pv, "norm", vec![]);
+        let range = s.range(Some(&zero), Some(&norm), false);
+        let body = s.block(&main);
+        let i = s.var("i");
+        let message = s.format(vec![Bit::Expr(i), Bit::Str("\n")]);
+        let message = s.print(&message);
+        s.statements(&body, vec![Stmt::Expr(message)]);
+        let for_loop = s.for_loop("i", &range, &body);
+        s.statements(&main, vec![Stmt::Let("p", None, p), Stmt::Expr(for_loop)]);
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store)),
+            r#"use std::option::Option;
+
+struct Point {
+    x: int,
+    y: int,
+}
+
+impl Point {
+    fn norm(self) -> int {
+        self.x * self.x + self.y * self.y
+    }
+}
+
+fn main() {
+    let p = Point { x: 3, y: 4 };
+    for i in 0..p.norm() {
+        print("{i}\n");
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_loops() {
+        let mut s = Sample::new("loops");
+
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let zero = s.int(0);
+        let three = s.int(3);
+        let ten = s.int(10);
+
+        let n = s.var("n");
+        let test = s.binary("<", &n, &ten);
+        let body = s.block(&main);
+        let skip = s.block(&body);
+        let cont = s.x_continue("");
+        s.statements(&skip, vec![Stmt::Expr(cont)]);
+        let is_three = s.binary("==", &n, &three);
+        let skip = s.if_else(&is_three, &skip, None);
+        s.statements(&body, vec![Stmt::Expr(skip)]);
+        let while_loop = s.while_loop("", &test, &body);
+
+        let outer = s.block(&main);
+        let inner = s.block(&outer);
+        let i = s.var("i");
+        let value = s.binary("*", &i, &ten);
+        let x_break = s.x_break("outer", Some(&value));
+        let next = s.x_continue("outer");
+        s.statements(&inner, vec![Stmt::Expr(next)]);
+        let range = s.range(Some(&zero), Some(&three), false);
+        let for_loop = s.for_loop("i", &range, &inner);
+        s.statements(&outer, vec![Stmt::Expr(for_loop), Stmt::Expr(x_break)]);
+        let x_loop = s.x_loop("outer", &outer);
+
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("n", None, zero),
+                Stmt::Expr(while_loop),
+                Stmt::Let("found", None, x_loop),
+            ],
+        );
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store)),
+            r#"fn main() {
+    let n = 0;
+    while n < 10 {
+        if n == 3 {
+            continue;
+        }
+    }
+    let found = 'outer: loop {
+        for i in 0..3 {
+            continue 'outer;
+        }
+        break 'outer i * 10;
+    };
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut s = Sample::new("operators");
+
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let x = s.var("x");
+        let [one, two, three, four] = [1, 2, 3, 4].map(|i| s.int(i));
+        // x |= 1 << 2 | 3 & 4 ^ x % 2
+        let shl = s.binary("<<", &one, &two);
+        let and = s.binary("&", &three, &four);
+        let rem = s.binary("%", &x, &two);
+        let xor = s.binary("^", &and, &rem);
+        let or = s.binary("|", &shl, &xor);
+        let first = s.binary("|=", &x, &or);
+        // x >>= (x | 1) & !(x + 1) << 3
+        let or = s.binary("|", &x, &one);
+        let or = s.group(&or);
+        let sum = s.binary("+", &x, &one);
+        let sum = s.group(&sum);
+        let not = s.unary("!", &sum);
+        let shl = s.binary("<<", &not, &three);
+        let and = s.binary("&", &or, &shl);
+        let second = s.binary(">>=", &x, &and);
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, one),
+                Stmt::Expr(first),
+                Stmt::Expr(second),
+            ],
+        );
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store)),
+            r#"fn main() {
+    let x = 1;
+    x |= 1 << 2 | 3 & 4 ^ x % 2;
+    x >>= (x | 1) & !(x + 1) << 3;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut s = Sample::new("shapes");
+
+        let float = s.float_ty();
+        let shape = s.enumeration("Shape", &[("Circle", Some(float.clone())), ("Empty", None)]);
+        let shape_ty = s.enum_ty(&shape);
+
+        let body = s.function(
+            "area",
+            &[("shape", shape_ty), ("scale", float.clone())],
+            &float,
+            None,
+        );
+
+        let empty = s.path(&["Shape", "Empty"]);
+        let zero = s.float(0.0);
+        let x = s.var("x");
+        let factor = s.float(2.5);
+        let cast = s.cast(&x, &float);
+        let times = s.binary("*", &factor, &cast);
+        let scrutinee = s.var("shape");
+        let x_match = s.x_match(&scrutinee, vec![(empty, zero.clone()), (x, times)]);
+
+        let a = s.var("a");
+        let scale = s.var("scale");
+        let one = s.float(1.0);
+        let is_zero = s.binary("==", &a, &zero);
+        let big = s.binary(">", &scale, &one);
+        let small = s.unary("!", &big);
+        let test = s.binary("&&", &is_zero, &small);
+
+        let true_block = s.block(&body);
+        let minus_one = s.unary("-", &one);
+        let ret = s.ret(&minus_one);
+        s.statements(&true_block, vec![Stmt::Expr(ret)]);
+
+        let false_block = s.block(&body);
+        let sum = s.binary("+", &a, &one);
+        let product = s.binary("*", &sum, &scale);
+        let difference = s.binary("-", &a, &scale);
+        let result = s.binary("-", &product, &difference);
+        s.statements(&false_block, vec![Stmt::Result(result)]);
+        let false_block = s.block_expr(&false_block);
+
+        let x_if = s.if_else(&test, &true_block, Some(&false_block));
+        s.statements(
+            &body,
+            vec![Stmt::Let("a", Some(float), x_match), Stmt::Result(x_if)],
+        );
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store)),
+            r#"enum Shape {
+    Circle(float),
+    Empty,
+}
+
+fn area(shape: Shape, scale: float) -> float {
+    let a: float = match shape {
+        Shape::Empty => 0.0,
+        x => 2.5 * x as float,
+    };
+    if a == 0.0 && !(scale > 1.0) {
+        return -1.0;
+    } else {
+        (a + 1.0) * scale - (a - scale)
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_config() {
+        let mut s = Sample::new("config");
+
+        let int = s.int_ty();
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+
+        let one = s.int(1);
+        let two = s.int(2);
+        let three = s.int(3);
+        let numbers = s.list(vec![one, two.clone(), three]);
+
+        let (lambda, body) = s.lambda(&[("n", Some(int.clone()))], &int, &main);
+        let n = s.var("n");
+        let double = s.binary("*", &n, &two);
+        s.statements(&body, vec![Stmt::Result(double)]);
+
+        let numbers_var = s.var("numbers");
+        let f = s.var("f");
+        let long = s.string("a long string argument");
+        let combine = s.call("combine", vec![numbers_var, f, long]);
+        let print_call = s.print(&combine);
+
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("numbers", None, numbers),
+                Stmt::Let("f", None, lambda),
+                Stmt::Expr(print_call),
+            ],
+        );
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store)),
+            r#"fn main() {
+    let numbers = [1, 2, 3];
+    let f = |n: int| -> int {
+        n * 2
+    };
+    print(combine(numbers, f, "a long string argument"));
+}
+"#
+        );
+
+        assert_eq!(
+            print(&s, Printer::new(&s.store).indent(2).width(40)),
+            r#"fn main() {
+  let numbers = [1, 2, 3];
+  let f = |n: int| -> int {
+    n * 2
+  };
+  print(combine(
+    numbers,
+    f,
+    "a long string argument",
+  ));
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,1220 @@
+//! Dwarf Name Resolution
+//!
+//! Names in a lu_dog program are just strings: a variable expression has the
+//! name of a variable, a function call the name of a function, and a static
+//! method call the names of a type and a function. This pass works out what
+//! each of them refers to, and records it in the store as a [`Resolution`],
+//! for the tools that come after.
+//!
+//! Scopes follow the blocks, as they do in the interpreter. Let statements,
+//! parameters, and lambda parameters introduce variables into the scope of
+//! their block, and the parent of a block's scope is the scope of
+//! `Block.parent`. Functions see their parameters and the items in the
+//! program, and nothing else.
+//!
+//! Functions in implementation blocks are namespaced by the type that they
+//! implement, so that `Point::new` and `Line::new` are different things.
+//! Imported names are taken on faith, as their definitions aren't in the
+//! store.
+//!
+//! Expressions that look the same are shared by the store, so the same
+//! expression may mean different things in different blocks. Resolutions are
+//! therefore recorded per block; see [`LuDogStore::exhume_resolution`].
+//!
+//! Names that don't refer to anything, and names that could refer to more than
+//! one thing, are reported as [`Diagnostic`]s.
+use std::collections::{HashMap, HashSet};
+
+use crate::dwarf::{Diagnostic, Problem};
+use crate::v2::lu_dog_vec::{
+    BodyEnum, CallEnum, DataStructureEnum, ExpressionEnum, FieldAccessTargetEnum, FormatBitEnum,
+    LiteralEnum, ObjectStore as LuDogStore, Resolution, StatementEnum, ValueTypeEnum, VariableEnum,
+    XValueEnum,
+};
+
+/// Methods that lists, maps and strings have built in, courtesy of the
+/// interpreter
+const BUILT_IN_METHODS: [&str; 5] = ["contains_key", "is_empty", "len", "pop", "push"];
+
+/// Resolve the names in the program in the store
+///
+/// Any resolutions from a previous run are forgotten first.
+pub fn resolve(store: &mut LuDogStore) -> Vec<Diagnostic> {
+    store.clear_resolution();
+    let mut resolver = Resolver::new(store);
+
+    let functions: Vec<_> = resolver
+        .store
+        .iter_function()
+        .map(|func| func.borrow().id)
+        .collect();
+    for func in functions {
+        resolver.function(func);
+    }
+
+    resolver.diagnostics
+}
+
+/// The names that are visible in a block
+///
+/// A name maps to the variable that it's bound to. Names bound by for loops
+/// and patterns don't have a variable in the model, so they map to `None`.
+struct Scope {
+    block: usize,
+    names: HashMap<String, Option<usize>>,
+    parent: Option<usize>,
+}
+
+struct Resolver<'a> {
+    store: &'a mut LuDogStore,
+    diagnostics: Vec<Diagnostic>,
+    /// Span ids, by block and expression id
+    spans: HashMap<(usize, usize), usize>,
+    /// XValue ids, by block and expression id
+    values: HashMap<(usize, usize), usize>,
+    /// XValue ids, by variable id
+    variable_values: HashMap<usize, usize>,
+    /// Variable ids, by local variable id
+    locals: HashMap<usize, usize>,
+    /// Variable ids, by parameter id
+    params: HashMap<usize, usize>,
+    /// Variable ids, by lambda parameter id
+    lambda_params: HashMap<usize, usize>,
+    /// Free functions, by name
+    functions: HashMap<String, Vec<usize>>,
+    /// Functions in implementation blocks, by type and function name
+    methods: HashMap<(String, String), Vec<usize>>,
+    /// Names brought in by imports
+    imports: HashSet<String>,
+    /// All of the scopes in the function being resolved
+    scopes: Vec<Scope>,
+    /// The index of the innermost scope
+    scope: Option<usize>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(store: &'a mut LuDogStore) -> Self {
+        let mut spans = HashMap::new();
+        let mut values = HashMap::new();
+        let mut variable_values = HashMap::new();
+        let mut locals = HashMap::new();
+        let mut params = HashMap::new();
+        let mut lambda_params = HashMap::new();
+
+        for value in store.iter_x_value() {
+            let value = value.borrow();
+            match value.subtype {
+                XValueEnum::Expression(expr) => {
+                    values.insert((value.block, expr), value.id);
+                }
+                XValueEnum::Variable(var) => {
+                    variable_values.insert(var, value.id);
+                }
+            }
+        }
+        for span in store.iter_span() {
+            let span = span.borrow();
+            if let Some(ref value) = span.x_value {
+                let value = store.exhume_x_value(value).unwrap();
+                let value = value.borrow();
+                if let XValueEnum::Expression(expr) = value.subtype {
+                    spans.insert((value.block, expr), span.id);
+                }
+            }
+        }
+        for var in store.iter_variable() {
+            let var = var.borrow();
+            let (vars, id) = match var.subtype {
+                VariableEnum::LambdaParameter(id) => (&mut lambda_params, id),
+                VariableEnum::LocalVariable(id) => (&mut locals, id),
+                VariableEnum::Parameter(id) => (&mut params, id),
+            };
+            vars.insert(id, var.id);
+        }
+
+        let mut functions: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut methods: HashMap<(String, String), Vec<usize>> = HashMap::new();
+        for func in store.iter_function() {
+            let func = func.borrow();
+            match func.impl_block {
+                Some(ref id) => {
+                    let block = store.exhume_implementation_block(id).unwrap();
+                    let block = block.borrow();
+                    let ty = match (block.model_type, block.enumeration) {
+                        (Some(ref id), _) => {
+                            store.exhume_woog_struct(id).unwrap().borrow().name.clone()
+                        }
+                        (None, Some(ref id)) => {
+                            store.exhume_enumeration(id).unwrap().borrow().name.clone()
+                        }
+                        (None, None) => continue,
+                    };
+                    methods
+                        .entry((ty, func.name.clone()))
+                        .or_default()
+                        .push(func.id);
+                }
+                None => {
+                    functions
+                        .entry(func.name.clone())
+                        .or_default()
+                        .push(func.id);
+                }
+            }
+        }
+
+        let imports = store
+            .iter_import()
+            .map(|import| {
+                let import = import.borrow();
+                if import.has_alias {
+                    import.alias.clone()
+                } else {
+                    import.name.clone()
+                }
+            })
+            .collect();
+
+        Self {
+            store,
+            diagnostics: Vec::new(),
+            spans,
+            values,
+            variable_values,
+            locals,
+            params,
+            lambda_params,
+            functions,
+            methods,
+            imports,
+            scopes: Vec::new(),
+            scope: None,
+        }
+    }
+
+    fn report(&mut self, problem: Problem, expr: usize) {
+        let span = self
+            .spans
+            .get(&(self.block(), expr))
+            .map(|span| self.store.exhume_span(span).unwrap().borrow().clone());
+        self.diagnostics.push(Diagnostic { problem, span });
+    }
+
+    fn record(&mut self, expr: usize, resolution: Resolution) {
+        let block = self.block();
+        self.store.inter_resolution(block, expr, resolution);
+    }
+
+    /// Record the one candidate, or report that there wasn't exactly one
+    fn choose(&mut self, name: String, candidates: &[usize], expr: usize) {
+        match candidates {
+            [func] => self.record(expr, Resolution::Function(*func)),
+            [] => self.report(Problem::Unresolved { name }, expr),
+            _ => {
+                let problem = Problem::Ambiguous {
+                    name,
+                    candidates: candidates.len(),
+                };
+                self.report(problem, expr);
+            }
+        }
+    }
+
+    /// Is this the name of something that's defined outside of the store?
+    fn is_foreign(&self, name: &str) -> bool {
+        self.imports.contains(name)
+            || self.store.exhume_z_object_store_id_by_name(name).is_some()
+            || self.store.exhume_x_plugin_id_by_name(name).is_some()
+    }
+
+    //
+    // Scopes
+    //
+
+    /// The block of the innermost scope
+    fn block(&self) -> usize {
+        self.scopes[self.scope.expect("no scope")].block
+    }
+
+    /// Run `f` in a new scope for `block`, whose parent is `parent`
+    fn in_scope(&mut self, block: usize, parent: Option<usize>, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(Scope {
+            block,
+            names: HashMap::new(),
+            parent,
+        });
+        let outer = self.scope.replace(self.scopes.len() - 1);
+        f(self);
+        self.scope = outer;
+    }
+
+    /// Run `f` in a scope for bindings that aren't introduced by a block, like
+    /// the variable of a for loop. It stands in for the block that it's in.
+    fn in_nested_scope(&mut self, f: impl FnOnce(&mut Self)) {
+        let block = self.block();
+        self.in_scope(block, self.scope, f);
+    }
+
+    /// Find the innermost scope that belongs to `block`
+    fn find(&self, block: usize) -> Option<usize> {
+        let mut scope = self.scope;
+        while let Some(index) = scope {
+            if self.scopes[index].block == block {
+                return Some(index);
+            }
+            scope = self.scopes[index].parent;
+        }
+        None
+    }
+
+    fn define(&mut self, name: &str, var: Option<usize>) {
+        let scope = self.scope.expect("no scope");
+        self.scopes[scope].names.insert(name.to_owned(), var);
+    }
+
+    /// The binding of a name, if it's bound
+    fn lookup(&self, name: &str) -> Option<Option<usize>> {
+        let mut scope = self.scope;
+        while let Some(index) = scope {
+            if let Some(var) = self.scopes[index].names.get(name) {
+                return Some(*var);
+            }
+            scope = self.scopes[index].parent;
+        }
+        None
+    }
+
+    //
+    // Functions and blocks
+    //
+
+    fn function(&mut self, func: usize) {
+        let (body, mut next) = {
+            let func = self.store.exhume_function(&func).unwrap();
+            let func = func.borrow();
+            (func.body, func.first_param)
+        };
+        let block = match self.store.exhume_body(&body).unwrap().borrow().subtype {
+            BodyEnum::Block(block) => block,
+            BodyEnum::ExternalImplementation(_) => return,
+        };
+
+        let mut params = Vec::new();
+        while let Some(id) = next {
+            next = self.store.exhume_parameter(&id).unwrap().borrow().next;
+            params.push(self.params[&id]);
+        }
+
+        // Functions don't see the scopes around them.
+        self.scopes.clear();
+        self.scope = None;
+        self.block_with(block, params);
+    }
+
+    /// Resolve a block, with the variables that it starts out with
+    fn block_with(&mut self, block: usize, vars: Vec<usize>) {
+        let parent = self.store.exhume_block(&block).unwrap().borrow().parent;
+        let parent = parent.and_then(|parent| self.find(parent)).or(self.scope);
+
+        self.in_scope(block, parent, |this| {
+            for var in vars {
+                let name = this
+                    .store
+                    .exhume_variable(&var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                this.define(&name, Some(var));
+            }
+            this.statements(block);
+        });
+    }
+
+    fn statements(&mut self, block: usize) {
+        let mut next = self.store.exhume_block(&block).unwrap().borrow().statement;
+        while let Some(id) = next {
+            let statement = self.store.exhume_statement(&id).unwrap();
+            let subtype = statement.borrow().subtype.clone();
+            next = statement.borrow().next;
+
+            match subtype {
+                StatementEnum::ExpressionStatement(ref id) => {
+                    let expr = self
+                        .store
+                        .exhume_expression_statement(id)
+                        .unwrap()
+                        .borrow()
+                        .expression;
+                    self.expression(expr);
+                }
+                StatementEnum::ItemStatement(_) => {}
+                StatementEnum::LetStatement(ref id) => {
+                    let (expr, local) = {
+                        let stmt = self.store.exhume_let_statement(id).unwrap();
+                        let stmt = stmt.borrow();
+                        (stmt.expression, stmt.variable)
+                    };
+                    // The variable isn't in scope in it's own initializer.
+                    self.expression(expr);
+
+                    let var = self.locals[&local];
+                    let name = self
+                        .store
+                        .exhume_variable(&var)
+                        .unwrap()
+                        .borrow()
+                        .name
+                        .clone();
+                    self.define(&name, Some(var));
+                }
+                StatementEnum::ResultStatement(ref id) => {
+                    let expr = self
+                        .store
+                        .exhume_result_statement(id)
+                        .unwrap()
+                        .borrow()
+                        .expression;
+                    self.expression(expr);
+                }
+            }
+        }
+    }
+
+    //
+    // Expressions
+    //
+
+    fn expression(&mut self, id: usize) {
+        let expr = self.store.exhume_expression(&id).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::AWait(ref a_wait) => {
+                let future = self.store.exhume_a_wait(a_wait).unwrap().borrow().x_future;
+                self.expression(future);
+            }
+            ExpressionEnum::Block(ref block) => self.block_with(*block, Vec::new()),
+            ExpressionEnum::XBreak(ref x_break) => {
+                let expr = self
+                    .store
+                    .exhume_x_break(x_break)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                if let Some(expr) = expr {
+                    self.expression(expr);
+                }
+            }
+            ExpressionEnum::Call(ref call) => self.call(*call, id),
+            ExpressionEnum::XContinue(_)
+            | ExpressionEnum::XDebugger(_)
+            | ExpressionEnum::EmptyExpression(_) => {}
+            ExpressionEnum::FieldAccess(ref access) => self.field_access(*access, id),
+            ExpressionEnum::FieldExpression(ref field) => {
+                let expr = self
+                    .store
+                    .exhume_field_expression(field)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::ForLoop(ref for_loop) => {
+                let (ident, block, expr) = {
+                    let for_loop = self.store.exhume_for_loop(for_loop).unwrap();
+                    let for_loop = for_loop.borrow();
+                    (for_loop.ident.clone(), for_loop.block, for_loop.expression)
+                };
+                self.expression(expr);
+                self.in_nested_scope(|this| {
+                    this.define(&ident, None);
+                    this.expression(block);
+                });
+            }
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::HaltAndCatchFire(ref hcf) => {
+                let expr = self
+                    .store
+                    .exhume_halt_and_catch_fire(hcf)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::XIf(ref x_if) => {
+                let (test, true_block, false_block) = {
+                    let x_if = self.store.exhume_x_if(x_if).unwrap();
+                    let x_if = x_if.borrow();
+                    (x_if.test, x_if.true_block, x_if.false_block)
+                };
+                self.expression(test);
+                self.block_with(true_block, Vec::new());
+                if let Some(expr) = false_block {
+                    self.expression(expr);
+                }
+            }
+            ExpressionEnum::Index(ref index) => {
+                let (index, target) = {
+                    let index = self.store.exhume_index(index).unwrap();
+                    let index = index.borrow();
+                    (index.index, index.target)
+                };
+                self.expression(target);
+                self.expression(index);
+            }
+            ExpressionEnum::Lambda(ref lambda) => self.lambda(*lambda),
+            ExpressionEnum::ListElement(ref element) => {
+                let expr = self
+                    .store
+                    .exhume_list_element(element)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::ListExpression(ref list) => {
+                let mut next = self
+                    .store
+                    .exhume_list_expression(list)
+                    .unwrap()
+                    .borrow()
+                    .elements;
+                while let Some(id) = next {
+                    let (expr, following) = {
+                        let element = self.store.exhume_list_element(&id).unwrap();
+                        let element = element.borrow();
+                        (element.expression, element.next)
+                    };
+                    next = following;
+                    self.expression(expr);
+                }
+            }
+            ExpressionEnum::Literal(ref literal) => self.literal(*literal),
+            ExpressionEnum::XLoop(ref x_loop) => {
+                let block = self.store.exhume_x_loop(x_loop).unwrap().borrow().block;
+                self.expression(block);
+            }
+            ExpressionEnum::XMatch(ref x_match) => self.x_match(*x_match),
+            ExpressionEnum::Operator(ref operator) => {
+                let (lhs, rhs) = {
+                    let operator = self.store.exhume_operator(operator).unwrap();
+                    let operator = operator.borrow();
+                    (operator.lhs, operator.rhs)
+                };
+                self.expression(lhs);
+                if let Some(rhs) = rhs {
+                    self.expression(rhs);
+                }
+            }
+            ExpressionEnum::XPath(ref path) => self.path(*path, id),
+            ExpressionEnum::XPrint(ref print) => {
+                let expr = self
+                    .store
+                    .exhume_x_print(print)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::RangeExpression(ref range) => {
+                let (lhs, rhs) = {
+                    let range = self.store.exhume_range_expression(range).unwrap();
+                    let range = range.borrow();
+                    (range.lhs, range.rhs)
+                };
+                for bound in lhs.into_iter().chain(rhs) {
+                    self.expression(bound);
+                }
+            }
+            ExpressionEnum::XReturn(ref x_return) => {
+                let expr = self
+                    .store
+                    .exhume_x_return(x_return)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.expression(expr);
+            }
+            ExpressionEnum::StructExpression(ref expr) => {
+                self.struct_expression(*expr, id);
+                for field in self.field_expressions(*expr) {
+                    self.expression(field);
+                }
+            }
+            ExpressionEnum::TypeCast(ref cast) => {
+                let lhs = self.store.exhume_type_cast(cast).unwrap().borrow().lhs;
+                self.expression(lhs);
+            }
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                self.name(name, id);
+            }
+            ExpressionEnum::XWhile(ref x_while) => {
+                let (test, block) = {
+                    let x_while = self.store.exhume_x_while(x_while).unwrap();
+                    let x_while = x_while.borrow();
+                    (x_while.test, x_while.block)
+                };
+                self.expression(test);
+                self.expression(block);
+            }
+        }
+    }
+
+    /// Resolve a bare name, to a variable if one is in scope, and otherwise to
+    /// a free function
+    fn name(&mut self, name: String, expr: usize) {
+        match self.lookup(&name) {
+            Some(Some(var)) => self.record(expr, Resolution::Variable(var)),
+            Some(None) => {}
+            None if self.is_foreign(&name) => {}
+            None => {
+                let candidates = self.functions.get(&name).cloned().unwrap_or_default();
+                self.choose(name, &candidates, expr);
+            }
+        }
+    }
+
+    fn literal(&mut self, id: usize) {
+        let subtype = self
+            .store
+            .exhume_literal(&id)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone();
+
+        match subtype {
+            LiteralEnum::FormatString(ref id) => {
+                let mut next = self
+                    .store
+                    .exhume_format_string(id)
+                    .unwrap()
+                    .borrow()
+                    .first_format_bit;
+                while let Some(id) = next {
+                    let bit = self.store.exhume_format_bit(&id).unwrap();
+                    let subtype = bit.borrow().subtype.clone();
+                    next = bit.borrow().next;
+                    if let FormatBitEnum::ExpressionBit(ref id) = subtype {
+                        let expr = self
+                            .store
+                            .exhume_expression_bit(id)
+                            .unwrap()
+                            .borrow()
+                            .expression;
+                        self.expression(expr);
+                    }
+                }
+            }
+            LiteralEnum::MapExpression(ref id) => {
+                let elements: Vec<_> = self
+                    .store
+                    .iter_map_element()
+                    .filter(|element| element.borrow().map == *id)
+                    .map(|element| {
+                        let element = element.borrow();
+                        (element.key, element.x_value)
+                    })
+                    .collect();
+                for (key, value) in elements {
+                    self.expression(key);
+                    self.expression(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn path_names(&self, path: usize) -> Vec<String> {
+        let mut names = Vec::new();
+        let mut next = self.store.exhume_x_path(&path).unwrap().borrow().first;
+        while let Some(id) = next {
+            let element = self.store.exhume_path_element(&id).unwrap();
+            let element = element.borrow();
+            next = element.next;
+            names.push(element.name.clone());
+        }
+
+        names
+    }
+
+    /// `Enum::Variant` is an enum field, `Type::func` is a function in an
+    /// implementation block, and a single name is resolved like any other.
+    fn path(&mut self, path: usize, expr: usize) {
+        let names = self.path_names(path);
+
+        match names.as_slice() {
+            [name] => self.name(name.clone(), expr),
+            [.., ty, name] => self.qualified(ty, name, expr),
+            [] => {}
+        }
+    }
+
+    /// Resolve `ty::name`
+    fn qualified(&mut self, ty: &str, name: &str, expr: usize) {
+        if self.is_foreign(ty) {
+            return;
+        }
+        if let Some(field) = self.enum_field(ty, name) {
+            self.record(expr, Resolution::EnumField(field));
+            return;
+        }
+
+        let candidates = self
+            .methods
+            .get(&(ty.to_owned(), name.to_owned()))
+            .cloned()
+            .unwrap_or_default();
+        self.choose(format!("{}::{}", ty, name), &candidates, expr);
+    }
+
+    /// The variant of an enum, by the names of both
+    fn enum_field(&self, woog_enum: &str, name: &str) -> Option<usize> {
+        let woog_enum = self.store.exhume_enumeration_id_by_name(woog_enum)?;
+        let field = self.store.iter_enum_field().find(|field| {
+            let field = field.borrow();
+            field.woog_enum == woog_enum && field.name == name
+        })?;
+        let id = field.borrow().id;
+        Some(id)
+    }
+
+    fn arguments(&mut self, first: Option<usize>) {
+        let mut next = first;
+        while let Some(id) = next {
+            let (expr, following) = {
+                let arg = self.store.exhume_argument(&id).unwrap();
+                let arg = arg.borrow();
+                (arg.expression, arg.next)
+            };
+            next = following;
+            self.expression(expr);
+        }
+    }
+
+    fn call(&mut self, id: usize, expr: usize) {
+        let call = self.store.exhume_call(&id).unwrap();
+        let call = call.borrow().clone();
+
+        match call.subtype {
+            CallEnum::FunctionCall(ref id) => {
+                let name = self
+                    .store
+                    .exhume_function_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                match call.expression {
+                    // The callee is resolved in it's own right, and the call
+                    // resolves to the same thing.
+                    Some(callee) => {
+                        self.expression(callee);
+                        let block = self.block();
+                        if let Some(resolution) = self.store.exhume_resolution(&block, &callee) {
+                            self.record(expr, resolution);
+                        }
+                    }
+                    None => self.name(name, expr),
+                }
+                self.arguments(call.argument);
+            }
+            CallEnum::MacroCall(_) => self.arguments(call.argument),
+            CallEnum::MethodCall(ref id) => {
+                let name = self
+                    .store
+                    .exhume_method_call(id)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                if let Some(receiver) = call.expression {
+                    self.expression(receiver);
+                    self.method(receiver, name, expr);
+                }
+                self.arguments(call.argument);
+            }
+            CallEnum::StaticMethodCall(ref id) => {
+                let (ty, func) = {
+                    let method = self.store.exhume_static_method_call(id).unwrap();
+                    let method = method.borrow();
+                    (method.ty.clone(), method.func.clone())
+                };
+                self.qualified(&ty, &func, expr);
+                self.arguments(call.argument);
+            }
+        }
+    }
+
+    /// Resolve a method by the type of it's receiver. When the type isn't
+    /// known, a method name that's only defined once is good enough.
+    fn method(&mut self, receiver: usize, name: String, expr: usize) {
+        if let Some(ty) = self.receiver_type(receiver) {
+            let candidates = self
+                .methods
+                .get(&(ty.clone(), name.clone()))
+                .cloned()
+                .unwrap_or_default();
+            self.choose(name, &candidates, expr);
+            return;
+        }
+
+        let candidates: Vec<_> = self
+            .methods
+            .iter()
+            .filter(|((_, func), _)| *func == name)
+            .flat_map(|(_, funcs)| funcs.iter().copied())
+            .collect();
+        if candidates.is_empty() && BUILT_IN_METHODS.contains(&name.as_str()) {
+            return;
+        }
+        self.choose(name, &candidates, expr);
+    }
+
+    /// The name of the struct or enum that the receiver of a method is, if
+    /// that's known
+    fn receiver_type(&self, receiver: usize) -> Option<String> {
+        let block = self.block();
+        let from_expression = self.values.get(&(block, receiver));
+        let from_variable = match self.store.exhume_resolution(&block, &receiver) {
+            Some(Resolution::Variable(var)) => self.variable_values.get(&var),
+            _ => None,
+        };
+
+        from_expression
+            .into_iter()
+            .chain(from_variable)
+            .find_map(|value| {
+                let ty = self.store.exhume_x_value(value).unwrap().borrow().ty;
+                match self.store.exhume_value_type(&ty).unwrap().borrow().subtype {
+                    ValueTypeEnum::WoogStruct(ref id) => Some(
+                        self.store
+                            .exhume_woog_struct(id)
+                            .unwrap()
+                            .borrow()
+                            .name
+                            .clone(),
+                    ),
+                    ValueTypeEnum::Enumeration(ref id) => Some(
+                        self.store
+                            .exhume_enumeration(id)
+                            .unwrap()
+                            .borrow()
+                            .name
+                            .clone(),
+                    ),
+                    _ => None,
+                }
+            })
+    }
+
+    fn field_access(&mut self, id: usize, expr: usize) {
+        let (receiver, target) = {
+            let access = self.store.exhume_field_access(&id).unwrap();
+            let access = access.borrow();
+            (access.expression, access.field)
+        };
+        self.expression(receiver);
+
+        let target = self
+            .store
+            .exhume_field_access_target(&target)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone();
+        let resolution = match target {
+            FieldAccessTargetEnum::EnumField(field) => Resolution::EnumField(field),
+            FieldAccessTargetEnum::Field(field) => Resolution::Field(field),
+            FieldAccessTargetEnum::Function(func) => Resolution::Function(func),
+        };
+        self.record(expr, resolution);
+    }
+
+    fn lambda(&mut self, id: usize) {
+        let (body, mut next) = {
+            let lambda = self.store.exhume_lambda(&id).unwrap();
+            let lambda = lambda.borrow();
+            (lambda.body, lambda.first_param)
+        };
+
+        let mut params = Vec::new();
+        while let Some(id) = next {
+            next = self
+                .store
+                .exhume_lambda_parameter(&id)
+                .unwrap()
+                .borrow()
+                .next;
+            params.push(self.lambda_params[&id]);
+        }
+
+        if let Some(ref body) = body {
+            if let BodyEnum::Block(block) = self.store.exhume_body(body).unwrap().borrow().subtype {
+                // Lambdas see the scopes around them.
+                self.block_with(block, params);
+            }
+        }
+    }
+
+    fn x_match(&mut self, id: usize) {
+        let scrutinee = self.store.exhume_x_match(&id).unwrap().borrow().scrutinee;
+        self.expression(scrutinee);
+
+        let arms: Vec<_> = self
+            .store
+            .iter_pattern()
+            .filter(|pattern| pattern.borrow().x_match == id)
+            .map(|pattern| {
+                let pattern = pattern.borrow();
+                (pattern.match_expr, pattern.expression)
+            })
+            .collect();
+        for (pattern, expr) in arms {
+            self.in_nested_scope(|this| {
+                this.pattern(pattern);
+                this.expression(expr);
+            });
+        }
+    }
+
+    /// Bind the variables in a pattern, and resolve the rest of it
+    fn pattern(&mut self, pattern: usize) {
+        let expr = self.store.exhume_expression(&pattern).unwrap();
+        let subtype = expr.borrow().subtype.clone();
+
+        match subtype {
+            ExpressionEnum::VariableExpression(ref var) => {
+                let name = self
+                    .store
+                    .exhume_variable_expression(var)
+                    .unwrap()
+                    .borrow()
+                    .name
+                    .clone();
+                if name != "_" {
+                    self.define(&name, None);
+                }
+            }
+            ExpressionEnum::Grouped(ref grouped) => {
+                let expr = self
+                    .store
+                    .exhume_grouped(grouped)
+                    .unwrap()
+                    .borrow()
+                    .expression;
+                self.pattern(expr);
+            }
+            ExpressionEnum::StructExpression(ref id) => {
+                self.struct_expression(*id, pattern);
+                for field in self.field_expressions(*id) {
+                    self.pattern(field);
+                }
+            }
+            // Anything else is a value to compare against.
+            _ => self.expression(pattern),
+        }
+    }
+
+    /// The expressions of the fields of a struct expression
+    fn field_expressions(&self, id: usize) -> Vec<usize> {
+        self.store
+            .iter_field_expression()
+            .filter(|field| field.borrow().woog_struct == id)
+            .map(|field| field.borrow().expression)
+            .collect()
+    }
+
+    /// An enum variant with fields resolves to it's enum field.
+    fn struct_expression(&mut self, id: usize, expr: usize) {
+        let (data, path) = {
+            let expr = self.store.exhume_struct_expression(&id).unwrap();
+            let expr = expr.borrow();
+            (expr.data, expr.x_path)
+        };
+        let data = self
+            .store
+            .exhume_data_structure(&data)
+            .unwrap()
+            .borrow()
+            .subtype
+            .clone();
+
+        if let DataStructureEnum::Enumeration(ref woog_enum) = data {
+            let woog_enum = self
+                .store
+                .exhume_enumeration(woog_enum)
+                .unwrap()
+                .borrow()
+                .name
+                .clone();
+            if let Some(variant) = self.path_names(path).last() {
+                match self.enum_field(&woog_enum, variant) {
+                    Some(field) => self.record(expr, Resolution::EnumField(field)),
+                    None => {
+                        let name = format!("{}::{}", woog_enum, variant);
+                        self.report(Problem::Unresolved { name }, expr);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::dwarf::sample::{Sample, Stmt};
+    use crate::v2::lu_dog_vec::Block;
+
+    #[test]
+    fn test_namespaces() {
+        let mut s = Sample::new("namespaces");
+
+        // struct Point { x: int }
+        // impl Point { fn new() -> Point { Point { x: 0 } } }
+        // struct Line {}
+        // impl Line { fn new() -> Line { Line {} } }
+        let int = s.int_ty();
+        let point = s.structure("Point", &[("x", int.clone())]);
+        let point_ty = s.struct_ty(&point);
+        let line = s.structure("Line", &[]);
+        let line_ty = s.struct_ty(&line);
+        let point_impl = s.implementation(&point);
+        let line_impl = s.implementation(&line);
+        let point_new = s.function("new", &[], &point_ty, Some(&point_impl));
+        let zero = s.int(0);
+        let origin = s.struct_expr(&point, vec![("x", zero)]);
+        s.statements(&point_new, vec![Stmt::Result(origin)]);
+        let line_new = s.function("new", &[], &line_ty, Some(&line_impl));
+        let nothing = s.struct_expr(&line, vec![]);
+        s.statements(&line_new, vec![Stmt::Result(nothing)]);
+
+        // fn main() {
+        //     let p = Point::new();
+        //     let l = Line::new();
+        //     p.x
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let new_point = s.static_call("Point", "new", vec![]);
+        let new_line = s.static_call("Line", "new", vec![]);
+        let p = s.var("p");
+        let x = s.field(&p, &point, "x");
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("p", None, new_point.clone()),
+                Stmt::Let("l", None, new_line.clone()),
+                Stmt::Result(x.clone()),
+            ],
+        );
+
+        let diagnostics = resolve(&mut s.store);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+        let store = &s.store;
+        let main = main.borrow().id;
+        let function_of = |block: &Rc<RefCell<Block>>| {
+            let block = block.borrow().id;
+            store
+                .iter_function()
+                .find(|func| {
+                    let body = store.exhume_body(&func.borrow().body).unwrap();
+                    let body = body.borrow();
+                    body.subtype == BodyEnum::Block(block)
+                })
+                .map(|func| func.borrow().id)
+                .unwrap()
+        };
+        assert_eq!(
+            store.exhume_resolution(&main, &new_point.borrow().id),
+            Some(Resolution::Function(function_of(&point_new)))
+        );
+        assert_eq!(
+            store.exhume_resolution(&main, &new_line.borrow().id),
+            Some(Resolution::Function(function_of(&line_new)))
+        );
+
+        let field = store
+            .iter_field()
+            .find(|field| field.borrow().name == "x")
+            .unwrap();
+        assert_eq!(
+            store.exhume_resolution(&main, &x.borrow().id),
+            Some(Resolution::Field(field.borrow().id))
+        );
+        let p_var = store
+            .iter_variable()
+            .find(|var| var.borrow().name == "p")
+            .unwrap();
+        assert_eq!(
+            store.exhume_resolution(&main, &p.borrow().id),
+            Some(Resolution::Variable(p_var.borrow().id))
+        );
+    }
+
+    #[test]
+    fn test_scopes() {
+        let mut s = Sample::new("scopes");
+
+        // fn main() {
+        //     let x = 1;
+        //     {
+        //         let x = 2;
+        //         x
+        //     };
+        //     let add = |y| x + y;
+        //     x
+        // }
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+        let one = s.int(1);
+        let two = s.int(2);
+        let x = s.var("x");
+        let y = s.var("y");
+
+        let inner = s.block(&main);
+        s.statements(
+            &inner,
+            vec![Stmt::Let("x", None, two), Stmt::Result(x.clone())],
+        );
+        let inner_expr = s.block_expr(&inner);
+
+        let unknown = s.unknown_ty();
+        let (lambda, body) = s.lambda(&[("y", None)], &unknown, &main);
+        let sum = s.binary("+", &x, &y);
+        s.statements(&body, vec![Stmt::Result(sum)]);
+
+        s.statements(
+            &main,
+            vec![
+                Stmt::Let("x", None, one),
+                Stmt::Expr(inner_expr),
+                Stmt::Let("add", None, lambda),
+                Stmt::Result(x.clone()),
+            ],
+        );
+
+        let diagnostics = resolve(&mut s.store);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+        let store = &s.store;
+        // The variable named `x` that's declared in a block
+        let x_in = |block: &Rc<RefCell<Block>>| {
+            store
+                .iter_x_value()
+                .find_map(|value| match value.borrow().subtype {
+                    XValueEnum::Variable(var)
+                        if value.borrow().block == block.borrow().id
+                            && store.exhume_variable(&var).unwrap().borrow().name == "x" =>
+                    {
+                        Some(var)
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let (outer_x, inner_x) = (x_in(&main), x_in(&inner));
+        let y_var = store
+            .iter_variable()
+            .find(|var| var.borrow().name == "y")
+            .unwrap()
+            .borrow()
+            .id;
+
+        let x = x.borrow().id;
+        let resolution = |block: &Rc<RefCell<Block>>, expr: usize| {
+            store.exhume_resolution(&block.borrow().id, &expr)
+        };
+        assert_eq!(resolution(&main, x), Some(Resolution::Variable(outer_x)));
+        assert_eq!(resolution(&inner, x), Some(Resolution::Variable(inner_x)));
+        assert_eq!(resolution(&body, x), Some(Resolution::Variable(outer_x)));
+        assert_eq!(
+            resolution(&body, y.borrow().id),
+            Some(Resolution::Variable(y_var))
+        );
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let mut s = Sample::new("diagnostics");
+
+        // struct Circle {}
+        // impl Circle { fn area(self) -> float { 0.0 } }
+        // struct Square {}
+        // impl Square { fn area(self) -> float { 0.0 } }
+        let float = s.float_ty();
+        let zero = s.float(0.0);
+        for name in ["Circle", "Square"] {
+            let shape = s.structure(name, &[]);
+            let ty = s.struct_ty(&shape);
+            let block = s.implementation(&shape);
+            let area = s.function("area", &[("self", ty)], &float, Some(&block));
+            s.statements(&area, vec![Stmt::Result(zero.clone())]);
+        }
+
+        // fn main(shape: ?) {
+        //     shape.area();
+        //     missing;
+        //     Circle::perimeter();
+        // }
+        let empty = s.empty_ty();
+        let unknown = s.unknown_ty();
+        let main = s.function("main", &[("shape", unknown)], &empty, None);
+        let shape = s.var("shape");
+        let area = s.method(&shape, "area", vec![]);
+        let missing = s.var("missing");
+        s.span(&missing, &main, 30, 37);
+        let perimeter = s.static_call("Circle", "perimeter", vec![]);
+        s.statements(
+            &main,
+            vec![Stmt::Expr(area), Stmt::Expr(missing), Stmt::Expr(perimeter)],
+        );
+
+        let diagnostics = resolve(&mut s.store);
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.problem.clone())
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                Problem::Ambiguous {
+                    name: "area".to_owned(),
+                    candidates: 2,
+                },
+                Problem::Unresolved {
+                    name: "missing".to_owned(),
+                },
+                Problem::Unresolved {
+                    name: "Circle::perimeter".to_owned(),
+                },
+            ]
+        );
+
+        assert!(diagnostics[0].span.is_none());
+        let span = diagnostics[1].span.as_ref().unwrap();
+        assert_eq!((span.start, span.end), (30, 37));
+        assert_eq!(diagnostics[1].to_string(), "cannot find `missing`");
+    }
+}
@@ -0,0 +1,718 @@
+//! Sample Programs
+//!
+//! There's no dwarf parser in this crate, so tests build their programs a node
+//! at a time. This takes care of the bookkeeping: linking lists, naming
+//! variables, and wiring statements into blocks.
+//!
+//! Blocks are created from the top down, since statements need to know their
+//! block, and expressions from the bottom up.
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::v2::{
+    lu_dog_vec::{
+        Argument, Binary, Block, Body, BooleanLiteral, BooleanOperator, Call, CharLiteral,
+        Comparison, DataStructure, DwarfSourceFile, EnumField, Enumeration, Expression,
+        ExpressionBit, ExpressionStatement, Field, FieldAccess, FieldAccessTarget, FieldExpression,
+        FloatLiteral, ForLoop, FormatBit, FormatString, Function, FunctionCall, Grouped,
+        ImplementationBlock, Import, Index, IntegerLiteral, Item, Lambda, LambdaParameter,
+        LetStatement, List, ListElement, ListExpression, Literal, LocalVariable, MethodCall,
+        NamedFieldExpression, ObjectStore as LuDogStore, Operator, Parameter, PathElement, Pattern,
+        RangeExpression, ResultStatement, Span, Statement, StaticMethodCall, StringBit,
+        StringLiteral, StructExpression, TupleField, TypeCast, Unary, Unit, UnnamedFieldExpression,
+        ValueType, Variable, VariableExpression, WoogStruct, XBreak, XContinue, XIf, XLoop, XMatch,
+        XPath, XPrint, XReturn, XValue, XWhile,
+    },
+    sarzak::{store::ObjectStore as SarzakStore, types::Ty},
+};
+
+pub(crate) type Expr = Rc<RefCell<Expression>>;
+pub(crate) type Type = Rc<RefCell<ValueType>>;
+
+/// A statement, to be added to a block
+///
+pub(crate) enum Stmt {
+    /// `let name: ty = expr;`, with no type annotation if `ty` is `None`
+    Let(&'static str, Option<Type>, Expr),
+    /// `expr;`
+    Expr(Expr),
+    /// `expr`, as the value of the block
+    Result(Expr),
+}
+
+/// A piece of a format string
+///
+pub(crate) enum Bit {
+    Str(&'static str),
+    Expr(Expr),
+}
+
+/// A dwarf program under construction
+///
+pub(crate) struct Sample {
+    pub(crate) store: LuDogStore,
+    sarzak: SarzakStore,
+    source: Rc<RefCell<DwarfSourceFile>>,
+}
+
+impl Sample {
+    pub(crate) fn new(name: &str) -> Self {
+        let mut store = LuDogStore::new();
+        let source = DwarfSourceFile::new(
+            name.to_owned(),
+            format!("{}.tao", name),
+            name.to_owned(),
+            &mut store,
+        );
+
+        Self {
+            store,
+            sarzak: SarzakStore::new(),
+            source,
+        }
+    }
+
+    pub(crate) fn source(&self) -> Rc<RefCell<DwarfSourceFile>> {
+        self.source.clone()
+    }
+
+    //
+    // Types
+    //
+
+    pub(crate) fn int_ty(&mut self) -> Type {
+        ValueType::new_ty(false, &Ty::new_integer(&self.sarzak), &mut self.store)
+    }
+
+    pub(crate) fn float_ty(&mut self) -> Type {
+        ValueType::new_ty(false, &Ty::new_float(&self.sarzak), &mut self.store)
+    }
+
+    pub(crate) fn bool_ty(&mut self) -> Type {
+        ValueType::new_ty(false, &Ty::new_boolean(&self.sarzak), &mut self.store)
+    }
+
+    pub(crate) fn string_ty(&mut self) -> Type {
+        ValueType::new_ty(false, &Ty::new_z_string(&self.sarzak), &mut self.store)
+    }
+
+    pub(crate) fn empty_ty(&mut self) -> Type {
+        ValueType::new_empty(false, &mut self.store)
+    }
+
+    pub(crate) fn unknown_ty(&mut self) -> Type {
+        ValueType::new_unknown(false, &mut self.store)
+    }
+
+    pub(crate) fn list_ty(&mut self, ty: &Type) -> Type {
+        let list = List::new(ty, &mut self.store);
+        ValueType::new_list(false, &list, &mut self.store)
+    }
+
+    pub(crate) fn struct_ty(&mut self, woog_struct: &Rc<RefCell<WoogStruct>>) -> Type {
+        ValueType::new_woog_struct(false, woog_struct, &mut self.store)
+    }
+
+    pub(crate) fn enum_ty(&mut self, woog_enum: &Rc<RefCell<Enumeration>>) -> Type {
+        ValueType::new_enumeration(false, woog_enum, &mut self.store)
+    }
+
+    //
+    // Items
+    //
+
+    pub(crate) fn import(&mut self, path: &str, name: &str, alias: Option<&str>) {
+        let import = Import::new(
+            alias.unwrap_or_default().to_owned(),
+            alias.is_some(),
+            name.to_owned(),
+            path.to_owned(),
+            None,
+            &mut self.store,
+        );
+        Item::new_import(&self.source, &import, &mut self.store);
+    }
+
+    pub(crate) fn structure(
+        &mut self,
+        name: &str,
+        fields: &[(&str, Type)],
+    ) -> Rc<RefCell<WoogStruct>> {
+        let woog_struct = WoogStruct::new(
+            name.to_owned(),
+            name.to_owned(),
+            None,
+            None,
+            &mut self.store,
+        );
+        for (name, ty) in fields {
+            Field::new((*name).to_owned(), &woog_struct, ty, &mut self.store);
+        }
+        Item::new_woog_struct(&self.source, &woog_struct, &mut self.store);
+
+        woog_struct
+    }
+
+    /// An enum, with unit variants, and tuple variants when there's a type
+    ///
+    pub(crate) fn enumeration(
+        &mut self,
+        name: &str,
+        variants: &[(&str, Option<Type>)],
+    ) -> Rc<RefCell<Enumeration>> {
+        let woog_enum = Enumeration::new(name.to_owned(), name.to_owned(), None, &mut self.store);
+        for (n, (name, ty)) in variants.iter().enumerate() {
+            match ty {
+                Some(ty) => {
+                    let field = TupleField::new(Uuid::new_v4(), ty, &mut self.store);
+                    EnumField::new_tuple_field(
+                        (*name).to_owned(),
+                        &woog_enum,
+                        &field,
+                        &mut self.store,
+                    );
+                }
+                None => {
+                    let unit = Unit::new(n as i64, &mut self.store);
+                    EnumField::new_unit((*name).to_owned(), &woog_enum, &unit, &mut self.store);
+                }
+            }
+        }
+        Item::new_enumeration(&self.source, &woog_enum, &mut self.store);
+
+        woog_enum
+    }
+
+    pub(crate) fn implementation(
+        &mut self,
+        woog_struct: &Rc<RefCell<WoogStruct>>,
+    ) -> Rc<RefCell<ImplementationBlock>> {
+        let block = ImplementationBlock::new(None, Some(woog_struct), None, &mut self.store);
+        Item::new_implementation_block(&self.source, &block, &mut self.store);
+
+        block
+    }
+
+    /// A function, returning the block that is it's body
+    ///
+    pub(crate) fn function(
+        &mut self,
+        name: &str,
+        params: &[(&str, Type)],
+        return_type: &Type,
+        impl_block: Option<&Rc<RefCell<ImplementationBlock>>>,
+    ) -> Rc<RefCell<Block>> {
+        let block = Block::new(false, Uuid::new_v4(), None, None, &mut self.store);
+        let body = Body::new_block(false, &block, &mut self.store);
+        let func = Function::new(
+            name.to_owned(),
+            &body,
+            None,
+            None,
+            impl_block,
+            return_type,
+            &mut self.store,
+        );
+
+        let mut next = None;
+        for (n, (name, ty)) in params.iter().enumerate().rev() {
+            let param = Parameter::new(n as i64, &func, next.as_ref(), ty, &mut self.store);
+            let var = Variable::new_parameter((*name).to_owned(), &param, &mut self.store);
+            XValue::new_variable(&block, ty, &var, &mut self.store);
+            next = Some(param);
+        }
+        func.borrow_mut().first_param = next.map(|param| param.borrow().id);
+
+        if impl_block.is_none() {
+            Item::new_function(&self.source, &func, &mut self.store);
+        }
+
+        block
+    }
+
+    pub(crate) fn block(&mut self, parent: &Rc<RefCell<Block>>) -> Rc<RefCell<Block>> {
+        Block::new(false, Uuid::new_v4(), Some(parent), None, &mut self.store)
+    }
+
+    /// Fill a block with statements
+    ///
+    pub(crate) fn statements(&mut self, block: &Rc<RefCell<Block>>, statements: Vec<Stmt>) {
+        let mut next: Option<Rc<RefCell<Statement>>> = None;
+        for (index, statement) in statements.into_iter().enumerate().rev() {
+            let index = index as i64;
+            let statement = match statement {
+                Stmt::Let(name, ty, expr) => {
+                    let local = LocalVariable::new(Uuid::new_v4(), &mut self.store);
+                    let var =
+                        Variable::new_local_variable(name.to_owned(), &local, &mut self.store);
+                    let ty = ty.unwrap_or_else(|| self.unknown_ty());
+                    XValue::new_variable(block, &ty, &var, &mut self.store);
+                    let stmt = LetStatement::new(&expr, &local, &mut self.store);
+                    Statement::new_let_statement(
+                        index,
+                        block,
+                        next.as_ref(),
+                        &stmt,
+                        &mut self.store,
+                    )
+                }
+                Stmt::Expr(expr) => {
+                    let stmt = ExpressionStatement::new(&expr, &mut self.store);
+                    Statement::new_expression_statement(
+                        index,
+                        block,
+                        next.as_ref(),
+                        &stmt,
+                        &mut self.store,
+                    )
+                }
+                Stmt::Result(expr) => {
+                    let stmt = ResultStatement::new(&expr, &mut self.store);
+                    Statement::new_result_statement(
+                        index,
+                        block,
+                        next.as_ref(),
+                        &stmt,
+                        &mut self.store,
+                    )
+                }
+            };
+            next = Some(statement);
+        }
+        block.borrow_mut().statement = next.map(|statement| statement.borrow().id);
+    }
+
+    /// Give an expression a span, as the parser would
+    ///
+    pub(crate) fn span(&mut self, expr: &Expr, block: &Rc<RefCell<Block>>, start: i64, end: i64) {
+        let ty = self.unknown_ty();
+        let value = XValue::new_expression(block, &ty, expr, &mut self.store);
+        Span::new(
+            end,
+            start,
+            &self.source,
+            None,
+            Some(&value),
+            &mut self.store,
+        );
+    }
+
+    pub(crate) fn block_expr(&mut self, block: &Rc<RefCell<Block>>) -> Expr {
+        Expression::new_block(false, block, &mut self.store)
+    }
+
+    //
+    // Literals
+    //
+
+    pub(crate) fn int(&mut self, value: i64) -> Expr {
+        let int = IntegerLiteral::new(value, &mut self.store);
+        let literal = Literal::new_integer_literal(false, &int, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    pub(crate) fn float(&mut self, value: f64) -> Expr {
+        let float = FloatLiteral::new(value, &mut self.store);
+        let literal = Literal::new_float_literal(false, &float, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    pub(crate) fn string(&mut self, value: &str) -> Expr {
+        let string = StringLiteral::new(value.to_owned(), &mut self.store);
+        let literal = Literal::new_string_literal(false, &string, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    pub(crate) fn boolean(&mut self, value: bool) -> Expr {
+        let boolean = if value {
+            BooleanLiteral::new_true_literal(false, &mut self.store)
+        } else {
+            BooleanLiteral::new_false_literal(false, &mut self.store)
+        };
+        let literal = Literal::new_boolean_literal(false, &boolean, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    pub(crate) fn character(&mut self, value: char) -> Expr {
+        let character = CharLiteral::new(value as i64, &mut self.store);
+        let literal = Literal::new_char_literal(false, &character, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    pub(crate) fn format(&mut self, bits: Vec<Bit>) -> Expr {
+        let format = FormatString::new(None, &mut self.store);
+        let mut next = None;
+        for bit in bits.into_iter().rev() {
+            let bit = match bit {
+                Bit::Str(s) => {
+                    let string = StringLiteral::new(s.to_owned(), &mut self.store);
+                    let bit = StringBit::new(&string, &mut self.store);
+                    FormatBit::new_string_bit(&format, next.as_ref(), &bit, &mut self.store)
+                }
+                Bit::Expr(expr) => {
+                    let bit = ExpressionBit::new(&expr, &mut self.store);
+                    FormatBit::new_expression_bit(&format, next.as_ref(), &bit, &mut self.store)
+                }
+            };
+            next = Some(bit);
+        }
+        format.borrow_mut().first_format_bit = next.map(|bit| bit.borrow().id);
+
+        let literal = Literal::new_format_string(false, &format, &mut self.store);
+        Expression::new_literal(false, &literal, &mut self.store)
+    }
+
+    //
+    // Expressions
+    //
+
+    pub(crate) fn var(&mut self, name: &str) -> Expr {
+        let var = VariableExpression::new(name.to_owned(), &mut self.store);
+        Expression::new_variable_expression(false, &var, &mut self.store)
+    }
+
+    pub(crate) fn path(&mut self, path: &[&str]) -> Expr {
+        let path = self.x_path(path);
+        Expression::new_x_path(false, &path, &mut self.store)
+    }
+
+    fn x_path(&mut self, path: &[&str]) -> Rc<RefCell<XPath>> {
+        let x_path = XPath::new(Uuid::new_v4(), None, &mut self.store);
+        let mut next = None;
+        for name in path.iter().rev() {
+            let element =
+                PathElement::new((*name).to_owned(), next.as_ref(), &x_path, &mut self.store);
+            next = Some(element);
+        }
+        x_path.borrow_mut().first = next.map(|element| element.borrow().id);
+
+        x_path
+    }
+
+    /// A binary operator, or a comparison, by it's symbol
+    ///
+    pub(crate) fn binary(&mut self, op: &str, lhs: &Expr, rhs: &Expr) -> Expr {
+        let store = &mut self.store;
+        let operator = match op {
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => {
+                let comparison = match op {
+                    "==" => Comparison::new_equal(false, store),
+                    "!=" => Comparison::new_not_equal(false, store),
+                    "<" => Comparison::new_less_than(false, store),
+                    "<=" => Comparison::new_less_than_or_equal(false, store),
+                    ">" => Comparison::new_greater_than(false, store),
+                    _ => Comparison::new_greater_than_or_equal(false, store),
+                };
+                Operator::new_comparison(lhs, Some(rhs), &comparison, store)
+            }
+            _ => {
+                let binary = match op {
+                    "+" => Binary::new_addition(false, store),
+                    "-" => Binary::new_subtraction(false, store),
+                    "*" => Binary::new_multiplication(false, store),
+                    "/" => Binary::new_division(false, store),
+                    "%" => Binary::new_remainder(false, store),
+                    "&" => Binary::new_bitwise_and(false, store),
+                    "|" => Binary::new_bitwise_or(false, store),
+                    "^" => Binary::new_bitwise_xor(false, store),
+                    "<<" => Binary::new_shift_left(false, store),
+                    ">>" => Binary::new_shift_right(false, store),
+                    "=" => Binary::new_assignment(false, store),
+                    "+=" => Binary::new_addition_assignment(false, store),
+                    "-=" => Binary::new_subtraction_assignment(false, store),
+                    "*=" => Binary::new_multiplication_assignment(false, store),
+                    "/=" => Binary::new_division_assignment(false, store),
+                    "%=" => Binary::new_remainder_assignment(false, store),
+                    "&=" => Binary::new_bitwise_and_assignment(false, store),
+                    "|=" => Binary::new_bitwise_or_assignment(false, store),
+                    "^=" => Binary::new_bitwise_xor_assignment(false, store),
+                    "<<=" => Binary::new_shift_left_assignment(false, store),
+                    ">>=" => Binary::new_shift_right_assignment(false, store),
+                    "&&" => {
+                        let and = BooleanOperator::new_and(false, store);
+                        Binary::new_boolean_operator(false, &and, store)
+                    }
+                    "||" => {
+                        let or = BooleanOperator::new_or(false, store);
+                        Binary::new_boolean_operator(false, &or, store)
+                    }
+                    _ => panic!("unknown operator `{}`", op),
+                };
+                Operator::new_binary(lhs, Some(rhs), &binary, store)
+            }
+        };
+
+        Expression::new_operator(false, &operator, &mut self.store)
+    }
+
+    pub(crate) fn unary(&mut self, op: &str, operand: &Expr) -> Expr {
+        let unary = match op {
+            "-" => Unary::new_negation(false, &mut self.store),
+            "!" => Unary::new_not(false, &mut self.store),
+            _ => panic!("unknown operator `{}`", op),
+        };
+        let operator = Operator::new_unary(operand, None, &unary, &mut self.store);
+        Expression::new_operator(false, &operator, &mut self.store)
+    }
+
+    pub(crate) fn group(&mut self, expr: &Expr) -> Expr {
+        let grouped = Grouped::new(expr, &mut self.store);
+        Expression::new_grouped(false, &grouped, &mut self.store)
+    }
+
+    pub(crate) fn cast(&mut self, expr: &Expr, ty: &Type) -> Expr {
+        let cast = TypeCast::new(expr, ty, &mut self.store);
+        Expression::new_type_cast(false, &cast, &mut self.store)
+    }
+
+    /// Call a function by name
+    ///
+    pub(crate) fn call(&mut self, name: &str, args: Vec<Expr>) -> Expr {
+        let callee = self.var(name);
+        let func = FunctionCall::new(name.to_owned(), &mut self.store);
+        let call = Call::new_function_call(true, None, Some(&callee), &func, &mut self.store);
+        self.arguments(&call, args);
+        Expression::new_call(false, &call, &mut self.store)
+    }
+
+    pub(crate) fn method(&mut self, receiver: &Expr, name: &str, args: Vec<Expr>) -> Expr {
+        let method = MethodCall::new(name.to_owned(), &mut self.store);
+        let call = Call::new_method_call(true, None, Some(receiver), &method, &mut self.store);
+        self.arguments(&call, args);
+        Expression::new_call(false, &call, &mut self.store)
+    }
+
+    pub(crate) fn static_call(&mut self, ty: &str, func: &str, args: Vec<Expr>) -> Expr {
+        let method = StaticMethodCall::new(
+            func.to_owned(),
+            ty.to_owned(),
+            Uuid::new_v4(),
+            &mut self.store,
+        );
+        let call = Call::new_static_method_call(true, None, None, &method, &mut self.store);
+        self.arguments(&call, args);
+        Expression::new_call(false, &call, &mut self.store)
+    }
+
+    fn arguments(&mut self, call: &Rc<RefCell<Call>>, args: Vec<Expr>) {
+        let mut next = None;
+        for (n, arg) in args.iter().enumerate().rev() {
+            let arg = Argument::new(n as i64, arg, call, next.as_ref(), &mut self.store);
+            next = Some(arg);
+        }
+        call.borrow_mut().argument = next.map(|arg| arg.borrow().id);
+    }
+
+    /// Access a field of a struct, by name
+    ///
+    pub(crate) fn field(
+        &mut self,
+        expr: &Expr,
+        woog_struct: &Rc<RefCell<WoogStruct>>,
+        name: &str,
+    ) -> Expr {
+        let field = self
+            .store
+            .iter_field()
+            .find(|field| {
+                let field = field.borrow();
+                field.x_model == woog_struct.borrow().id && field.name == name
+            })
+            .unwrap();
+        let target = FieldAccessTarget::new_field(false, &field, &mut self.store);
+        let access = FieldAccess::new(expr, &target, woog_struct, &mut self.store);
+        Expression::new_field_access(false, &access, &mut self.store)
+    }
+
+    pub(crate) fn index(&mut self, target: &Expr, index: &Expr) -> Expr {
+        let index = Index::new(index, target, &mut self.store);
+        Expression::new_index(false, &index, &mut self.store)
+    }
+
+    pub(crate) fn list(&mut self, elements: Vec<Expr>) -> Expr {
+        let ty = self.unknown_ty();
+        let list = ListExpression::new(None, &ty, &mut self.store);
+        let mut next = None;
+        for (n, expr) in elements.iter().enumerate().rev() {
+            let element = ListElement::new(n as i64, expr, next.as_ref(), &mut self.store);
+            next = Some(element);
+        }
+        list.borrow_mut().elements = next.map(|element| element.borrow().id);
+        Expression::new_list_expression(false, &list, &mut self.store)
+    }
+
+    /// A range, `lhs..rhs`, or `lhs..=rhs` if it's inclusive
+    ///
+    pub(crate) fn range(
+        &mut self,
+        lhs: Option<&Expr>,
+        rhs: Option<&Expr>,
+        inclusive: bool,
+    ) -> Expr {
+        let store = &mut self.store;
+        let range = match (lhs, rhs, inclusive) {
+            (Some(_), Some(_), false) => RangeExpression::new_from(lhs, rhs, store),
+            (Some(_), Some(_), true) => RangeExpression::new_inclusive(lhs, rhs, store),
+            (Some(_), None, _) => RangeExpression::new_from(lhs, rhs, store),
+            (None, Some(_), false) => RangeExpression::new_to(lhs, rhs, store),
+            (None, Some(_), true) => RangeExpression::new_to_inclusive(lhs, rhs, store),
+            (None, None, _) => RangeExpression::new_full(lhs, rhs, store),
+        };
+        Expression::new_range_expression(false, &range, &mut self.store)
+    }
+
+    pub(crate) fn print(&mut self, expr: &Expr) -> Expr {
+        let print = XPrint::new(expr, &mut self.store);
+        Expression::new_x_print(false, &print, &mut self.store)
+    }
+
+    pub(crate) fn ret(&mut self, expr: &Expr) -> Expr {
+        let x_return = XReturn::new(expr, &mut self.store);
+        Expression::new_x_return(false, &x_return, &mut self.store)
+    }
+
+    pub(crate) fn if_else(
+        &mut self,
+        test: &Expr,
+        true_block: &Rc<RefCell<Block>>,
+        false_block: Option<&Expr>,
+    ) -> Expr {
+        let x_if = XIf::new(false_block, test, true_block, &mut self.store);
+        Expression::new_x_if(false, &x_if, &mut self.store)
+    }
+
+    pub(crate) fn for_loop(
+        &mut self,
+        ident: &str,
+        expr: &Expr,
+        block: &Rc<RefCell<Block>>,
+    ) -> Expr {
+        let block = self.block_expr(block);
+        let for_loop = ForLoop::new(
+            ident.to_owned(),
+            String::new(),
+            &block,
+            expr,
+            &mut self.store,
+        );
+        Expression::new_for_loop(false, &for_loop, &mut self.store)
+    }
+
+    /// A while loop, with a label, unless it's empty
+    ///
+    pub(crate) fn while_loop(
+        &mut self,
+        label: &str,
+        test: &Expr,
+        block: &Rc<RefCell<Block>>,
+    ) -> Expr {
+        let block = self.block_expr(block);
+        let x_while = XWhile::new(label.to_owned(), &block, test, &mut self.store);
+        Expression::new_x_while(false, &x_while, &mut self.store)
+    }
+
+    /// An infinite loop, with a label, unless it's empty
+    ///
+    pub(crate) fn x_loop(&mut self, label: &str, block: &Rc<RefCell<Block>>) -> Expr {
+        let block = self.block_expr(block);
+        let x_loop = XLoop::new(label.to_owned(), &block, &mut self.store);
+        Expression::new_x_loop(false, &x_loop, &mut self.store)
+    }
+
+    pub(crate) fn x_break(&mut self, label: &str, value: Option<&Expr>) -> Expr {
+        let x_break = XBreak::new(label.to_owned(), value, &mut self.store);
+        Expression::new_x_break(false, &x_break, &mut self.store)
+    }
+
+    pub(crate) fn x_continue(&mut self, label: &str) -> Expr {
+        let x_continue = XContinue::new(label.to_owned(), &mut self.store);
+        Expression::new_x_continue(false, &x_continue, &mut self.store)
+    }
+
+    /// A match, with arms of pattern and expression
+    ///
+    pub(crate) fn x_match(&mut self, scrutinee: &Expr, arms: Vec<(Expr, Expr)>) -> Expr {
+        let x_match = XMatch::new(Uuid::new_v4(), scrutinee, &mut self.store);
+        let mut next = None;
+        for (pattern, expr) in arms.iter().rev() {
+            let pattern = Pattern::new(expr, next.as_ref(), pattern, &x_match, &mut self.store);
+            next = Some(pattern);
+        }
+        Expression::new_x_match(false, &x_match, &mut self.store)
+    }
+
+    /// A lambda, returning it and it's body
+    ///
+    pub(crate) fn lambda(
+        &mut self,
+        params: &[(&str, Option<Type>)],
+        return_type: &Type,
+        parent: &Rc<RefCell<Block>>,
+    ) -> (Expr, Rc<RefCell<Block>>) {
+        let block = self.block(parent);
+        let body = Body::new_block(false, &block, &mut self.store);
+        let lambda = Lambda::new(Some(&body), None, return_type, &mut self.store);
+
+        let mut next = None;
+        for (n, (name, ty)) in params.iter().enumerate().rev() {
+            let param = LambdaParameter::new(
+                n as i64,
+                &lambda,
+                next.as_ref(),
+                ty.as_ref(),
+                &mut self.store,
+            );
+            let var = Variable::new_lambda_parameter((*name).to_owned(), &param, &mut self.store);
+            let ty = ty.clone().unwrap_or_else(|| self.unknown_ty());
+            XValue::new_variable(&block, &ty, &var, &mut self.store);
+            next = Some(param);
+        }
+        lambda.borrow_mut().first_param = next.map(|param| param.borrow().id);
+
+        (
+            Expression::new_lambda(false, &lambda, &mut self.store),
+            block,
+        )
+    }
+
+    /// A struct expression, with named fields
+    ///
+    pub(crate) fn struct_expr(
+        &mut self,
+        woog_struct: &Rc<RefCell<WoogStruct>>,
+        fields: Vec<(&str, Expr)>,
+    ) -> Expr {
+        let name = woog_struct.borrow().name.clone();
+        let path = self.x_path(&[&name]);
+        let data = DataStructure::new_woog_struct(false, woog_struct, &mut self.store);
+        let expr = StructExpression::new(Uuid::new_v4(), &data, &path, &mut self.store);
+        for (name, value) in fields {
+            let named = NamedFieldExpression::new(name.to_owned(), &mut self.store);
+            FieldExpression::new_named_field_expression(&value, &expr, &named, &mut self.store);
+        }
+
+        Expression::new_struct_expression(false, &expr, &mut self.store)
+    }
+
+    /// A tuple variant of an enum, `Enum::Variant(a, b)`
+    ///
+    pub(crate) fn variant(
+        &mut self,
+        woog_enum: &Rc<RefCell<Enumeration>>,
+        variant: &str,
+        fields: Vec<Expr>,
+    ) -> Expr {
+        let name = woog_enum.borrow().name.clone();
+        let path = self.x_path(&[&name, variant]);
+        let data = DataStructure::new_enumeration(false, woog_enum, &mut self.store);
+        let expr = StructExpression::new(Uuid::new_v4(), &data, &path, &mut self.store);
+        for (n, value) in fields.iter().enumerate() {
+            let unnamed = UnnamedFieldExpression::new(n as i64, &mut self.store);
+            FieldExpression::new_unnamed_field_expression(value, &expr, &unnamed, &mut self.store);
+        }
+
+        Expression::new_struct_expression(false, &expr, &mut self.store)
+    }
+}
@@ -0,0 +1,213 @@
+//! Source Locations
+//!
+//! A [`Span`] is a pair of byte offsets into a [`DwarfSourceFile`]. This turns
+//! spans into files, lines and columns, for people, and renders the source that
+//! they cover, with carets underneath, for error messages. Something like this:
+//!
+//! ```text
+//!  --> src/main.tao:3:5
+//!   |
+//! 3 |     missing;
+//!   |     ^^^^^^^
+//! ```
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::v2::lu_dog_vec::{DwarfSourceFile, ObjectStore as LuDogStore, Span};
+
+/// Where a span is, in one-based lines and columns
+///
+/// The end is exclusive, like the end of the span.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    /// The path of the source file, or it's name if it has no path
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// Find a source file in the store by it's path
+///
+pub fn source_file(store: &LuDogStore, path: &str) -> Option<Rc<RefCell<DwarfSourceFile>>> {
+    store
+        .iter_dwarf_source_file()
+        .find(|source| source.borrow().path == path)
+}
+
+/// The location of a span
+///
+pub fn location(span: &Span, store: &LuDogStore) -> Location {
+    let source = store.exhume_dwarf_source_file(&span.source).unwrap();
+    let source = source.borrow();
+
+    let (start, end) = offsets(span);
+    let (line, column) = source.line_col(start);
+    let (end_line, end_column) = source.line_col(end);
+    let file = if source.path.is_empty() {
+        source.name.clone()
+    } else {
+        source.path.clone()
+    };
+
+    Location {
+        file,
+        line,
+        column,
+        end_line,
+        end_column,
+    }
+}
+
+/// Render the location of a span, and the lines of source that it covers, with
+/// the span marked by carets
+///
+pub fn snippet(span: &Span, store: &LuDogStore) -> String {
+    let location = location(span, store);
+    let source = store.exhume_dwarf_source_file(&span.source).unwrap();
+    let source = source.borrow();
+
+    // A span that ends at the start of a line doesn't cover any of it.
+    let last = if location.end_line > location.line && location.end_column == 1 {
+        location.end_line - 1
+    } else {
+        location.end_line
+    };
+    let gutter = last.to_string().len();
+
+    let mut out = format!("{:gutter$}--> {}\n", "", location);
+    out += &format!("{:gutter$} |\n", "");
+    for line in location.line..=last {
+        let text = source.line(line).unwrap_or_default();
+        let from = if line == location.line {
+            location.column
+        } else {
+            1
+        };
+        let to = if line == location.end_line {
+            location.end_column
+        } else {
+            text.chars().count() + 1
+        };
+
+        // Tabs are kept in the padding, so that the carets line up.
+        let padding: String = text
+            .chars()
+            .take(from - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(to.saturating_sub(from).max(1));
+
+        out += &format!("{:>gutter$} | {}\n", line, text);
+        out += &format!("{:gutter$} | {}{}\n", "", padding, carets);
+    }
+
+    out
+}
+
+/// The start and end of a span, in order, as offsets
+fn offsets(span: &Span) -> (usize, usize) {
+    let start = span.start.max(0) as usize;
+    let end = span.end.max(0) as usize;
+
+    (start, end.max(start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::{Diagnostic, Problem};
+
+    #[test]
+    fn test_lines() {
+        let mut store = LuDogStore::new();
+        let source = DwarfSourceFile::new(
+            "main".to_owned(),
+            "src/main.tao".to_owned(),
+            "fn main() {\r\n    let π = 3;\n}".to_owned(),
+            &mut store,
+        );
+        let source = source.borrow();
+
+        assert_eq!(source.line_count(), 3);
+        assert_eq!(source.line(1), Some("fn main() {"));
+        assert_eq!(source.line(2), Some("    let π = 3;"));
+        assert_eq!(source.line(4), None);
+
+        // The `=` is after a two byte character.
+        let equals = source.source.find('=').unwrap();
+        assert_eq!(source.line_col(0), (1, 1));
+        assert_eq!(source.line_col(equals), (2, 11));
+        assert_eq!(source.offset(2, 11), Some(equals));
+        assert_eq!(source.line_col(1000), (3, 2));
+    }
+
+    #[test]
+    fn test_files() {
+        let mut store = LuDogStore::new();
+        let text = "fn main() {}".to_owned();
+        let a = DwarfSourceFile::new("a".to_owned(), "a.tao".to_owned(), text.clone(), &mut store);
+        let b = DwarfSourceFile::new("b".to_owned(), "b.tao".to_owned(), text, &mut store);
+
+        assert_ne!(a.borrow().id, b.borrow().id);
+        assert_eq!(store.iter_dwarf_source_file().count(), 2);
+        let found = source_file(&store, "b.tao").unwrap();
+        assert_eq!(found.borrow().id, b.borrow().id);
+    }
+
+    #[test]
+    fn test_snippet() {
+        let mut store = LuDogStore::new();
+        let source = DwarfSourceFile::new(
+            "main".to_owned(),
+            "src/main.tao".to_owned(),
+            "fn main() {\n\tlet x = 1;\n\tmissing;\n}\n".to_owned(),
+            &mut store,
+        );
+        let start = source.borrow().source.find("missing").unwrap() as i64;
+        let span = Span::new(start + 7, start, &source, None, None, &mut store);
+        let span = span.borrow();
+
+        let location = location(&span, &store);
+        assert_eq!(location.to_string(), "src/main.tao:3:2");
+        assert_eq!((location.end_line, location.end_column), (3, 9));
+        assert_eq!(
+            snippet(&span, &store),
+            " --> src/main.tao:3:2\n  |\n3 | \tmissing;\n  | \t^^^^^^^\n"
+        );
+
+        let diagnostic = Diagnostic {
+            problem: Problem::Unresolved {
+                name: "missing".to_owned(),
+            },
+            span: Some(span.clone()),
+        };
+        assert!(diagnostic
+            .render(&store)
+            .starts_with("error: cannot find `missing`\n --> src/main.tao:3:2\n"));
+
+        // Spans across lines mark all of them.
+        let span = Span::new(start + 9, 0, &source, None, None, &mut store);
+        let rendered = snippet(&span.borrow(), &store);
+        assert_eq!(
+            rendered.lines().skip(2).collect::<Vec<_>>(),
+            vec![
+                "1 | fn main() {",
+                "  | ^^^^^^^^^^^",
+                "2 | \tlet x = 1;",
+                "  | ^^^^^^^^^^^",
+                "3 | \tmissing;",
+                "  | ^^^^^^^^^",
+            ]
+        );
+    }
+}
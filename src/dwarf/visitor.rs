@@ -0,0 +1,937 @@
+//! Visitors for Dwarf Programs
+//!
+//! A dwarf program is a tree of lu_dog nodes, linked by id through the store.
+//! Rather than every consumer matching on `ExpressionEnum` and chasing `next`
+//! links itself, implement [`Visitor`] (or [`VisitorMut`]) and override the
+//! methods for the nodes of interest. The rest default to [`Walk::Continue`].
+//!
+//! [`walk`] visits the nodes depth first, in source order. The elements of
+//! linked lists, such as the statements in a block and the arguments to a call,
+//! are visited as children of the node that holds the head of the list. So a
+//! block's children are it's statements, in order, rather than it's first
+//! statement, with the rest nested beneath it.
+//!
+//! Returning [`Walk::Skip`] from a visit method prunes the subtree below that
+//! node, and [`Walk::Stop`] ends the walk altogether. [`Visitor::leave`] is
+//! called after the children of a node have been walked.
+//!
+//! Only ownership is followed. References to declarations, such as the struct
+//! in a [`FieldAccess`][lu_dog::FieldAccess], or the function in a
+//! [`ValueType`][lu_dog::ValueType], are visited as nodes but not descended
+//! into. Otherwise recursive types and functions would walk forever. The store
+//! shares identical nodes, such as two uses of the same literal, and these are
+//! visited once for each use.
+//!
+//! ```
+//! # use std::{cell::RefCell, rc::Rc};
+//! # use sarzak::dwarf::visitor::{walk, Visitor, Walk};
+//! # use sarzak::v2::lu_dog_vec::{ObjectStore, VariableExpression};
+//! /// Collect the names of the variables that are used
+//! #[derive(Default)]
+//! struct Uses(Vec<String>);
+//!
+//! impl Visitor for Uses {
+//!     fn visit_variable_expression(
+//!         &mut self,
+//!         _store: &ObjectStore,
+//!         node: &Rc<RefCell<VariableExpression>>,
+//!     ) -> Walk {
+//!         self.0.push(node.borrow().name.clone());
+//!         Walk::Continue
+//!     }
+//! }
+//!
+//! let store = ObjectStore::new();
+//! let mut uses = Uses::default();
+//! for source in store.iter_dwarf_source_file() {
+//!     walk(&store, &mut uses, source);
+//! }
+//! ```
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::v2::lu_dog_vec::{
+    self as lu_dog, BinaryEnum, BodyEnum, CallEnum, EnumFieldEnum, ExpressionEnum,
+    FieldExpressionEnum, FormatBitEnum, ItemEnum, LiteralEnum, ObjectStore as LuDogStore,
+    OperatorEnum, StatementEnum, ValueTypeEnum,
+};
+
+/// What to do after visiting a node
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Walk {
+    /// Walk the children of the node
+    Continue,
+    /// Don't walk the children of the node, but carry on with it's siblings
+    Skip,
+    /// Stop walking
+    Stop,
+}
+
+macro_rules! nodes {
+    ($($ty:ident($visit:ident, $visit_mut:ident),)*) => {
+        /// A node in a dwarf program
+        ///
+        #[derive(Clone, Debug)]
+        pub enum Node {
+            $($ty(Rc<RefCell<lu_dog::$ty>>),)*
+        }
+
+        $(
+            impl From<Rc<RefCell<lu_dog::$ty>>> for Node {
+                fn from(node: Rc<RefCell<lu_dog::$ty>>) -> Self {
+                    Node::$ty(node)
+                }
+            }
+        )*
+
+        /// Visit the nodes of a dwarf program
+        ///
+        /// Each method is called when [`walk`] reaches a node of that kind, and
+        /// returns whether to walk the node's children.
+        pub trait Visitor {
+            $(
+                fn $visit(
+                    &mut self,
+                    _store: &LuDogStore,
+                    _node: &Rc<RefCell<lu_dog::$ty>>,
+                ) -> Walk {
+                    Walk::Continue
+                }
+            )*
+
+            /// Called once the children of a node have been walked
+            ///
+            fn leave(&mut self, _store: &LuDogStore, _node: &Node) {}
+        }
+
+        /// Visit the nodes of a dwarf program, with the store open for writing
+        ///
+        /// Nodes may be changed through their `RefCell`, and new nodes added to
+        /// the store. The children of a node are found after it's been visited,
+        /// so [`walk_mut`] follows the program as the visitor leaves it.
+        pub trait VisitorMut {
+            $(
+                fn $visit_mut(
+                    &mut self,
+                    _store: &mut LuDogStore,
+                    _node: &Rc<RefCell<lu_dog::$ty>>,
+                ) -> Walk {
+                    Walk::Continue
+                }
+            )*
+
+            /// Called once the children of a node have been walked
+            ///
+            fn leave_mut(&mut self, _store: &mut LuDogStore, _node: &Node) {}
+        }
+
+        impl Node {
+            /// The names of the node types, for checking against the model
+            #[cfg(test)]
+            const TYPES: &'static [&'static str] = &[$(stringify!($ty),)*];
+
+            fn visit<V: Visitor + ?Sized>(&self, store: &LuDogStore, visitor: &mut V) -> Walk {
+                match self {
+                    $(Node::$ty(node) => visitor.$visit(store, node),)*
+                }
+            }
+
+            fn visit_mut<V: VisitorMut + ?Sized>(
+                &self,
+                store: &mut LuDogStore,
+                visitor: &mut V,
+            ) -> Walk {
+                match self {
+                    $(Node::$ty(node) => visitor.$visit_mut(store, node),)*
+                }
+            }
+        }
+    };
+}
+
+nodes! {
+    AWait(visit_a_wait, visit_a_wait_mut),
+    Argument(visit_argument, visit_argument_mut),
+    Binary(visit_binary, visit_binary_mut),
+    Block(visit_block, visit_block_mut),
+    Body(visit_body, visit_body_mut),
+    BooleanLiteral(visit_boolean_literal, visit_boolean_literal_mut),
+    BooleanOperator(visit_boolean_operator, visit_boolean_operator_mut),
+    Call(visit_call, visit_call_mut),
+    CharLiteral(visit_char_literal, visit_char_literal_mut),
+    Comparison(visit_comparison, visit_comparison_mut),
+    DataStructure(visit_data_structure, visit_data_structure_mut),
+    DwarfSourceFile(visit_dwarf_source_file, visit_dwarf_source_file_mut),
+    EnumField(visit_enum_field, visit_enum_field_mut),
+    EnumGeneric(visit_enum_generic, visit_enum_generic_mut),
+    EnumGenericType(visit_enum_generic_type, visit_enum_generic_type_mut),
+    Enumeration(visit_enumeration, visit_enumeration_mut),
+    Expression(visit_expression, visit_expression_mut),
+    ExpressionBit(visit_expression_bit, visit_expression_bit_mut),
+    ExpressionStatement(visit_expression_statement, visit_expression_statement_mut),
+    ExternalImplementation(visit_external_implementation, visit_external_implementation_mut),
+    Field(visit_field, visit_field_mut),
+    FieldAccess(visit_field_access, visit_field_access_mut),
+    FieldAccessTarget(visit_field_access_target, visit_field_access_target_mut),
+    FieldExpression(visit_field_expression, visit_field_expression_mut),
+    FloatLiteral(visit_float_literal, visit_float_literal_mut),
+    ForLoop(visit_for_loop, visit_for_loop_mut),
+    FormatBit(visit_format_bit, visit_format_bit_mut),
+    FormatString(visit_format_string, visit_format_string_mut),
+    FuncGeneric(visit_func_generic, visit_func_generic_mut),
+    Function(visit_function, visit_function_mut),
+    FunctionCall(visit_function_call, visit_function_call_mut),
+    Grouped(visit_grouped, visit_grouped_mut),
+    HaltAndCatchFire(visit_halt_and_catch_fire, visit_halt_and_catch_fire_mut),
+    ImplementationBlock(visit_implementation_block, visit_implementation_block_mut),
+    Import(visit_import, visit_import_mut),
+    Index(visit_index, visit_index_mut),
+    IntegerLiteral(visit_integer_literal, visit_integer_literal_mut),
+    Item(visit_item, visit_item_mut),
+    Lambda(visit_lambda, visit_lambda_mut),
+    LambdaParameter(visit_lambda_parameter, visit_lambda_parameter_mut),
+    LetStatement(visit_let_statement, visit_let_statement_mut),
+    List(visit_list, visit_list_mut),
+    ListElement(visit_list_element, visit_list_element_mut),
+    ListExpression(visit_list_expression, visit_list_expression_mut),
+    Literal(visit_literal, visit_literal_mut),
+    LocalVariable(visit_local_variable, visit_local_variable_mut),
+    Map(visit_map, visit_map_mut),
+    MapElement(visit_map_element, visit_map_element_mut),
+    MapExpression(visit_map_expression, visit_map_expression_mut),
+    MethodCall(visit_method_call, visit_method_call_mut),
+    NamedFieldExpression(visit_named_field_expression, visit_named_field_expression_mut),
+    ObjectWrapper(visit_object_wrapper, visit_object_wrapper_mut),
+    Operator(visit_operator, visit_operator_mut),
+    Parameter(visit_parameter, visit_parameter_mut),
+    PathElement(visit_path_element, visit_path_element_mut),
+    Pattern(visit_pattern, visit_pattern_mut),
+    RangeExpression(visit_range_expression, visit_range_expression_mut),
+    ResultStatement(visit_result_statement, visit_result_statement_mut),
+    Span(visit_span, visit_span_mut),
+    Statement(visit_statement, visit_statement_mut),
+    StaticMethodCall(visit_static_method_call, visit_static_method_call_mut),
+    StringBit(visit_string_bit, visit_string_bit_mut),
+    StringLiteral(visit_string_literal, visit_string_literal_mut),
+    StructExpression(visit_struct_expression, visit_struct_expression_mut),
+    StructField(visit_struct_field, visit_struct_field_mut),
+    StructGeneric(visit_struct_generic, visit_struct_generic_mut),
+    TupleField(visit_tuple_field, visit_tuple_field_mut),
+    TypeCast(visit_type_cast, visit_type_cast_mut),
+    Unary(visit_unary, visit_unary_mut),
+    Unit(visit_unit, visit_unit_mut),
+    UnnamedFieldExpression(visit_unnamed_field_expression, visit_unnamed_field_expression_mut),
+    ValueType(visit_value_type, visit_value_type_mut),
+    Variable(visit_variable, visit_variable_mut),
+    VariableExpression(visit_variable_expression, visit_variable_expression_mut),
+    WoogStruct(visit_woog_struct, visit_woog_struct_mut),
+    XBreak(visit_x_break, visit_x_break_mut),
+    XContinue(visit_x_continue, visit_x_continue_mut),
+    XFuture(visit_x_future, visit_x_future_mut),
+    XIf(visit_x_if, visit_x_if_mut),
+    XLoop(visit_x_loop, visit_x_loop_mut),
+    XMacro(visit_x_macro, visit_x_macro_mut),
+    XMatch(visit_x_match, visit_x_match_mut),
+    XPath(visit_x_path, visit_x_path_mut),
+    XPlugin(visit_x_plugin, visit_x_plugin_mut),
+    XPrint(visit_x_print, visit_x_print_mut),
+    XReturn(visit_x_return, visit_x_return_mut),
+    XValue(visit_x_value, visit_x_value_mut),
+    XWhile(visit_x_while, visit_x_while_mut),
+    ZObjectStore(visit_z_object_store, visit_z_object_store_mut),
+}
+
+/// Walk a dwarf program, depth first, starting at `node`
+///
+/// Returns [`Walk::Stop`] if the visitor stopped the walk, and otherwise
+/// [`Walk::Continue`].
+pub fn walk<V, N>(store: &LuDogStore, visitor: &mut V, node: N) -> Walk
+where
+    V: Visitor + ?Sized,
+    N: Into<Node>,
+{
+    let node = node.into();
+    match node.visit(store, visitor) {
+        Walk::Continue => {}
+        Walk::Skip => return Walk::Continue,
+        Walk::Stop => return Walk::Stop,
+    }
+
+    for child in node.children(store) {
+        if walk(store, visitor, child) == Walk::Stop {
+            return Walk::Stop;
+        }
+    }
+    visitor.leave(store, &node);
+
+    Walk::Continue
+}
+
+/// Walk a dwarf program, depth first, starting at `node`, with the store open
+/// for writing
+///
+/// See [`walk`].
+pub fn walk_mut<V, N>(store: &mut LuDogStore, visitor: &mut V, node: N) -> Walk
+where
+    V: VisitorMut + ?Sized,
+    N: Into<Node>,
+{
+    let node = node.into();
+    match node.visit_mut(store, visitor) {
+        Walk::Continue => {}
+        Walk::Skip => return Walk::Continue,
+        Walk::Stop => return Walk::Stop,
+    }
+
+    for child in node.children(store) {
+        if walk_mut(store, visitor, child) == Walk::Stop {
+            return Walk::Stop;
+        }
+    }
+    visitor.leave_mut(store, &node);
+
+    Walk::Continue
+}
+
+impl Node {
+    /// The children of this node, in source order
+    ///
+    pub fn children(&self, store: &LuDogStore) -> Vec<Node> {
+        let expr = |id: &usize| Node::Expression(store.exhume_expression(id).unwrap());
+        let ty = |id: &usize| Node::ValueType(store.exhume_value_type(id).unwrap());
+
+        match self {
+            Node::AWait(node) => vec![expr(&node.borrow().x_future)],
+            Node::Argument(node) => vec![expr(&node.borrow().expression)],
+            Node::Binary(node) => match node.borrow().subtype {
+                BinaryEnum::BooleanOperator(ref id) => {
+                    vec![store.exhume_boolean_operator(id).unwrap().into()]
+                }
+                _ => Vec::new(),
+            },
+            Node::Block(node) => chain(
+                node.borrow().statement,
+                |id| store.exhume_statement(id).unwrap(),
+                |statement| statement.next,
+            ),
+            Node::Body(node) => match node.borrow().subtype {
+                BodyEnum::Block(ref id) => vec![store.exhume_block(id).unwrap().into()],
+                BodyEnum::ExternalImplementation(ref id) => {
+                    vec![store.exhume_external_implementation(id).unwrap().into()]
+                }
+            },
+            Node::Call(node) => {
+                let node = node.borrow();
+                let mut children = Vec::new();
+                if let Some(ref id) = node.expression {
+                    children.push(expr(id));
+                }
+                match node.subtype {
+                    CallEnum::FunctionCall(ref id) => {
+                        children.push(store.exhume_function_call(id).unwrap().into())
+                    }
+                    CallEnum::MacroCall(_) => {}
+                    CallEnum::MethodCall(ref id) => {
+                        children.push(store.exhume_method_call(id).unwrap().into())
+                    }
+                    CallEnum::StaticMethodCall(ref id) => {
+                        children.push(store.exhume_static_method_call(id).unwrap().into())
+                    }
+                }
+                children.extend(chain(
+                    node.argument,
+                    |id| store.exhume_argument(id).unwrap(),
+                    |arg| arg.next,
+                ));
+                children
+            }
+            Node::DwarfSourceFile(node) => store
+                .iter_item()
+                .filter(|item| item.borrow().source == node.borrow().id)
+                .filter(|item| match item.borrow().subtype {
+                    // These belong to their impl block.
+                    ItemEnum::Function(ref id) => store
+                        .exhume_function(id)
+                        .unwrap()
+                        .borrow()
+                        .impl_block
+                        .is_none(),
+                    _ => true,
+                })
+                .map(Node::from)
+                .collect(),
+            Node::EnumField(node) => match node.borrow().subtype {
+                EnumFieldEnum::StructField(ref id) => {
+                    vec![store.exhume_struct_field(id).unwrap().into()]
+                }
+                EnumFieldEnum::TupleField(ref id) => {
+                    vec![store.exhume_tuple_field(id).unwrap().into()]
+                }
+                EnumFieldEnum::Unit(ref id) => vec![store.exhume_unit(id).unwrap().into()],
+            },
+            Node::EnumGenericType(node) => vec![ty(&node.borrow().ty)],
+            Node::Enumeration(node) => {
+                let node = node.borrow();
+                let mut children = chain(
+                    node.first_generic,
+                    |id| store.exhume_enum_generic(id).unwrap(),
+                    |generic| generic.next,
+                );
+                children.extend(
+                    store
+                        .iter_enum_field()
+                        .filter(|field| field.borrow().woog_enum == node.id)
+                        .map(Node::from),
+                );
+                children
+            }
+            Node::Expression(node) => match node.borrow().subtype {
+                ExpressionEnum::AWait(ref id) => vec![store.exhume_a_wait(id).unwrap().into()],
+                ExpressionEnum::Block(ref id) => vec![store.exhume_block(id).unwrap().into()],
+                ExpressionEnum::XBreak(ref id) => vec![store.exhume_x_break(id).unwrap().into()],
+                ExpressionEnum::Call(ref id) => vec![store.exhume_call(id).unwrap().into()],
+                ExpressionEnum::XContinue(ref id) => {
+                    vec![store.exhume_x_continue(id).unwrap().into()]
+                }
+                ExpressionEnum::XDebugger(_) | ExpressionEnum::EmptyExpression(_) => Vec::new(),
+                ExpressionEnum::FieldAccess(ref id) => {
+                    vec![store.exhume_field_access(id).unwrap().into()]
+                }
+                ExpressionEnum::FieldExpression(ref id) => {
+                    vec![store.exhume_field_expression(id).unwrap().into()]
+                }
+                ExpressionEnum::ForLoop(ref id) => vec![store.exhume_for_loop(id).unwrap().into()],
+                ExpressionEnum::Grouped(ref id) => vec![store.exhume_grouped(id).unwrap().into()],
+                ExpressionEnum::HaltAndCatchFire(ref id) => {
+                    vec![store.exhume_halt_and_catch_fire(id).unwrap().into()]
+                }
+                ExpressionEnum::XIf(ref id) => vec![store.exhume_x_if(id).unwrap().into()],
+                ExpressionEnum::Index(ref id) => vec![store.exhume_index(id).unwrap().into()],
+                ExpressionEnum::Lambda(ref id) => vec![store.exhume_lambda(id).unwrap().into()],
+                ExpressionEnum::ListElement(ref id) => {
+                    vec![store.exhume_list_element(id).unwrap().into()]
+                }
+                ExpressionEnum::ListExpression(ref id) => {
+                    vec![store.exhume_list_expression(id).unwrap().into()]
+                }
+                ExpressionEnum::Literal(ref id) => vec![store.exhume_literal(id).unwrap().into()],
+                ExpressionEnum::XLoop(ref id) => vec![store.exhume_x_loop(id).unwrap().into()],
+                ExpressionEnum::XMatch(ref id) => vec![store.exhume_x_match(id).unwrap().into()],
+                ExpressionEnum::Operator(ref id) => {
+                    vec![store.exhume_operator(id).unwrap().into()]
+                }
+                ExpressionEnum::XPath(ref id) => vec![store.exhume_x_path(id).unwrap().into()],
+                ExpressionEnum::XPrint(ref id) => vec![store.exhume_x_print(id).unwrap().into()],
+                ExpressionEnum::RangeExpression(ref id) => {
+                    vec![store.exhume_range_expression(id).unwrap().into()]
+                }
+                ExpressionEnum::XReturn(ref id) => {
+                    vec![store.exhume_x_return(id).unwrap().into()]
+                }
+                ExpressionEnum::StructExpression(ref id) => {
+                    vec![store.exhume_struct_expression(id).unwrap().into()]
+                }
+                ExpressionEnum::TypeCast(ref id) => {
+                    vec![store.exhume_type_cast(id).unwrap().into()]
+                }
+                ExpressionEnum::VariableExpression(ref id) => {
+                    vec![store.exhume_variable_expression(id).unwrap().into()]
+                }
+                ExpressionEnum::XWhile(ref id) => vec![store.exhume_x_while(id).unwrap().into()],
+            },
+            Node::ExpressionBit(node) => vec![expr(&node.borrow().expression)],
+            Node::ExpressionStatement(node) => vec![expr(&node.borrow().expression)],
+            Node::Field(node) => vec![ty(&node.borrow().ty)],
+            Node::FieldAccess(node) => {
+                let node = node.borrow();
+                vec![
+                    expr(&node.expression),
+                    store
+                        .exhume_field_access_target(&node.field)
+                        .unwrap()
+                        .into(),
+                ]
+            }
+            Node::FieldExpression(node) => {
+                let node = node.borrow();
+                let field = match node.subtype {
+                    FieldExpressionEnum::NamedFieldExpression(ref id) => {
+                        store.exhume_named_field_expression(id).unwrap().into()
+                    }
+                    FieldExpressionEnum::UnnamedFieldExpression(ref id) => {
+                        store.exhume_unnamed_field_expression(id).unwrap().into()
+                    }
+                };
+                vec![field, expr(&node.expression)]
+            }
+            Node::ForLoop(node) => {
+                let node = node.borrow();
+                vec![expr(&node.expression), expr(&node.block)]
+            }
+            Node::FormatBit(node) => match node.borrow().subtype {
+                FormatBitEnum::ExpressionBit(ref id) => {
+                    vec![store.exhume_expression_bit(id).unwrap().into()]
+                }
+                FormatBitEnum::StringBit(ref id) => {
+                    vec![store.exhume_string_bit(id).unwrap().into()]
+                }
+            },
+            Node::FormatString(node) => chain(
+                node.borrow().first_format_bit,
+                |id| store.exhume_format_bit(id).unwrap(),
+                |bit| bit.next,
+            ),
+            Node::Function(node) => {
+                let node = node.borrow();
+                let mut children = chain(
+                    node.first_generic,
+                    |id| store.exhume_func_generic(id).unwrap(),
+                    |generic| generic.next,
+                );
+                children.extend(chain(
+                    node.first_param,
+                    |id| store.exhume_parameter(id).unwrap(),
+                    |param| param.next,
+                ));
+                children.push(ty(&node.return_type));
+                children.push(store.exhume_body(&node.body).unwrap().into());
+                children
+            }
+            Node::Grouped(node) => vec![expr(&node.borrow().expression)],
+            Node::HaltAndCatchFire(node) => vec![expr(&node.borrow().expression)],
+            Node::ImplementationBlock(node) => store
+                .iter_function()
+                .filter(|func| func.borrow().impl_block == Some(node.borrow().id))
+                .map(Node::from)
+                .collect(),
+            Node::Index(node) => {
+                let node = node.borrow();
+                vec![expr(&node.target), expr(&node.index)]
+            }
+            Node::Item(node) => vec![match node.borrow().subtype {
+                ItemEnum::Enumeration(ref id) => store.exhume_enumeration(id).unwrap().into(),
+                ItemEnum::Function(ref id) => store.exhume_function(id).unwrap().into(),
+                ItemEnum::ImplementationBlock(ref id) => {
+                    store.exhume_implementation_block(id).unwrap().into()
+                }
+                ItemEnum::Import(ref id) => store.exhume_import(id).unwrap().into(),
+                ItemEnum::XMacro(ref id) => store.exhume_x_macro(id).unwrap().into(),
+                ItemEnum::WoogStruct(ref id) => store.exhume_woog_struct(id).unwrap().into(),
+            }],
+            Node::Lambda(node) => {
+                let node = node.borrow();
+                let mut children = chain(
+                    node.first_param,
+                    |id| store.exhume_lambda_parameter(id).unwrap(),
+                    |param| param.next,
+                );
+                children.push(ty(&node.return_type));
+                if let Some(ref id) = node.body {
+                    children.push(store.exhume_body(id).unwrap().into());
+                }
+                children
+            }
+            Node::LambdaParameter(node) => node.borrow().ty.iter().map(ty).collect(),
+            Node::LetStatement(node) => {
+                let node = node.borrow();
+                vec![
+                    store.exhume_local_variable(&node.variable).unwrap().into(),
+                    expr(&node.expression),
+                ]
+            }
+            Node::List(node) => vec![ty(&node.borrow().ty)],
+            Node::ListElement(node) => vec![expr(&node.borrow().expression)],
+            Node::ListExpression(node) => chain(
+                node.borrow().elements,
+                |id| store.exhume_list_element(id).unwrap(),
+                |element| element.next,
+            ),
+            Node::Literal(node) => vec![match node.borrow().subtype {
+                LiteralEnum::BooleanLiteral(ref id) => {
+                    store.exhume_boolean_literal(id).unwrap().into()
+                }
+                LiteralEnum::CharLiteral(ref id) => store.exhume_char_literal(id).unwrap().into(),
+                LiteralEnum::FloatLiteral(ref id) => store.exhume_float_literal(id).unwrap().into(),
+                LiteralEnum::FormatString(ref id) => store.exhume_format_string(id).unwrap().into(),
+                LiteralEnum::IntegerLiteral(ref id) => {
+                    store.exhume_integer_literal(id).unwrap().into()
+                }
+                LiteralEnum::MapExpression(ref id) => {
+                    store.exhume_map_expression(id).unwrap().into()
+                }
+                LiteralEnum::StringLiteral(ref id) => {
+                    store.exhume_string_literal(id).unwrap().into()
+                }
+            }],
+            Node::Map(node) => {
+                let node = node.borrow();
+                vec![ty(&node.key_type), ty(&node.value_type)]
+            }
+            Node::MapElement(node) => {
+                let node = node.borrow();
+                vec![expr(&node.key), expr(&node.x_value)]
+            }
+            Node::MapExpression(node) => store
+                .iter_map_element()
+                .filter(|element| element.borrow().map == node.borrow().id)
+                .map(Node::from)
+                .collect(),
+            Node::Operator(node) => {
+                let node = node.borrow();
+                let mut children = vec![expr(&node.lhs)];
+                children.push(match node.subtype {
+                    OperatorEnum::Binary(ref id) => store.exhume_binary(id).unwrap().into(),
+                    OperatorEnum::Comparison(ref id) => store.exhume_comparison(id).unwrap().into(),
+                    OperatorEnum::Unary(ref id) => store.exhume_unary(id).unwrap().into(),
+                });
+                children.extend(node.rhs.iter().map(expr));
+                children
+            }
+            Node::Parameter(node) => vec![ty(&node.borrow().ty)],
+            Node::Pattern(node) => {
+                let node = node.borrow();
+                vec![expr(&node.match_expr), expr(&node.expression)]
+            }
+            Node::RangeExpression(node) => {
+                let node = node.borrow();
+                node.lhs.iter().chain(node.rhs.iter()).map(expr).collect()
+            }
+            Node::ResultStatement(node) => vec![expr(&node.borrow().expression)],
+            Node::Statement(node) => match node.borrow().subtype {
+                StatementEnum::ExpressionStatement(ref id) => {
+                    vec![store.exhume_expression_statement(id).unwrap().into()]
+                }
+                StatementEnum::ItemStatement(_) => Vec::new(),
+                StatementEnum::LetStatement(ref id) => {
+                    vec![store.exhume_let_statement(id).unwrap().into()]
+                }
+                StatementEnum::ResultStatement(ref id) => {
+                    vec![store.exhume_result_statement(id).unwrap().into()]
+                }
+            },
+            Node::StringBit(node) => {
+                vec![store
+                    .exhume_string_literal(&node.borrow().z_string)
+                    .unwrap()
+                    .into()]
+            }
+            Node::StructExpression(node) => {
+                let node = node.borrow();
+                let mut children = vec![store.exhume_x_path(&node.x_path).unwrap().into()];
+                children.extend(
+                    store
+                        .iter_field_expression()
+                        .filter(|field| field.borrow().woog_struct == node.id)
+                        .map(Node::from),
+                );
+                children
+            }
+            Node::TupleField(node) => vec![ty(&node.borrow().ty)],
+            Node::TypeCast(node) => {
+                let node = node.borrow();
+                vec![expr(&node.lhs), ty(&node.ty)]
+            }
+            Node::ValueType(node) => match node.borrow().subtype {
+                // Only the types that are built from other types.
+                ValueTypeEnum::XFuture(ref id) => vec![store.exhume_x_future(id).unwrap().into()],
+                ValueTypeEnum::List(ref id) => vec![store.exhume_list(id).unwrap().into()],
+                ValueTypeEnum::Map(ref id) => vec![store.exhume_map(id).unwrap().into()],
+                _ => Vec::new(),
+            },
+            Node::WoogStruct(node) => {
+                let node = node.borrow();
+                let mut children = chain(
+                    node.first_generic,
+                    |id| store.exhume_struct_generic(id).unwrap(),
+                    |generic| generic.next,
+                );
+                children.extend(
+                    store
+                        .iter_field()
+                        .filter(|field| field.borrow().x_model == node.id)
+                        .map(Node::from),
+                );
+                children
+            }
+            Node::XBreak(node) => node.borrow().expression.iter().map(expr).collect(),
+            Node::XFuture(node) => vec![ty(&node.borrow().x_value)],
+            Node::XIf(node) => {
+                let node = node.borrow();
+                let mut children = vec![
+                    expr(&node.test),
+                    store.exhume_block(&node.true_block).unwrap().into(),
+                ];
+                children.extend(node.false_block.iter().map(expr));
+                children
+            }
+            Node::XLoop(node) => vec![expr(&node.borrow().block)],
+            Node::XMatch(node) => {
+                let node = node.borrow();
+                let patterns = store
+                    .iter_pattern()
+                    .filter(|pattern| pattern.borrow().x_match == node.id)
+                    .collect::<Vec<_>>();
+                // The first pattern is the one that doesn't follow any other.
+                let first = patterns
+                    .iter()
+                    .find(|pattern| {
+                        !patterns
+                            .iter()
+                            .any(|other| other.borrow().next == Some(pattern.borrow().id))
+                    })
+                    .map(|pattern| pattern.borrow().id);
+
+                let mut children = vec![expr(&node.scrutinee)];
+                children.extend(chain(
+                    first,
+                    |id| store.exhume_pattern(id).unwrap(),
+                    |pattern| pattern.next,
+                ));
+                children
+            }
+            Node::XPath(node) => chain(
+                node.borrow().first,
+                |id| store.exhume_path_element(id).unwrap(),
+                |element| element.next,
+            ),
+            Node::XPrint(node) => vec![expr(&node.borrow().expression)],
+            Node::XReturn(node) => vec![expr(&node.borrow().expression)],
+            Node::XWhile(node) => {
+                let node = node.borrow();
+                vec![expr(&node.test), expr(&node.block)]
+            }
+            // Everything else is a leaf, or only refers to other nodes.
+            Node::BooleanLiteral(_)
+            | Node::BooleanOperator(_)
+            | Node::CharLiteral(_)
+            | Node::Comparison(_)
+            | Node::DataStructure(_)
+            | Node::EnumGeneric(_)
+            | Node::ExternalImplementation(_)
+            | Node::FieldAccessTarget(_)
+            | Node::FloatLiteral(_)
+            | Node::FuncGeneric(_)
+            | Node::FunctionCall(_)
+            | Node::Import(_)
+            | Node::IntegerLiteral(_)
+            | Node::LocalVariable(_)
+            | Node::MethodCall(_)
+            | Node::NamedFieldExpression(_)
+            | Node::ObjectWrapper(_)
+            | Node::PathElement(_)
+            | Node::Span(_)
+            | Node::StaticMethodCall(_)
+            | Node::StringLiteral(_)
+            | Node::StructField(_)
+            | Node::StructGeneric(_)
+            | Node::Unary(_)
+            | Node::Unit(_)
+            | Node::UnnamedFieldExpression(_)
+            | Node::Variable(_)
+            | Node::VariableExpression(_)
+            | Node::XContinue(_)
+            | Node::XMacro(_)
+            | Node::XPlugin(_)
+            | Node::XValue(_)
+            | Node::ZObjectStore(_) => Vec::new(),
+        }
+    }
+}
+
+/// Follow a linked list from it's head
+///
+fn chain<T>(
+    first: Option<usize>,
+    exhume: impl Fn(&usize) -> Rc<RefCell<T>>,
+    next: impl Fn(&T) -> Option<usize>,
+) -> Vec<Node>
+where
+    Node: From<Rc<RefCell<T>>>,
+{
+    let mut nodes = Vec::new();
+    let mut id = first;
+    while let Some(this) = id {
+        let node = exhume(&this);
+        id = next(&node.borrow());
+        nodes.push(node.into());
+    }
+
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dwarf::{
+        sample::{Sample, Stmt},
+        Printer,
+    };
+
+    /// fn main() {
+    ///     let f = |n: int| -> int {
+    ///         n * k
+    ///     };
+    ///     print(a + b * c);
+    /// }
+    fn sample() -> (Sample, Rc<RefCell<lu_dog::Block>>) {
+        let mut s = Sample::new("visitor");
+
+        let int = s.int_ty();
+        let empty = s.empty_ty();
+        let main = s.function("main", &[], &empty, None);
+
+        let (lambda, body) = s.lambda(&[("n", Some(int.clone()))], &int, &main);
+        let n = s.var("n");
+        let k = s.var("k");
+        let product = s.binary("*", &n, &k);
+        s.statements(&body, vec![Stmt::Result(product)]);
+
+        let a = s.var("a");
+        let b = s.var("b");
+        let c = s.var("c");
+        let product = s.binary("*", &b, &c);
+        let sum = s.binary("+", &a, &product);
+        let print = s.print(&sum);
+
+        s.statements(&main, vec![Stmt::Let("f", None, lambda), Stmt::Expr(print)]);
+
+        (s, main)
+    }
+
+    /// Collects variable names, skipping lambdas and stopping at `stop`
+    #[derive(Default)]
+    struct Names {
+        names: Vec<String>,
+        skip_lambdas: bool,
+        stop: Option<&'static str>,
+        left: usize,
+    }
+
+    impl Visitor for Names {
+        fn visit_lambda(&mut self, _: &LuDogStore, _: &Rc<RefCell<lu_dog::Lambda>>) -> Walk {
+            if self.skip_lambdas {
+                Walk::Skip
+            } else {
+                Walk::Continue
+            }
+        }
+
+        fn visit_variable_expression(
+            &mut self,
+            _: &LuDogStore,
+            node: &Rc<RefCell<lu_dog::VariableExpression>>,
+        ) -> Walk {
+            let name = node.borrow().name.clone();
+            if self.stop == Some(name.as_str()) {
+                return Walk::Stop;
+            }
+            self.names.push(name);
+            Walk::Continue
+        }
+
+        fn leave(&mut self, _: &LuDogStore, node: &Node) {
+            if let Node::Statement(_) = node {
+                self.left += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        let (s, main) = sample();
+        assert_eq!(Node::from(main).children(&s.store).len(), 2);
+
+        let mut names = Names::default();
+        let result = walk(&s.store, &mut names, s.source());
+        assert_eq!(result, Walk::Continue);
+        assert_eq!(names.names, ["n", "k", "a", "b", "c"]);
+        assert_eq!(names.left, 3);
+    }
+
+    #[test]
+    fn test_prune() {
+        let (s, _) = sample();
+
+        let mut names = Names {
+            skip_lambdas: true,
+            ..Default::default()
+        };
+        walk(&s.store, &mut names, s.source());
+        assert_eq!(names.names, ["a", "b", "c"]);
+
+        let mut names = Names {
+            stop: Some("a"),
+            ..Default::default()
+        };
+        let result = walk(&s.store, &mut names, s.source());
+        assert_eq!(result, Walk::Stop);
+        assert_eq!(names.names, ["n", "k"]);
+        // The let, and the statement in the lambda, were finished.
+        assert_eq!(names.left, 2);
+    }
+
+    #[test]
+    fn test_walk_mut() {
+        /// Renames `b` to `bee`
+        struct Rename;
+
+        impl VisitorMut for Rename {
+            fn visit_variable_expression_mut(
+                &mut self,
+                _: &mut LuDogStore,
+                node: &Rc<RefCell<lu_dog::VariableExpression>>,
+            ) -> Walk {
+                let mut node = node.borrow_mut();
+                if node.name == "b" {
+                    node.name = "bee".to_owned();
+                }
+                Walk::Continue
+            }
+        }
+
+        let (mut s, _) = sample();
+        let source = s.source();
+        walk_mut(&mut s.store, &mut Rename, source);
+
+        let printed = Printer::new(&s.store).print_source(&s.source().borrow());
+        assert!(printed.contains("print(a + bee * c);"), "{}", printed);
+    }
+
+    /// Every lu_dog type that isn't a singleton is a node
+    ///
+    /// Singletons are exported along with a constant holding their id, and
+    /// carry nothing worth visiting.
+    #[test]
+    fn test_nodes_cover_the_model() {
+        use heck::ToUpperCamelCase;
+        use std::collections::HashMap;
+
+        let mut exports: HashMap<&str, Vec<&str>> = HashMap::new();
+        for line in include_str!("../v2/lu_dog_vec/types.rs").lines() {
+            if let Some((module, name)) = line
+                .strip_prefix("pub use crate::v2::lu_dog_vec::")
+                .and_then(|path| path.strip_suffix(';'))
+                .and_then(|path| path.split_once("::"))
+            {
+                exports.entry(module).or_default().push(name);
+            }
+        }
+
+        let mut expected = exports
+            .iter()
+            .filter(|(module, names)| !names.contains(&module.to_uppercase().as_str()))
+            .map(|(module, names)| {
+                let name = module.to_upper_camel_case();
+                assert!(names.contains(&name.as_str()), "{}", module);
+                name
+            })
+            .collect::<Vec<_>>();
+        expected.sort();
+        let mut nodes = Node::TYPES.to_vec();
+        nodes.sort();
+
+        assert_eq!(nodes, expected);
+    }
+}
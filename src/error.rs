@@ -19,4 +19,8 @@ pub(crate) enum SarzakError {
     },
     #[snafu(display("error building domain: {}", message))]
     DomainBuilder { message: String },
+    #[snafu(display("parse error on line {}: {}", line, message))]
+    Parse { line: usize, message: String },
+    #[snafu(display("error exporting cuckoo model: {}", message))]
+    CuckooExport { message: String },
 }
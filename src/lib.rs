@@ -1,6 +1,8 @@
 #![allow(warnings)]
 
 pub mod domain;
+#[cfg(feature = "lu-dog-vec")]
+pub mod dwarf;
 pub mod error;
 pub mod mc;
 pub mod v1;
@@ -25,3 +25,4 @@ pub mod sarzak_single;
 pub mod woog;
 #[cfg(feature = "woog-single")]
 pub mod woog_single;
+pub mod workspace;
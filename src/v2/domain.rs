@@ -1,9 +1,14 @@
 //! Version 2 Sarzak Domain
 //!
 use std::path::PathBuf;
-use std::{fs, io, path::Path};
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
 
 use serde::{Deserialize, Serialize};
+use snafu::prelude::*;
 use uuid::Uuid;
 
 use crate::v1::domain::Domain as DomainV1;
@@ -19,7 +24,27 @@ cfg_if::cfg_if! {
     }
 }
 
-use crate::VERSION;
+use crate::{
+    error::{FileOpenSnafu, Result},
+    VERSION,
+};
+
+pub mod builder;
+pub mod cuckoo;
+pub mod diagram;
+pub mod layout;
+pub mod svg;
+pub mod sync;
+pub mod text;
+
+/// The default size of the drawing area for a new domain
+const DEFAULT_EXTENTS: [u16; 2] = [4000, 3200];
+/// The first bytes of a bincode domain file
+const BINCODE_MAGIC: [u8; 4] = *b"SZKD";
+/// The version of the bincode domain file format
+///
+/// Bump this whenever the layout of [`Domain`], or anything in it, changes.
+const BINCODE_VERSION: u32 = 1;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 struct MetaData {
@@ -40,6 +65,27 @@ pub struct Domain {
 }
 
 impl Domain {
+    /// Create a new, empty, domain
+    ///
+    /// The sarzak and merlin stores contain only the usual singletons. See
+    /// [`ModelBuilder`][builder::ModelBuilder] for a convenient way to populate
+    /// them.
+    pub fn new<S: AsRef<str>>(name: S, description: S) -> Self {
+        Domain {
+            meta: MetaData {
+                version: VERSION.to_owned(),
+                domain: name.as_ref().to_owned(),
+                id: Uuid::new_v4(),
+                description: description.as_ref().to_owned(),
+                extents: DEFAULT_EXTENTS,
+                view: [0, 0],
+                path: PathBuf::new(),
+            },
+            sarzak: SarzakStore::new(),
+            merlin: MerlinStore::new(),
+        }
+    }
+
     /// Return the name of the domain
     ///
     pub fn domain(&self) -> &str {
@@ -70,6 +116,13 @@ impl Domain {
         &self.meta.extents
     }
 
+    /// Return the domain view
+    ///
+    /// This is the offset of the drawing area, as last seen in the GUI.
+    pub fn view(&self) -> &[i32; 2] {
+        &self.meta.view
+    }
+
     /// Return a reference to the sarzak store
     ///
     /// This returns a reference to the [`ObjectStore`] that contains the domain
@@ -102,6 +155,26 @@ impl Domain {
         &mut self.merlin
     }
 
+    /// Return mutable references to both stores at once
+    ///
+    pub(crate) fn stores_mut(&mut self) -> (&mut SarzakStore, &mut MerlinStore) {
+        (&mut self.sarzak, &mut self.merlin)
+    }
+
+    /// Report the drift between the model and it's drawing
+    ///
+    /// See [`sync::check`].
+    pub fn drift(&self) -> Vec<sync::Drift> {
+        sync::check(self)
+    }
+
+    /// Bring the drawing in line with the model
+    ///
+    /// See [`sync::sync`].
+    pub fn sync(&mut self) -> Vec<sync::Drift> {
+        sync::sync(self)
+    }
+
     pub fn path(&self) -> &PathBuf {
         &self.meta.path
     }
@@ -140,6 +213,28 @@ impl Domain {
         Ok(())
     }
 
+    /// Persist the domain as a cuckoo model
+    ///
+    /// See [`cuckoo::export`].
+    pub fn persist_cuckoo<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let model = cuckoo::export(self)?;
+
+        let file = fs::File::create(path).context(FileOpenSnafu { path })?;
+        let writer = io::BufWriter::new(file);
+        serde_json::to_writer_pretty(writer, &model)
+            .map_err(io::Error::from)
+            .context(FileOpenSnafu { path })?;
+
+        Ok(())
+    }
+
+    /// Persist the sarzak store as bincode
+    ///
+    /// This is just the model, as found in `models/*.bin`, and loaded with
+    /// [`ObjectStore::from_bincode`][crate::v2::sarzak::store::ObjectStore::from_bincode].
+    /// See [`persist_domain_bincode`][Self::persist_domain_bincode] for the
+    /// whole domain.
     pub fn persist_bincode<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
         let path = path.as_ref();
         fs::create_dir_all(path.parent().unwrap())?;
@@ -148,6 +243,65 @@ impl Domain {
 
         Ok(())
     }
+
+    /// Persist the domain as a single bincode file
+    ///
+    /// The file holds everything: the metadata, and the sarzak and merlin
+    /// stores. It starts with a magic number and a format version, which
+    /// [`load_domain_bincode`][Self::load_domain_bincode] checks before it goes
+    /// any further.
+    pub fn persist_domain_bincode<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = fs::File::create(path)?;
+        let mut writer = io::BufWriter::new(file);
+        writer.write_all(&BINCODE_MAGIC)?;
+        bincode::serialize_into(&mut writer, &BINCODE_VERSION).map_err(bincode_error)?;
+        bincode::serialize_into(&mut writer, self).map_err(bincode_error)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Load a domain from a bincode file
+    ///
+    /// See [`persist_domain_bincode`][Self::persist_domain_bincode].
+    pub fn load_domain_bincode<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = fs::File::open(path.as_ref())?;
+        let mut reader = io::BufReader::new(file);
+
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != BINCODE_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a bincode domain file",
+            ));
+        }
+
+        let version: u32 = bincode::deserialize_from(&mut reader).map_err(bincode_error)?;
+        if version != BINCODE_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "unsupported bincode domain version {}, expected {}",
+                    version, BINCODE_VERSION
+                ),
+            ));
+        }
+
+        bincode::deserialize_from(&mut reader).map_err(bincode_error)
+    }
+}
+
+fn bincode_error(error: bincode::Error) -> io::Error {
+    match *error {
+        bincode::ErrorKind::Io(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, error),
+    }
 }
 
 impl From<DomainV1> for Domain {
@@ -173,3 +327,96 @@ impl From<DomainV1> for Domain {
         domain
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        sarzak::types::{Ty, Z_STRING, Z_UUID},
+    };
+
+    fn pets() -> Domain {
+        ModelBuilder::new("pets", "A domain about pets")
+            .object("Animal")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .attr("name", Ty::ZString(Z_STRING))
+            .object("Cat")
+            .object("Dog")
+            .object("Owner")
+            .attr("name", Ty::ZString(Z_STRING))
+            .object("Vet")
+            .object("Visit")
+            .isa(1, "Animal", &["Cat", "Dog"])
+            .binary(2, "Animal", Many, "Owner", One, "is owned by")
+            .associative(3, "Visit", "Animal", Many, "Vet", Many)
+            .build()
+            .unwrap()
+    }
+
+    /// Domains don't implement `PartialEq`, but their JSON does
+    ///
+    fn assert_same(a: &Domain, b: &Domain) {
+        assert_eq!(
+            serde_json::to_value(a).unwrap(),
+            serde_json::to_value(b).unwrap()
+        );
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("sarzak-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let domain = pets();
+        let path = scratch("json").join("pets");
+
+        domain.persist(&path).unwrap();
+        let loaded = Domain::load(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_same(&domain, &loaded);
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let domain = pets();
+        let path = scratch("bincode").join("pets.bin");
+
+        domain.persist_domain_bincode(&path).unwrap();
+        let loaded = Domain::load_domain_bincode(&path).unwrap();
+
+        assert_same(&domain, &loaded);
+        assert_eq!(loaded.merlin().iter_x_box().count(), 6);
+        assert_eq!(loaded.name(), "pets");
+
+        // Both ways of persisting give the same domain.
+        let dir = path.parent().unwrap().join("pets");
+        loaded.persist(&dir).unwrap();
+        assert_same(&domain, &Domain::load(&dir).unwrap());
+
+        // Anything that isn't one of ours is rejected.
+        fs::write(&path, b"not a domain").unwrap();
+        let err = Domain::load_domain_bincode(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut bytes = BINCODE_MAGIC.to_vec();
+        bytes.extend(bincode::serialize(&(BINCODE_VERSION + 1)).unwrap());
+        fs::write(&path, bytes).unwrap();
+        let err = Domain::load_domain_bincode(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // The bare store is still what it always was.
+        domain.persist_bincode(&path).unwrap();
+        let sarzak = SarzakStore::load_bincode(&path).unwrap();
+        assert_eq!(
+            sarzak.iter_object().count(),
+            domain.sarzak().iter_object().count()
+        );
+        assert!(Domain::load_domain_bincode(&path).is_err());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
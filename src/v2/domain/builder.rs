@@ -0,0 +1,1088 @@
+//! Programmatic Domain Builder
+//!
+//! Building a model in code means creating an [`Object`], and then remembering
+//! to create it's [`Ty`], and it's [`XBox`], and so on. Relationships are
+//! worse. This is a fluent interface that takes care of all of that, and
+//! produces a [`Domain`] with both the sarzak and the merlin stores populated.
+//!
+//! The drawing is laid out on a simple grid, in the order in which the objects
+//! were created. It's not pretty, but it's something that may be opened and
+//! cleaned up by hand.
+//!
+//! ```
+//! use sarzak::v2::domain::builder::{ModelBuilder, Multiplicity::*};
+//! use sarzak::v2::sarzak::types::{Ty, Z_STRING, Z_UUID};
+//!
+//! let domain = ModelBuilder::new("pets", "A domain about pets")
+//!     .object("Owner")
+//!     .attr("id", Ty::ZUuid(Z_UUID))
+//!     .attr("name", Ty::ZString(Z_STRING))
+//!     .object("Dog")
+//!     .attr("id", Ty::ZUuid(Z_UUID))
+//!     .attr("name", Ty::ZString(Z_STRING))
+//!     .binary(1, "Dog", Many, "Owner", One, "is owned by")
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(domain.sarzak().iter_object().count(), 2);
+//! assert_eq!(domain.merlin().iter_x_box().count(), 2);
+//! ```
+use std::sync::{Arc, RwLock};
+
+use heck::{ToSnakeCase, ToTitleCase, ToUpperCamelCase};
+use snafu::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    error::{DomainBuilderSnafu, Result},
+    v2::{
+        domain::Domain,
+        merlin::draw::{anchor_phrase, cardinality_glyph},
+        merlin::geometry::position_on_edge,
+        merlin::store::ObjectStore as MerlinStore,
+        merlin::types::{
+            Anchor, Bisection, Edge, Glyph, Line, LineSegment, LineSegmentPoint, Point,
+            RelationshipName, XBox,
+        },
+        sarzak::{
+            store::ObjectStore as SarzakStore,
+            types::{
+                AnAssociativeReferent, Associative, AssociativeReferent, AssociativeReferrer,
+                Attribute, Binary, Cardinality, Conditionality, Event, Isa, Object, Referent,
+                Referrer, Relationship, State, Subtype, Supertype, Ty,
+            },
+            UUID_NS,
+        },
+    },
+};
+
+/// Horizontal space consumed by a character of text in a box
+pub(super) const CHAR_WIDTH: i64 = 10;
+/// Vertical space consumed by a line of text in a box
+pub(super) const LINE_HEIGHT: i64 = 20;
+/// Boxes are never narrower than this
+pub(super) const MIN_WIDTH: i64 = 150;
+/// The box header, i.e., the object name and the line below it
+pub(super) const HEADER_HEIGHT: i64 = 40;
+/// Space between grid cells, and between the grid and the edge of the paper
+const MARGIN: i64 = 100;
+/// Grid cell dimensions
+const CELL_WIDTH: i64 = 300;
+const CELL_HEIGHT: i64 = 250;
+/// Number of boxes in a row before starting a new one
+const COLUMNS: usize = 5;
+/// Distance from an anchor to the point at which the line leaves the box
+pub(super) const ANCHOR_OFFSET: i64 = 40;
+/// Where along an edge successive anchors are placed
+const EDGE_OFFSETS: [f64; 7] = [0.5, 0.25, 0.75, 0.125, 0.375, 0.625, 0.875];
+
+/// Relationship Cardinality
+///
+/// This is shorthand for the sarzak [`Cardinality`], which requires a store to
+/// instantiate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Multiplicity {
+    One,
+    Many,
+}
+
+impl Multiplicity {
+    fn cardinality(&self, domain: &Domain) -> Arc<RwLock<Cardinality>> {
+        match self {
+            Multiplicity::One => Cardinality::new_one(&domain.sarzak),
+            Multiplicity::Many => Cardinality::new_many(&domain.sarzak),
+        }
+    }
+}
+
+/// The bits of a relationship that we need to draw it
+///
+/// The drawing happens once all of the boxes have been sized.
+enum Drawing {
+    Binary {
+        number: i64,
+        relationship: Arc<RwLock<Relationship>>,
+        from: Uuid,
+        from_card: Multiplicity,
+        to: Uuid,
+        to_card: Multiplicity,
+        referrer: Arc<RwLock<Referrer>>,
+        referent: Arc<RwLock<Referent>>,
+    },
+    Isa {
+        number: i64,
+        relationship: Arc<RwLock<Relationship>>,
+        supertype: Uuid,
+        subtypes: Vec<Uuid>,
+    },
+    Associative {
+        number: i64,
+        relationship: Arc<RwLock<Relationship>>,
+        associative: Uuid,
+        referrer: Arc<RwLock<AssociativeReferrer>>,
+        sides: Vec<(Uuid, Multiplicity, Arc<RwLock<AssociativeReferent>>)>,
+    },
+}
+
+/// A box, before it's been laid out
+struct Sketch {
+    object: Uuid,
+    lines: i64,
+    chars: i64,
+    anchors: Vec<(Uuid, usize)>,
+    x_box: Option<Arc<RwLock<XBox>>>,
+}
+
+/// Fluent Domain Builder
+///
+/// Objects are created, or selected if they already exist, with
+/// [`object`][Self::object]. Attributes, states, and events apply to the most
+/// recently selected object. Relationships refer to objects by name, and the
+/// objects must exist before the relationship is added.
+///
+/// Errors are deferred until [`build`][Self::build] is called.
+pub struct ModelBuilder {
+    domain: Domain,
+    current: Option<Arc<RwLock<Object>>>,
+    referrer: Option<Arc<RwLock<Referrer>>>,
+    referent: Option<Arc<RwLock<Referent>>>,
+    sides: Vec<Arc<RwLock<AssociativeReferent>>>,
+    sketches: Vec<Sketch>,
+    drawings: Vec<Drawing>,
+    error: Option<String>,
+}
+
+impl ModelBuilder {
+    /// Start building a new domain
+    ///
+    /// Ids are derived from the name of the domain, so running the same code
+    /// twice produces the same model, and the same drawing. See
+    /// [`ObjectStore::set_namespace`][crate::v2::sarzak::store::ObjectStore::set_namespace].
+    pub fn new<S: AsRef<str>>(name: S, description: S) -> Self {
+        let mut domain = Domain::new(name, description);
+        let namespace = Uuid::new_v5(&UUID_NS, domain.domain().as_bytes());
+        domain.meta.id = namespace;
+        domain.sarzak.set_namespace(Some(namespace));
+        domain.merlin.set_namespace(Some(namespace));
+
+        ModelBuilder {
+            domain,
+            current: None,
+            referrer: None,
+            referent: None,
+            sides: Vec::new(),
+            sketches: Vec::new(),
+            drawings: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Create an [`Object`], or select an existing one
+    ///
+    /// The object's [`Ty`] is created along with it, and the key letters are
+    /// taken from the capitals in the name.
+    pub fn object<S: AsRef<str>>(mut self, name: S) -> Self {
+        let name = name.as_ref().to_upper_camel_case();
+
+        if let Some(obj) = self.lookup(&name) {
+            self.current = Some(obj);
+            return self;
+        }
+
+        let key_letters = name.chars().filter(|c| c.is_uppercase()).collect();
+        let obj = Object::new(
+            "".to_owned(),
+            key_letters,
+            name.to_title_case(),
+            &mut self.domain.sarzak,
+        );
+        Ty::new_object(&obj, &mut self.domain.sarzak);
+
+        self.sketches.push(Sketch {
+            object: obj.read().unwrap().id,
+            lines: 0,
+            chars: name.len() as i64,
+            anchors: Vec::new(),
+            x_box: None,
+        });
+        self.current = Some(obj);
+
+        self
+    }
+
+    /// Set the description of the current object
+    ///
+    pub fn description<S: AsRef<str>>(mut self, description: S) -> Self {
+        if let Some(obj) = self.current_object("description") {
+            obj.write().unwrap().description = description.as_ref().to_owned();
+        }
+
+        self
+    }
+
+    /// Add an [`Attribute`] to the current object
+    ///
+    /// The type must already exist in the store. This is always the case for the
+    /// primitive types, and for objects created by this builder.
+    pub fn attr<S: AsRef<str>>(mut self, name: S, ty: Ty) -> Self {
+        let name = name.as_ref();
+        let obj = match self.current_object("attr") {
+            Some(obj) => obj,
+            None => return self,
+        };
+
+        let ty = match self.domain.sarzak.exhume_ty(&ty.id()) {
+            Some(ty) => ty,
+            None => {
+                self.fail(format!(
+                    "the type of attribute `{}` is not in the store",
+                    name
+                ));
+                return self;
+            }
+        };
+
+        Attribute::new(name.to_owned(), &obj, &ty, &mut self.domain.sarzak);
+
+        let id = obj.read().unwrap().id;
+        if let Some(sketch) = self.sketches.iter_mut().find(|s| s.object == id) {
+            sketch.lines += 1;
+            sketch.chars = sketch.chars.max(name.len() as i64 + 8);
+        }
+
+        self
+    }
+
+    /// Add a [`State`] to the current object
+    ///
+    pub fn state<S: AsRef<str>>(mut self, name: S) -> Self {
+        if let Some(obj) = self.current_object("state") {
+            State::new(name.as_ref().to_owned(), &obj, &mut self.domain.sarzak);
+        }
+
+        self
+    }
+
+    /// Add an [`Event`] to the current object
+    ///
+    pub fn event<S: AsRef<str>>(mut self, name: S) -> Self {
+        if let Some(obj) = self.current_object("event") {
+            Event::new(name.as_ref().to_owned(), &obj, &mut self.domain.sarzak);
+        }
+
+        self
+    }
+
+    /// Add a [`Binary`] relationship
+    ///
+    /// `from` is the referrer, and `to` the referent. The phrase is read from
+    /// the referrer to the referent, e.g., "Dog _is owned by_ Owner". The
+    /// referential attribute is named after the referent.
+    ///
+    /// Both sides are unconditional. See [`conditional`][Self::conditional].
+    pub fn binary<S: AsRef<str>>(
+        mut self,
+        number: i64,
+        from: S,
+        from_card: Multiplicity,
+        to: S,
+        to_card: Multiplicity,
+        phrase: S,
+    ) -> Self {
+        let (from, to) = match (self.require(from), self.require(to)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return self,
+        };
+
+        let unconditional = Conditionality::new_unconditional(&self.domain.sarzak);
+        let referrer = Referrer::new(
+            phrase.as_ref().to_owned(),
+            to.read().unwrap().name.to_snake_case(),
+            &from_card.cardinality(&self.domain),
+            &unconditional,
+            &from,
+            &mut self.domain.sarzak,
+        );
+        let referent = Referent::new(
+            "".to_owned(),
+            &to_card.cardinality(&self.domain),
+            &unconditional,
+            &to,
+            &mut self.domain.sarzak,
+        );
+        let binary = Binary::new(number, &referrer, &referent, &mut self.domain.sarzak);
+        let relationship = Relationship::new_binary(&binary, &mut self.domain.sarzak);
+
+        self.drawings.push(Drawing::Binary {
+            number,
+            relationship,
+            from: from.read().unwrap().id,
+            from_card,
+            to: to.read().unwrap().id,
+            to_card,
+            referrer: referrer.clone(),
+            referent: referent.clone(),
+        });
+        self.referrer = Some(referrer);
+        self.referent = Some(referent);
+        self.sides.clear();
+
+        self
+    }
+
+    /// Make the referent of the most recent binary relationship conditional
+    ///
+    pub fn conditional(mut self) -> Self {
+        match self.referent {
+            Some(ref referent) => {
+                let conditional = Conditionality::new_conditional(&self.domain.sarzak);
+                referent.write().unwrap().conditionality = conditional.read().unwrap().id();
+            }
+            None => self.fail("`conditional` must follow a binary relationship".to_owned()),
+        }
+
+        self
+    }
+
+    /// Make the referrer of the most recent binary relationship conditional
+    ///
+    pub fn referrer_conditional(mut self) -> Self {
+        match self.referrer {
+            Some(ref referrer) => {
+                let conditional = Conditionality::new_conditional(&self.domain.sarzak);
+                referrer.write().unwrap().conditionality = conditional.read().unwrap().id();
+            }
+            None => {
+                self.fail("`referrer_conditional` must follow a binary relationship".to_owned())
+            }
+        }
+
+        self
+    }
+
+    /// Set the phrase read from the referent to the referrer of the most recent
+    /// binary relationship, e.g., "Owner _owns_ Dog"
+    ///
+    pub fn referent_phrase<S: AsRef<str>>(mut self, phrase: S) -> Self {
+        match self.referent {
+            Some(ref referent) => {
+                referent.write().unwrap().description = phrase.as_ref().to_owned()
+            }
+            None => self.fail("`referent_phrase` must follow a binary relationship".to_owned()),
+        }
+
+        self
+    }
+
+    /// Name the referential attribute of the most recent binary relationship
+    ///
+    pub fn referential_attribute<S: AsRef<str>>(mut self, name: S) -> Self {
+        match self.referrer {
+            Some(ref referrer) => {
+                referrer.write().unwrap().referential_attribute = name.as_ref().to_owned()
+            }
+            None => {
+                self.fail("`referential_attribute` must follow a binary relationship".to_owned())
+            }
+        }
+
+        self
+    }
+
+    /// Add an [`Isa`] relationship
+    ///
+    pub fn isa<S: AsRef<str>>(mut self, number: i64, supertype: S, subtypes: &[S]) -> Self {
+        let supertype = match self.require(supertype) {
+            Some(obj) => obj,
+            None => return self,
+        };
+        let subtypes: Vec<_> = subtypes.iter().filter_map(|s| self.require(s)).collect();
+        if self.error.is_some() {
+            return self;
+        }
+
+        let sup = Supertype::new(&supertype, &mut self.domain.sarzak);
+        let isa = Isa::new(number, &sup, &mut self.domain.sarzak);
+        for sub in &subtypes {
+            Subtype::new(&isa, sub, &mut self.domain.sarzak);
+        }
+        let relationship = Relationship::new_isa(&isa, &mut self.domain.sarzak);
+
+        self.referrer = None;
+        self.referent = None;
+        self.sides.clear();
+        self.drawings.push(Drawing::Isa {
+            number,
+            relationship,
+            supertype: supertype.read().unwrap().id,
+            subtypes: subtypes.iter().map(|s| s.read().unwrap().id).collect(),
+        });
+
+        self
+    }
+
+    /// Add an [`Associative`] relationship
+    ///
+    /// `associative` is the associative object, formalizing the relationship
+    /// between `one` and `other`.
+    pub fn associative<S: AsRef<str>>(
+        self,
+        number: i64,
+        associative: S,
+        one: S,
+        one_card: Multiplicity,
+        other: S,
+        other_card: Multiplicity,
+    ) -> Self {
+        self.associative_sides(number, associative, &[(one, one_card), (other, other_card)])
+    }
+
+    /// Add an [`Associative`] relationship between any number of objects
+    ///
+    /// The sides are unconditional, and have no phrases. See
+    /// [`side_conditional`][Self::side_conditional] and
+    /// [`side_phrase`][Self::side_phrase].
+    pub fn associative_sides<S: AsRef<str>>(
+        mut self,
+        number: i64,
+        associative: S,
+        sides: &[(S, Multiplicity)],
+    ) -> Self {
+        let associative = match self.require(associative) {
+            Some(obj) => obj,
+            None => return self,
+        };
+        let sides: Vec<_> = sides
+            .iter()
+            .filter_map(|(name, card)| self.require(name).map(|obj| (obj, *card)))
+            .collect();
+        if self.error.is_some() {
+            return self;
+        }
+
+        let referrer = AssociativeReferrer::new(
+            &Cardinality::new_one(&self.domain.sarzak),
+            &associative,
+            &mut self.domain.sarzak,
+        );
+        let assoc = Associative::new(number, &referrer, &mut self.domain.sarzak);
+
+        let unconditional = Conditionality::new_unconditional(&self.domain.sarzak);
+        self.sides.clear();
+        for (obj, card) in &sides {
+            let referent = AssociativeReferent::new(
+                "".to_owned(),
+                &card.cardinality(&self.domain),
+                &unconditional,
+                obj,
+                &mut self.domain.sarzak,
+            );
+            AnAssociativeReferent::new(
+                obj.read().unwrap().name.to_snake_case(),
+                &assoc,
+                &referent,
+                &mut self.domain.sarzak,
+            );
+            self.sides.push(referent);
+        }
+        let relationship = Relationship::new_associative(&assoc, &mut self.domain.sarzak);
+
+        self.referrer = None;
+        self.referent = None;
+        self.drawings.push(Drawing::Associative {
+            number,
+            relationship,
+            associative: associative.read().unwrap().id,
+            referrer,
+            sides: sides
+                .iter()
+                .zip(self.sides.iter())
+                .map(|((obj, card), referent)| (obj.read().unwrap().id, *card, referent.clone()))
+                .collect(),
+        });
+
+        self
+    }
+
+    /// Make side `n` of the most recent associative relationship conditional
+    ///
+    pub fn side_conditional(mut self, n: usize) -> Self {
+        match self.sides.get(n) {
+            Some(referent) => {
+                let conditional = Conditionality::new_conditional(&self.domain.sarzak);
+                referent.write().unwrap().conditionality = conditional.read().unwrap().id();
+            }
+            None => self.fail(format!(
+                "`side_conditional` must follow an associative relationship with a side {}",
+                n
+            )),
+        }
+
+        self
+    }
+
+    /// Set the phrase of side `n` of the most recent associative relationship
+    ///
+    pub fn side_phrase<S: AsRef<str>>(mut self, n: usize, phrase: S) -> Self {
+        match self.sides.get(n) {
+            Some(referent) => referent.write().unwrap().description = phrase.as_ref().to_owned(),
+            None => self.fail(format!(
+                "`side_phrase` must follow an associative relationship with a side {}",
+                n
+            )),
+        }
+
+        self
+    }
+
+    /// Return a reference to the sarzak store
+    ///
+    pub fn sarzak(&self) -> &SarzakStore {
+        &self.domain.sarzak
+    }
+
+    /// Return a mutable reference to the sarzak store
+    ///
+    /// This is useful for creating types, e.g., [`List`][crate::v2::sarzak::types::List],
+    /// to pass to [`attr`][Self::attr].
+    pub fn sarzak_mut(&mut self) -> &mut SarzakStore {
+        &mut self.domain.sarzak
+    }
+
+    /// The final step
+    ///
+    /// Lay out the boxes, draw the relationships, and return the domain.
+    pub fn build(mut self) -> Result<Domain> {
+        ensure!(
+            self.error.is_none(),
+            DomainBuilderSnafu {
+                message: self.error.take().unwrap_or_default()
+            }
+        );
+
+        for n in 0..self.sketches.len() {
+            let sketch = &self.sketches[n];
+            let obj = self.domain.sarzak.exhume_object(&sketch.object).unwrap();
+            let x = MARGIN + (n % COLUMNS) as i64 * (CELL_WIDTH + MARGIN);
+            let y = MARGIN + (n / COLUMNS) as i64 * (CELL_HEIGHT + MARGIN);
+            let width = MIN_WIDTH.max(sketch.chars * CHAR_WIDTH);
+            let height = HEADER_HEIGHT + (sketch.lines + 1) * LINE_HEIGHT;
+
+            let x_box = XBox::new(
+                height,
+                width,
+                x,
+                y,
+                &obj.read().unwrap(),
+                &mut self.domain.merlin,
+            );
+            self.sketches[n].x_box = Some(x_box);
+        }
+
+        for drawing in std::mem::take(&mut self.drawings) {
+            match drawing {
+                Drawing::Binary {
+                    number,
+                    relationship,
+                    from,
+                    from_card,
+                    to,
+                    to_card,
+                    referrer,
+                    referent,
+                } => {
+                    let (line, segment) = self.line(&relationship, number);
+
+                    let phrase = referrer.read().unwrap().description.to_owned();
+                    self.end(from, from_card, &phrase, to, &line, &segment);
+                    let phrase = referent.read().unwrap().description.to_owned();
+                    self.end(to, to_card, &phrase, from, &line, &segment);
+                }
+                Drawing::Isa {
+                    number,
+                    relationship,
+                    supertype,
+                    subtypes,
+                } => {
+                    if subtypes.is_empty() {
+                        continue;
+                    }
+
+                    // The supertype gets a single anchor, and there is a segment
+                    // from it to each subtype.
+                    let (line, first) = self.line(&relationship, number);
+                    let glyph = Glyph::new_z_super(&line, &mut self.domain.merlin);
+                    let (_, point) = self.anchor(supertype, subtypes[0], &glyph, &line, &first);
+
+                    for (n, sub) in subtypes.iter().enumerate() {
+                        let segment = if n == 0 {
+                            first.clone()
+                        } else {
+                            let segment = LineSegment::new(&line, &mut self.domain.merlin);
+                            LineSegmentPoint::new(&segment, &point, &mut self.domain.merlin);
+                            segment
+                        };
+                        let glyph = Glyph::new_sub(&line, &mut self.domain.merlin);
+                        self.anchor(*sub, supertype, &glyph, &line, &segment);
+                    }
+                }
+                Drawing::Associative {
+                    number,
+                    relationship,
+                    associative,
+                    referrer,
+                    sides,
+                } => {
+                    // The glyph at the associative object is the referrer's.
+                    let card = referrer
+                        .read()
+                        .unwrap()
+                        .r89_cardinality(&self.domain.sarzak)[0]
+                        .clone();
+                    let sides: Vec<_> = sides
+                        .into_iter()
+                        .map(|(obj, card, referent)| {
+                            let phrase = referent.read().unwrap().description.to_owned();
+                            (obj, card, phrase)
+                        })
+                        .collect();
+                    let (one, one_card, one_phrase) = match sides.first() {
+                        Some(side) => side,
+                        None => continue,
+                    };
+                    let (line, segment) = self.line(&relationship, number);
+
+                    let (other, other_card, other_phrase) = match sides.get(1) {
+                        Some(side) => side,
+                        None => {
+                            // With only one side there's nothing to bisect, so
+                            // the line runs straight to the associative object.
+                            self.end(*one, *one_card, one_phrase, associative, &line, &segment);
+                            let glyph = cardinality_glyph(&card, &line, &mut self.domain.merlin);
+                            self.anchor(associative, *one, &glyph, &line, &segment);
+                            continue;
+                        }
+                    };
+                    let start = self.end(*one, *one_card, one_phrase, *other, &line, &segment);
+                    let end = self.end(*other, *other_card, other_phrase, *one, &line, &segment);
+
+                    // The associative object hangs off of the middle of the line,
+                    // which runs between the anchors, and not the box centers.
+                    let (x, y) = {
+                        let (start, end) = (start.read().unwrap(), end.read().unwrap());
+                        ((start.x + end.x) / 2, (start.y + end.y) / 2)
+                    };
+                    let bisection = Bisection::new(0.5, &segment, &mut self.domain.merlin);
+                    let segment = LineSegment::new(&line, &mut self.domain.merlin);
+                    let glyph = cardinality_glyph(&card, &line, &mut self.domain.merlin);
+                    self.anchor(associative, *one, &glyph, &line, &segment);
+                    let point = Point::new_bisection(x, y, &bisection, &mut self.domain.merlin);
+                    LineSegmentPoint::new(&segment, &point, &mut self.domain.merlin);
+
+                    // Any further sides get a segment from the bisection point.
+                    for (side, card, phrase) in &sides[2..] {
+                        let segment = LineSegment::new(&line, &mut self.domain.merlin);
+                        LineSegmentPoint::new(&segment, &point, &mut self.domain.merlin);
+                        self.end(*side, *card, phrase, associative, &line, &segment);
+                    }
+                }
+            }
+        }
+
+        Ok(self.domain)
+    }
+
+    /// Create a line with a single segment, and name it
+    ///
+    fn line(
+        &mut self,
+        relationship: &Arc<RwLock<Relationship>>,
+        number: i64,
+    ) -> (Arc<RwLock<Line>>, Arc<RwLock<LineSegment>>) {
+        let line = Line::new(&relationship.read().unwrap(), &mut self.domain.merlin);
+        let segment = LineSegment::new(&line, &mut self.domain.merlin);
+        self.name(&line, &segment, number);
+
+        (line, segment)
+    }
+
+    /// Put the relationship name at the middle of the segment
+    ///
+    fn name(&mut self, line: &Arc<RwLock<Line>>, segment: &Arc<RwLock<LineSegment>>, number: i64) {
+        let bisection = Bisection::new(0.5, segment, &mut self.domain.merlin);
+        RelationshipName::new(
+            format!("R{}", number),
+            0,
+            0,
+            line,
+            &bisection,
+            &mut self.domain.merlin,
+        );
+    }
+
+    /// Draw the end of a line at `object`, with it's glyph and phrase
+    ///
+    /// Returns the point on the edge of the box.
+    fn end(
+        &mut self,
+        object: Uuid,
+        card: Multiplicity,
+        phrase: &str,
+        toward: Uuid,
+        line: &Arc<RwLock<Line>>,
+        segment: &Arc<RwLock<LineSegment>>,
+    ) -> Arc<RwLock<Point>> {
+        let card = card.cardinality(&self.domain);
+        let glyph = cardinality_glyph(&card, line, &mut self.domain.merlin);
+        let (anchor, point) = self.anchor(object, toward, &glyph, line, segment);
+        anchor_phrase(phrase, line, &anchor, &mut self.domain.merlin);
+
+        point
+    }
+
+    /// Anchor a line to the box of `object`, on the edge facing `toward`
+    ///
+    /// Returns the anchor, and the point on the edge of the box.
+    fn anchor(
+        &mut self,
+        object: Uuid,
+        toward: Uuid,
+        glyph: &Arc<RwLock<Glyph>>,
+        line: &Arc<RwLock<Line>>,
+        segment: &Arc<RwLock<LineSegment>>,
+    ) -> (Arc<RwLock<Anchor>>, Arc<RwLock<Point>>) {
+        let x_box = self.x_box(object);
+        let (cx, cy) = self.center(object);
+        let (tx, ty) = self.center(toward);
+        let (dx, dy) = (tx - cx, ty - cy);
+
+        let edge = if object == toward || dx.abs() >= dy.abs() {
+            if dx >= 0 {
+                Edge::new_right(&self.domain.merlin)
+            } else {
+                Edge::new_left(&self.domain.merlin)
+            }
+        } else if dy >= 0 {
+            Edge::new_bottom(&self.domain.merlin)
+        } else {
+            Edge::new_top(&self.domain.merlin)
+        };
+        let edge_id = edge.read().unwrap().id();
+
+        let sketch = self
+            .sketches
+            .iter_mut()
+            .find(|s| s.object == object)
+            .unwrap();
+        let count = match sketch.anchors.iter_mut().find(|(e, _)| *e == edge_id) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                sketch.anchors.push((edge_id, 0));
+                0
+            }
+        };
+        let offset = EDGE_OFFSETS[count % EDGE_OFFSETS.len()];
+
+        let (px, py) = position_on_edge(&x_box.read().unwrap(), &edge.read().unwrap(), offset);
+        let (ox, oy) = match *edge.read().unwrap() {
+            Edge::Top(_) => (px, py - ANCHOR_OFFSET),
+            Edge::Bottom(_) => (px, py + ANCHOR_OFFSET),
+            Edge::Left(_) => (px - ANCHOR_OFFSET, py),
+            Edge::Right(_) => (px + ANCHOR_OFFSET, py),
+        };
+
+        let anchor = Anchor::new(
+            offset,
+            ox,
+            oy,
+            &edge,
+            glyph,
+            &x_box,
+            line,
+            &mut self.domain.merlin,
+        );
+        let point = Point::new_anchor(px, py, &anchor, &mut self.domain.merlin);
+        LineSegmentPoint::new(segment, &point, &mut self.domain.merlin);
+
+        (anchor, point)
+    }
+
+    fn x_box(&self, object: Uuid) -> Arc<RwLock<XBox>> {
+        self.sketches
+            .iter()
+            .find(|s| s.object == object)
+            .and_then(|s| s.x_box.clone())
+            .unwrap()
+    }
+
+    fn center(&self, object: Uuid) -> (i64, i64) {
+        let x_box = self.x_box(object);
+        let b = x_box.read().unwrap();
+
+        (b.x + b.width / 2, b.y + b.height / 2)
+    }
+
+    fn lookup(&self, name: &str) -> Option<Arc<RwLock<Object>>> {
+        self.domain
+            .sarzak
+            .exhume_object_id_by_name(&name.to_upper_camel_case())
+            .and_then(|id| self.domain.sarzak.exhume_object(&id))
+    }
+
+    fn require<S: AsRef<str>>(&mut self, name: S) -> Option<Arc<RwLock<Object>>> {
+        let obj = self.lookup(name.as_ref());
+        if obj.is_none() {
+            self.fail(format!("unknown object `{}`", name.as_ref()));
+        }
+
+        obj
+    }
+
+    fn current_object(&mut self, method: &str) -> Option<Arc<RwLock<Object>>> {
+        if self.current.is_none() {
+            self.fail(format!("`{}` requires an object", method));
+        }
+
+        self.current.clone()
+    }
+
+    /// Remember the first error
+    ///
+    fn fail(&mut self, message: String) {
+        if self.error.is_none() {
+            self.error = Some(message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        merlin::types::PointEnum,
+        sarzak::types::{Z_STRING, Z_UUID},
+    };
+
+    use Multiplicity::*;
+
+    fn pets() -> ModelBuilder {
+        ModelBuilder::new("pets", "A domain about pets")
+            .object("Owner")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .attr("name", Ty::ZString(Z_STRING))
+            .object("Pet")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .state("Hungry")
+            .event("feed")
+            .object("Dog")
+            .object("Cat")
+            .object("Vet")
+            .object("Visit")
+            .binary(1, "Pet", Many, "Owner", One, "is owned by")
+            .conditional()
+            .referent_phrase("owns")
+            .isa(2, "Pet", &["Dog", "Cat"])
+            .associative(3, "Visit", "Pet", Many, "Vet", Many)
+    }
+
+    #[test]
+    fn test_sarzak() {
+        let domain = pets().build().unwrap();
+        let sarzak = domain.sarzak();
+
+        assert_eq!(sarzak.iter_object().count(), 6);
+        assert_eq!(sarzak.iter_relationship().count(), 3);
+        assert_eq!(sarzak.iter_state().count(), 1);
+        assert_eq!(sarzak.iter_event().count(), 1);
+
+        let pet = sarzak
+            .exhume_object(&sarzak.exhume_object_id_by_name("Pet").unwrap())
+            .unwrap();
+        let pet = pet.read().unwrap();
+        let attrs = pet
+            .r1_attribute(sarzak)
+            .iter()
+            .map(|a| a.read().unwrap().name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(attrs, vec!["id".to_owned()]);
+
+        let binary = sarzak.iter_binary().next().unwrap();
+        let binary = binary.read().unwrap();
+        let referrer = binary.r6_referrer(sarzak)[0].read().unwrap().clone();
+        let referent = binary.r5_referent(sarzak)[0].read().unwrap().clone();
+        assert_eq!(referrer.obj_id, pet.id);
+        assert_eq!(referrer.description, "is owned by");
+        assert_eq!(referrer.referential_attribute, "owner");
+        assert_eq!(referent.description, "owns");
+        assert_eq!(
+            *referent.r12_conditionality(sarzak)[0].read().unwrap(),
+            Conditionality::Conditional(crate::v2::sarzak::types::CONDITIONAL)
+        );
+
+        assert_eq!(sarzak.iter_subtype().count(), 2);
+        assert_eq!(sarzak.iter_an_associative_referent().count(), 2);
+    }
+
+    #[test]
+    fn test_merlin() {
+        let domain = pets().build().unwrap();
+        let merlin = domain.merlin();
+
+        assert_eq!(merlin.iter_x_box().count(), 6);
+        assert_eq!(merlin.iter_line().count(), 3);
+        assert_eq!(merlin.iter_relationship_name().count(), 3);
+        assert_eq!(merlin.iter_relationship_phrase().count(), 2);
+        // Two for the binary, one per subtype and one for the supertype, and
+        // three for the associative.
+        assert_eq!(merlin.iter_anchor().count(), 2 + 3 + 3);
+
+        // Every anchor is on the edge of it's box.
+        for anchor in merlin.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            let point = anchor.r6_point(merlin)[0].read().unwrap().clone();
+            let x_box = merlin.exhume_x_box(&anchor.x_box).unwrap();
+            let b = x_box.read().unwrap();
+            let on_x = point.x == b.x || point.x == b.x + b.width;
+            let on_y = point.y == b.y || point.y == b.y + b.height;
+            assert!(
+                (on_x && (b.y..=b.y + b.height).contains(&point.y))
+                    || (on_y && (b.x..=b.x + b.width).contains(&point.x)),
+                "anchor off of it's box: {point:?}, {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_associative_bisection() {
+        let domain = pets().build().unwrap();
+        let merlin = domain.merlin();
+
+        let mut bisections = merlin.iter_point().filter_map(|p| {
+            let p = p.read().unwrap();
+            match p.subtype {
+                PointEnum::Bisection(id) => Some((p.x, p.y, id)),
+                _ => None,
+            }
+        });
+        let (x, y, bisection) = bisections.next().unwrap();
+        assert!(bisections.next().is_none());
+
+        // The point is in the middle of the segment that it bisects.
+        let bisection = merlin.exhume_bisection(&bisection).unwrap();
+        let segment = bisection.read().unwrap().segment;
+        let ends = merlin
+            .iter_line_segment_point()
+            .filter(|lsp| lsp.read().unwrap().segment == segment)
+            .map(|lsp| {
+                let point = merlin.exhume_point(&lsp.read().unwrap().point).unwrap();
+                let point = point.read().unwrap();
+                (point.x, point.y)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ends.len(), 2);
+        assert_eq!(
+            (x, y),
+            ((ends[0].0 + ends[1].0) / 2, (ends[0].1 + ends[1].1) / 2)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let result = ModelBuilder::new("pets", "")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .object("Dog")
+            .binary(1, "Dog", Many, "Owner", One, "is owned by")
+            .build();
+        let message = result.err().unwrap().to_string();
+        // Only the first error is reported.
+        assert!(message.contains("`attr` requires an object"), "{message}");
+        assert!(!message.contains("Owner"), "{message}");
+
+        let result = ModelBuilder::new("pets", "")
+            .object("Dog")
+            .isa(1, "Dog", &["Puppy"])
+            .build();
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("unknown object `Puppy`"), "{message}");
+    }
+
+    #[test]
+    fn test_select_existing() {
+        let domain = ModelBuilder::new("pets", "")
+            .object("Dog")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .object("Owner")
+            .object("Dog")
+            .attr("name", Ty::ZString(Z_STRING))
+            .build()
+            .unwrap();
+        let sarzak = domain.sarzak();
+
+        assert_eq!(sarzak.iter_object().count(), 2);
+        let dog = sarzak
+            .exhume_object(&sarzak.exhume_object_id_by_name("Dog").unwrap())
+            .unwrap();
+        assert_eq!(dog.read().unwrap().r1_attribute(sarzak).len(), 2);
+        assert_eq!(domain.merlin().iter_x_box().count(), 2);
+    }
+
+    #[test]
+    fn test_ids_are_stable() {
+        fn ids(domain: &Domain) -> Vec<Uuid> {
+            let sarzak = domain.sarzak();
+            let merlin = domain.merlin();
+
+            let mut ids = Vec::new();
+            ids.extend(sarzak.iter_object().map(|o| o.read().unwrap().id));
+            ids.extend(sarzak.iter_attribute().map(|a| a.read().unwrap().id));
+            ids.extend(sarzak.iter_relationship().map(|r| r.read().unwrap().id()));
+            ids.extend(sarzak.iter_referrer().map(|r| r.read().unwrap().id));
+            ids.extend(sarzak.iter_subtype().map(|s| s.read().unwrap().id));
+            ids.extend(merlin.iter_x_box().map(|b| b.read().unwrap().id));
+            ids.extend(merlin.iter_line().map(|l| l.read().unwrap().id));
+            ids.extend(merlin.iter_line_segment().map(|s| s.read().unwrap().id));
+            ids.extend(
+                merlin
+                    .iter_line_segment_point()
+                    .map(|p| p.read().unwrap().id),
+            );
+            ids.extend(merlin.iter_point().map(|p| p.read().unwrap().id));
+            ids.extend(merlin.iter_anchor().map(|a| a.read().unwrap().id));
+            ids.extend(merlin.iter_glyph().map(|g| g.read().unwrap().id));
+            ids.extend(merlin.iter_bisection().map(|b| b.read().unwrap().id));
+            ids.extend(
+                merlin
+                    .iter_relationship_name()
+                    .map(|n| n.read().unwrap().id),
+            );
+            ids.extend(
+                merlin
+                    .iter_relationship_phrase()
+                    .map(|p| p.read().unwrap().id),
+            );
+            ids.sort();
+
+            ids
+        }
+
+        let first = ids(&pets().build().unwrap());
+        let second = ids(&pets().build().unwrap());
+        assert_eq!(first, second);
+
+        // And they are all different.
+        let mut unique = first.clone();
+        unique.dedup();
+        assert_eq!(unique.len(), first.len());
+
+        // A different domain gets different ids.
+        let other = ModelBuilder::new("other pets", "")
+            .object("Owner")
+            .build()
+            .unwrap();
+        assert!(ids(&other).iter().all(|id| !first.contains(id)));
+    }
+}
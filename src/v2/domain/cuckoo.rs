@@ -0,0 +1,582 @@
+//! Cuckoo Export
+//!
+//! Write a [`Domain`] as a cuckoo model file, so that a domain that was built or
+//! edited in code may be opened in the cuckoo GUI. This is the inverse of
+//! [`DomainBuilder::cuckoo_model`][crate::domain::DomainBuilder::cuckoo_model].
+//!
+//! The model comes from the sarzak store, and the `paper` from the merlin
+//! store. Cuckoo doesn't know about inflection points, so lines are reduced to
+//! their anchors, and an associative line to it's anchors and the point at which
+//! the associative object hangs off of it.
+//!
+//! Every object must have a box, and every relationship a line -- see
+//! [`sync`][super::sync::sync]. The only attribute types that cuckoo knows about
+//! are `Boolean`, `Float`, `Integer`, `String`, and `Uuid`.
+use std::sync::{Arc, RwLock};
+
+use heck::ToUpperCamelCase;
+use serde_json::{json, Map, Value};
+use snafu::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    error::{CuckooExportSnafu, Result},
+    v2::{
+        domain::Domain,
+        merlin::{
+            geometry::line_segments,
+            store::ObjectStore as MerlinStore,
+            types::{Anchor, Edge, Line, PointEnum},
+        },
+        sarzak::{
+            store::ObjectStore as SarzakStore,
+            types::{Relationship, Ty, CONDITIONAL, MANY},
+        },
+    },
+};
+
+/// Export the domain as a cuckoo model
+///
+pub fn export(domain: &Domain) -> Result<Value> {
+    let sarzak = domain.sarzak();
+    let merlin = domain.merlin();
+
+    let mut objects = Map::new();
+    let mut boxes = Map::new();
+    for obj in sarzak.iter_object() {
+        let obj = obj.read().unwrap();
+
+        let mut attributes = Map::new();
+        for attr in obj.r1_attribute(sarzak) {
+            let attr = attr.read().unwrap();
+            let ty = attr.r2_ty(sarzak)[0].read().unwrap().to_owned();
+            let ty = match ty {
+                Ty::Boolean(_) => "Boolean",
+                Ty::Float(_) => "Float",
+                Ty::Integer(_) => "Integer",
+                Ty::ZString(_) => "String",
+                Ty::ZUuid(_) => "Uuid",
+                _ => {
+                    return CuckooExportSnafu {
+                        message: format!(
+                            "attribute `{}` of `{}` has a type that cuckoo doesn't support",
+                            attr.name, obj.name
+                        ),
+                    }
+                    .fail()
+                    .map_err(|e| e.into())
+                }
+            };
+            attributes.insert(
+                attr.id.to_string(),
+                json!({
+                    "id": attr.id,
+                    "name": attr.name,
+                    "type": ty,
+                }),
+            );
+        }
+
+        objects.insert(
+            obj.id.to_string(),
+            json!({
+                "id": obj.id,
+                "key_letter": obj.key_letters,
+                "name": obj.name,
+                "description": obj.description,
+                "attributes": attributes,
+            }),
+        );
+
+        let x_box = merlin
+            .iter_x_box()
+            .find(|b| b.read().unwrap().object == obj.id)
+            .context(CuckooExportSnafu {
+                message: format!("object `{}` doesn't have a box", obj.name),
+            })?;
+        let x_box = x_box.read().unwrap();
+        boxes.insert(
+            obj.id.to_string(),
+            json!({
+                "x": x_box.x,
+                "y": x_box.y,
+                "width": x_box.width,
+                "height": x_box.height,
+            }),
+        );
+    }
+
+    let mut relationships = Map::new();
+    let mut lines = Map::new();
+    for relationship in sarzak.iter_relationship() {
+        let relationship = relationship.read().unwrap();
+        let id = relationship.id();
+        let (model, ui) = match *relationship {
+            Relationship::Binary(id) => binary(&id, sarzak, merlin)?,
+            Relationship::Isa(id) => isa(&id, sarzak, merlin)?,
+            Relationship::Associative(id) => associative(&id, sarzak, merlin)?,
+        };
+        relationships.insert(id.to_string(), model);
+        lines.insert(id.to_string(), ui);
+    }
+
+    let paper = domain.id().to_string();
+    let [width, height] = *domain.extents();
+    let [x, y] = *domain.view();
+
+    let mut papers = Map::new();
+    papers.insert(
+        paper.clone(),
+        json!({
+            "id": paper,
+            "description": domain.description(),
+            "domain_name": domain.name(),
+            "domain_ns": paper,
+            "width": width,
+            "height": height,
+            "offset": { "x": x, "y": y },
+            "objects": boxes,
+            "relationships": lines,
+        }),
+    );
+
+    Ok(json!({
+        "paper": {
+            "ids": [paper],
+            "entities": papers,
+        },
+        "objects": {
+            "ids": objects.keys().collect::<Vec<_>>(),
+            "entities": objects,
+        },
+        "relationships": {
+            "ids": relationships.keys().collect::<Vec<_>>(),
+            "entities": relationships,
+        },
+    }))
+}
+
+fn binary(id: &Uuid, sarzak: &SarzakStore, merlin: &MerlinStore) -> Result<(Value, Value)> {
+    let binary = sarzak.exhume_binary(id).unwrap();
+    let binary = binary.read().unwrap();
+    let referrer = binary.r6_referrer(sarzak)[0].read().unwrap().to_owned();
+    let referent = binary.r5_referent(sarzak)[0].read().unwrap().to_owned();
+
+    let model = json!({
+        "Binary": {
+            "id": binary.id,
+            "number": binary.number,
+            "from": {
+                "obj_id": referrer.obj_id,
+                "description": referrer.description,
+                "cardinality": cardinality(&referrer.cardinality),
+                "conditionality": conditionality(&referrer.conditionality),
+                "formalizing_attribute_name": referrer.referential_attribute,
+            },
+            "to": {
+                "obj_id": referent.obj_id,
+                "description": referent.description,
+                "cardinality": cardinality(&referent.cardinality),
+                "conditionality": conditionality(&referent.conditionality),
+            },
+        }
+    });
+
+    let mut anchors = anchors(id, binary.number, merlin)?;
+    let from = take(
+        &mut anchors,
+        referrer.obj_id,
+        Some(&referrer.description),
+        binary.number,
+        merlin,
+    )?;
+    let to = take(
+        &mut anchors,
+        referent.obj_id,
+        Some(&referent.description),
+        binary.number,
+        merlin,
+    )?;
+    let ui = json!({
+        "BinaryUI": {
+            "from": anchor(&from, merlin),
+            "to": anchor(&to, merlin),
+        }
+    });
+
+    Ok((model, ui))
+}
+
+fn isa(id: &Uuid, sarzak: &SarzakStore, merlin: &MerlinStore) -> Result<(Value, Value)> {
+    let isa = sarzak.exhume_isa(id).unwrap();
+    let isa = isa.read().unwrap();
+    let supertype = isa.r13_supertype(sarzak)[0].read().unwrap().obj_id;
+    let mut subtypes = isa
+        .r27_subtype(sarzak)
+        .iter()
+        .map(|s| {
+            let s = s.read().unwrap();
+            let name = s.r15_object(sarzak)[0].read().unwrap().name.clone();
+            (name, s.obj_id)
+        })
+        .collect::<Vec<_>>();
+    subtypes.sort();
+
+    let model = json!({
+        "Isa": {
+            "id": isa.id,
+            "number": isa.number,
+            "obj_id": supertype,
+            "subtypes": subtypes.iter().map(|(_, id)| id).collect::<Vec<_>>(),
+        }
+    });
+
+    let mut anchors = anchors(id, isa.number, merlin)?;
+    let from = take(&mut anchors, supertype, None, isa.number, merlin)?;
+    let to = subtypes
+        .iter()
+        .map(|(_, obj)| {
+            take(&mut anchors, *obj, None, isa.number, merlin).map(|a| anchor(&a, merlin))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let ui = json!({
+        "IsaUI": {
+            "from": anchor(&from, merlin),
+            "to": to,
+        }
+    });
+
+    Ok((model, ui))
+}
+
+fn associative(id: &Uuid, sarzak: &SarzakStore, merlin: &MerlinStore) -> Result<(Value, Value)> {
+    let assoc = sarzak.exhume_associative(id).unwrap();
+    let assoc = assoc.read().unwrap();
+    let referrer = assoc.r21_associative_referrer(sarzak)[0]
+        .read()
+        .unwrap()
+        .to_owned();
+
+    // Cuckoo distinguishes between the two sides, and we don't, so we order
+    // them by name, as the text format does.
+    let mut sides = assoc
+        .r22_an_associative_referent(sarzak)
+        .iter()
+        .map(|r| {
+            let r = r.read().unwrap();
+            let referent = r.r22_associative_referent(sarzak)[0]
+                .read()
+                .unwrap()
+                .to_owned();
+            let name = referent.r25_object(sarzak)[0]
+                .read()
+                .unwrap()
+                .name
+                .to_upper_camel_case();
+            (name, r.referential_attribute.clone(), referent)
+        })
+        .collect::<Vec<_>>();
+    sides.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+    let (one, other) = match &sides[..] {
+        [(_, one_attr, one), (_, other_attr, other)] => ((one_attr, one), (other_attr, other)),
+        _ => {
+            return CuckooExportSnafu {
+                message: format!("R{} doesn't have two sides", assoc.number),
+            }
+            .fail()
+            .map_err(|e| e.into())
+        }
+    };
+
+    let model = json!({
+        "Associative": {
+            "id": assoc.id,
+            "number": assoc.number,
+            "from": {
+                "id": referrer.id,
+                "obj_id": referrer.obj_id,
+                "cardinality": cardinality(&referrer.cardinality),
+                "one_referential_attribute": one.0,
+                "other_referential_attribute": other.0,
+            },
+            "one": {
+                "obj_id": one.1.obj_id,
+                "description": one.1.description,
+                "cardinality": cardinality(&one.1.cardinality),
+                "conditionality": conditionality(&one.1.conditionality),
+            },
+            "other": {
+                "obj_id": other.1.obj_id,
+                "description": other.1.description,
+                "cardinality": cardinality(&other.1.cardinality),
+                "conditionality": conditionality(&other.1.conditionality),
+            },
+        }
+    });
+
+    let mut anchors = anchors(id, assoc.number, merlin)?;
+    let middle = take(&mut anchors, referrer.obj_id, None, assoc.number, merlin)?;
+    let one = take(
+        &mut anchors,
+        one.1.obj_id,
+        Some(&one.1.description),
+        assoc.number,
+        merlin,
+    )?;
+    let other = take(
+        &mut anchors,
+        other.1.obj_id,
+        Some(&other.1.description),
+        assoc.number,
+        merlin,
+    )?;
+
+    // The associative object hangs off of the bisection point. If there isn't
+    // one then the middle of the line will have to do.
+    let line = line(id, assoc.number, merlin)?;
+    let (x, y) = bisection_point(&line.read().unwrap(), merlin).unwrap_or_else(|| {
+        let (x1, y1) = point(&one, merlin);
+        let (x2, y2) = point(&other, merlin);
+        ((x1 + x2) / 2, (y1 + y2) / 2)
+    });
+
+    let ui = json!({
+        "AssociativeUI": {
+            "from": { "x": x, "y": y },
+            "middle": anchor(&middle, merlin),
+            "one": anchor(&one, merlin),
+            "other": anchor(&other, merlin),
+        }
+    });
+
+    Ok((model, ui))
+}
+
+fn line(id: &Uuid, number: i64, merlin: &MerlinStore) -> Result<Arc<RwLock<Line>>> {
+    merlin
+        .iter_line()
+        .find(|l| l.read().unwrap().relationship == *id)
+        .context(CuckooExportSnafu {
+            message: format!("R{} doesn't have a line", number),
+        })
+        .map_err(|e| e.into())
+}
+
+fn anchors(id: &Uuid, number: i64, merlin: &MerlinStore) -> Result<Vec<Arc<RwLock<Anchor>>>> {
+    let line = line(id, number, merlin)?;
+    let anchors = line.read().unwrap().r3_anchor(merlin);
+
+    Ok(anchors)
+}
+
+/// Remove and return the anchor attached to the box of `object`
+///
+/// A reflexive relationship has both ends on the same box. The ends are told
+/// apart by `phrase`, the text drawn at the anchor. Empty phrases aren't
+/// drawn at all.
+fn take(
+    anchors: &mut Vec<Arc<RwLock<Anchor>>>,
+    object: Uuid,
+    phrase: Option<&str>,
+    number: i64,
+    merlin: &MerlinStore,
+) -> Result<Arc<RwLock<Anchor>>> {
+    let candidates = anchors
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| {
+            let a = a.read().unwrap();
+            merlin
+                .exhume_x_box(&a.x_box)
+                .map(|b| b.read().unwrap().object)
+                == Some(object)
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let index = candidates
+        .iter()
+        .find(|i| {
+            let text = anchors[**i]
+                .read()
+                .unwrap()
+                .r13c_relationship_phrase(merlin)
+                .first()
+                .map(|p| p.read().unwrap().text.clone())
+                .unwrap_or_default();
+            phrase == Some(text.as_str())
+        })
+        .or_else(|| candidates.first())
+        .copied()
+        .context(CuckooExportSnafu {
+            message: format!("R{} isn't anchored to object {}", number, object),
+        })?;
+
+    Ok(anchors.remove(index))
+}
+
+fn anchor(anchor: &Arc<RwLock<Anchor>>, merlin: &MerlinStore) -> Value {
+    let anchor = anchor.read().unwrap();
+    let object = merlin
+        .exhume_x_box(&anchor.x_box)
+        .unwrap()
+        .read()
+        .unwrap()
+        .object;
+    let (x, y) = {
+        let point = &anchor.r6_point(merlin)[0];
+        let point = point.read().unwrap();
+        (point.x, point.y)
+    };
+    let dir = match *anchor.r9_edge(merlin)[0].read().unwrap() {
+        Edge::Top(_) => "North",
+        Edge::Bottom(_) => "South",
+        Edge::Left(_) => "West",
+        Edge::Right(_) => "East",
+    };
+
+    // The offset is where the phrase goes, relative to the anchor point.
+    let (dx, dy) = match anchor.r13c_relationship_phrase(merlin).first() {
+        Some(phrase) => {
+            let phrase = phrase.read().unwrap();
+            (phrase.x - x, phrase.y - y)
+        }
+        None => (anchor.x_offset - x, anchor.y_offset - y),
+    };
+
+    json!({
+        "id": object,
+        "dir": dir,
+        "x": x,
+        "y": y,
+        "offset": { "x": dx, "y": dy },
+    })
+}
+
+fn point(anchor: &Arc<RwLock<Anchor>>, merlin: &MerlinStore) -> (i64, i64) {
+    let point = &anchor.read().unwrap().r6_point(merlin)[0];
+    let point = point.read().unwrap();
+
+    (point.x, point.y)
+}
+
+fn bisection_point(line: &Line, merlin: &MerlinStore) -> Option<(i64, i64)> {
+    let segments = line_segments(line, merlin)
+        .iter()
+        .map(|s| s.read().unwrap().id)
+        .collect::<Vec<_>>();
+
+    merlin.iter_point().find_map(|point| {
+        let point = point.read().unwrap();
+        match point.subtype {
+            PointEnum::Bisection(id) => {
+                let bisection = merlin.exhume_bisection(&id)?;
+                let on_line = segments.contains(&bisection.read().unwrap().segment);
+                on_line.then_some((point.x, point.y))
+            }
+            _ => None,
+        }
+    })
+}
+
+fn cardinality(id: &Uuid) -> &'static str {
+    if *id == MANY {
+        "Many"
+    } else {
+        "One"
+    }
+}
+
+fn conditionality(id: &Uuid) -> &'static str {
+    if *id == CONDITIONAL {
+        "Conditional"
+    } else {
+        "Unconditional"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::BTreeSet, fs, path::PathBuf};
+
+    use crate::domain::DomainBuilder;
+
+    /// Every cuckoo model that we ship
+    fn models() -> Vec<PathBuf> {
+        let mut models: Vec<_> = fs::read_dir("models")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |ext| ext == "json"))
+            .collect();
+        models.sort();
+
+        models
+    }
+
+    /// The object names and relationship numbers in a model
+    fn contents(model: &Value) -> (BTreeSet<String>, Vec<i64>) {
+        let names = model["objects"]["entities"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|obj| obj["name"].as_str().unwrap().to_owned())
+            .collect();
+        let mut numbers: Vec<_> = model["relationships"]["entities"]
+            .as_object()
+            .unwrap()
+            .values()
+            .flat_map(|rel| rel.as_object().unwrap().values())
+            .map(|rel| rel["number"].as_i64().unwrap())
+            .collect();
+        numbers.sort();
+
+        (names, numbers)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let models = models();
+        assert!(!models.is_empty());
+        for model in &models {
+            let model = model.to_str().unwrap();
+            let original: Value =
+                serde_json::from_str(&fs::read_to_string(model).unwrap()).unwrap();
+            let domain = DomainBuilder::new()
+                .cuckoo_model(model)
+                .unwrap()
+                .build_v2()
+                .unwrap();
+
+            let exported = export(&domain).unwrap();
+            assert_eq!(contents(&exported), contents(&original), "{}", model);
+            for section in ["objects", "relationships"] {
+                let mut ids = original[section]["ids"].as_array().unwrap().clone();
+                ids.sort_by_key(|id| id.as_str().unwrap().to_owned());
+                assert_eq!(
+                    exported[section]["ids"].as_array().unwrap(),
+                    &ids,
+                    "{}",
+                    model
+                );
+            }
+
+            let path = std::env::temp_dir().join(format!(
+                "cuckoo-{}-{}",
+                std::process::id(),
+                model.trim_start_matches("models/")
+            ));
+            domain.persist_cuckoo(&path).unwrap();
+            let reloaded = DomainBuilder::new()
+                .cuckoo_model(&path)
+                .unwrap()
+                .build_v2()
+                .unwrap();
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(exported, export(&reloaded).unwrap(), "{}", model);
+        }
+    }
+}
@@ -0,0 +1,661 @@
+//! Class and State Diagrams
+//!
+//! Export a [`Domain`] as a [Mermaid](https://mermaid.js.org) or
+//! [PlantUML](https://plantuml.com) class diagram, suitable for pasting into
+//! documentation. Objects become classes, with their attributes. Binary
+//! relationships become associations, labeled with the relationship number and
+//! the phrases of both ends. Isa relationships become generalizations, and
+//! associative objects are attached to the association that they formalize.
+//! An associative object without exactly two participants has no association to
+//! be attached to, so it gets a line to each participant instead.
+//!
+//! Multiplicity is written in the usual UML fashion:
+//!
+//! | Cardinality | Conditionality | Multiplicity |
+//! |-------------|----------------|--------------|
+//! | One         | Unconditional  | `1`          |
+//! | One         | Conditional    | `0..1`       |
+//! | Many        | Unconditional  | `1..*`       |
+//! | Many        | Conditional    | `*`          |
+//!
+//! State diagrams are exported per object. Sarzak doesn't (yet) model
+//! transitions, so the diagram consists of the object's states, each annotated
+//! with the events that it acknowledges.
+use std::fmt::Write;
+use std::sync::{Arc, RwLock};
+
+use heck::ToUpperCamelCase;
+
+use crate::v2::{
+    domain::{text::type_name, Domain},
+    sarzak::{
+        store::ObjectStore as SarzakStore,
+        types::{Object, Relationship, Ty, CONDITIONAL, MANY},
+    },
+};
+
+/// Export the domain as a Mermaid `classDiagram`
+///
+pub fn mermaid(domain: &Domain) -> String {
+    let store = domain.sarzak();
+    let mut out = String::new();
+
+    writeln!(out, "classDiagram").unwrap();
+
+    for obj in objects(store) {
+        let obj = obj.read().unwrap();
+        let mut attrs = obj.r1_attribute(store);
+        attrs.sort_by_key(|a| a.read().unwrap().name.clone());
+
+        if attrs.is_empty() {
+            writeln!(out, "    class {}", obj.name.to_upper_camel_case()).unwrap();
+            continue;
+        }
+
+        writeln!(out, "    class {} {{", obj.name.to_upper_camel_case()).unwrap();
+        for attr in attrs {
+            let attr = attr.read().unwrap();
+            let ty = attr.r2_ty(store)[0].read().unwrap().to_owned();
+            writeln!(out, "        -{} {}", mermaid_type(&ty, store), attr.name).unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+    }
+
+    let relationships = relationships(store);
+    if !relationships.is_empty() {
+        writeln!(out).unwrap();
+    }
+    for relationship in relationships {
+        match relationship {
+            Rel::Binary {
+                number,
+                from,
+                from_mult,
+                from_phrase,
+                to,
+                to_mult,
+                to_phrase,
+            } => writeln!(
+                out,
+                "    {} \"{}\" -- \"{}\" {} : {}",
+                from,
+                from_mult,
+                to_mult,
+                to,
+                label(number, &from_phrase, &to_phrase)
+            )
+            .unwrap(),
+            Rel::Isa {
+                number,
+                supertype,
+                subtypes,
+            } => {
+                for sub in subtypes {
+                    writeln!(out, "    {} <|-- {} : R{}", supertype, sub, number).unwrap();
+                }
+            }
+            Rel::Associative {
+                number,
+                associative,
+                sides,
+            } => match &sides[..] {
+                [(one, one_mult), (other, other_mult)] => {
+                    // Mermaid doesn't do association classes, so we make do with
+                    // dotted lines to both participants.
+                    writeln!(
+                        out,
+                        "    {} \"{}\" -- \"{}\" {} : R{}",
+                        one, one_mult, other_mult, other, number
+                    )
+                    .unwrap();
+                    writeln!(out, "    {} .. {} : R{}", associative, one, number).unwrap();
+                    writeln!(out, "    {} .. {} : R{}", associative, other, number).unwrap();
+                }
+                sides => {
+                    for (side, mult) in sides {
+                        writeln!(
+                            out,
+                            "    {} .. \"{}\" {} : R{}",
+                            associative, mult, side, number
+                        )
+                        .unwrap();
+                    }
+                }
+            },
+        }
+    }
+
+    out
+}
+
+/// Export the domain as a PlantUML class diagram
+///
+pub fn plantuml(domain: &Domain) -> String {
+    let store = domain.sarzak();
+    let mut out = String::new();
+
+    writeln!(out, "@startuml {}", domain.name()).unwrap();
+    writeln!(out, "hide empty methods").unwrap();
+
+    for obj in objects(store) {
+        let obj = obj.read().unwrap();
+        let mut attrs = obj.r1_attribute(store);
+        attrs.sort_by_key(|a| a.read().unwrap().name.clone());
+
+        writeln!(out).unwrap();
+        if attrs.is_empty() {
+            writeln!(out, "class {}", obj.name.to_upper_camel_case()).unwrap();
+            continue;
+        }
+
+        writeln!(out, "class {} {{", obj.name.to_upper_camel_case()).unwrap();
+        for attr in attrs {
+            let attr = attr.read().unwrap();
+            let ty = attr.r2_ty(store)[0].read().unwrap().to_owned();
+            writeln!(out, "    -{} : {}", attr.name, type_name(&ty, store)).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    writeln!(out).unwrap();
+    for relationship in relationships(store) {
+        match relationship {
+            Rel::Binary {
+                number,
+                from,
+                from_mult,
+                from_phrase,
+                to,
+                to_mult,
+                to_phrase,
+            } => writeln!(
+                out,
+                "{} \"{}\" -- \"{}\" {} : {} >",
+                from,
+                from_mult,
+                to_mult,
+                to,
+                label(number, &from_phrase, &to_phrase)
+            )
+            .unwrap(),
+            Rel::Isa {
+                number,
+                supertype,
+                subtypes,
+            } => {
+                for sub in subtypes {
+                    writeln!(out, "{} <|-- {} : R{}", supertype, sub, number).unwrap();
+                }
+            }
+            Rel::Associative {
+                number,
+                associative,
+                sides,
+            } => match &sides[..] {
+                [(one, one_mult), (other, other_mult)] => {
+                    writeln!(
+                        out,
+                        "{} \"{}\" -- \"{}\" {} : R{}",
+                        one, one_mult, other_mult, other, number
+                    )
+                    .unwrap();
+                    writeln!(out, "({}, {}) .. {}", one, other, associative).unwrap();
+                }
+                sides => {
+                    for (side, mult) in sides {
+                        writeln!(
+                            out,
+                            "{} .. \"{}\" {} : R{}",
+                            associative, mult, side, number
+                        )
+                        .unwrap();
+                    }
+                }
+            },
+        }
+    }
+
+    writeln!(out, "@enduml").unwrap();
+
+    out
+}
+
+/// Export the states of an object as a Mermaid `stateDiagram-v2`
+///
+/// Returns `None` if there is no such object, or if it has no states.
+pub fn mermaid_states(domain: &Domain, object: &str) -> Option<String> {
+    let states = states(domain.sarzak(), object)?;
+    let mut out = String::new();
+
+    writeln!(out, "stateDiagram-v2").unwrap();
+    for (name, events) in states {
+        let id = name.to_upper_camel_case();
+        if id != name {
+            writeln!(out, "    state \"{}\" as {}", name, id).unwrap();
+        } else {
+            writeln!(out, "    {}", id).unwrap();
+        }
+        for event in events {
+            writeln!(out, "    {} : {}", id, event).unwrap();
+        }
+    }
+
+    Some(out)
+}
+
+/// Export the states of an object as a PlantUML state diagram
+///
+/// Returns `None` if there is no such object, or if it has no states.
+pub fn plantuml_states(domain: &Domain, object: &str) -> Option<String> {
+    let states = states(domain.sarzak(), object)?;
+    let mut out = String::new();
+
+    writeln!(out, "@startuml {}", object.to_upper_camel_case()).unwrap();
+    for (name, events) in states {
+        let id = name.to_upper_camel_case();
+        writeln!(out, "state \"{}\" as {}", name, id).unwrap();
+        for event in events {
+            writeln!(out, "{} : {}", id, event).unwrap();
+        }
+    }
+    writeln!(out, "@enduml").unwrap();
+
+    Some(out)
+}
+
+/// A relationship, reduced to what we need to draw it
+///
+enum Rel {
+    Binary {
+        number: i64,
+        from: String,
+        from_mult: &'static str,
+        from_phrase: String,
+        to: String,
+        to_mult: &'static str,
+        to_phrase: String,
+    },
+    Isa {
+        number: i64,
+        supertype: String,
+        subtypes: Vec<String>,
+    },
+    /// The sides are sorted by name, and there is at least one
+    Associative {
+        number: i64,
+        associative: String,
+        sides: Vec<(String, &'static str)>,
+    },
+}
+
+fn objects(store: &SarzakStore) -> Vec<Arc<RwLock<Object>>> {
+    let mut objects: Vec<_> = store.iter_object().collect();
+    objects.sort_by_key(|o| o.read().unwrap().name.to_upper_camel_case());
+
+    objects
+}
+
+fn relationships(store: &SarzakStore) -> Vec<Rel> {
+    let name = |obj: &Arc<RwLock<Object>>| obj.read().unwrap().name.to_upper_camel_case();
+
+    let mut relationships: Vec<_> = store
+        .iter_relationship()
+        .filter_map(|r| match *r.read().unwrap() {
+            Relationship::Binary(id) => {
+                let binary = store.exhume_binary(&id).unwrap();
+                let binary = binary.read().unwrap();
+                let referrer = &binary.r6_referrer(store)[0];
+                let referrer = referrer.read().unwrap();
+                let referent = &binary.r5_referent(store)[0];
+                let referent = referent.read().unwrap();
+
+                Some(Rel::Binary {
+                    number: binary.number,
+                    from: name(&referrer.r17_object(store)[0]),
+                    from_mult: multiplicity(referrer.cardinality == MANY, referrer.conditionality),
+                    from_phrase: referrer.description.to_owned(),
+                    to: name(&referent.r16_object(store)[0]),
+                    to_mult: multiplicity(referent.cardinality == MANY, referent.conditionality),
+                    to_phrase: referent.description.to_owned(),
+                })
+            }
+            Relationship::Isa(id) => {
+                let isa = store.exhume_isa(&id).unwrap();
+                let isa = isa.read().unwrap();
+                let supertype = &isa.r13_supertype(store)[0];
+                let supertype = &supertype.read().unwrap().r14_object(store)[0];
+                let mut subtypes: Vec<_> = isa
+                    .r27_subtype(store)
+                    .iter()
+                    .map(|s| name(&s.read().unwrap().r15_object(store)[0]))
+                    .collect();
+                subtypes.sort();
+
+                Some(Rel::Isa {
+                    number: isa.number,
+                    supertype: name(supertype),
+                    subtypes,
+                })
+            }
+            Relationship::Associative(id) => {
+                let assoc = store.exhume_associative(&id).unwrap();
+                let assoc = assoc.read().unwrap();
+                let referrer = &assoc.r21_associative_referrer(store)[0];
+                let mut sides: Vec<_> = assoc
+                    .r22_an_associative_referent(store)
+                    .iter()
+                    .map(|r| {
+                        let referent = &r.read().unwrap().r22_associative_referent(store)[0];
+                        let referent = referent.read().unwrap();
+                        (
+                            name(&referent.r25_object(store)[0]),
+                            multiplicity(referent.cardinality == MANY, referent.conditionality),
+                        )
+                    })
+                    .collect();
+                sides.sort();
+                // With nothing to associate, there's nothing to draw.
+                if sides.is_empty() {
+                    return None;
+                }
+                let associative = name(&referrer.read().unwrap().r26_object(store)[0]);
+
+                Some(Rel::Associative {
+                    number: assoc.number,
+                    associative,
+                    sides,
+                })
+            }
+        })
+        .collect();
+
+    relationships.sort_by_key(|r| match r {
+        Rel::Binary { number, .. } | Rel::Isa { number, .. } | Rel::Associative { number, .. } => {
+            *number
+        }
+    });
+
+    relationships
+}
+
+/// Return the states of an object, and the events that each acknowledges
+///
+fn states(store: &SarzakStore, object: &str) -> Option<Vec<(String, Vec<String>)>> {
+    let id = store.exhume_object_id_by_name(&object.to_upper_camel_case())?;
+    let obj = store.exhume_object(&id)?;
+    let obj = obj.read().unwrap();
+
+    let mut states: Vec<_> = obj
+        .r18_state(store)
+        .iter()
+        .map(|s| {
+            let state = s.read().unwrap();
+            let mut events: Vec<_> = state
+                .r20_acknowledged_event(store)
+                .iter()
+                .filter_map(|a| store.exhume_event(&a.read().unwrap().event_id))
+                .map(|e| e.read().unwrap().name.to_owned())
+                .collect();
+            events.sort();
+
+            (state.name.to_owned(), events)
+        })
+        .collect();
+    states.sort();
+
+    if states.is_empty() {
+        None
+    } else {
+        Some(states)
+    }
+}
+
+/// The label of a binary association: the number, the referrer's phrase, and
+/// the referent's phrase, if it has one
+///
+fn label(number: i64, from_phrase: &str, to_phrase: &str) -> String {
+    if to_phrase.is_empty() {
+        format!("R{} {}", number, from_phrase)
+    } else {
+        format!("R{} {} / {}", number, from_phrase, to_phrase)
+    }
+}
+
+fn multiplicity(many: bool, conditionality: uuid::Uuid) -> &'static str {
+    match (many, conditionality == CONDITIONAL) {
+        (false, false) => "1",
+        (false, true) => "0..1",
+        (true, false) => "1..*",
+        (true, true) => "*",
+    }
+}
+
+/// Mermaid uses `~` for generics
+///
+fn mermaid_type(ty: &Ty, store: &SarzakStore) -> String {
+    match ty {
+        Ty::List(id) => {
+            let list = store.exhume_list(id).unwrap();
+            let inner = list.read().unwrap().r29_ty(store)[0]
+                .read()
+                .unwrap()
+                .to_owned();
+            format!("List~{}~", mermaid_type(&inner, store))
+        }
+        Ty::ZOption(id) => {
+            let option = store.exhume_z_option(id).unwrap();
+            let inner = option.read().unwrap().r30_ty(store)[0]
+                .read()
+                .unwrap()
+                .to_owned();
+            format!("Option~{}~", mermaid_type(&inner, store))
+        }
+        ty => type_name(ty, store),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        sarzak::types::{AcknowledgedEvent, List, Z_STRING, Z_UUID},
+    };
+
+    fn pets() -> Domain {
+        let mut builder = ModelBuilder::new("pets", "A domain about pets");
+        let store = builder.sarzak_mut();
+        let string = store.exhume_ty(&Z_STRING).unwrap();
+        let list = List::new(&string, store);
+        let nicknames = Ty::new_list(&list, store).read().unwrap().to_owned();
+
+        let mut domain = builder
+            .object("Owner")
+            .attr("name", Ty::ZString(Z_STRING))
+            .attr("nicknames", nicknames)
+            .object("Pet")
+            .attr("id", Ty::ZUuid(Z_UUID))
+            .state("Sleeping")
+            .state("Chasing Cars")
+            .event("wake")
+            .event("honk")
+            .object("Dog")
+            .object("Cat")
+            .object("Vet")
+            .object("Visit")
+            .binary(1, "Pet", Many, "Owner", One, "is owned by")
+            .conditional()
+            .referent_phrase("owns")
+            .isa(2, "Pet", &["Dog", "Cat"])
+            .associative(3, "Visit", "Pet", Many, "Vet", Many)
+            .side_conditional(1)
+            .build()
+            .unwrap();
+
+        // The builder doesn't do acknowledged events.
+        let store = domain.sarzak_mut();
+        let state = |name: &str| {
+            store
+                .iter_state()
+                .find(|s| s.read().unwrap().name == name)
+                .unwrap()
+        };
+        let event = |name: &str| {
+            store
+                .iter_event()
+                .find(|e| e.read().unwrap().name == name)
+                .unwrap()
+        };
+        let acks = [
+            (event("wake"), state("Sleeping")),
+            (event("honk"), state("Chasing Cars")),
+            (event("wake"), state("Chasing Cars")),
+        ];
+        for (event, state) in acks {
+            AcknowledgedEvent::new(&event, &state, store);
+        }
+
+        domain
+    }
+
+    #[test]
+    fn test_mermaid() {
+        let expected = r#"classDiagram
+    class Cat
+    class Dog
+    class Owner {
+        -String name
+        -List~String~ nicknames
+    }
+    class Pet {
+        -Uuid id
+    }
+    class Vet
+    class Visit
+
+    Pet "1..*" -- "0..1" Owner : R1 is owned by / owns
+    Pet <|-- Cat : R2
+    Pet <|-- Dog : R2
+    Pet "1..*" -- "*" Vet : R3
+    Visit .. Pet : R3
+    Visit .. Vet : R3
+"#;
+
+        assert_eq!(mermaid(&pets()), expected);
+    }
+
+    #[test]
+    fn test_plantuml() {
+        let expected = r#"@startuml pets
+hide empty methods
+
+class Cat
+
+class Dog
+
+class Owner {
+    -name : String
+    -nicknames : [String]
+}
+
+class Pet {
+    -id : Uuid
+}
+
+class Vet
+
+class Visit
+
+Pet "1..*" -- "0..1" Owner : R1 is owned by / owns >
+Pet <|-- Cat : R2
+Pet <|-- Dog : R2
+Pet "1..*" -- "*" Vet : R3
+(Pet, Vet) .. Visit
+@enduml
+"#;
+
+        assert_eq!(plantuml(&pets()), expected);
+    }
+
+    #[test]
+    fn test_associative_sides() {
+        let domain = ModelBuilder::new("clinic", "A domain about a vet clinic")
+            .object("Pet")
+            .object("Vet")
+            .object("Owner")
+            .object("Appointment")
+            .object("Checkup")
+            .object("Nothing")
+            .associative_sides(
+                1,
+                "Appointment",
+                &[("Pet", One), ("Vet", Many), ("Owner", One)],
+            )
+            .associative_sides(2, "Checkup", &[("Pet", Many)])
+            .associative_sides(3, "Nothing", &[])
+            .build()
+            .unwrap();
+
+        let relationships = |diagram: String| -> Vec<String> {
+            diagram
+                .lines()
+                .filter(|line| line.contains(" : R"))
+                .map(|line| line.trim().to_owned())
+                .collect()
+        };
+
+        assert_eq!(
+            relationships(mermaid(&domain)),
+            vec![
+                "Appointment .. \"1\" Owner : R1",
+                "Appointment .. \"1\" Pet : R1",
+                "Appointment .. \"1..*\" Vet : R1",
+                "Checkup .. \"1..*\" Pet : R2",
+            ]
+        );
+        assert_eq!(
+            relationships(plantuml(&domain)),
+            vec![
+                "Appointment .. \"1\" Owner : R1",
+                "Appointment .. \"1\" Pet : R1",
+                "Appointment .. \"1..*\" Vet : R1",
+                "Checkup .. \"1..*\" Pet : R2",
+            ]
+        );
+        assert!(!mermaid(&domain).contains("\"\""));
+    }
+
+    #[test]
+    fn test_mermaid_states() {
+        let expected = r#"stateDiagram-v2
+    state "Chasing Cars" as ChasingCars
+    ChasingCars : honk
+    ChasingCars : wake
+    Sleeping
+    Sleeping : wake
+"#;
+
+        let domain = pets();
+        assert_eq!(mermaid_states(&domain, "pet").unwrap(), expected);
+        assert!(mermaid_states(&domain, "Dog").is_none());
+        assert!(mermaid_states(&domain, "Goldfish").is_none());
+    }
+
+    #[test]
+    fn test_plantuml_states() {
+        let expected = r#"@startuml Pet
+state "Chasing Cars" as ChasingCars
+ChasingCars : honk
+ChasingCars : wake
+state "Sleeping" as Sleeping
+Sleeping : wake
+@enduml
+"#;
+
+        let domain = pets();
+        assert_eq!(plantuml_states(&domain, "pet").unwrap(), expected);
+        assert!(plantuml_states(&domain, "Dog").is_none());
+        assert!(plantuml_states(&domain, "Goldfish").is_none());
+    }
+}
@@ -0,0 +1,857 @@
+//! Automatic Layout
+//!
+//! Place the boxes of a [`Domain`], and then anchor the lines to them. This is
+//! meant for domains that were built in code, imported, or parsed from text,
+//! which have boxes in no particular arrangement.
+//!
+//! The layout is layered. Every relationship points "down" the page:
+//! supertypes are above their subtypes, referents are above their referrers,
+//! and associative objects are below the objects that they formalize. Cycles
+//! are broken by ignoring the relationships that would close them, but never an
+//! isa. Boxes in each layer are then ordered by the barycenter of their
+//! neighbors, which does a decent job of minimizing crossings.
+//!
+//! Once the boxes are placed, every anchor is moved to the edge of it's box
+//! that faces the other end of the line. Anchors that share an edge are spread
+//! out along it, in the order of their other ends, so that their lines don't
+//! cross. Anchor points, bisection points, and relationship phrases follow.
+//!
+//! [`layout_new`] is the incremental flavor. It leaves existing boxes where
+//! they are, and finds a spot for any object that doesn't yet have a box near
+//! the objects that it's related to.
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use crate::v2::{
+    domain::{
+        builder::{ANCHOR_OFFSET, CHAR_WIDTH, HEADER_HEIGHT, LINE_HEIGHT, MIN_WIDTH},
+        Domain,
+    },
+    merlin::{
+        geometry::position_on_edge,
+        store::ObjectStore as MerlinStore,
+        types::{Anchor, Edge, PointEnum, XBox},
+    },
+    sarzak::store::ObjectStore as SarzakStore,
+};
+
+/// Space between the drawing and the edge of the paper
+const MARGIN: i64 = 100;
+/// Space between boxes in a layer
+const H_GAP: i64 = 120;
+/// Space between layers
+const V_GAP: i64 = 160;
+/// Number of barycenter sweeps, each of which is down and then back up
+const SWEEPS: usize = 8;
+/// Granularity of the search for a free spot for a new box
+const STEP: i64 = 40;
+
+/// Lay out the entire domain
+///
+/// Objects without a box are given one. Every box is moved, and every line is
+/// re-anchored.
+pub fn layout(domain: &mut Domain) {
+    add_missing_boxes(domain);
+
+    let boxes = boxes(domain.merlin());
+    let graph = Graph::new(domain.sarzak(), &boxes);
+    graph.place(&boxes);
+
+    let anchors = domain.merlin().iter_anchor().collect::<Vec<_>>();
+    anchor(domain.merlin(), &anchors);
+}
+
+/// Place new boxes only
+///
+/// Objects without a box are given one, in a free spot near the boxes of the
+/// objects that they are related to. Existing boxes are left alone. Lines that
+/// touch a new box are re-anchored, as are those sharing an edge with them.
+///
+/// If there were no boxes to begin with, this is the same as [`layout`].
+pub fn layout_new(domain: &mut Domain) {
+    let new = add_missing_boxes(domain);
+    place_new(domain, &new, &HashSet::new());
+}
+
+/// Place the boxes of the `new` objects, and re-anchor `lines`
+///
+/// Lines that touch a new box are re-anchored too. If every box is new, the
+/// entire domain is laid out.
+pub(super) fn place_new(domain: &mut Domain, new: &[Uuid], lines: &HashSet<Uuid>) {
+    if new.is_empty() && lines.is_empty() {
+        return;
+    }
+
+    let boxes = boxes(domain.merlin());
+    if !new.is_empty() && new.len() == boxes.len() {
+        layout(domain);
+        return;
+    }
+
+    if !new.is_empty() {
+        let graph = Graph::new(domain.sarzak(), &boxes);
+        graph.place_new(&boxes, new);
+    }
+
+    let new_boxes = new
+        .iter()
+        .map(|obj| boxes[obj].read().unwrap().id)
+        .collect::<HashSet<_>>();
+    let merlin = domain.merlin();
+    let lines = merlin
+        .iter_anchor()
+        .filter(|a| new_boxes.contains(&a.read().unwrap().x_box))
+        .map(|a| a.read().unwrap().line)
+        .chain(lines.iter().cloned())
+        .collect::<HashSet<_>>();
+    let anchors = merlin
+        .iter_anchor()
+        .filter(|a| lines.contains(&a.read().unwrap().line))
+        .collect::<Vec<_>>();
+    anchor(merlin, &anchors);
+}
+
+/// Create a box for every object that doesn't have one
+///
+/// The boxes are sized as the builder would size them, and placed at the
+/// origin. Returns the ids of the objects.
+pub(super) fn add_missing_boxes(domain: &mut Domain) -> Vec<Uuid> {
+    let have = domain
+        .merlin()
+        .iter_x_box()
+        .map(|b| b.read().unwrap().object)
+        .collect::<HashSet<_>>();
+
+    let mut objects = domain
+        .sarzak()
+        .iter_object()
+        .filter(|o| !have.contains(&o.read().unwrap().id))
+        .collect::<Vec<_>>();
+    objects.sort_by_key(|o| o.read().unwrap().name.clone());
+
+    let mut new = Vec::new();
+    for obj in objects {
+        let obj = obj.read().unwrap();
+        let attrs = obj.r1_attribute(domain.sarzak());
+        let chars = attrs
+            .iter()
+            .map(|a| a.read().unwrap().name.len() as i64 + 8)
+            .chain([obj.name.len() as i64])
+            .max()
+            .unwrap_or_default();
+        let width = MIN_WIDTH.max(chars * CHAR_WIDTH);
+        let height = HEADER_HEIGHT + (attrs.len() as i64 + 1) * LINE_HEIGHT;
+
+        XBox::new(height, width, 0, 0, &obj, domain.merlin_mut());
+        new.push(obj.id);
+    }
+
+    new
+}
+
+/// Boxes, by object id
+///
+fn boxes(merlin: &MerlinStore) -> HashMap<Uuid, Arc<RwLock<XBox>>> {
+    merlin
+        .iter_x_box()
+        .map(|b| (b.read().unwrap().object, b.clone()))
+        .collect()
+}
+
+fn center(x_box: &XBox) -> (f64, f64) {
+    (
+        x_box.x as f64 + x_box.width as f64 / 2.0,
+        x_box.y as f64 + x_box.height as f64 / 2.0,
+    )
+}
+
+/// The objects, and the relationships between them
+///
+/// Edges point down the page. Isa edges are listed first, so that they are
+/// the last to be sacrificed when breaking cycles.
+struct Graph {
+    nodes: Vec<Uuid>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    fn new(sarzak: &SarzakStore, boxes: &HashMap<Uuid, Arc<RwLock<XBox>>>) -> Self {
+        let mut nodes = boxes.keys().cloned().collect::<Vec<_>>();
+        nodes.sort_by_key(|id| {
+            sarzak
+                .exhume_object(id)
+                .map(|o| o.read().unwrap().name.clone())
+                .unwrap_or_default()
+        });
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(n, id)| (*id, n))
+            .collect::<HashMap<_, _>>();
+
+        let mut edges = Vec::new();
+        let mut add = |from: &Uuid, to: &Uuid| {
+            if let (Some(from), Some(to)) = (index.get(from), index.get(to)) {
+                if from != to {
+                    edges.push((*from, *to));
+                }
+            }
+        };
+
+        let mut isas = sarzak.iter_isa().collect::<Vec<_>>();
+        isas.sort_by_key(|i| i.read().unwrap().number);
+        for isa in isas {
+            let isa = isa.read().unwrap();
+            let supertype = isa.r13_supertype(sarzak)[0].read().unwrap().obj_id;
+            for sub in isa.r27_subtype(sarzak) {
+                add(&supertype, &sub.read().unwrap().obj_id);
+            }
+        }
+
+        let mut binaries = sarzak.iter_binary().collect::<Vec<_>>();
+        binaries.sort_by_key(|b| b.read().unwrap().number);
+        for binary in binaries {
+            let binary = binary.read().unwrap();
+            let referrer = binary.r6_referrer(sarzak)[0].read().unwrap().obj_id;
+            let referent = binary.r5_referent(sarzak)[0].read().unwrap().obj_id;
+            add(&referent, &referrer);
+        }
+
+        let mut associatives = sarzak.iter_associative().collect::<Vec<_>>();
+        associatives.sort_by_key(|a| a.read().unwrap().number);
+        for assoc in associatives {
+            let assoc = assoc.read().unwrap();
+            let referrer = assoc.r21_associative_referrer(sarzak)[0]
+                .read()
+                .unwrap()
+                .obj_id;
+            for aar in assoc.r22_an_associative_referent(sarzak) {
+                let referent = aar.read().unwrap().r22_associative_referent(sarzak)[0]
+                    .read()
+                    .unwrap()
+                    .obj_id;
+                add(&referent, &referrer);
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    /// Undirected adjacency
+    ///
+    fn neighbors(&self) -> Vec<Vec<usize>> {
+        let mut neighbors = vec![Vec::new(); self.nodes.len()];
+        for &(from, to) in &self.edges {
+            neighbors[from].push(to);
+            neighbors[to].push(from);
+        }
+
+        neighbors
+    }
+
+    /// Assign each node to a layer
+    ///
+    /// Edges that would create a cycle are dropped, and then each node is
+    /// placed one layer below the lowest of it's predecessors.
+    fn layers(&self) -> Vec<usize> {
+        let count = self.nodes.len();
+        let mut successors = vec![Vec::new(); count];
+
+        for &(from, to) in &self.edges {
+            if !reaches(&successors, to, from) {
+                successors[from].push(to);
+            }
+        }
+
+        let mut incoming = vec![0; count];
+        for succ in &successors {
+            for &to in succ {
+                incoming[to] += 1;
+            }
+        }
+
+        let mut layer = vec![0; count];
+        let mut queue = (0..count)
+            .filter(|&n| incoming[n] == 0)
+            .collect::<VecDeque<_>>();
+        while let Some(node) = queue.pop_front() {
+            for &to in &successors[node] {
+                layer[to] = layer[to].max(layer[node] + 1);
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        layer
+    }
+
+    /// Order the nodes within each layer
+    ///
+    /// Repeatedly sort each layer by the average position of each node's
+    /// neighbors in the layers above it, and then the layers below it.
+    fn order(&self, layer: &[usize]) -> Vec<Vec<usize>> {
+        let depth = layer.iter().max().map_or(0, |d| d + 1);
+        let mut layers = vec![Vec::new(); depth];
+        for (node, &l) in layer.iter().enumerate() {
+            layers[l].push(node);
+        }
+
+        let neighbors = self.neighbors();
+        let mut position = vec![0.0; self.nodes.len()];
+        let update = |layers: &Vec<Vec<usize>>, position: &mut Vec<f64>| {
+            for nodes in layers {
+                for (n, &node) in nodes.iter().enumerate() {
+                    position[node] = n as f64;
+                }
+            }
+        };
+        update(&layers, &mut position);
+
+        let sort = |nodes: &mut Vec<usize>, position: &[f64], above: bool| {
+            let key = |node: usize| {
+                let adjacent = neighbors[node]
+                    .iter()
+                    .filter(|&&n| {
+                        if above {
+                            layer[n] < layer[node]
+                        } else {
+                            layer[n] > layer[node]
+                        }
+                    })
+                    .map(|&n| position[n])
+                    .collect::<Vec<_>>();
+                if adjacent.is_empty() {
+                    position[node]
+                } else {
+                    adjacent.iter().sum::<f64>() / adjacent.len() as f64
+                }
+            };
+            let mut keyed = nodes.iter().map(|&n| (key(n), n)).collect::<Vec<_>>();
+            keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            *nodes = keyed.into_iter().map(|(_, n)| n).collect();
+        };
+
+        for _ in 0..SWEEPS {
+            for l in 1..depth {
+                sort(&mut layers[l], &position, true);
+                update(&layers, &mut position);
+            }
+            for l in (0..depth.saturating_sub(1)).rev() {
+                sort(&mut layers[l], &position, false);
+                update(&layers, &mut position);
+            }
+        }
+
+        layers
+    }
+
+    /// Move every box
+    ///
+    fn place(&self, boxes: &HashMap<Uuid, Arc<RwLock<XBox>>>) {
+        let layers = self.order(&self.layers());
+        let size = |node: usize| {
+            let b = boxes[&self.nodes[node]].read().unwrap();
+            (b.width, b.height)
+        };
+
+        let widths = layers
+            .iter()
+            .map(|nodes| {
+                nodes.iter().map(|&n| size(n).0).sum::<i64>()
+                    + H_GAP * (nodes.len() as i64 - 1).max(0)
+            })
+            .collect::<Vec<_>>();
+        let widest = widths.iter().max().cloned().unwrap_or_default();
+
+        let mut y = MARGIN;
+        for (nodes, width) in layers.iter().zip(widths) {
+            // Center the layer
+            let mut x = MARGIN + (widest - width) / 2;
+            let mut tallest = 0;
+            for &node in nodes {
+                let mut b = boxes[&self.nodes[node]].write().unwrap();
+                b.x = x;
+                b.y = y;
+                x += b.width + H_GAP;
+                tallest = tallest.max(b.height);
+            }
+            y += tallest + V_GAP;
+        }
+    }
+
+    /// Find a spot for each of the `new` boxes
+    ///
+    /// The ideal spot is below the boxes of the objects that point at it, or
+    /// above those that it points at. Failing that it goes below everything
+    /// else. We then search outward for a spot that doesn't overlap anything.
+    fn place_new(&self, boxes: &HashMap<Uuid, Arc<RwLock<XBox>>>, new: &[Uuid]) {
+        let mut placed = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| !new.contains(id))
+            .map(|(n, _)| n)
+            .collect::<HashSet<_>>();
+        let rect = |node: usize| {
+            let b = boxes[&self.nodes[node]].read().unwrap();
+            (b.x, b.y, b.width, b.height)
+        };
+
+        for id in new {
+            let node = self.nodes.iter().position(|n| n == id).unwrap();
+            let (_, _, width, height) = rect(node);
+
+            let mut targets = Vec::new();
+            for &(from, to) in &self.edges {
+                if to == node && placed.contains(&from) {
+                    let (x, y, w, h) = rect(from);
+                    targets.push((x + w / 2, y + h + V_GAP + height / 2));
+                } else if from == node && placed.contains(&to) {
+                    let (x, y, w, _) = rect(to);
+                    targets.push((x + w / 2, y - V_GAP - height / 2));
+                }
+            }
+
+            let (cx, cy) = if targets.is_empty() {
+                let bottom = placed
+                    .iter()
+                    .map(|&n| {
+                        let (_, y, _, h) = rect(n);
+                        y + h
+                    })
+                    .max()
+                    .unwrap_or_default();
+                (MARGIN + width / 2, bottom + V_GAP + height / 2)
+            } else {
+                let count = targets.len() as i64;
+                (
+                    targets.iter().map(|t| t.0).sum::<i64>() / count,
+                    targets.iter().map(|t| t.1).sum::<i64>() / count,
+                )
+            };
+            let (x, y) = (cx - width / 2, cy - height / 2);
+
+            let free = |x: i64, y: i64| {
+                placed.iter().all(|&n| {
+                    let (ox, oy, ow, oh) = rect(n);
+                    x + width + H_GAP / 2 <= ox
+                        || ox + ow + H_GAP / 2 <= x
+                        || y + height + H_GAP / 2 <= oy
+                        || oy + oh + H_GAP / 2 <= y
+                })
+            };
+
+            // Search rings of increasing radius around the ideal spot.
+            let (x, y) = (0..)
+                .flat_map(|ring: i64| {
+                    (-ring..=ring).flat_map(move |i| {
+                        (-ring..=ring)
+                            .filter(move |j| i.abs() == ring || j.abs() == ring)
+                            .map(move |j| (x + i * STEP, y + j * STEP))
+                    })
+                })
+                .find(|&(x, y)| x >= 0 && y >= 0 && free(x, y))
+                .unwrap();
+
+            {
+                let mut b = boxes[id].write().unwrap();
+                b.x = x;
+                b.y = y;
+            }
+            placed.insert(node);
+        }
+    }
+}
+
+/// Is `to` reachable from `from`?
+///
+fn reaches(successors: &[Vec<usize>], from: usize, to: usize) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return true;
+        }
+        if seen.insert(node) {
+            stack.extend(&successors[node]);
+        }
+    }
+
+    false
+}
+
+/// Re-anchor `anchors` to their boxes
+///
+/// Each anchor goes on the edge facing the other end of it's line. All of the
+/// anchors on an edge are then spread along it, ordered by where their lines
+/// are headed.
+fn anchor(merlin: &MerlinStore, anchors: &[Arc<RwLock<Anchor>>]) {
+    let moving = anchors
+        .iter()
+        .map(|a| a.read().unwrap().id)
+        .collect::<HashSet<_>>();
+
+    let mut targets = HashMap::new();
+    for anchor in anchors {
+        let (tx, ty) = match toward(&anchor.read().unwrap(), merlin) {
+            Some(target) => target,
+            None => continue,
+        };
+
+        let mut anchor = anchor.write().unwrap();
+        targets.insert(anchor.id, (tx, ty));
+        let x_box = merlin.exhume_x_box(&anchor.x_box).unwrap();
+        let (cx, cy) = center(&x_box.read().unwrap());
+
+        let (dx, dy) = (tx - cx, ty - cy);
+        if dx == 0.0 && dy == 0.0 {
+            continue;
+        }
+        let edge = if dx.abs() >= dy.abs() {
+            if dx >= 0.0 {
+                Edge::new_right(merlin)
+            } else {
+                Edge::new_left(merlin)
+            }
+        } else if dy >= 0.0 {
+            Edge::new_bottom(merlin)
+        } else {
+            Edge::new_top(merlin)
+        };
+        anchor.edge = edge.read().unwrap().id();
+    }
+
+    // Gather every anchor sharing an edge with one that moved.
+    let edges = anchors
+        .iter()
+        .map(|a| {
+            let a = a.read().unwrap();
+            (a.x_box, a.edge)
+        })
+        .collect::<HashSet<_>>();
+    let mut groups: HashMap<(Uuid, Uuid), Vec<(f64, Arc<RwLock<Anchor>>)>> = HashMap::new();
+    for anchor in merlin.iter_anchor() {
+        let key = {
+            let a = anchor.read().unwrap();
+            (a.x_box, a.edge)
+        };
+        if !edges.contains(&key) {
+            continue;
+        }
+
+        let id = anchor.read().unwrap().id;
+        let target = match targets.get(&id) {
+            Some(target) => Some(*target),
+            None if !moving.contains(&id) => toward(&anchor.read().unwrap(), merlin),
+            None => None,
+        };
+        let (tx, ty) = target.unwrap_or_else(|| {
+            let point = &anchor.read().unwrap().r6_point(merlin)[0];
+            let point = point.read().unwrap();
+            (point.x as f64, point.y as f64)
+        });
+        let along = match *merlin.exhume_edge(&key.1).unwrap().read().unwrap() {
+            Edge::Top(_) | Edge::Bottom(_) => tx,
+            Edge::Left(_) | Edge::Right(_) => ty,
+        };
+
+        groups.entry(key).or_default().push((along, anchor));
+    }
+
+    let mut lines = HashSet::new();
+    for ((x_box, edge), mut group) in groups {
+        group.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let count = group.len();
+        let x_box = merlin.exhume_x_box(&x_box).unwrap();
+        let edge = merlin.exhume_edge(&edge).unwrap();
+
+        for (n, (_, anchor)) in group.iter().enumerate() {
+            let offset = (n + 1) as f64 / (count + 1) as f64;
+            place(
+                &mut anchor.write().unwrap(),
+                &x_box.read().unwrap(),
+                &edge.read().unwrap(),
+                offset,
+                merlin,
+            );
+            lines.insert(anchor.read().unwrap().line);
+        }
+    }
+
+    // Bisections follow the segments that they bisect, and phrases follow their
+    // anchors.
+    for point in merlin.iter_point() {
+        let mut point = point.write().unwrap();
+        if let PointEnum::Bisection(id) = point.subtype {
+            let bisection = merlin.exhume_bisection(&id).unwrap();
+            let bisection = bisection.read().unwrap();
+            let segment = &bisection.r14_line_segment(merlin)[0];
+            if !lines.contains(&segment.read().unwrap().line) {
+                continue;
+            }
+
+            let ends = segment
+                .read()
+                .unwrap()
+                .r5_line_segment_point(merlin)
+                .iter()
+                .filter(|lsp| lsp.read().unwrap().point != point.id)
+                .map(|lsp| lsp.read().unwrap().r5_point(merlin)[0].clone())
+                .filter(|p| matches!(p.read().unwrap().subtype, PointEnum::Anchor(_)))
+                .map(|p| {
+                    let p = p.read().unwrap();
+                    (p.x as f64, p.y as f64)
+                })
+                .collect::<Vec<_>>();
+            if let [(x1, y1), (x2, y2)] = ends[..] {
+                point.x = (x1 + (x2 - x1) * bisection.offset) as i64;
+                point.y = (y1 + (y2 - y1) * bisection.offset) as i64;
+            }
+        }
+    }
+
+    for phrase in merlin.iter_relationship_phrase() {
+        let mut phrase = phrase.write().unwrap();
+        if !lines.contains(&phrase.line) {
+            continue;
+        }
+        let anchor = merlin.exhume_anchor(&phrase.origin).unwrap();
+        let anchor = anchor.read().unwrap();
+        phrase.x = anchor.x_offset;
+        phrase.y = anchor.y_offset;
+    }
+}
+
+/// Where the line leaving `anchor` is headed
+///
+/// This is the average of the other ends of the segments that the anchor is
+/// on. Anchors stand in for their boxes, and bisections for the middle of the
+/// boxes at either end of the segment that they bisect.
+fn toward(anchor: &Anchor, merlin: &MerlinStore) -> Option<(f64, f64)> {
+    let point = anchor.r6_point(merlin)[0].read().unwrap().id;
+
+    let mut targets = Vec::new();
+    for segment in merlin
+        .iter_line_segment_point()
+        .filter(|lsp| lsp.read().unwrap().point == point)
+        .map(|lsp| lsp.read().unwrap().segment)
+    {
+        for lsp in merlin.iter_line_segment_point() {
+            let lsp = lsp.read().unwrap();
+            if lsp.segment != segment || lsp.point == point {
+                continue;
+            }
+
+            let other = lsp.r5_point(merlin)[0].clone();
+            let other = other.read().unwrap();
+            let target = match other.subtype {
+                PointEnum::Anchor(id) => anchor_center(&id, merlin),
+                PointEnum::Bisection(id) => bisection_center(&id, merlin),
+                PointEnum::Inflection(_) => None,
+            };
+            targets.push(target.unwrap_or((other.x as f64, other.y as f64)));
+        }
+    }
+
+    if targets.is_empty() {
+        None
+    } else {
+        let count = targets.len() as f64;
+        Some((
+            targets.iter().map(|t| t.0).sum::<f64>() / count,
+            targets.iter().map(|t| t.1).sum::<f64>() / count,
+        ))
+    }
+}
+
+fn anchor_center(id: &Uuid, merlin: &MerlinStore) -> Option<(f64, f64)> {
+    let anchor = merlin.exhume_anchor(id)?;
+    let x_box = merlin.exhume_x_box(&anchor.read().unwrap().x_box)?;
+    let center = center(&x_box.read().unwrap());
+
+    Some(center)
+}
+
+fn bisection_center(id: &Uuid, merlin: &MerlinStore) -> Option<(f64, f64)> {
+    let bisection = merlin.exhume_bisection(id)?;
+    let bisection = bisection.read().unwrap();
+    let segment = &bisection.r14_line_segment(merlin)[0];
+
+    let ends = segment
+        .read()
+        .unwrap()
+        .r5_line_segment_point(merlin)
+        .iter()
+        .filter_map(|lsp| {
+            match lsp.read().unwrap().r5_point(merlin)[0]
+                .read()
+                .unwrap()
+                .subtype
+            {
+                PointEnum::Anchor(id) => anchor_center(&id, merlin),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match ends[..] {
+        [(x1, y1), (x2, y2)] => Some((
+            x1 + (x2 - x1) * bisection.offset,
+            y1 + (y2 - y1) * bisection.offset,
+        )),
+        _ => None,
+    }
+}
+
+/// Put the anchor `offset` of the way along `edge` of `x_box`
+///
+fn place(anchor: &mut Anchor, x_box: &XBox, edge: &Edge, offset: f64, merlin: &MerlinStore) {
+    let (px, py) = position_on_edge(x_box, edge, offset);
+    let (ox, oy) = match edge {
+        Edge::Top(_) => (px, py - ANCHOR_OFFSET),
+        Edge::Bottom(_) => (px, py + ANCHOR_OFFSET),
+        Edge::Left(_) => (px - ANCHOR_OFFSET, py),
+        Edge::Right(_) => (px + ANCHOR_OFFSET, py),
+    };
+
+    anchor.offset = offset;
+    anchor.x_offset = ox;
+    anchor.y_offset = oy;
+
+    let point = &anchor.r6_point(merlin)[0];
+    let mut point = point.write().unwrap();
+    point.x = px;
+    point.y = py;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        sarzak::types::Object,
+    };
+
+    fn pets() -> Domain {
+        ModelBuilder::new("pets", "")
+            .object("Animal")
+            .object("Cat")
+            .object("Dog")
+            .object("Owner")
+            .object("Vet")
+            .object("Visit")
+            .isa(1, "Animal", &["Cat", "Dog"])
+            .binary(2, "Animal", Many, "Owner", One, "is owned by")
+            .associative(3, "Visit", "Animal", Many, "Vet", Many)
+            .build()
+            .unwrap()
+    }
+
+    fn rect(domain: &Domain, name: &str) -> (i64, i64, i64, i64) {
+        let id = domain.sarzak().exhume_object_id_by_name(name).unwrap();
+        let b = domain
+            .merlin()
+            .iter_x_box()
+            .find(|b| b.read().unwrap().object == id)
+            .unwrap();
+        let b = b.read().unwrap();
+
+        (b.x, b.y, b.width, b.height)
+    }
+
+    fn assert_disjoint(domain: &Domain) {
+        let boxes = domain
+            .merlin()
+            .iter_x_box()
+            .map(|b| {
+                let b = b.read().unwrap();
+                (b.x, b.y, b.width, b.height)
+            })
+            .collect::<Vec<_>>();
+        for (n, a) in boxes.iter().enumerate() {
+            for b in &boxes[n + 1..] {
+                assert!(
+                    a.0 + a.2 <= b.0 || b.0 + b.2 <= a.0 || a.1 + a.3 <= b.1 || b.1 + b.3 <= a.1,
+                    "{:?} overlaps {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    fn assert_anchored(domain: &Domain) {
+        let merlin = domain.merlin();
+        for anchor in merlin.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            let x_box = &anchor.r3_x_box(merlin)[0];
+            let b = x_box.read().unwrap();
+            let point = &anchor.r6_point(merlin)[0];
+            let p = point.read().unwrap();
+
+            let on_edge = match *anchor.r9_edge(merlin)[0].read().unwrap() {
+                Edge::Top(_) => p.y == b.y,
+                Edge::Bottom(_) => p.y == b.y + b.height,
+                Edge::Left(_) => p.x == b.x,
+                Edge::Right(_) => p.x == b.x + b.width,
+            };
+            assert!(on_edge);
+            assert!(p.x >= b.x && p.x <= b.x + b.width);
+            assert!(p.y >= b.y && p.y <= b.y + b.height);
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let mut domain = pets();
+        layout(&mut domain);
+
+        assert_disjoint(&domain);
+        assert_anchored(&domain);
+
+        // Supertypes above subtypes, referents above referrers, and associative
+        // objects below the objects that they formalize.
+        let below = |a, b| {
+            let (a, b) = (rect(&domain, a), rect(&domain, b));
+            a.1 >= b.1 + b.3
+        };
+        assert!(below("Cat", "Animal"));
+        assert!(below("Dog", "Animal"));
+        assert!(below("Animal", "Owner"));
+        assert!(below("Visit", "Animal"));
+        assert!(below("Visit", "Vet"));
+    }
+
+    #[test]
+    fn test_layout_new() {
+        let mut domain = pets();
+        layout(&mut domain);
+
+        let before = ["Animal", "Cat", "Dog", "Owner", "Vet", "Visit"]
+            .iter()
+            .map(|name| rect(&domain, name))
+            .collect::<Vec<_>>();
+
+        Object::new(
+            "".to_owned(),
+            "H".to_owned(),
+            "Hamster".to_owned(),
+            domain.sarzak_mut(),
+        );
+        layout_new(&mut domain);
+
+        let after = ["Animal", "Cat", "Dog", "Owner", "Vet", "Visit"]
+            .iter()
+            .map(|name| rect(&domain, name))
+            .collect::<Vec<_>>();
+        assert_eq!(before, after);
+        assert_eq!(domain.merlin().iter_x_box().count(), 7);
+        assert_disjoint(&domain);
+        assert_anchored(&domain);
+    }
+}
@@ -0,0 +1,291 @@
+//! SVG Rendering
+//!
+//! Render the merlin drawing of a [`Domain`] as a standalone SVG document. This
+//! is a headless alternative to the GUI, suitable for publishing diagrams from
+//! CI.
+//!
+//! Boxes are drawn with the object name in a header, and the object's attributes
+//! below it. Lines are drawn segment by segment, and each anchor gets a glyph
+//! pointing into the box to which it's attached:
+//!
+//! | Glyph    | Drawn as                         |
+//! |----------|----------------------------------|
+//! | `One`    | a single arrowhead               |
+//! | `Many`   | a double arrowhead               |
+//! | `Sub`    | nothing -- the line just ends    |
+//! | `ZSuper` | a hollow triangle                |
+//!
+//! Relationship names are drawn at their bisection, and relationship phrases
+//! where the drawing put them.
+use std::fmt::Write;
+
+use crate::v2::{
+    domain::{text::type_name, Domain},
+    merlin::{
+        geometry::{bounds, line_segments, name_position, segment_points},
+        store::ObjectStore as MerlinStore,
+        types::{Anchor, Edge, GlyphEnum, XBox},
+    },
+    sarzak::store::ObjectStore as SarzakStore,
+};
+
+const MARGIN: i64 = 40;
+const HEADER: i64 = 24;
+const LINE_HEIGHT: i64 = 16;
+const ARROW_LENGTH: f64 = 14.0;
+const ARROW_WIDTH: f64 = 7.0;
+const SUPER_LENGTH: f64 = 20.0;
+const SUPER_WIDTH: f64 = 12.0;
+
+const STYLE: &str = r#"<style>
+    .box { fill: white; stroke: black; stroke-width: 1.5; }
+    .name { font: bold 14px sans-serif; text-anchor: middle; }
+    .attr { font: 12px monospace; }
+    .line { fill: none; stroke: black; stroke-width: 1.5; }
+    .one, .many { fill: black; stroke: black; }
+    .super { fill: white; stroke: black; stroke-width: 1.5; }
+    .rel-name { font: italic 12px sans-serif; text-anchor: middle; }
+    .phrase { font: 11px sans-serif; text-anchor: middle; }
+</style>"#;
+
+/// Render the domain as an SVG document
+///
+/// The view box is sized to fit the drawing, plus a margin.
+pub fn render(domain: &Domain) -> String {
+    let sarzak = domain.sarzak();
+    let merlin = domain.merlin();
+    let mut out = String::new();
+
+    let bounds = bounds(merlin).unwrap_or_default().inflate(MARGIN);
+    let (min_x, min_y, width, height) = (bounds.x, bounds.y, bounds.width, bounds.height);
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        min_x, min_y, width, height, width, height
+    )
+    .unwrap();
+    writeln!(out, "<title>{}</title>", escape(domain.name())).unwrap();
+    writeln!(out, "{}", STYLE).unwrap();
+    writeln!(
+        out,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="white"/>"#,
+        min_x, min_y, width, height
+    )
+    .unwrap();
+
+    let mut boxes = merlin.iter_x_box().collect::<Vec<_>>();
+    boxes.sort_by_key(|b| {
+        let b = b.read().unwrap();
+        (b.y, b.x)
+    });
+    for x_box in boxes {
+        render_box(&x_box.read().unwrap(), sarzak, &mut out);
+    }
+
+    let mut lines = merlin.iter_line().collect::<Vec<_>>();
+    lines.sort_by_key(|l| {
+        let names = l.read().unwrap().r11_relationship_name(merlin);
+        names
+            .first()
+            .map(|n| n.read().unwrap().text.clone())
+            .unwrap_or_default()
+    });
+    for line in lines {
+        let line = line.read().unwrap();
+
+        for segment in line_segments(&line, merlin) {
+            let points = segment_points(&segment, merlin);
+            let points = points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .collect::<Vec<_>>();
+            writeln!(
+                out,
+                r#"<polyline class="line" points="{}"/>"#,
+                points.join(" ")
+            )
+            .unwrap();
+        }
+
+        for anchor in line.r3_anchor(merlin) {
+            render_glyph(&anchor.read().unwrap(), merlin, &mut out);
+        }
+
+        for name in line.r11_relationship_name(merlin) {
+            let name = name.read().unwrap();
+            let (x, y) = name_position(&name, merlin);
+
+            writeln!(
+                out,
+                r#"<text class="rel-name" x="{}" y="{}">{}</text>"#,
+                x,
+                y,
+                escape(&name.text)
+            )
+            .unwrap();
+        }
+
+        for phrase in line.r12_relationship_phrase(merlin) {
+            let phrase = phrase.read().unwrap();
+            writeln!(
+                out,
+                r#"<text class="phrase" x="{}" y="{}">{}</text>"#,
+                phrase.x,
+                phrase.y,
+                escape(&phrase.text)
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(out, "</svg>").unwrap();
+
+    out
+}
+
+fn render_box(x_box: &XBox, sarzak: &SarzakStore, out: &mut String) {
+    writeln!(
+        out,
+        r#"<rect class="box" x="{}" y="{}" width="{}" height="{}"/>"#,
+        x_box.x, x_box.y, x_box.width, x_box.height
+    )
+    .unwrap();
+
+    let obj = match sarzak.exhume_object(&x_box.object) {
+        Some(obj) => obj,
+        None => return,
+    };
+    let obj = obj.read().unwrap();
+
+    writeln!(
+        out,
+        r#"<text class="name" x="{}" y="{}">{}</text>"#,
+        x_box.x + x_box.width / 2,
+        x_box.y + HEADER - 7,
+        escape(&obj.name)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        r#"<line class="line" x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+        x_box.x,
+        x_box.y + HEADER,
+        x_box.x + x_box.width,
+        x_box.y + HEADER
+    )
+    .unwrap();
+
+    let mut attrs = obj.r1_attribute(sarzak);
+    attrs.sort_by_key(|a| a.read().unwrap().name.clone());
+    for (n, attr) in attrs.iter().enumerate() {
+        let attr = attr.read().unwrap();
+        let ty = attr.r2_ty(sarzak)[0].read().unwrap().to_owned();
+        writeln!(
+            out,
+            r#"<text class="attr" x="{}" y="{}">{}: {}</text>"#,
+            x_box.x + 8,
+            x_box.y + HEADER + LINE_HEIGHT * (n as i64 + 1),
+            escape(&attr.name),
+            escape(&type_name(&ty, sarzak))
+        )
+        .unwrap();
+    }
+}
+
+fn render_glyph(anchor: &Anchor, merlin: &MerlinStore, out: &mut String) {
+    let point = &anchor.r6_point(merlin)[0];
+    let tip = (
+        point.read().unwrap().x as f64,
+        point.read().unwrap().y as f64,
+    );
+
+    // The direction pointing into the box
+    let into = match *anchor.r9_edge(merlin)[0].read().unwrap() {
+        Edge::Top(_) => (0.0, 1.0),
+        Edge::Bottom(_) => (0.0, -1.0),
+        Edge::Left(_) => (1.0, 0.0),
+        Edge::Right(_) => (-1.0, 0.0),
+    };
+
+    match anchor.r10_glyph(merlin)[0].read().unwrap().subtype {
+        GlyphEnum::One(_) => {
+            let points = triangle(tip, into, ARROW_LENGTH, ARROW_WIDTH);
+            writeln!(out, r#"<polygon class="one" points="{}"/>"#, points).unwrap();
+        }
+        GlyphEnum::Many(_) => {
+            let points = triangle(tip, into, ARROW_LENGTH, ARROW_WIDTH);
+            writeln!(out, r#"<polygon class="many" points="{}"/>"#, points).unwrap();
+            let tip = (tip.0 - into.0 * ARROW_LENGTH, tip.1 - into.1 * ARROW_LENGTH);
+            let points = triangle(tip, into, ARROW_LENGTH, ARROW_WIDTH);
+            writeln!(out, r#"<polygon class="many" points="{}"/>"#, points).unwrap();
+        }
+        GlyphEnum::ZSuper(_) => {
+            let points = triangle(tip, into, SUPER_LENGTH, SUPER_WIDTH);
+            writeln!(out, r#"<polygon class="super" points="{}"/>"#, points).unwrap();
+        }
+        GlyphEnum::Sub(_) => {}
+    }
+}
+
+/// The points of a triangle with it's tip at `tip`, pointing in direction `dir`
+///
+fn triangle(tip: (f64, f64), dir: (f64, f64), length: f64, width: f64) -> String {
+    let base = (tip.0 - dir.0 * length, tip.1 - dir.1 * length);
+    // Perpendicular to the direction
+    let perp = (-dir.1 * width, dir.0 * width);
+
+    format!(
+        "{},{} {},{} {},{}",
+        tip.0,
+        tip.1,
+        base.0 + perp.0,
+        base.1 + perp.1,
+        base.0 - perp.0,
+        base.1 - perp.1
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        sarzak::types::{Ty, Z_STRING},
+    };
+
+    #[test]
+    fn test_render() {
+        let domain = ModelBuilder::new("pets", "")
+            .object("Animal")
+            .attr("name", Ty::ZString(Z_STRING))
+            .object("Cat")
+            .object("Dog")
+            .object("Owner")
+            .isa(1, "Animal", &["Cat", "Dog"])
+            .binary(2, "Animal", Many, "Owner", One, "is owned by")
+            .build()
+            .unwrap();
+
+        let svg = render(&domain);
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(">Animal</text>"));
+        assert!(svg.contains(">name: String</text>"));
+        assert!(svg.contains(">R1</text>"));
+        assert!(svg.contains(">is owned by</text>"));
+        assert_eq!(svg.matches(r#"class="super""#).count(), 1);
+        assert_eq!(svg.matches(r#"class="many""#).count(), 2);
+        // A segment to each subtype, and one for the binary.
+        assert_eq!(svg.matches("<polyline").count(), 3);
+    }
+}
@@ -0,0 +1,809 @@
+//! Model and Drawing Synchronization
+//!
+//! The sarzak store holds the model, and the merlin store holds the drawing of
+//! it. Nothing keeps the two in step: adding an [`Object`] doesn't give it an
+//! [`XBox`], and removing a [`Binary`] leaves it's [`Line`] pointing at
+//! nothing.
+//!
+//! [`check`] compares the two stores and reports the ways in which they have
+//! drifted apart. [`sync`] does the same, and then fixes them:
+//!
+//! * Boxes whose object is gone are removed.
+//! * Lines whose relationship is gone, or that are anchored to a box that is
+//!   gone, are removed. So is everything hanging off of them -- segments,
+//!   points, anchors, glyphs, names, and phrases.
+//! * Objects without a box get one, placed near the objects that they are
+//!   related to, as [`layout_new`][super::layout::layout_new] would.
+//! * Relationships without a line get one, drawn the way that the
+//!   [`ModelBuilder`][super::builder::ModelBuilder] draws them.
+//! * Relationship names are made to match the relationship numbers.
+//!
+//! An isa without any subtypes, or an associative without any sides, isn't
+//! drawn, and so isn't reported as missing it's line.
+//!
+//! [`Object`]: crate::v2::sarzak::types::Object
+//! [`Binary`]: crate::v2::sarzak::types::Binary
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use crate::v2::{
+    domain::{builder::ANCHOR_OFFSET, layout, Domain},
+    merlin::{
+        draw::{anchor_phrase, cardinality_glyph},
+        geometry::{line_segments, nearest_on_edge, Rect},
+        store::ObjectStore as MerlinStore,
+        types::{
+            Anchor, Bisection, Edge, Glyph, Line, LineSegment, LineSegmentPoint, Point, PointEnum,
+            RelationshipName, XBox,
+        },
+    },
+    sarzak::{
+        store::ObjectStore as SarzakStore,
+        types::{Cardinality, Relationship},
+    },
+};
+
+/// A difference between the model and it's drawing
+///
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Drift {
+    /// An object without a box
+    MissingBox { object: Uuid },
+    /// A box whose object is gone
+    OrphanBox { x_box: Uuid },
+    /// A relationship without a line
+    MissingLine { relationship: Uuid },
+    /// A line whose relationship, or one of whose boxes, is gone
+    OrphanLine { line: Uuid },
+    /// A relationship name that doesn't match the relationship number
+    StaleName {
+        name: Uuid,
+        text: String,
+        expected: String,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Drift::MissingBox { object } => write!(f, "object {} has no box", object),
+            Drift::OrphanBox { x_box } => write!(f, "box {} has no object", x_box),
+            Drift::MissingLine { relationship } => {
+                write!(f, "relationship {} has no line", relationship)
+            }
+            Drift::OrphanLine { line } => write!(f, "line {} is orphaned", line),
+            Drift::StaleName {
+                name,
+                text,
+                expected,
+            } => write!(
+                f,
+                "relationship name {} is `{}`, expected `{}`",
+                name, text, expected
+            ),
+        }
+    }
+}
+
+/// Report the drift between the model and the drawing
+///
+/// Neither store is modified. An empty result means that they are in sync.
+pub fn check(domain: &Domain) -> Vec<Drift> {
+    let sarzak = domain.sarzak();
+    let merlin = domain.merlin();
+    let mut drift = Vec::new();
+
+    let mut boxed = HashSet::new();
+    let mut orphans = HashSet::new();
+    for x_box in merlin.iter_x_box() {
+        let x_box = x_box.read().unwrap();
+        if sarzak.exhume_object(&x_box.object).is_some() {
+            boxed.insert(x_box.object);
+        } else {
+            orphans.insert(x_box.id);
+            drift.push(Drift::OrphanBox { x_box: x_box.id });
+        }
+    }
+
+    for obj in sarzak.iter_object() {
+        let id = obj.read().unwrap().id;
+        if !boxed.contains(&id) {
+            drift.push(Drift::MissingBox { object: id });
+        }
+    }
+
+    let mut drawn = HashSet::new();
+    for line in merlin.iter_line() {
+        let line = line.read().unwrap();
+        let relationship = match sarzak.exhume_relationship(&line.relationship) {
+            Some(relationship) => relationship,
+            None => {
+                drift.push(Drift::OrphanLine { line: line.id });
+                continue;
+            }
+        };
+
+        let dangling = line.r3_anchor(merlin).iter().any(|anchor| {
+            let x_box = anchor.read().unwrap().x_box;
+            orphans.contains(&x_box) || merlin.exhume_x_box(&x_box).is_none()
+        });
+        if dangling {
+            drift.push(Drift::OrphanLine { line: line.id });
+            continue;
+        }
+
+        drawn.insert(line.relationship);
+
+        let expected = format!("R{}", number(&relationship.read().unwrap(), sarzak));
+        for name in line.r11_relationship_name(merlin) {
+            let name = name.read().unwrap();
+            if name.text != expected {
+                drift.push(Drift::StaleName {
+                    name: name.id,
+                    text: name.text.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+    }
+
+    for relationship in sarzak.iter_relationship() {
+        let relationship = relationship.read().unwrap();
+        if drawn.contains(&relationship.id()) {
+            continue;
+        }
+        match *relationship {
+            Relationship::Isa(id) => {
+                let isa = sarzak.exhume_isa(&id).unwrap();
+                if isa.read().unwrap().r27_subtype(sarzak).is_empty() {
+                    continue;
+                }
+            }
+            Relationship::Associative(id) => {
+                let assoc = sarzak.exhume_associative(&id).unwrap();
+                if assoc
+                    .read()
+                    .unwrap()
+                    .r22_an_associative_referent(sarzak)
+                    .is_empty()
+                {
+                    continue;
+                }
+            }
+            Relationship::Binary(_) => {}
+        }
+        drift.push(Drift::MissingLine {
+            relationship: relationship.id(),
+        });
+    }
+
+    drift.sort();
+    drift
+}
+
+/// Bring the drawing in line with the model
+///
+/// Returns the drift that was found, as [`check`] would have reported it.
+/// Running [`check`] afterwards should come back empty, unless the model
+/// itself is broken, e.g., a relationship refers to an object that doesn't
+/// exist.
+pub fn sync(domain: &mut Domain) -> Vec<Drift> {
+    let drift = check(domain);
+
+    let mut missing = Vec::new();
+    for d in &drift {
+        match d {
+            Drift::MissingBox { .. } => {}
+            Drift::OrphanBox { x_box } => {
+                domain.merlin.exorcise_x_box(x_box);
+            }
+            Drift::MissingLine { relationship } => missing.push(*relationship),
+            Drift::OrphanLine { line } => remove_line(line, &mut domain.merlin),
+            Drift::StaleName { name, expected, .. } => {
+                if let Some(name) = domain.merlin.exhume_relationship_name(name) {
+                    name.write().unwrap().text = expected.clone();
+                }
+            }
+        }
+    }
+
+    let new = layout::add_missing_boxes(domain);
+    let lines = missing
+        .iter()
+        .filter_map(|relationship| draw(relationship, domain))
+        .collect::<HashSet<_>>();
+    layout::place_new(domain, &new, &lines);
+
+    drift
+}
+
+/// The number of a relationship, whatever it's flavor
+///
+fn number(relationship: &Relationship, sarzak: &SarzakStore) -> i64 {
+    match *relationship {
+        Relationship::Associative(id) => {
+            sarzak
+                .exhume_associative(&id)
+                .unwrap()
+                .read()
+                .unwrap()
+                .number
+        }
+        Relationship::Binary(id) => sarzak.exhume_binary(&id).unwrap().read().unwrap().number,
+        Relationship::Isa(id) => sarzak.exhume_isa(&id).unwrap().read().unwrap().number,
+    }
+}
+
+/// Remove a line, and everything that belongs to it
+///
+fn remove_line(id: &Uuid, merlin: &mut MerlinStore) {
+    let line = match merlin.exhume_line(id) {
+        Some(line) => line,
+        None => return,
+    };
+    let (anchors, names, phrases, segments) = {
+        let line = line.read().unwrap();
+        (
+            line.r3_anchor(merlin),
+            line.r11_relationship_name(merlin),
+            line.r12_relationship_phrase(merlin),
+            line_segments(&line, merlin),
+        )
+    };
+
+    let mut points = HashSet::new();
+    let mut bisections = HashSet::new();
+    for segment in &segments {
+        let segment = segment.read().unwrap().id;
+        for lsp in merlin
+            .iter_line_segment_point()
+            .filter(|lsp| lsp.read().unwrap().segment == segment)
+            .collect::<Vec<_>>()
+        {
+            let lsp = lsp.read().unwrap();
+            points.insert(lsp.point);
+            merlin.exorcise_line_segment_point(&lsp.id);
+        }
+        bisections.extend(
+            merlin
+                .iter_bisection()
+                .filter(|b| b.read().unwrap().segment == segment)
+                .map(|b| b.read().unwrap().id),
+        );
+    }
+
+    // Some points might not be on a segment, if the drawing is already broken.
+    for point in merlin.iter_point() {
+        let point = point.read().unwrap();
+        match point.subtype {
+            PointEnum::Anchor(id) if anchors.iter().any(|a| a.read().unwrap().id == id) => {
+                points.insert(point.id);
+            }
+            PointEnum::Bisection(id) if bisections.contains(&id) => {
+                points.insert(point.id);
+            }
+            _ => {}
+        }
+    }
+    for point in points {
+        merlin.exorcise_point(&point);
+    }
+
+    for phrase in phrases {
+        merlin.exorcise_relationship_phrase(&phrase.read().unwrap().id);
+    }
+    for name in names {
+        merlin.exorcise_relationship_name(&name.read().unwrap().id);
+    }
+    for bisection in bisections {
+        merlin.exorcise_bisection(&bisection);
+    }
+    for anchor in anchors {
+        merlin.exorcise_anchor(&anchor.read().unwrap().id);
+    }
+    let glyphs = merlin
+        .iter_glyph()
+        .filter(|g| g.read().unwrap().line == *id)
+        .map(|g| g.read().unwrap().id)
+        .collect::<Vec<_>>();
+    for glyph in glyphs {
+        merlin.exorcise_glyph(&glyph);
+    }
+    for segment in segments {
+        merlin.exorcise_line_segment(&segment.read().unwrap().id);
+    }
+    merlin.exorcise_line(id);
+}
+
+/// Draw a line for a relationship
+///
+/// Each end is anchored to the side of it's box that faces the other end. It's
+/// up to the caller to spread the anchors out, and route the line.
+///
+/// Returns the line's id, or `None` if one of the objects doesn't have a box.
+fn draw(id: &Uuid, domain: &mut Domain) -> Option<Uuid> {
+    let boxes = domain
+        .merlin
+        .iter_x_box()
+        .map(|b| (b.read().unwrap().object, b.clone()))
+        .collect::<HashMap<_, _>>();
+    let sarzak = &domain.sarzak;
+    let merlin = &mut domain.merlin;
+
+    let relationship = sarzak.exhume_relationship(id)?;
+    let relationship = relationship.read().unwrap();
+    let line = match *relationship {
+        Relationship::Binary(id) => {
+            let binary = sarzak.exhume_binary(&id)?;
+            let binary = binary.read().unwrap();
+            let referrer = binary.r6_referrer(sarzak)[0].clone();
+            let referrer = referrer.read().unwrap();
+            let referent = binary.r5_referent(sarzak)[0].clone();
+            let referent = referent.read().unwrap();
+            let from = boxes.get(&referrer.obj_id)?;
+            let to = boxes.get(&referent.obj_id)?;
+
+            let (line, segment) = make_line(&relationship, binary.number, merlin);
+
+            let glyph = cardinality_glyph(&referrer.r9_cardinality(sarzak)[0], &line, merlin);
+            let (anchor, _) = attach(from, to, &glyph, &line, &segment, merlin);
+            anchor_phrase(&referrer.description, &line, &anchor, merlin);
+
+            let glyph = cardinality_glyph(&referent.r8_cardinality(sarzak)[0], &line, merlin);
+            let (anchor, _) = attach(to, from, &glyph, &line, &segment, merlin);
+            anchor_phrase(&referent.description, &line, &anchor, merlin);
+
+            line
+        }
+        Relationship::Isa(id) => {
+            let isa = sarzak.exhume_isa(&id)?;
+            let isa = isa.read().unwrap();
+            let supertype = isa.r13_supertype(sarzak)[0].read().unwrap().obj_id;
+            let supertype = boxes.get(&supertype)?;
+            let subtypes = isa
+                .r27_subtype(sarzak)
+                .iter()
+                .map(|s| boxes.get(&s.read().unwrap().obj_id))
+                .collect::<Option<Vec<_>>>()?;
+            if subtypes.is_empty() {
+                return None;
+            }
+
+            // The supertype gets a single anchor, and there is a segment from
+            // it to each subtype.
+            let (line, first) = make_line(&relationship, isa.number, merlin);
+            let glyph = Glyph::new_z_super(&line, merlin);
+            let (_, point) = attach(supertype, subtypes[0], &glyph, &line, &first, merlin);
+
+            for (n, sub) in subtypes.iter().enumerate() {
+                let segment = if n == 0 {
+                    first.clone()
+                } else {
+                    let segment = LineSegment::new(&line, merlin);
+                    LineSegmentPoint::new(&segment, &point, merlin);
+                    segment
+                };
+                let glyph = Glyph::new_sub(&line, merlin);
+                attach(sub, supertype, &glyph, &line, &segment, merlin);
+            }
+
+            line
+        }
+        Relationship::Associative(id) => {
+            let assoc = sarzak.exhume_associative(&id)?;
+            let assoc = assoc.read().unwrap();
+            let referrer = assoc.r21_associative_referrer(sarzak)[0].clone();
+            let referrer = referrer.read().unwrap();
+            let associative = boxes.get(&referrer.obj_id)?;
+            let sides = assoc
+                .r22_an_associative_referent(sarzak)
+                .iter()
+                .map(|r| {
+                    let referent = r.read().unwrap().r22_associative_referent(sarzak)[0].clone();
+                    let referent = referent.read().unwrap();
+                    let x_box = boxes.get(&referent.obj_id)?;
+                    let card = referent.r88_cardinality(sarzak)[0].clone();
+                    Some((x_box, card, referent.description.clone()))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let (one, one_card, one_phrase) = sides.first()?;
+
+            let (line, segment) = make_line(&relationship, assoc.number, merlin);
+            let card = referrer.r89_cardinality(sarzak)[0].clone();
+
+            let (other, other_card, other_phrase) = match sides.get(1) {
+                Some(side) => side,
+                None => {
+                    // With only one side there's nothing to bisect, so the line
+                    // runs straight to the associative object.
+                    end(
+                        one,
+                        one_card,
+                        one_phrase,
+                        associative,
+                        &line,
+                        &segment,
+                        merlin,
+                    );
+                    let glyph = cardinality_glyph(&card, &line, merlin);
+                    attach(associative, one, &glyph, &line, &segment, merlin);
+
+                    let id = line.read().unwrap().id;
+                    return Some(id);
+                }
+            };
+            end(one, one_card, one_phrase, other, &line, &segment, merlin);
+            end(
+                other,
+                other_card,
+                other_phrase,
+                one,
+                &line,
+                &segment,
+                merlin,
+            );
+
+            // The associative object hangs off of the middle of the line. The
+            // bisection point is put in it's place when the line is anchored.
+            let bisection = Bisection::new(0.5, &segment, merlin);
+            let middle = LineSegment::new(&line, merlin);
+            let glyph = cardinality_glyph(&card, &line, merlin);
+            attach(associative, one, &glyph, &line, &middle, merlin);
+            let point = Point::new_bisection(0, 0, &bisection, merlin);
+            LineSegmentPoint::new(&middle, &point, merlin);
+
+            // Any further sides get a segment from the bisection point.
+            for (side, card, phrase) in &sides[2..] {
+                let segment = LineSegment::new(&line, merlin);
+                LineSegmentPoint::new(&segment, &point, merlin);
+                end(side, card, phrase, associative, &line, &segment, merlin);
+            }
+
+            line
+        }
+    };
+
+    let id = line.read().unwrap().id;
+    Some(id)
+}
+
+/// Create a line with a single segment, and name it
+///
+fn make_line(
+    relationship: &Relationship,
+    number: i64,
+    merlin: &mut MerlinStore,
+) -> (Arc<RwLock<Line>>, Arc<RwLock<LineSegment>>) {
+    let line = Line::new(relationship, merlin);
+    let segment = LineSegment::new(&line, merlin);
+    let bisection = Bisection::new(0.5, &segment, merlin);
+    RelationshipName::new(format!("R{}", number), 0, 0, &line, &bisection, merlin);
+
+    (line, segment)
+}
+
+/// Draw the end of a line at `x_box`, with the glyph for `card`, and the phrase
+///
+fn end(
+    x_box: &Arc<RwLock<XBox>>,
+    card: &Arc<RwLock<Cardinality>>,
+    phrase: &str,
+    toward: &Arc<RwLock<XBox>>,
+    line: &Arc<RwLock<Line>>,
+    segment: &Arc<RwLock<LineSegment>>,
+    merlin: &mut MerlinStore,
+) {
+    let glyph = cardinality_glyph(card, line, merlin);
+    let (anchor, _) = attach(x_box, toward, &glyph, line, segment, merlin);
+    anchor_phrase(phrase, line, &anchor, merlin);
+}
+
+/// Anchor a line to the edge of `x_box` nearest to the center of `toward`
+///
+fn attach(
+    x_box: &Arc<RwLock<XBox>>,
+    toward: &Arc<RwLock<XBox>>,
+    glyph: &Arc<RwLock<Glyph>>,
+    line: &Arc<RwLock<Line>>,
+    segment: &Arc<RwLock<LineSegment>>,
+    merlin: &mut MerlinStore,
+) -> (Arc<RwLock<Anchor>>, Arc<RwLock<Point>>) {
+    let (tx, ty) = Rect::from(&*toward.read().unwrap()).center();
+    let (edge, offset, (x, y)) = nearest_on_edge(&x_box.read().unwrap(), tx, ty);
+    let (ox, oy) = match edge {
+        Edge::Top(_) => (x, y - ANCHOR_OFFSET),
+        Edge::Bottom(_) => (x, y + ANCHOR_OFFSET),
+        Edge::Left(_) => (x - ANCHOR_OFFSET, y),
+        Edge::Right(_) => (x + ANCHOR_OFFSET, y),
+    };
+
+    let edge = merlin.exhume_edge(&edge.id()).unwrap();
+    let anchor = Anchor::new(offset, ox, oy, &edge, glyph, x_box, line, merlin);
+    let point = Point::new_anchor(x, y, &anchor, merlin);
+    LineSegmentPoint::new(segment, &point, merlin);
+
+    (anchor, point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        merlin::geometry::anchor_location,
+        sarzak::types::{Binary, Cardinality, Conditionality, Object, Referent, Referrer, Ty},
+    };
+
+    fn pets() -> Domain {
+        ModelBuilder::new("pets", "")
+            .object("Animal")
+            .object("Cat")
+            .object("Dog")
+            .object("Owner")
+            .object("Vet")
+            .object("Visit")
+            .isa(1, "Animal", &["Cat", "Dog"])
+            .binary(2, "Animal", Many, "Owner", One, "is owned by")
+            .associative(3, "Visit", "Animal", Many, "Vet", Many)
+            .build()
+            .unwrap()
+    }
+
+    fn object(domain: &Domain, name: &str) -> Arc<RwLock<Object>> {
+        let id = domain.sarzak().exhume_object_id_by_name(name).unwrap();
+        domain.sarzak().exhume_object(&id).unwrap()
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut domain = pets();
+        assert!(check(&domain).is_empty());
+
+        // A new object, related to an existing one.
+        let toy = Object::new(
+            "".to_owned(),
+            "T".to_owned(),
+            "Toy".to_owned(),
+            domain.sarzak_mut(),
+        );
+        Ty::new_object(&toy, domain.sarzak_mut());
+        let dog = object(&domain, "Dog");
+        let referrer = Referrer::new(
+            "belongs to".to_owned(),
+            "dog".to_owned(),
+            &Cardinality::new_many(domain.sarzak()),
+            &Conditionality::new_unconditional(domain.sarzak()),
+            &toy,
+            domain.sarzak_mut(),
+        );
+        let referent = Referent::new(
+            "".to_owned(),
+            &Cardinality::new_one(domain.sarzak()),
+            &Conditionality::new_unconditional(domain.sarzak()),
+            &dog,
+            domain.sarzak_mut(),
+        );
+        let binary = Binary::new(4, &referrer, &referent, domain.sarzak_mut());
+        let r4 = Relationship::new_binary(&binary, domain.sarzak_mut());
+
+        // Owner, and the relationship to it, go away.
+        let owner = object(&domain, "Owner").read().unwrap().id;
+        domain.sarzak_mut().exorcise_object(&owner);
+        let r2 = domain
+            .sarzak()
+            .iter_binary()
+            .find(|b| b.read().unwrap().number == 2)
+            .unwrap();
+        let r2 = r2.read().unwrap().r4_relationship(domain.sarzak())[0]
+            .read()
+            .unwrap()
+            .id();
+        domain.sarzak_mut().exorcise_relationship(&r2);
+
+        // R1 is renumbered.
+        let isa = domain.sarzak().iter_isa().next().unwrap();
+        isa.write().unwrap().number = 7;
+
+        let owner_box = domain
+            .merlin()
+            .iter_x_box()
+            .find(|b| b.read().unwrap().object == owner)
+            .unwrap()
+            .read()
+            .unwrap()
+            .id;
+        let r2_line = domain
+            .merlin()
+            .iter_line()
+            .find(|l| l.read().unwrap().relationship == r2)
+            .unwrap()
+            .read()
+            .unwrap()
+            .id;
+
+        let drift = check(&domain);
+        assert_eq!(drift.len(), 5);
+        assert!(drift.contains(&Drift::MissingBox {
+            object: toy.read().unwrap().id
+        }));
+        assert!(drift.contains(&Drift::OrphanBox { x_box: owner_box }));
+        assert!(drift.contains(&Drift::MissingLine {
+            relationship: r4.read().unwrap().id()
+        }));
+        assert!(drift.contains(&Drift::OrphanLine { line: r2_line }));
+        assert!(drift
+            .iter()
+            .any(|d| matches!(d, Drift::StaleName { text, expected, .. } if text == "R1" && expected == "R7")));
+
+        // Checking doesn't change anything.
+        assert_eq!(check(&domain), drift);
+
+        assert_eq!(sync(&mut domain), drift);
+        assert!(check(&domain).is_empty());
+
+        let merlin = domain.merlin();
+        assert_eq!(
+            merlin.iter_x_box().count(),
+            domain.sarzak().iter_object().count()
+        );
+        assert_eq!(
+            merlin.iter_line().count(),
+            domain.sarzak().iter_relationship().count()
+        );
+        assert_eq!(merlin.iter_anchor().count(), merlin.iter_glyph().count());
+        assert_eq!(merlin.iter_anchor().count(), 3 + 2 + 3);
+        assert_eq!(merlin.iter_relationship_phrase().count(), 1);
+        let mut names = merlin
+            .iter_relationship_name()
+            .map(|n| n.read().unwrap().text.clone())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["R3", "R4", "R7"]);
+
+        // Nothing is left pointing at a box or a line that's gone.
+        for anchor in merlin.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            assert!(merlin.exhume_x_box(&anchor.x_box).is_some());
+            assert!(merlin.exhume_line(&anchor.line).is_some());
+            assert_eq!(anchor.r6_point(merlin).len(), 1);
+        }
+        for lsp in merlin.iter_line_segment_point() {
+            let lsp = lsp.read().unwrap();
+            assert!(merlin.exhume_point(&lsp.point).is_some());
+            assert!(merlin.exhume_line_segment(&lsp.segment).is_some());
+        }
+    }
+
+    #[test]
+    fn test_remove_lines() {
+        let mut domain = pets();
+
+        // Both of these are drawn with more than one segment.
+        let relationships = domain
+            .sarzak()
+            .iter_relationship()
+            .filter(|r| !matches!(*r.read().unwrap(), Relationship::Binary(_)))
+            .map(|r| r.read().unwrap().id())
+            .collect::<Vec<_>>();
+        assert_eq!(relationships.len(), 2);
+        for id in &relationships {
+            domain.sarzak_mut().exorcise_relationship(id);
+        }
+
+        assert_eq!(sync(&mut domain).len(), 2);
+        assert!(check(&domain).is_empty());
+
+        let merlin = domain.merlin();
+        assert_eq!(merlin.iter_line().count(), 1);
+        assert_eq!(merlin.iter_line_segment().count(), 1);
+        assert_eq!(merlin.iter_bisection().count(), 1);
+        assert_eq!(merlin.iter_anchor().count(), 2);
+        assert_eq!(merlin.iter_point().count(), 2);
+        assert_eq!(merlin.iter_line_segment_point().count(), 2);
+    }
+
+    #[test]
+    fn test_redraw_associative_sides() {
+        let mut domain = ModelBuilder::new("clinic", "")
+            .object("Pet")
+            .object("Vet")
+            .object("Owner")
+            .object("Appointment")
+            .object("Checkup")
+            .associative_sides(
+                1,
+                "Appointment",
+                &[("Pet", Many), ("Vet", Many), ("Owner", One)],
+            )
+            .associative_sides(2, "Checkup", &[("Pet", Many)])
+            .build()
+            .unwrap();
+        assert!(check(&domain).is_empty());
+
+        let counts = |domain: &Domain| {
+            let merlin = domain.merlin();
+            [
+                merlin.iter_line().count(),
+                merlin.iter_line_segment().count(),
+                merlin.iter_line_segment_point().count(),
+                merlin.iter_bisection().count(),
+                merlin.iter_anchor().count(),
+                merlin.iter_glyph().count(),
+                merlin.iter_point().count(),
+            ]
+        };
+        let drawn = counts(&domain);
+
+        let lines = domain
+            .merlin()
+            .iter_line()
+            .map(|l| l.read().unwrap().id)
+            .collect::<Vec<_>>();
+        for line in &lines {
+            remove_line(line, domain.merlin_mut());
+        }
+        assert_eq!(check(&domain).len(), 2);
+
+        assert_eq!(sync(&mut domain).len(), 2);
+        assert!(check(&domain).is_empty());
+
+        // The lines are drawn just as the builder drew them: the three sided
+        // one with a segment from the bisection for the third side, and the
+        // one sided one straight to the associative object.
+        assert_eq!(counts(&domain), drawn);
+        let merlin = domain.merlin();
+        assert_eq!(merlin.iter_line_segment().count(), 3 + 1);
+        assert_eq!(merlin.iter_anchor().count(), 4 + 2);
+    }
+
+    #[test]
+    fn test_draw_faces_the_other_end() {
+        let mut domain = pets();
+        let animal = object(&domain, "Animal").read().unwrap().id;
+        let r2 = domain
+            .sarzak()
+            .iter_binary()
+            .find(|b| b.read().unwrap().number == 2)
+            .unwrap();
+        let r2 = r2.read().unwrap().r4_relationship(domain.sarzak())[0]
+            .read()
+            .unwrap()
+            .id();
+        let line = domain
+            .merlin()
+            .iter_line()
+            .find(|l| l.read().unwrap().relationship == r2)
+            .unwrap()
+            .read()
+            .unwrap()
+            .id;
+        remove_line(&line, domain.merlin_mut());
+
+        // Owner is to the right of Animal, in the same row.
+        let line = draw(&r2, &mut domain).unwrap();
+        let merlin = domain.merlin();
+        let anchors = merlin
+            .iter_anchor()
+            .filter(|a| a.read().unwrap().line == line)
+            .collect::<Vec<_>>();
+        assert_eq!(anchors.len(), 2);
+        for anchor in anchors {
+            let anchor = anchor.read().unwrap();
+            let point = &anchor.r6_point(merlin)[0];
+            let point = point.read().unwrap();
+            assert_eq!(anchor_location(&anchor, merlin), (point.x, point.y));
+
+            let edge = *anchor.r9_edge(merlin)[0].read().unwrap();
+            if anchor.r3_x_box(merlin)[0].read().unwrap().object == animal {
+                assert!(matches!(edge, Edge::Right(_)));
+                assert_eq!(anchor.r13c_relationship_phrase(merlin).len(), 1);
+            } else {
+                assert!(matches!(edge, Edge::Left(_)));
+            }
+        }
+    }
+}
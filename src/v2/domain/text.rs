@@ -0,0 +1,1312 @@
+//! Textual Domain Models
+//!
+//! A plain text format for domains, so that they may be written and reviewed
+//! without the GUI. [`parse`] reads the text into a [`Domain`], laying out the
+//! drawing with the [`ModelBuilder`]. [`print`] goes the other direction, and
+//! works on any domain.
+//!
+//! Here's what it looks like:
+//!
+//! ```text
+//! /// A domain about pets
+//! domain pets
+//!
+//! enum Color {
+//!     Black,
+//!     Brown,
+//!     Spotted = 10,
+//! }
+//!
+//! external Clock "std::time" "now"
+//!
+//! /// Something with fur
+//! object Animal {
+//!     id: Uuid
+//!     name: String
+//!     color: Option<Color>
+//!     age: u8
+//!     state Sleeping
+//!     state "Chasing Cars"
+//!     event Wake
+//! }
+//!
+//! object Dog {}
+//!
+//! object Cat {}
+//!
+//! object Owner {
+//!     id: Uuid
+//!     nicknames: [String]
+//! }
+//!
+//! object Adoption {
+//!     date: Timestamp
+//! }
+//!
+//! R1: Animal isa Dog, Cat
+//! R2: Dog M "is owned by" -> Owner 1c "owns" as owner_id
+//! R3: Adoption formalizes Cat M "is adopted by" <-> Owner Mc "adopts"
+//! ```
+//!
+//! Descriptions are written as `///` comments, ahead of the thing they
+//! describe. Other comments start with `//`.
+//!
+//! Cardinality is one of `1`, `1c`, `M`, or `Mc`, where the trailing `c` means
+//! conditional. In a binary relationship the left side is the referrer and the
+//! right side is the referent. The phrases are read left to right, and right to
+//! left, respectively. The referent phrase is optional, as is the name of the
+//! referential attribute, which defaults to the snake case name of the referent.
+//!
+//! The sides of an associative relationship are separated by `<->`, and each
+//! may be followed by a phrase. There are usually two of them, but there may
+//! be any number. Likewise, an isa may be written without any subtypes.
+//!
+//! The text doesn't include the drawing.
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::{Arc, RwLock};
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use snafu::prelude::*;
+
+use crate::{
+    error::{ParseSnafu, Result},
+    v2::{
+        domain::{
+            builder::{ModelBuilder, Multiplicity},
+            Domain,
+        },
+        sarzak::{
+            store::ObjectStore as SarzakStore,
+            types::{
+                EnumValue, Enumeration, External, List, Relationship, SizedInteger, Ty, ZOption,
+                BOOLEAN, CONDITIONAL, DURATION, FLOAT, INTEGER, MANY, TIMESTAMP, Z_STRING, Z_UUID,
+            },
+        },
+    },
+};
+
+/// Parse a textual model into a [`Domain`]
+///
+pub fn parse(src: &str) -> Result<Domain> {
+    let tokens = lex(src)?;
+    let model = Parser { tokens, pos: 0 }.model()?;
+
+    model.build()
+}
+
+/// Write a [`Domain`] as text
+///
+/// Everything is sorted by name, or by number in the case of relationships, so
+/// that the output is stable.
+pub fn print(domain: &Domain) -> String {
+    let store = domain.sarzak();
+    let mut out = String::new();
+
+    doc(&mut out, domain.description());
+    writeln!(out, "domain {}", word_or_string(domain.name())).unwrap();
+
+    let mut enums: Vec<_> = store.iter_enumeration().collect();
+    enums.sort_by_key(|e| e.read().unwrap().name.to_upper_camel_case());
+    for enumeration in enums {
+        let enumeration = enumeration.read().unwrap();
+        let mut values = enumeration.r28_enum_value(store);
+        values.sort_by_key(|v| v.read().unwrap().value);
+
+        writeln!(out).unwrap();
+        doc(&mut out, &enumeration.description);
+        writeln!(out, "enum {} {{", enumeration.name.to_upper_camel_case()).unwrap();
+        let mut next = 0;
+        for value in values {
+            let value = value.read().unwrap();
+            if value.value == next {
+                writeln!(out, "    {},", value.name).unwrap();
+            } else {
+                writeln!(out, "    {} = {},", value.name, value.value).unwrap();
+            }
+            next = value.value + 1;
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    let mut externals: Vec<_> = store.iter_external().collect();
+    externals.sort_by_key(|e| e.read().unwrap().name.to_upper_camel_case());
+    if !externals.is_empty() {
+        writeln!(out).unwrap();
+    }
+    for external in externals {
+        let external = external.read().unwrap();
+        writeln!(
+            out,
+            "external {} {:?} {:?}",
+            external.name.to_upper_camel_case(),
+            external.x_path,
+            external.ctor
+        )
+        .unwrap();
+    }
+
+    let mut objects: Vec<_> = store.iter_object().collect();
+    objects.sort_by_key(|o| o.read().unwrap().name.to_upper_camel_case());
+    for obj in objects {
+        let obj = obj.read().unwrap();
+
+        writeln!(out).unwrap();
+        doc(&mut out, &obj.description);
+
+        let mut attrs = obj.r1_attribute(store);
+        attrs.sort_by_key(|a| a.read().unwrap().name.clone());
+        let mut states = obj.r18_state(store);
+        states.sort_by_key(|s| s.read().unwrap().name.clone());
+        let mut events = obj.r19_event(store);
+        events.sort_by_key(|e| e.read().unwrap().name.clone());
+
+        if attrs.is_empty() && states.is_empty() && events.is_empty() {
+            writeln!(out, "object {} {{}}", obj.name.to_upper_camel_case()).unwrap();
+            continue;
+        }
+
+        writeln!(out, "object {} {{", obj.name.to_upper_camel_case()).unwrap();
+        for attr in attrs {
+            let attr = attr.read().unwrap();
+            let ty = attr.r2_ty(store)[0].read().unwrap().to_owned();
+            writeln!(out, "    {}: {}", attr.name, type_name(&ty, store)).unwrap();
+        }
+        for state in states {
+            writeln!(
+                out,
+                "    state {}",
+                word_or_string(&state.read().unwrap().name)
+            )
+            .unwrap();
+        }
+        for event in events {
+            writeln!(
+                out,
+                "    event {}",
+                word_or_string(&event.read().unwrap().name)
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    let mut relationships: Vec<_> = store
+        .iter_relationship()
+        .map(|r| {
+            let number = match *r.read().unwrap() {
+                Relationship::Associative(id) => {
+                    store
+                        .exhume_associative(&id)
+                        .unwrap()
+                        .read()
+                        .unwrap()
+                        .number
+                }
+                Relationship::Binary(id) => {
+                    store.exhume_binary(&id).unwrap().read().unwrap().number
+                }
+                Relationship::Isa(id) => store.exhume_isa(&id).unwrap().read().unwrap().number,
+            };
+            (number, r)
+        })
+        .collect();
+    relationships.sort_by_key(|(number, _)| *number);
+    if !relationships.is_empty() {
+        writeln!(out).unwrap();
+    }
+    for (number, relationship) in relationships {
+        match *relationship.read().unwrap() {
+            Relationship::Binary(id) => {
+                let binary = store.exhume_binary(&id).unwrap();
+                let binary = binary.read().unwrap();
+                let referrer = binary.r6_referrer(store)[0].read().unwrap().to_owned();
+                let referent = binary.r5_referent(store)[0].read().unwrap().to_owned();
+                let from = referrer.r17_object(store)[0]
+                    .read()
+                    .unwrap()
+                    .name
+                    .to_owned();
+                let to = referent.r16_object(store)[0]
+                    .read()
+                    .unwrap()
+                    .name
+                    .to_owned();
+
+                write!(
+                    out,
+                    "R{}: {} {} {:?} -> {} {}",
+                    number,
+                    from.to_upper_camel_case(),
+                    cardinality(referrer.cardinality == MANY, referrer.conditionality),
+                    referrer.description,
+                    to.to_upper_camel_case(),
+                    cardinality(referent.cardinality == MANY, referent.conditionality),
+                )
+                .unwrap();
+                if !referent.description.is_empty() {
+                    write!(out, " {:?}", referent.description).unwrap();
+                }
+                if referrer.referential_attribute != to.to_snake_case() {
+                    write!(out, " as {}", referrer.referential_attribute).unwrap();
+                }
+                writeln!(out).unwrap();
+            }
+            Relationship::Isa(id) => {
+                let isa = store.exhume_isa(&id).unwrap();
+                let isa = isa.read().unwrap();
+                let supertype = &isa.r13_supertype(store)[0];
+                let supertype = &supertype.read().unwrap().r14_object(store)[0];
+                let mut subtypes: Vec<_> = isa
+                    .r27_subtype(store)
+                    .iter()
+                    .map(|s| {
+                        let obj = &s.read().unwrap().r15_object(store)[0];
+                        let name = obj.read().unwrap().name.to_upper_camel_case();
+                        name
+                    })
+                    .collect();
+                subtypes.sort();
+
+                write!(
+                    out,
+                    "R{}: {} isa",
+                    number,
+                    supertype.read().unwrap().name.to_upper_camel_case(),
+                )
+                .unwrap();
+                if !subtypes.is_empty() {
+                    write!(out, " {}", subtypes.join(", ")).unwrap();
+                }
+                writeln!(out).unwrap();
+            }
+            Relationship::Associative(id) => {
+                let assoc = store.exhume_associative(&id).unwrap();
+                let assoc = assoc.read().unwrap();
+                let referrer = &assoc.r21_associative_referrer(store)[0];
+                let obj = &referrer.read().unwrap().r26_object(store)[0];
+                let mut sides: Vec<_> = assoc
+                    .r22_an_associative_referent(store)
+                    .iter()
+                    .map(|r| {
+                        let referent = &r.read().unwrap().r22_associative_referent(store)[0];
+                        let referent = referent.read().unwrap();
+                        let obj = &referent.r25_object(store)[0];
+                        let name = obj.read().unwrap().name.to_upper_camel_case();
+                        (
+                            name,
+                            cardinality(referent.cardinality == MANY, referent.conditionality),
+                            referent.description.to_owned(),
+                        )
+                    })
+                    .collect();
+                sides.sort();
+
+                write!(
+                    out,
+                    "R{}: {} formalizes",
+                    number,
+                    obj.read().unwrap().name.to_upper_camel_case(),
+                )
+                .unwrap();
+                for (n, (name, card, phrase)) in sides.iter().enumerate() {
+                    if n > 0 {
+                        write!(out, " <->").unwrap();
+                    }
+                    write!(out, " {} {}", name, card).unwrap();
+                    if !phrase.is_empty() {
+                        write!(out, " {:?}", phrase).unwrap();
+                    }
+                }
+                writeln!(out).unwrap();
+            }
+        }
+    }
+
+    out
+}
+
+fn doc(out: &mut String, text: &str) {
+    for line in text.lines() {
+        if line.is_empty() {
+            writeln!(out, "///").unwrap();
+        } else {
+            writeln!(out, "/// {}", line).unwrap();
+        }
+    }
+}
+
+fn word_or_string(text: &str) -> String {
+    if !text.is_empty() && text.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        text.to_owned()
+    } else {
+        format!("{:?}", text)
+    }
+}
+
+fn cardinality(many: bool, conditionality: uuid::Uuid) -> String {
+    format!(
+        "{}{}",
+        if many { "M" } else { "1" },
+        if conditionality == CONDITIONAL {
+            "c"
+        } else {
+            ""
+        }
+    )
+}
+
+pub(super) fn type_name(ty: &Ty, store: &SarzakStore) -> String {
+    match ty {
+        Ty::Boolean(_) => "Boolean".to_owned(),
+        Ty::Duration(_) => "Duration".to_owned(),
+        Ty::Enumeration(id) => {
+            let enumeration = store.exhume_enumeration(id).unwrap();
+            let name = enumeration.read().unwrap().name.to_upper_camel_case();
+            name
+        }
+        Ty::External(id) => {
+            let external = store.exhume_external(id).unwrap();
+            let name = external.read().unwrap().name.to_upper_camel_case();
+            name
+        }
+        Ty::Float(_) => "Float".to_owned(),
+        Ty::Integer(_) => "Integer".to_owned(),
+        Ty::List(id) => {
+            let list = store.exhume_list(id).unwrap();
+            let inner = list.read().unwrap().r29_ty(store)[0]
+                .read()
+                .unwrap()
+                .to_owned();
+            format!("[{}]", type_name(&inner, store))
+        }
+        Ty::Object(id) => {
+            let obj = store.exhume_object(id).unwrap();
+            let name = obj.read().unwrap().name.to_upper_camel_case();
+            name
+        }
+        Ty::SizedInteger(id) => {
+            let int = store.exhume_sized_integer(id).unwrap();
+            let int = int.read().unwrap();
+            format!("{}{}", if int.signed { "i" } else { "u" }, int.bits)
+        }
+        Ty::Timestamp(_) => "Timestamp".to_owned(),
+        Ty::ZOption(id) => {
+            let option = store.exhume_z_option(id).unwrap();
+            let inner = option.read().unwrap().r30_ty(store)[0]
+                .read()
+                .unwrap()
+                .to_owned();
+            format!("Option<{}>", type_name(&inner, store))
+        }
+        Ty::ZString(_) => "String".to_owned(),
+        Ty::ZUuid(_) => "Uuid".to_owned(),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Doc(String),
+    Word(String),
+    Str(String),
+    Punct(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Lexeme {
+    token: Token,
+    line: usize,
+}
+
+const PUNCTUATION: [&str; 11] = ["<->", "->", "{", "}", "[", "]", "<", ">", ":", ",", "="];
+
+fn lex(src: &str) -> Result<Vec<Lexeme>> {
+    let mut tokens = Vec::new();
+
+    for (n, text) in src.lines().enumerate() {
+        let line = n + 1;
+        let mut rest = text.trim_start();
+
+        while !rest.is_empty() {
+            if let Some(doc) = rest.strip_prefix("///") {
+                let doc = doc.strip_prefix(' ').unwrap_or(doc);
+                tokens.push(Lexeme {
+                    token: Token::Doc(doc.to_owned()),
+                    line,
+                });
+                break;
+            } else if rest.starts_with("//") {
+                break;
+            } else if let Some(punct) = PUNCTUATION.iter().find(|p| rest.starts_with(*p)) {
+                tokens.push(Lexeme {
+                    token: Token::Punct(*punct),
+                    line,
+                });
+                rest = &rest[punct.len()..];
+            } else if rest.starts_with('"') {
+                let (string, len) = string(rest, line)?;
+                tokens.push(Lexeme {
+                    token: Token::Str(string),
+                    line,
+                });
+                rest = &rest[len..];
+            } else {
+                let len = rest
+                    .char_indices()
+                    .find(|(i, c)| !(c.is_alphanumeric() || *c == '_' || (*i == 0 && *c == '-')))
+                    .map(|(i, _)| i)
+                    .unwrap_or(rest.len());
+                ensure!(
+                    len > 0,
+                    ParseSnafu {
+                        line,
+                        message: format!(
+                            "unexpected character `{}`",
+                            rest.chars().next().unwrap_or_default()
+                        )
+                    }
+                );
+                tokens.push(Lexeme {
+                    token: Token::Word(rest[..len].to_owned()),
+                    line,
+                });
+                rest = &rest[len..];
+            }
+
+            rest = rest.trim_start();
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read a string literal, returning it and the number of bytes consumed
+///
+/// The usual escapes are supported, which is to say, the ones that `{:?}`
+/// produces for the sort of text one finds in a model.
+fn string(src: &str, line: usize) -> Result<(String, usize)> {
+    let mut string = String::new();
+    let mut chars = src.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((string, i + 1)),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => string.push('\n'),
+                Some((_, 't')) => string.push('\t'),
+                Some((_, 'r')) => string.push('\r'),
+                Some((_, c)) => string.push(c),
+                None => break,
+            },
+            c => string.push(c),
+        }
+    }
+
+    ParseSnafu {
+        line,
+        message: "unterminated string",
+    }
+    .fail()
+    .map_err(|e| e.into())
+}
+
+enum TypeExpr {
+    Named(String),
+    List(Box<TypeExpr>),
+    Option(Box<TypeExpr>),
+}
+
+struct ObjectDef {
+    name: String,
+    description: String,
+    attributes: Vec<(String, TypeExpr, usize)>,
+    states: Vec<String>,
+    events: Vec<String>,
+}
+
+struct Side {
+    object: String,
+    many: bool,
+    conditional: bool,
+}
+
+enum RelDef {
+    Binary {
+        number: i64,
+        from: Side,
+        phrase: String,
+        to: Side,
+        referent_phrase: Option<String>,
+        attribute: Option<String>,
+    },
+    Isa {
+        number: i64,
+        supertype: String,
+        subtypes: Vec<String>,
+    },
+    Associative {
+        number: i64,
+        associative: String,
+        sides: Vec<(Side, Option<String>)>,
+    },
+}
+
+impl RelDef {
+    fn number(&self) -> i64 {
+        match self {
+            RelDef::Binary { number, .. }
+            | RelDef::Isa { number, .. }
+            | RelDef::Associative { number, .. } => *number,
+        }
+    }
+}
+
+struct Model {
+    name: String,
+    description: String,
+    enums: Vec<(String, String, Vec<(String, i64)>)>,
+    externals: Vec<(String, String, String)>,
+    objects: Vec<ObjectDef>,
+    relationships: Vec<(RelDef, usize)>,
+}
+
+struct Parser {
+    tokens: Vec<Lexeme>,
+    pos: usize,
+}
+
+impl Parser {
+    fn model(&mut self) -> Result<Model> {
+        let description = self.docs();
+        self.keyword("domain")?;
+        let name = self.name()?;
+
+        let mut model = Model {
+            name,
+            description,
+            enums: Vec::new(),
+            externals: Vec::new(),
+            objects: Vec::new(),
+            relationships: Vec::new(),
+        };
+
+        loop {
+            let description = self.docs();
+            let line = self.line();
+            match self.next() {
+                None => break,
+                Some(Token::Word(word)) if word == "object" => {
+                    let object = self.object(description)?;
+                    let name = object.name.to_upper_camel_case();
+                    ensure!(
+                        !model
+                            .objects
+                            .iter()
+                            .any(|o| o.name.to_upper_camel_case() == name),
+                        ParseSnafu {
+                            line,
+                            message: format!("duplicate object `{}`", object.name)
+                        }
+                    );
+                    model.objects.push(object);
+                }
+                Some(Token::Word(word)) if word == "enum" => {
+                    let name = self.word()?;
+                    let values = self.enum_values()?;
+                    model.enums.push((name, description, values));
+                }
+                Some(Token::Word(word)) if word == "external" => {
+                    let name = self.word()?;
+                    let path = self.string()?;
+                    let ctor = self.string()?;
+                    model.externals.push((name, path, ctor));
+                }
+                Some(Token::Word(word)) if relationship_number(&word).is_some() => {
+                    let number = relationship_number(&word).unwrap();
+                    self.punct(":")?;
+                    ensure!(
+                        !model
+                            .relationships
+                            .iter()
+                            .any(|(r, _)| r.number() == number),
+                        ParseSnafu {
+                            line,
+                            message: format!("duplicate relationship `R{}`", number)
+                        }
+                    );
+                    let relationship = self.relationship(number)?;
+                    model.relationships.push((relationship, line));
+                }
+                Some(token) => return self.unexpected(token, line, "a definition"),
+            }
+        }
+
+        Ok(model)
+    }
+
+    fn object(&mut self, description: String) -> Result<ObjectDef> {
+        let mut object = ObjectDef {
+            name: self.word()?,
+            description,
+            attributes: Vec::new(),
+            states: Vec::new(),
+            events: Vec::new(),
+        };
+
+        self.punct("{")?;
+        loop {
+            let line = self.line();
+            match self.next() {
+                Some(Token::Punct("}")) => break,
+                Some(Token::Word(word)) if self.peek() == Some(&Token::Punct(":")) => {
+                    self.punct(":")?;
+                    let ty = self.ty()?;
+                    object.attributes.push((word, ty, line));
+                }
+                Some(Token::Word(word)) if word == "state" => object.states.push(self.name()?),
+                Some(Token::Word(word)) if word == "event" => object.events.push(self.name()?),
+                Some(token) => return self.unexpected(token, line, "an attribute"),
+                None => return self.eof("`}`"),
+            }
+        }
+
+        Ok(object)
+    }
+
+    fn enum_values(&mut self) -> Result<Vec<(String, i64)>> {
+        let mut values = Vec::new();
+        let mut next = 0;
+
+        self.punct("{")?;
+        loop {
+            let line = self.line();
+            match self.next() {
+                Some(Token::Punct("}")) => break,
+                Some(Token::Punct(",")) => continue,
+                Some(Token::Word(name)) => {
+                    if self.peek() == Some(&Token::Punct("=")) {
+                        self.punct("=")?;
+                        let line = self.line();
+                        let value = self.word()?;
+                        next = value.parse().ok().context(ParseSnafu {
+                            line,
+                            message: format!("expected a number, found `{}`", value),
+                        })?;
+                    }
+                    values.push((name, next));
+                    next += 1;
+                }
+                Some(token) => return self.unexpected(token, line, "an enumeration value"),
+                None => return self.eof("`}`"),
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn ty(&mut self) -> Result<TypeExpr> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Punct("[")) => {
+                let inner = self.ty()?;
+                self.punct("]")?;
+                Ok(TypeExpr::List(Box::new(inner)))
+            }
+            Some(Token::Word(word)) if word == "Option" => {
+                self.punct("<")?;
+                let inner = self.ty()?;
+                self.punct(">")?;
+                Ok(TypeExpr::Option(Box::new(inner)))
+            }
+            Some(Token::Word(word)) => Ok(TypeExpr::Named(word)),
+            Some(token) => self.unexpected(token, line, "a type"),
+            None => self.eof("a type"),
+        }
+    }
+
+    fn relationship(&mut self, number: i64) -> Result<RelDef> {
+        let first = self.word()?;
+
+        match self.peek() {
+            Some(Token::Word(word)) if word == "isa" => {
+                let line = self.line();
+                self.next();
+
+                // As with associatives, there may be no subtypes at all.
+                let mut subtypes = Vec::new();
+                if self.tokens.get(self.pos).map(|l| l.line) == Some(line) {
+                    subtypes.push(self.word()?);
+                    while self.peek() == Some(&Token::Punct(",")) {
+                        self.next();
+                        subtypes.push(self.word()?);
+                    }
+                }
+
+                Ok(RelDef::Isa {
+                    number,
+                    supertype: first,
+                    subtypes,
+                })
+            }
+            Some(Token::Word(word)) if word == "formalizes" => {
+                let line = self.line();
+                self.next();
+
+                // There may be no sides at all, in which case the next token is
+                // on another line.
+                let mut sides = Vec::new();
+                if self.tokens.get(self.pos).map(|l| l.line) == Some(line) {
+                    loop {
+                        let object = self.word()?;
+                        let side = self.side(object)?;
+                        let phrase = match self.peek() {
+                            Some(Token::Str(_)) => Some(self.string()?),
+                            _ => None,
+                        };
+                        sides.push((side, phrase));
+
+                        if self.peek() != Some(&Token::Punct("<->")) {
+                            break;
+                        }
+                        self.next();
+                    }
+                }
+
+                Ok(RelDef::Associative {
+                    number,
+                    associative: first,
+                    sides,
+                })
+            }
+            _ => {
+                let from = self.side(first)?;
+                let phrase = self.string()?;
+                self.punct("->")?;
+                let to = self.word()?;
+                let to = self.side(to)?;
+                let referent_phrase = match self.peek() {
+                    Some(Token::Str(_)) => Some(self.string()?),
+                    _ => None,
+                };
+                let attribute = match self.peek() {
+                    Some(Token::Word(word)) if word == "as" => {
+                        self.next();
+                        Some(self.word()?)
+                    }
+                    _ => None,
+                };
+
+                Ok(RelDef::Binary {
+                    number,
+                    from,
+                    phrase,
+                    to,
+                    referent_phrase,
+                    attribute,
+                })
+            }
+        }
+    }
+
+    fn side(&mut self, object: String) -> Result<Side> {
+        let line = self.line();
+        let card = self.word()?;
+        let (many, conditional) = match card.as_str() {
+            "1" => (false, false),
+            "1c" => (false, true),
+            "M" => (true, false),
+            "Mc" => (true, true),
+            _ => {
+                return ParseSnafu {
+                    line,
+                    message: format!("expected one of `1`, `1c`, `M`, `Mc`, found `{}`", card),
+                }
+                .fail()
+                .map_err(|e| e.into())
+            }
+        };
+
+        Ok(Side {
+            object,
+            many,
+            conditional,
+        })
+    }
+
+    /// Collect any doc comments into a description
+    ///
+    fn docs(&mut self) -> String {
+        let mut lines = Vec::new();
+        while let Some(Token::Doc(doc)) = self.peek() {
+            lines.push(doc.to_owned());
+            self.pos += 1;
+        }
+
+        lines.join("\n")
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<()> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Word(word)) if word == keyword => Ok(()),
+            Some(token) => self.unexpected(token, line, &format!("`{}`", keyword)),
+            None => self.eof(&format!("`{}`", keyword)),
+        }
+    }
+
+    fn punct(&mut self, punct: &'static str) -> Result<()> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Punct(p)) if p == punct => Ok(()),
+            Some(token) => self.unexpected(token, line, &format!("`{}`", punct)),
+            None => self.eof(&format!("`{}`", punct)),
+        }
+    }
+
+    fn word(&mut self) -> Result<String> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(token) => self.unexpected(token, line, "a name"),
+            None => self.eof("a name"),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Str(string)) => Ok(string),
+            Some(token) => self.unexpected(token, line, "a string"),
+            None => self.eof("a string"),
+        }
+    }
+
+    /// A word or a string
+    ///
+    fn name(&mut self) -> Result<String> {
+        let line = self.line();
+        match self.next() {
+            Some(Token::Word(name)) | Some(Token::Str(name)) => Ok(name),
+            Some(token) => self.unexpected(token, line, "a name"),
+            None => self.eof("a name"),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|l| &l.token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|l| l.token.clone());
+        self.pos += 1;
+
+        token
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|l| l.line)
+            .unwrap_or(1)
+    }
+
+    fn unexpected<T>(&self, token: Token, line: usize, expected: &str) -> Result<T> {
+        let found = match token {
+            Token::Doc(_) => "a doc comment".to_owned(),
+            Token::Word(word) => format!("`{}`", word),
+            Token::Str(string) => format!("{:?}", string),
+            Token::Punct(punct) => format!("`{}`", punct),
+        };
+
+        ParseSnafu {
+            line,
+            message: format!("expected {}, found {}", expected, found),
+        }
+        .fail()
+        .map_err(|e| e.into())
+    }
+
+    fn eof<T>(&self, expected: &str) -> Result<T> {
+        ParseSnafu {
+            line: self.line(),
+            message: format!("expected {}, found end of file", expected),
+        }
+        .fail()
+        .map_err(|e| e.into())
+    }
+}
+
+fn relationship_number(word: &str) -> Option<i64> {
+    word.strip_prefix('R').and_then(|n| n.parse().ok())
+}
+
+impl Model {
+    fn build(self) -> Result<Domain> {
+        let mut builder = ModelBuilder::new(self.name, self.description);
+        let mut types = HashMap::new();
+
+        for (name, description, values) in self.enums {
+            let store = builder.sarzak_mut();
+            let enumeration = Enumeration::new(description, name.to_owned(), store);
+            for (name, value) in values {
+                EnumValue::new(name, value, &enumeration, store);
+            }
+            let ty = Ty::new_enumeration(&enumeration, store);
+            types.insert(name.to_upper_camel_case(), ty);
+        }
+
+        for (name, path, ctor) in self.externals {
+            let store = builder.sarzak_mut();
+            let external = External::new(ctor, name.to_owned(), path, store);
+            let ty = Ty::new_external(&external, store);
+            types.insert(name.to_upper_camel_case(), ty);
+        }
+
+        // Create all of the objects first, so that they may be referenced as
+        // types.
+        for object in &self.objects {
+            builder = builder
+                .object(&object.name)
+                .description(&object.description);
+        }
+
+        for object in self.objects {
+            builder = builder.object(&object.name);
+            for (name, ty, line) in object.attributes {
+                let ty = resolve(&ty, &types, builder.sarzak_mut(), line)?;
+                builder = builder.attr(name, ty);
+            }
+            for state in object.states {
+                builder = builder.state(state);
+            }
+            for event in object.events {
+                builder = builder.event(event);
+            }
+        }
+
+        for (relationship, line) in self.relationships {
+            let objects = match &relationship {
+                RelDef::Binary { from, to, .. } => vec![&from.object, &to.object],
+                RelDef::Isa {
+                    supertype,
+                    subtypes,
+                    ..
+                } => std::iter::once(supertype).chain(subtypes.iter()).collect(),
+                RelDef::Associative {
+                    associative, sides, ..
+                } => std::iter::once(associative)
+                    .chain(sides.iter().map(|(side, _)| &side.object))
+                    .collect(),
+            };
+            for name in objects {
+                ensure!(
+                    builder
+                        .sarzak()
+                        .exhume_object_id_by_name(&name.to_upper_camel_case())
+                        .is_some(),
+                    ParseSnafu {
+                        line,
+                        message: format!("unknown object `{}`", name)
+                    }
+                );
+            }
+
+            builder = match relationship {
+                RelDef::Binary {
+                    number,
+                    from,
+                    phrase,
+                    to,
+                    referent_phrase,
+                    attribute,
+                } => {
+                    let mut builder = builder.binary(
+                        number,
+                        from.object.as_str(),
+                        multiplicity(from.many),
+                        to.object.as_str(),
+                        multiplicity(to.many),
+                        phrase.as_str(),
+                    );
+                    if from.conditional {
+                        builder = builder.referrer_conditional();
+                    }
+                    if to.conditional {
+                        builder = builder.conditional();
+                    }
+                    if let Some(phrase) = referent_phrase {
+                        builder = builder.referent_phrase(phrase);
+                    }
+                    if let Some(attribute) = attribute {
+                        builder = builder.referential_attribute(attribute);
+                    }
+                    builder
+                }
+                RelDef::Isa {
+                    number,
+                    supertype,
+                    subtypes,
+                } => builder.isa(number, &supertype, &subtypes.iter().collect::<Vec<_>>()),
+                RelDef::Associative {
+                    number,
+                    associative,
+                    sides,
+                } => {
+                    let cards: Vec<_> = sides
+                        .iter()
+                        .map(|(side, _)| (side.object.as_str(), multiplicity(side.many)))
+                        .collect();
+                    let mut builder =
+                        builder.associative_sides(number, associative.as_str(), &cards);
+                    for (n, (side, phrase)) in sides.iter().enumerate() {
+                        if side.conditional {
+                            builder = builder.side_conditional(n);
+                        }
+                        if let Some(phrase) = phrase {
+                            builder = builder.side_phrase(n, phrase);
+                        }
+                    }
+                    builder
+                }
+            };
+        }
+
+        builder.build()
+    }
+}
+
+fn multiplicity(many: bool) -> Multiplicity {
+    if many {
+        Multiplicity::Many
+    } else {
+        Multiplicity::One
+    }
+}
+
+fn resolve(
+    ty: &TypeExpr,
+    types: &HashMap<String, Arc<RwLock<Ty>>>,
+    store: &mut SarzakStore,
+    line: usize,
+) -> Result<Ty> {
+    let ty = match ty {
+        TypeExpr::List(inner) => {
+            let inner = resolve(inner, types, store, line)?;
+            let inner = store.exhume_ty(&inner.id()).unwrap();
+            let list = List::new(&inner, store);
+            Ty::new_list(&list, store)
+        }
+        TypeExpr::Option(inner) => {
+            let inner = resolve(inner, types, store, line)?;
+            let inner = store.exhume_ty(&inner.id()).unwrap();
+            let option = ZOption::new(&inner, store);
+            Ty::new_z_option(&option, store)
+        }
+        TypeExpr::Named(name) => match name.as_str() {
+            "Boolean" => return Ok(Ty::Boolean(BOOLEAN)),
+            "Duration" => return Ok(Ty::Duration(DURATION)),
+            "Float" => return Ok(Ty::Float(FLOAT)),
+            "Integer" => return Ok(Ty::Integer(INTEGER)),
+            "String" => return Ok(Ty::ZString(Z_STRING)),
+            "Timestamp" => return Ok(Ty::Timestamp(TIMESTAMP)),
+            "Uuid" => return Ok(Ty::ZUuid(Z_UUID)),
+            name => {
+                let camel = name.to_upper_camel_case();
+                if let Some(ty) = types.get(&camel) {
+                    ty.clone()
+                } else if let Some(id) = store.exhume_object_id_by_name(&camel) {
+                    store.exhume_ty(&id).unwrap()
+                } else if let Some((signed, bits)) = sized_integer(name) {
+                    let int = SizedInteger::new(bits, signed, store);
+                    Ty::new_sized_integer(&int, store)
+                } else {
+                    return ParseSnafu {
+                        line,
+                        message: format!("unknown type `{}`", name),
+                    }
+                    .fail()
+                    .map_err(|e| e.into());
+                }
+            }
+        },
+    };
+
+    let ty = ty.read().unwrap().to_owned();
+    Ok(ty)
+}
+
+fn sized_integer(name: &str) -> Option<(bool, i64)> {
+    let (signed, bits) = if let Some(bits) = name.strip_prefix('i') {
+        (true, bits)
+    } else if let Some(bits) = name.strip_prefix('u') {
+        (false, bits)
+    } else {
+        return None;
+    };
+
+    match bits.parse() {
+        Ok(bits @ (8 | 16 | 32 | 64 | 128)) => Some((signed, bits)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETS: &str = r#"
+/// A domain about pets
+domain pets
+
+enum Color {
+    Black,
+    Brown,
+    Spotted = 10,
+}
+
+/// Something with fur
+///
+/// Or feathers, I suppose.
+object Animal {
+    age: u8
+    color: Option<Color>
+    id: Uuid
+    name: String
+    state "Chasing Cars"
+    state Sleeping
+    event Wake
+}
+
+object Cat {}
+
+object Dog {}
+
+object Owner {
+    id: Uuid
+    nicknames: [String]
+}
+
+object Ownership {}
+
+R1: Animal isa Cat, Dog
+R2: Dog M "is owned by" -> Owner 1c "owns" as owner_id
+R3: Ownership formalizes Cat Mc "is owned by" <-> Owner M "owns"
+"#;
+
+    #[test]
+    fn test_round_trip() {
+        let domain = parse(PETS).unwrap();
+
+        assert_eq!(domain.name(), "pets");
+        assert_eq!(domain.sarzak().iter_object().count(), 5);
+        assert_eq!(domain.sarzak().iter_attribute().count(), 6);
+        assert_eq!(domain.sarzak().iter_relationship().count(), 3);
+        assert_eq!(domain.merlin().iter_x_box().count(), 5);
+
+        let text = print(&domain);
+        assert_eq!(text, print(&parse(&text).unwrap()));
+        assert_eq!(text.trim(), PETS.trim());
+    }
+
+    #[test]
+    fn test_associative_sides() {
+        let text = r#"
+domain vets
+
+object Clinic {}
+
+object Pet {}
+
+object Vet {}
+
+object Visit {}
+
+R1: Visit formalizes Clinic 1 <-> Pet M "is seen at" <-> Vet Mc
+R2: Pet formalizes Vet 1c "treats"
+R3: Clinic formalizes
+"#;
+        let domain = parse(text).unwrap();
+
+        assert_eq!(domain.sarzak().iter_associative().count(), 3);
+        assert_eq!(domain.sarzak().iter_an_associative_referent().count(), 4);
+        assert_eq!(print(&domain).trim(), text.trim());
+    }
+
+    #[test]
+    fn test_isa_without_subtypes() {
+        let text = r#"
+domain pets
+
+object Animal {}
+
+object Dog {}
+
+R1: Animal isa
+R2: Dog isa
+"#;
+        let domain = parse(text).unwrap();
+
+        assert_eq!(domain.sarzak().iter_isa().count(), 2);
+        assert_eq!(domain.sarzak().iter_subtype().count(), 0);
+        assert_eq!(print(&domain).trim(), text.trim());
+        assert_eq!(print(&parse(&print(&domain)).unwrap()), print(&domain));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = parse("domain pets\nobject Dog {\n    name: Strang\n}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 3: unknown type `Strang`"
+        );
+
+        let err = parse("domain pets\nR1: Dog M \"is\" -> Cat 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 2: unknown object `Dog`"
+        );
+
+        let err = parse(
+            "domain pets
+object Dog {}
+object dog {}",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 3: duplicate object `dog`"
+        );
+
+        let err = parse(
+            "domain pets
+object Dog {}
+R1: Dog isa Dog
+R1: Dog isa Dog",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 4: duplicate relationship `R1`"
+        );
+
+        let err = parse(
+            "domain pets
+R1: Dog → Cat",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error on line 2: unexpected character `→`"
+        );
+
+        let err = parse(
+            "domain pets
+object Dog {
+    name: é8
+}",
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "parse error on line 3: unknown type `é8`");
+    }
+}
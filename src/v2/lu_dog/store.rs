@@ -14,9 +14,11 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`CharLiteral`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -55,6 +57,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`Map`]
 //! * [`MapElement`]
@@ -92,6 +95,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog-object-store-definition"}}}
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -117,7 +121,8 @@ use crate::v2::lu_dog::types::{
     PathElement, Pattern, RangeExpression, ResultStatement, Span, Statement, StaticMethodCall,
     StringBit, StringLiteral, StructExpression, StructField, StructGeneric, TupleField, TypeCast,
     Unary, Unit, UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct,
-    XFuture, XIf, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore,
+    XBreak, XContinue, XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn,
+    XValue, XWhile, ZObjectStore,
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -129,9 +134,11 @@ pub struct ObjectStore {
     body: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Body>>>>>,
     boolean_literal: Rc<RefCell<HashMap<Uuid, Rc<RefCell<BooleanLiteral>>>>>,
     boolean_operator: Rc<RefCell<HashMap<Uuid, Rc<RefCell<BooleanOperator>>>>>,
+    x_break: Rc<RefCell<HashMap<Uuid, Rc<RefCell<XBreak>>>>>,
     call: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Call>>>>>,
     char_literal: Rc<RefCell<HashMap<Uuid, Rc<RefCell<CharLiteral>>>>>,
     comparison: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Comparison>>>>>,
+    x_continue: Rc<RefCell<HashMap<Uuid, Rc<RefCell<XContinue>>>>>,
     data_structure: Rc<RefCell<HashMap<Uuid, Rc<RefCell<DataStructure>>>>>,
     dwarf_source_file: Rc<RefCell<HashMap<Uuid, Rc<RefCell<DwarfSourceFile>>>>>,
     enum_field: Rc<RefCell<HashMap<Uuid, Rc<RefCell<EnumField>>>>>,
@@ -173,6 +180,7 @@ pub struct ObjectStore {
     list_expression: Rc<RefCell<HashMap<Uuid, Rc<RefCell<ListExpression>>>>>,
     literal: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Literal>>>>>,
     local_variable: Rc<RefCell<HashMap<Uuid, Rc<RefCell<LocalVariable>>>>>,
+    x_loop: Rc<RefCell<HashMap<Uuid, Rc<RefCell<XLoop>>>>>,
     x_macro: Rc<RefCell<HashMap<Uuid, Rc<RefCell<XMacro>>>>>,
     map: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Map>>>>>,
     map_element: Rc<RefCell<HashMap<Uuid, Rc<RefCell<MapElement>>>>>,
@@ -213,6 +221,7 @@ pub struct ObjectStore {
     value_type: Rc<RefCell<HashMap<Uuid, Rc<RefCell<ValueType>>>>>,
     variable: Rc<RefCell<HashMap<Uuid, Rc<RefCell<Variable>>>>>,
     variable_expression: Rc<RefCell<HashMap<Uuid, Rc<RefCell<VariableExpression>>>>>,
+    x_while: Rc<RefCell<HashMap<Uuid, Rc<RefCell<XWhile>>>>>,
 }
 
 impl Clone for ObjectStore {
@@ -225,9 +234,11 @@ impl Clone for ObjectStore {
             body: Rc::new(RefCell::new(self.body.borrow().clone())),
             boolean_literal: Rc::new(RefCell::new(self.boolean_literal.borrow().clone())),
             boolean_operator: Rc::new(RefCell::new(self.boolean_operator.borrow().clone())),
+            x_break: Rc::new(RefCell::new(self.x_break.borrow().clone())),
             call: Rc::new(RefCell::new(self.call.borrow().clone())),
             char_literal: Rc::new(RefCell::new(self.char_literal.borrow().clone())),
             comparison: Rc::new(RefCell::new(self.comparison.borrow().clone())),
+            x_continue: Rc::new(RefCell::new(self.x_continue.borrow().clone())),
             data_structure: Rc::new(RefCell::new(self.data_structure.borrow().clone())),
             dwarf_source_file: Rc::new(RefCell::new(self.dwarf_source_file.borrow().clone())),
             enum_field: Rc::new(RefCell::new(self.enum_field.borrow().clone())),
@@ -273,6 +284,7 @@ impl Clone for ObjectStore {
             list_expression: Rc::new(RefCell::new(self.list_expression.borrow().clone())),
             literal: Rc::new(RefCell::new(self.literal.borrow().clone())),
             local_variable: Rc::new(RefCell::new(self.local_variable.borrow().clone())),
+            x_loop: Rc::new(RefCell::new(self.x_loop.borrow().clone())),
             x_macro: Rc::new(RefCell::new(self.x_macro.borrow().clone())),
             map: Rc::new(RefCell::new(self.map.borrow().clone())),
             map_element: Rc::new(RefCell::new(self.map_element.borrow().clone())),
@@ -321,6 +333,7 @@ impl Clone for ObjectStore {
             value_type: Rc::new(RefCell::new(self.value_type.borrow().clone())),
             variable: Rc::new(RefCell::new(self.variable.borrow().clone())),
             variable_expression: Rc::new(RefCell::new(self.variable_expression.borrow().clone())),
+            x_while: Rc::new(RefCell::new(self.x_while.borrow().clone())),
         }
     }
 }
@@ -334,9 +347,11 @@ impl ObjectStore {
             body: Rc::new(RefCell::new(HashMap::default())),
             boolean_literal: Rc::new(RefCell::new(HashMap::default())),
             boolean_operator: Rc::new(RefCell::new(HashMap::default())),
+            x_break: Rc::new(RefCell::new(HashMap::default())),
             call: Rc::new(RefCell::new(HashMap::default())),
             char_literal: Rc::new(RefCell::new(HashMap::default())),
             comparison: Rc::new(RefCell::new(HashMap::default())),
+            x_continue: Rc::new(RefCell::new(HashMap::default())),
             data_structure: Rc::new(RefCell::new(HashMap::default())),
             dwarf_source_file: Rc::new(RefCell::new(HashMap::default())),
             enum_field: Rc::new(RefCell::new(HashMap::default())),
@@ -378,6 +393,7 @@ impl ObjectStore {
             list_expression: Rc::new(RefCell::new(HashMap::default())),
             literal: Rc::new(RefCell::new(HashMap::default())),
             local_variable: Rc::new(RefCell::new(HashMap::default())),
+            x_loop: Rc::new(RefCell::new(HashMap::default())),
             x_macro: Rc::new(RefCell::new(HashMap::default())),
             map: Rc::new(RefCell::new(HashMap::default())),
             map_element: Rc::new(RefCell::new(HashMap::default())),
@@ -418,6 +434,7 @@ impl ObjectStore {
             value_type: Rc::new(RefCell::new(HashMap::default())),
             variable: Rc::new(RefCell::new(HashMap::default())),
             variable_expression: Rc::new(RefCell::new(HashMap::default())),
+            x_while: Rc::new(RefCell::new(HashMap::default())),
         };
 
         // Initialize Singleton Subtypes
@@ -458,6 +475,9 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_break
+            .borrow_mut()
+            .extend(other.x_break.borrow().iter().map(|(k, v)| (*k, v.clone())));
         self.call
             .borrow_mut()
             .extend(other.call.borrow().iter().map(|(k, v)| (*k, v.clone())));
@@ -475,6 +495,13 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_continue.borrow_mut().extend(
+            other
+                .x_continue
+                .borrow()
+                .iter()
+                .map(|(k, v)| (*k, v.clone())),
+        );
         self.data_structure.borrow_mut().extend(
             other
                 .data_structure
@@ -714,6 +741,9 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_loop
+            .borrow_mut()
+            .extend(other.x_loop.borrow().iter().map(|(k, v)| (*k, v.clone())));
         self.x_macro
             .borrow_mut()
             .extend(other.x_macro.borrow().iter().map(|(k, v)| (*k, v.clone())));
@@ -938,6 +968,9 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_while
+            .borrow_mut()
+            .extend(other.x_while.borrow().iter().map(|(k, v)| (*k, v.clone())));
     }
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog-object-store-methods"}}}
     /// Inter (insert) [`Argument`] into the store.
@@ -1195,6 +1228,41 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    pub fn inter_x_break(&mut self, x_break: Rc<RefCell<XBreak>>) {
+        let read = x_break.borrow();
+        self.x_break.borrow_mut().insert(read.id, x_break.clone());
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    pub fn exhume_x_break(&self, id: &Uuid) -> Option<Rc<RefCell<XBreak>>> {
+        self.x_break.borrow().get(id).map(|x_break| x_break.clone())
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    pub fn exorcise_x_break(&mut self, id: &Uuid) -> Option<Rc<RefCell<XBreak>>> {
+        self.x_break
+            .borrow_mut()
+            .remove(id)
+            .map(|x_break| x_break.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    pub fn iter_x_break(&self) -> impl Iterator<Item = Rc<RefCell<XBreak>>> + '_ {
+        let values: Vec<Rc<RefCell<XBreak>>> = self
+            .x_break
+            .borrow()
+            .values()
+            .map(|x_break| x_break.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     pub fn inter_call(&mut self, call: Rc<RefCell<Call>>) {
@@ -1307,6 +1375,46 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    pub fn inter_x_continue(&mut self, x_continue: Rc<RefCell<XContinue>>) {
+        let read = x_continue.borrow();
+        self.x_continue
+            .borrow_mut()
+            .insert(read.id, x_continue.clone());
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    pub fn exhume_x_continue(&self, id: &Uuid) -> Option<Rc<RefCell<XContinue>>> {
+        self.x_continue
+            .borrow()
+            .get(id)
+            .map(|x_continue| x_continue.clone())
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    pub fn exorcise_x_continue(&mut self, id: &Uuid) -> Option<Rc<RefCell<XContinue>>> {
+        self.x_continue
+            .borrow_mut()
+            .remove(id)
+            .map(|x_continue| x_continue.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    pub fn iter_x_continue(&self) -> impl Iterator<Item = Rc<RefCell<XContinue>>> + '_ {
+        let values: Vec<Rc<RefCell<XContinue>>> = self
+            .x_continue
+            .borrow()
+            .values()
+            .map(|x_continue| x_continue.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     pub fn inter_data_structure(&mut self, data_structure: Rc<RefCell<DataStructure>>) {
@@ -2859,6 +2967,41 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    pub fn inter_x_loop(&mut self, x_loop: Rc<RefCell<XLoop>>) {
+        let read = x_loop.borrow();
+        self.x_loop.borrow_mut().insert(read.id, x_loop.clone());
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    pub fn exhume_x_loop(&self, id: &Uuid) -> Option<Rc<RefCell<XLoop>>> {
+        self.x_loop.borrow().get(id).map(|x_loop| x_loop.clone())
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    pub fn exorcise_x_loop(&mut self, id: &Uuid) -> Option<Rc<RefCell<XLoop>>> {
+        self.x_loop
+            .borrow_mut()
+            .remove(id)
+            .map(|x_loop| x_loop.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    pub fn iter_x_loop(&self) -> impl Iterator<Item = Rc<RefCell<XLoop>>> + '_ {
+        let values: Vec<Rc<RefCell<XLoop>>> = self
+            .x_loop
+            .borrow()
+            .values()
+            .map(|x_loop| x_loop.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     pub fn inter_x_macro(&mut self, x_macro: Rc<RefCell<XMacro>>) {
@@ -4344,6 +4487,41 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    pub fn inter_x_while(&mut self, x_while: Rc<RefCell<XWhile>>) {
+        let read = x_while.borrow();
+        self.x_while.borrow_mut().insert(read.id, x_while.clone());
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    pub fn exhume_x_while(&self, id: &Uuid) -> Option<Rc<RefCell<XWhile>>> {
+        self.x_while.borrow().get(id).map(|x_while| x_while.clone())
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    pub fn exorcise_x_while(&mut self, id: &Uuid) -> Option<Rc<RefCell<XWhile>>> {
+        self.x_while
+            .borrow_mut()
+            .remove(id)
+            .map(|x_while| x_while.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    pub fn iter_x_while(&self) -> impl Iterator<Item = Rc<RefCell<XWhile>>> + '_ {
+        let values: Vec<Rc<RefCell<XWhile>>> = self
+            .x_while
+            .borrow()
+            .values()
+            .map(|x_while| x_while.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog-object-store-persistence"}}}
@@ -4454,6 +4632,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Break.
+        {
+            let path = path.join("x_break");
+            fs::create_dir_all(&path)?;
+            for x_break in self.x_break.borrow().values() {
+                let path = path.join(format!("{}.json", x_break.borrow().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_break)?;
+            }
+        }
+
         // Persist Call.
         {
             let path = path.join("call");
@@ -4490,6 +4680,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Continue.
+        {
+            let path = path.join("x_continue");
+            fs::create_dir_all(&path)?;
+            for x_continue in self.x_continue.borrow().values() {
+                let path = path.join(format!("{}.json", x_continue.borrow().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_continue)?;
+            }
+        }
+
         // Persist Data Structure.
         {
             let path = path.join("data_structure");
@@ -4946,6 +5148,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Loop.
+        {
+            let path = path.join("x_loop");
+            fs::create_dir_all(&path)?;
+            for x_loop in self.x_loop.borrow().values() {
+                let path = path.join(format!("{}.json", x_loop.borrow().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_loop)?;
+            }
+        }
+
         // Persist Macro.
         {
             let path = path.join("x_macro");
@@ -5390,6 +5604,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist While.
+        {
+            let path = path.join("x_while");
+            fs::create_dir_all(&path)?;
+            for x_while in self.x_while.borrow().values() {
+                let path = path.join(format!("{}.json", x_while.borrow().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_while)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -5537,6 +5763,25 @@ impl ObjectStore {
             }
         }
 
+        // Load Break. Stores persisted before there were breaks don't have any.
+        {
+            let path = path.join("x_break");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_break: Rc<RefCell<XBreak>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_break
+                        .borrow_mut()
+                        .insert(x_break.borrow().id, x_break.clone());
+                }
+            }
+        }
+
         // Load Call.
         {
             let path = path.join("call");
@@ -5588,6 +5833,26 @@ impl ObjectStore {
             }
         }
 
+        // Load Continue. Stores persisted before there were continues don't have
+        // any.
+        {
+            let path = path.join("x_continue");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_continue: Rc<RefCell<XContinue>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_continue
+                        .borrow_mut()
+                        .insert(x_continue.borrow().id, x_continue.clone());
+                }
+            }
+        }
+
         // Load Data Structure.
         {
             let path = path.join("data_structure");
@@ -6255,6 +6520,25 @@ impl ObjectStore {
             }
         }
 
+        // Load Loop. Stores persisted before there were loops don't have any.
+        {
+            let path = path.join("x_loop");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_loop: Rc<RefCell<XLoop>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_loop
+                        .borrow_mut()
+                        .insert(x_loop.borrow().id, x_loop.clone());
+                }
+            }
+        }
+
         // Load Macro.
         {
             let path = path.join("x_macro");
@@ -6900,6 +7184,26 @@ impl ObjectStore {
             }
         }
 
+        // Load While. Stores persisted before there were while loops don't have
+        // any.
+        {
+            let path = path.join("x_while");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_while: Rc<RefCell<XWhile>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_while
+                        .borrow_mut()
+                        .insert(x_while.borrow().id, x_while.clone());
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"addition_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"addition_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"addition_assignment-const-documentation"}}}
+/// Compound assignment with the addition operator: `+=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"addition_assignment-const-definition"}}}
+pub const ADDITION_ASSIGNMENT: Uuid = uuid!["5f6f66e8-0262-5074-b51d-665d79606d44"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AdditionAssignment;
+
+impl AdditionAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        ADDITION_ASSIGNMENT
+    }
+}
+
+impl Default for AdditionAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
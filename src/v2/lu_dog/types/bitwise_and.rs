@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_and-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and-const-documentation"}}}
+/// The bitwise and operator: `&`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and-const-definition"}}}
+pub const BITWISE_AND: Uuid = uuid!["9d241aa1-8cee-50a1-bffd-d97ce815b42c"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BitwiseAnd;
+
+impl BitwiseAnd {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_AND
+    }
+}
+
+impl Default for BitwiseAnd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
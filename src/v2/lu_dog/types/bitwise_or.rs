@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_or-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or-const-documentation"}}}
+/// The bitwise or operator: `|`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or-const-definition"}}}
+pub const BITWISE_OR: Uuid = uuid!["88ef19a2-1c40-5dd9-9f66-262bf2bb96e1"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BitwiseOr;
+
+impl BitwiseOr {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_OR
+    }
+}
+
+impl Default for BitwiseOr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_xor-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor-const-documentation"}}}
+/// The bitwise exclusive or operator: `^`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor-const-definition"}}}
+pub const BITWISE_XOR: Uuid = uuid!["ca4e5715-d4f9-5d78-a9c4-2de0b3478501"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BitwiseXor;
+
+impl BitwiseXor {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_XOR
+    }
+}
+
+impl Default for BitwiseXor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
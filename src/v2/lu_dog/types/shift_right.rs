@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"shift_right-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right-const-documentation"}}}
+/// The arithmetic right shift operator: `>>`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right-const-definition"}}}
+pub const SHIFT_RIGHT: Uuid = uuid!["086b930e-5dea-574d-a772-eb1631829cdd"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ShiftRight;
+
+impl ShiftRight {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        SHIFT_RIGHT
+    }
+}
+
+impl Default for ShiftRight {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
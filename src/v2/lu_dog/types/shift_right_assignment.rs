@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"shift_right_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right_assignment-const-documentation"}}}
+/// Compound assignment with the shift right operator: `>>=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_right_assignment-const-definition"}}}
+pub const SHIFT_RIGHT_ASSIGNMENT: Uuid = uuid!["7ff572ba-a140-5132-b48c-a565b115dc8e"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ShiftRightAssignment;
+
+impl ShiftRightAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        SHIFT_RIGHT_ASSIGNMENT
+    }
+}
+
+impl Default for ShiftRightAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
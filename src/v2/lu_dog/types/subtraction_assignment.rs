@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"subtraction_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"subtraction_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"subtraction_assignment-const-documentation"}}}
+/// Compound assignment with the subtraction operator: `-=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"subtraction_assignment-const-definition"}}}
+pub const SUBTRACTION_ASSIGNMENT: Uuid = uuid!["dd8aa265-b187-559a-b62b-580dab9e8658"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SubtractionAssignment;
+
+impl SubtractionAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        SUBTRACTION_ASSIGNMENT
+    }
+}
+
+impl Default for SubtractionAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,75 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_break-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-use-statements"}}}
+use std::cell::RefCell;
+use std::rc::Rc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog::types::expression::Expression;
+use crate::v2::lu_dog::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog::store::ObjectStore as LuDogStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-documentation"}}}
+/// The Break Expression
+///
+/// Leaves the innermost loop, or the loop with the label, optionally with a value.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct XBreak {
+    pub id: Uuid,
+    pub label: String,
+    /// R124: [`XBreak`] 'exits with' [`Expression`]
+    pub expression: Option<Uuid>,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-implementation"}}}
+impl XBreak {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-new"}}}
+    /// Inter a new 'Break' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        expression: Option<&Rc<RefCell<Expression>>>,
+        store: &mut LuDogStore,
+    ) -> Rc<RefCell<XBreak>> {
+        let id = Uuid::new_v4();
+        let new = Rc::new(RefCell::new(XBreak {
+            id,
+            label,
+            expression: expression.map(|expression| expression.borrow().id),
+        }));
+        store.inter_x_break(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-nav-forward-cond-to-expression"}}}
+    /// Navigate to [`Expression`] across R124(1-*c)
+    pub fn r124_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        match self.expression {
+            Some(ref expression) => vec![store.exhume_expression(&expression).unwrap()],
+            None => Vec::new(),
+        }
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XBreak(id) = expression.borrow().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,56 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_continue-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-use-statements"}}}
+use std::cell::RefCell;
+use std::rc::Rc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog::types::expression::Expression;
+use crate::v2::lu_dog::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog::store::ObjectStore as LuDogStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-documentation"}}}
+/// The Continue Expression
+///
+/// Skips to the next iteration of the innermost loop, or of the loop with the label.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct XContinue {
+    pub id: Uuid,
+    pub label: String,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-implementation"}}}
+impl XContinue {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-impl-new"}}}
+    /// Inter a new 'Continue' in the store, and return it's `id`.
+    pub fn new(label: String, store: &mut LuDogStore) -> Rc<RefCell<XContinue>> {
+        let id = Uuid::new_v4();
+        let new = Rc::new(RefCell::new(XContinue { id, label }));
+        store.inter_x_continue(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XContinue(id) = expression.borrow().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
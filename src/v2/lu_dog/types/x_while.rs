@@ -0,0 +1,81 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_while-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-use-statements"}}}
+use std::cell::RefCell;
+use std::rc::Rc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog::types::expression::Expression;
+use crate::v2::lu_dog::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog::store::ObjectStore as LuDogStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-documentation"}}}
+/// The While Expression
+///
+/// An expression that evaluates BLOCK for as long as TEST is true. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct XWhile {
+    pub id: Uuid,
+    pub label: String,
+    /// R121: [`XWhile`] 'executes a' [`Expression`]
+    pub block: Uuid,
+    /// R122: [`XWhile`] 'loops while' [`Expression`]
+    pub test: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl XWhile {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-new"}}}
+    /// Inter a new 'While' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        block: &Rc<RefCell<Expression>>,
+        test: &Rc<RefCell<Expression>>,
+        store: &mut LuDogStore,
+    ) -> Rc<RefCell<XWhile>> {
+        let id = Uuid::new_v4();
+        let new = Rc::new(RefCell::new(XWhile {
+            id,
+            label,
+            block: block.borrow().id,
+            test: test.borrow().id,
+        }));
+        store.inter_x_while(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R121(1-*)
+    pub fn r121_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        vec![store.exhume_expression(&self.block).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-test"}}}
+    /// Navigate to [`Expression`] across R122(1-*)
+    pub fn r122_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        vec![store.exhume_expression(&self.test).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(&'a self, store: &'a LuDogStore) -> Vec<Rc<RefCell<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XWhile(id) = expression.borrow().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
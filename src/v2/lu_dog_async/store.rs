@@ -14,8 +14,10 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -49,6 +51,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`XMatch`]
 //! * [`MethodCall`]
@@ -82,6 +85,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_async-object-store-definition"}}}
 use async_std::sync::Arc;
 use async_std::sync::RwLock;
@@ -113,12 +117,16 @@ use crate::v2::lu_dog_async::types::{
     NamedFieldExpression, ObjectWrapper, Operator, Parameter, PathElement, Pattern,
     RangeExpression, ResultStatement, Span, Statement, StaticMethodCall, StringLiteral,
     StructExpression, StructField, StructGeneric, TupleField, TypeCast, Unary, Unit,
-    UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct, XFuture, XIf,
-    XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore, ADDITION, AND,
-    ASSIGNMENT, CHAR, DIVISION, EMPTY, EMPTY_EXPRESSION, EQUAL, FALSE_LITERAL, FROM, FULL,
+    UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct, XBreak, XContinue,
+    XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, XWhile,
+    ZObjectStore, ADDITION, ADDITION_ASSIGNMENT, AND, ASSIGNMENT, BITWISE_AND,
+    BITWISE_AND_ASSIGNMENT, BITWISE_OR, BITWISE_OR_ASSIGNMENT, BITWISE_XOR, BITWISE_XOR_ASSIGNMENT,
+    CHAR, DIVISION, DIVISION_ASSIGNMENT, EMPTY, EMPTY_EXPRESSION, EQUAL, FALSE_LITERAL, FROM, FULL,
     GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT, LESS_THAN, LESS_THAN_OR_EQUAL,
-    MACRO_CALL, MULTIPLICATION, NEGATION, NOT, NOT_EQUAL, OR, RANGE, SUBTRACTION, TASK, TO,
-    TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
+    MACRO_CALL, MULTIPLICATION, MULTIPLICATION_ASSIGNMENT, NEGATION, NOT, NOT_EQUAL, OR, RANGE,
+    REMAINDER, REMAINDER_ASSIGNMENT, SHIFT_LEFT, SHIFT_LEFT_ASSIGNMENT, SHIFT_RIGHT,
+    SHIFT_RIGHT_ASSIGNMENT, SUBTRACTION, SUBTRACTION_ASSIGNMENT, TASK, TO, TO_INCLUSIVE,
+    TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
 };
 
 #[derive(Debug)]
@@ -136,11 +144,15 @@ pub struct ObjectStore {
     boolean_literal_free_list: async_std::sync::Mutex<Vec<usize>>,
     boolean_literal: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanLiteral>>>>>>,
     boolean_operator_free_list: async_std::sync::Mutex<Vec<usize>>,
+    x_break_free_list: async_std::sync::Mutex<Vec<usize>>,
     boolean_operator: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanOperator>>>>>>,
+    x_break: Arc<RwLock<Vec<Option<Arc<RwLock<XBreak>>>>>>,
     call_free_list: async_std::sync::Mutex<Vec<usize>>,
     call: Arc<RwLock<Vec<Option<Arc<RwLock<Call>>>>>>,
     comparison_free_list: async_std::sync::Mutex<Vec<usize>>,
+    x_continue_free_list: async_std::sync::Mutex<Vec<usize>>,
     comparison: Arc<RwLock<Vec<Option<Arc<RwLock<Comparison>>>>>>,
+    x_continue: Arc<RwLock<Vec<Option<Arc<RwLock<XContinue>>>>>>,
     data_structure_free_list: async_std::sync::Mutex<Vec<usize>>,
     data_structure: Arc<RwLock<Vec<Option<Arc<RwLock<DataStructure>>>>>>,
     dwarf_source_file_free_list: async_std::sync::Mutex<Vec<usize>>,
@@ -209,7 +221,9 @@ pub struct ObjectStore {
     literal_free_list: async_std::sync::Mutex<Vec<usize>>,
     literal: Arc<RwLock<Vec<Option<Arc<RwLock<Literal>>>>>>,
     local_variable_free_list: async_std::sync::Mutex<Vec<usize>>,
+    x_loop_free_list: async_std::sync::Mutex<Vec<usize>>,
     local_variable: Arc<RwLock<Vec<Option<Arc<RwLock<LocalVariable>>>>>>,
+    x_loop: Arc<RwLock<Vec<Option<Arc<RwLock<XLoop>>>>>>,
     x_macro_free_list: async_std::sync::Mutex<Vec<usize>>,
     x_macro: Arc<RwLock<Vec<Option<Arc<RwLock<XMacro>>>>>>,
     x_match_free_list: async_std::sync::Mutex<Vec<usize>>,
@@ -278,7 +292,9 @@ pub struct ObjectStore {
     variable_free_list: async_std::sync::Mutex<Vec<usize>>,
     variable: Arc<RwLock<Vec<Option<Arc<RwLock<Variable>>>>>>,
     variable_expression_free_list: async_std::sync::Mutex<Vec<usize>>,
+    x_while_free_list: async_std::sync::Mutex<Vec<usize>>,
     variable_expression: Arc<RwLock<Vec<Option<Arc<RwLock<VariableExpression>>>>>>,
+    x_while: Arc<RwLock<Vec<Option<Arc<RwLock<XWhile>>>>>>,
 }
 
 impl Serialize for ObjectStore {
@@ -286,7 +302,7 @@ impl Serialize for ObjectStore {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_struct("ObjectStore", 75)?;
+        let mut map = serializer.serialize_struct("ObjectStore", 79)?;
 
         let argument = futures::executor::block_on(async { self.argument.read().await }).clone();
         let values: Vec<Argument> = argument
@@ -386,6 +402,19 @@ impl Serialize for ObjectStore {
             .collect();
         map.serialize_field("boolean_operator", &values)?;
 
+        let x_break = futures::executor::block_on(async { self.x_break.read().await }).clone();
+        let values: Vec<XBreak> = x_break
+            .into_iter()
+            .filter_map(|x_break| {
+                if let Some(x_break) = x_break {
+                    Some(futures::executor::block_on(async { x_break.read().await }).clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        map.serialize_field("x_break", &values)?;
+
         let call = futures::executor::block_on(async { self.call.read().await }).clone();
         let values: Vec<Call> = call
             .into_iter()
@@ -413,6 +442,20 @@ impl Serialize for ObjectStore {
             .collect();
         map.serialize_field("comparison", &values)?;
 
+        let x_continue =
+            futures::executor::block_on(async { self.x_continue.read().await }).clone();
+        let values: Vec<XContinue> = x_continue
+            .into_iter()
+            .filter_map(|x_continue| {
+                if let Some(x_continue) = x_continue {
+                    Some(futures::executor::block_on(async { x_continue.read().await }).clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        map.serialize_field("x_continue", &values)?;
+
         let data_structure =
             futures::executor::block_on(async { self.data_structure.read().await }).clone();
         let values: Vec<DataStructure> = data_structure
@@ -889,6 +932,19 @@ impl Serialize for ObjectStore {
             .collect();
         map.serialize_field("local_variable", &values)?;
 
+        let x_loop = futures::executor::block_on(async { self.x_loop.read().await }).clone();
+        let values: Vec<XLoop> = x_loop
+            .into_iter()
+            .filter_map(|x_loop| {
+                if let Some(x_loop) = x_loop {
+                    Some(futures::executor::block_on(async { x_loop.read().await }).clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        map.serialize_field("x_loop", &values)?;
+
         let x_macro = futures::executor::block_on(async { self.x_macro.read().await }).clone();
         let values: Vec<XMacro> = x_macro
             .into_iter()
@@ -1359,6 +1415,19 @@ impl Serialize for ObjectStore {
             .collect();
         map.serialize_field("variable_expression", &values)?;
 
+        let x_while = futures::executor::block_on(async { self.x_while.read().await }).clone();
+        let values: Vec<XWhile> = x_while
+            .into_iter()
+            .filter_map(|x_while| {
+                if let Some(x_while) = x_while {
+                    Some(futures::executor::block_on(async { x_while.read().await }).clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        map.serialize_field("x_while", &values)?;
+
         map.end()
     }
 }
@@ -1376,8 +1445,10 @@ impl<'de> Deserialize<'de> for ObjectStore {
             Body,
             BooleanLiteral,
             BooleanOperator,
+            XBreak,
             Call,
             Comparison,
+            XContinue,
             DataStructure,
             DwarfSourceFile,
             EnumField,
@@ -1411,6 +1482,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
             ListExpression,
             Literal,
             LocalVariable,
+            XLoop,
             XMacro,
             XMatch,
             MethodCall,
@@ -1444,6 +1516,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
             ValueType,
             Variable,
             VariableExpression,
+            XWhile,
         }
         impl<'de> Deserialize<'de> for SerdeField {
             fn deserialize<D>(deserializer: D) -> Result<SerdeField, D::Error>
@@ -1468,8 +1541,10 @@ impl<'de> Deserialize<'de> for ObjectStore {
                             "body" => Ok(SerdeField::Body),
                             "boolean_literal" => Ok(SerdeField::BooleanLiteral),
                             "boolean_operator" => Ok(SerdeField::BooleanOperator),
+                            "x_break" => Ok(SerdeField::XBreak),
                             "call" => Ok(SerdeField::Call),
                             "comparison" => Ok(SerdeField::Comparison),
+                            "x_continue" => Ok(SerdeField::XContinue),
                             "data_structure" => Ok(SerdeField::DataStructure),
                             "dwarf_source_file" => Ok(SerdeField::DwarfSourceFile),
                             "enum_field" => Ok(SerdeField::EnumField),
@@ -1503,6 +1578,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
                             "list_expression" => Ok(SerdeField::ListExpression),
                             "literal" => Ok(SerdeField::Literal),
                             "local_variable" => Ok(SerdeField::LocalVariable),
+                            "x_loop" => Ok(SerdeField::XLoop),
                             "x_macro" => Ok(SerdeField::XMacro),
                             "x_match" => Ok(SerdeField::XMatch),
                             "method_call" => Ok(SerdeField::MethodCall),
@@ -1536,6 +1612,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
                             "value_type" => Ok(SerdeField::ValueType),
                             "variable" => Ok(SerdeField::Variable),
                             "variable_expression" => Ok(SerdeField::VariableExpression),
+                            "x_while" => Ok(SerdeField::XWhile),
                             _ => Err(de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1608,6 +1685,13 @@ impl<'de> Deserialize<'de> for ObjectStore {
                                 guard.push(Some(Arc::new(RwLock::new(value))));
                             }
                         }
+                        SerdeField::XBreak => {
+                            let mut guard = futures::executor::block_on(result.x_break.write());
+                            let values: Vec<XBreak> = map.next_value()?;
+                            for value in values {
+                                guard.push(Some(Arc::new(RwLock::new(value))));
+                            }
+                        }
                         SerdeField::Call => {
                             let mut guard = futures::executor::block_on(result.call.write());
                             let values: Vec<Call> = map.next_value()?;
@@ -1622,6 +1706,13 @@ impl<'de> Deserialize<'de> for ObjectStore {
                                 guard.push(Some(Arc::new(RwLock::new(value))));
                             }
                         }
+                        SerdeField::XContinue => {
+                            let mut guard = futures::executor::block_on(result.x_continue.write());
+                            let values: Vec<XContinue> = map.next_value()?;
+                            for value in values {
+                                guard.push(Some(Arc::new(RwLock::new(value))));
+                            }
+                        }
                         SerdeField::DataStructure => {
                             let mut guard =
                                 futures::executor::block_on(result.data_structure.write());
@@ -1871,6 +1962,13 @@ impl<'de> Deserialize<'de> for ObjectStore {
                                 guard.push(Some(Arc::new(RwLock::new(value))));
                             }
                         }
+                        SerdeField::XLoop => {
+                            let mut guard = futures::executor::block_on(result.x_loop.write());
+                            let values: Vec<XLoop> = map.next_value()?;
+                            for value in values {
+                                guard.push(Some(Arc::new(RwLock::new(value))));
+                            }
+                        }
                         SerdeField::XMacro => {
                             let mut guard = futures::executor::block_on(result.x_macro.write());
                             let values: Vec<XMacro> = map.next_value()?;
@@ -2116,6 +2214,13 @@ impl<'de> Deserialize<'de> for ObjectStore {
                                 guard.push(Some(Arc::new(RwLock::new(value))));
                             }
                         }
+                        SerdeField::XWhile => {
+                            let mut guard = futures::executor::block_on(result.x_while.write());
+                            let values: Vec<XWhile> = map.next_value()?;
+                            for value in values {
+                                guard.push(Some(Arc::new(RwLock::new(value))));
+                            }
+                        }
                     }
                 }
                 Ok(result)
@@ -2248,6 +2353,24 @@ impl<'de> Deserialize<'de> for ObjectStore {
             }
         }
 
+        struct XBreakVisitor;
+        impl<'de> Visitor<'de> for XBreakVisitor {
+            type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<XBreak>>>>>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("XBreak map")
+            }
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut map = HashMap::default();
+                while let Some((key, value)) = access.next_entry::<Uuid, XBreak>()? {
+                    map.insert(key, Arc::new(RwLock::new(value)));
+                }
+                Ok(Arc::new(RwLock::new(map)))
+            }
+        }
+
         struct CallVisitor;
         impl<'de> Visitor<'de> for CallVisitor {
             type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<Call>>>>>;
@@ -2284,6 +2407,24 @@ impl<'de> Deserialize<'de> for ObjectStore {
             }
         }
 
+        struct XContinueVisitor;
+        impl<'de> Visitor<'de> for XContinueVisitor {
+            type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<XContinue>>>>>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("XContinue map")
+            }
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut map = HashMap::default();
+                while let Some((key, value)) = access.next_entry::<Uuid, XContinue>()? {
+                    map.insert(key, Arc::new(RwLock::new(value)));
+                }
+                Ok(Arc::new(RwLock::new(map)))
+            }
+        }
+
         struct DataStructureVisitor;
         impl<'de> Visitor<'de> for DataStructureVisitor {
             type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<DataStructure>>>>>;
@@ -2880,6 +3021,24 @@ impl<'de> Deserialize<'de> for ObjectStore {
             }
         }
 
+        struct XLoopVisitor;
+        impl<'de> Visitor<'de> for XLoopVisitor {
+            type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<XLoop>>>>>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("XLoop map")
+            }
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut map = HashMap::default();
+                while let Some((key, value)) = access.next_entry::<Uuid, XLoop>()? {
+                    map.insert(key, Arc::new(RwLock::new(value)));
+                }
+                Ok(Arc::new(RwLock::new(map)))
+            }
+        }
+
         struct XMacroVisitor;
         impl<'de> Visitor<'de> for XMacroVisitor {
             type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<XMacro>>>>>;
@@ -3476,6 +3635,24 @@ impl<'de> Deserialize<'de> for ObjectStore {
             }
         }
 
+        struct XWhileVisitor;
+        impl<'de> Visitor<'de> for XWhileVisitor {
+            type Value = Arc<RwLock<HashMap<Uuid, Arc<RwLock<XWhile>>>>>;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("XWhile map")
+            }
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut map = HashMap::default();
+                while let Some((key, value)) = access.next_entry::<Uuid, XWhile>()? {
+                    map.insert(key, Arc::new(RwLock::new(value)));
+                }
+                Ok(Arc::new(RwLock::new(map)))
+            }
+        }
+
         const FIELDS: &'static [&'static str] = &[
             "argument",
             "a_wait",
@@ -3484,8 +3661,10 @@ impl<'de> Deserialize<'de> for ObjectStore {
             "body",
             "boolean_literal",
             "boolean_operator",
+            "x_break",
             "call",
             "comparison",
+            "x_continue",
             "data_structure",
             "dwarf_source_file",
             "enum_field",
@@ -3519,6 +3698,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
             "list_expression",
             "literal",
             "local_variable",
+            "x_loop",
             "x_macro",
             "x_match",
             "method_call",
@@ -3552,6 +3732,7 @@ impl<'de> Deserialize<'de> for ObjectStore {
             "value_type",
             "variable",
             "variable_expression",
+            "x_while",
         ];
         deserializer.deserialize_struct("ObjectStore", FIELDS, ObjectStoreVisitor)
     }
@@ -3573,11 +3754,15 @@ impl ObjectStore {
             boolean_literal_free_list: async_std::sync::Mutex::new(Vec::new()),
             boolean_literal: Arc::new(RwLock::new(Vec::new())),
             boolean_operator_free_list: async_std::sync::Mutex::new(Vec::new()),
+            x_break_free_list: async_std::sync::Mutex::new(Vec::new()),
             boolean_operator: Arc::new(RwLock::new(Vec::new())),
+            x_break: Arc::new(RwLock::new(Vec::new())),
             call_free_list: async_std::sync::Mutex::new(Vec::new()),
             call: Arc::new(RwLock::new(Vec::new())),
             comparison_free_list: async_std::sync::Mutex::new(Vec::new()),
+            x_continue_free_list: async_std::sync::Mutex::new(Vec::new()),
             comparison: Arc::new(RwLock::new(Vec::new())),
+            x_continue: Arc::new(RwLock::new(Vec::new())),
             data_structure_free_list: async_std::sync::Mutex::new(Vec::new()),
             data_structure: Arc::new(RwLock::new(Vec::new())),
             dwarf_source_file_free_list: async_std::sync::Mutex::new(Vec::new()),
@@ -3646,7 +3831,9 @@ impl ObjectStore {
             literal_free_list: async_std::sync::Mutex::new(Vec::new()),
             literal: Arc::new(RwLock::new(Vec::new())),
             local_variable_free_list: async_std::sync::Mutex::new(Vec::new()),
+            x_loop_free_list: async_std::sync::Mutex::new(Vec::new()),
             local_variable: Arc::new(RwLock::new(Vec::new())),
+            x_loop: Arc::new(RwLock::new(Vec::new())),
             x_macro_free_list: async_std::sync::Mutex::new(Vec::new()),
             x_macro: Arc::new(RwLock::new(Vec::new())),
             x_match_free_list: async_std::sync::Mutex::new(Vec::new()),
@@ -3715,7 +3902,9 @@ impl ObjectStore {
             variable_free_list: async_std::sync::Mutex::new(Vec::new()),
             variable: Arc::new(RwLock::new(Vec::new())),
             variable_expression_free_list: async_std::sync::Mutex::new(Vec::new()),
+            x_while_free_list: async_std::sync::Mutex::new(Vec::new()),
             variable_expression: Arc::new(RwLock::new(Vec::new())),
+            x_while: Arc::new(RwLock::new(Vec::new())),
         };
 
         // Initialize Singleton Subtypes
@@ -4355,6 +4544,93 @@ impl ObjectStore {
         })
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    #[inline]
+    pub async fn inter_x_break<F>(&mut self, x_break: F) -> Arc<RwLock<XBreak>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XBreak>>,
+    {
+        let _index = if let Some(_index) = self.x_break_free_list.lock().await.pop() {
+            log::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_break.read().await.len();
+            log::trace!(target: "store", "allocating block {_index}.");
+            self.x_break.write().await.push(None);
+            _index
+        };
+
+        let x_break = x_break(_index);
+
+        let iter = self.x_break.read().await;
+        let iter = iter.iter();
+        let iter = stream::iter(iter);
+        let found = iter
+            .filter_map(|stored| {
+                Box::pin({
+                    let stored = stored.clone();
+                    async {
+                        if let Some(stored) = stored {
+                            if *stored.read().await == *x_break.read().await {
+                                Some(stored)
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                })
+            })
+            .next()
+            .await;
+
+        if let Some(x_break) = found {
+            log::debug!(target: "store", "found duplicate {x_break:?}.");
+            self.x_break_free_list.lock().await.push(_index);
+            x_break.clone()
+        } else {
+            log::debug!(target: "store", "interring {x_break:?}.");
+            self.x_break.write().await[_index] = Some(x_break.clone());
+            x_break
+        }
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub async fn exhume_x_break(&self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        match self.x_break.read().await.get(*id) {
+            Some(x_break) => x_break.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub async fn exorcise_x_break(&mut self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        log::debug!(target: "store", "exorcising x_break slot: {id}.");
+        let result = self.x_break.write().await[*id].take();
+        self.x_break_free_list.lock().await.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    #[inline]
+    pub async fn iter_x_break(&self) -> impl stream::Stream<Item = Arc<RwLock<XBreak>>> + '_ {
+        let len = self.x_break.read().await.len();
+        stream::iter((0..len)).filter_map(move |i| async move {
+            if self.x_break.read().await[i].is_some() {
+                self.x_break.read().await[i].clone()
+            } else {
+                None
+            }
+        })
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     #[inline]
@@ -4531,6 +4807,93 @@ impl ObjectStore {
         })
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    #[inline]
+    pub async fn inter_x_continue<F>(&mut self, x_continue: F) -> Arc<RwLock<XContinue>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XContinue>>,
+    {
+        let _index = if let Some(_index) = self.x_continue_free_list.lock().await.pop() {
+            log::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_continue.read().await.len();
+            log::trace!(target: "store", "allocating block {_index}.");
+            self.x_continue.write().await.push(None);
+            _index
+        };
+
+        let x_continue = x_continue(_index);
+
+        let iter = self.x_continue.read().await;
+        let iter = iter.iter();
+        let iter = stream::iter(iter);
+        let found = iter
+            .filter_map(|stored| {
+                Box::pin({
+                    let stored = stored.clone();
+                    async {
+                        if let Some(stored) = stored {
+                            if *stored.read().await == *x_continue.read().await {
+                                Some(stored)
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                })
+            })
+            .next()
+            .await;
+
+        if let Some(x_continue) = found {
+            log::debug!(target: "store", "found duplicate {x_continue:?}.");
+            self.x_continue_free_list.lock().await.push(_index);
+            x_continue.clone()
+        } else {
+            log::debug!(target: "store", "interring {x_continue:?}.");
+            self.x_continue.write().await[_index] = Some(x_continue.clone());
+            x_continue
+        }
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub async fn exhume_x_continue(&self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        match self.x_continue.read().await.get(*id) {
+            Some(x_continue) => x_continue.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub async fn exorcise_x_continue(&mut self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        log::debug!(target: "store", "exorcising x_continue slot: {id}.");
+        let result = self.x_continue.write().await[*id].take();
+        self.x_continue_free_list.lock().await.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    #[inline]
+    pub async fn iter_x_continue(&self) -> impl stream::Stream<Item = Arc<RwLock<XContinue>>> + '_ {
+        let len = self.x_continue.read().await.len();
+        stream::iter((0..len)).filter_map(move |i| async move {
+            if self.x_continue.read().await[i].is_some() {
+                self.x_continue.read().await[i].clone()
+            } else {
+                None
+            }
+        })
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     #[inline]
@@ -7594,6 +7957,93 @@ impl ObjectStore {
         })
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    #[inline]
+    pub async fn inter_x_loop<F>(&mut self, x_loop: F) -> Arc<RwLock<XLoop>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XLoop>>,
+    {
+        let _index = if let Some(_index) = self.x_loop_free_list.lock().await.pop() {
+            log::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_loop.read().await.len();
+            log::trace!(target: "store", "allocating block {_index}.");
+            self.x_loop.write().await.push(None);
+            _index
+        };
+
+        let x_loop = x_loop(_index);
+
+        let iter = self.x_loop.read().await;
+        let iter = iter.iter();
+        let iter = stream::iter(iter);
+        let found = iter
+            .filter_map(|stored| {
+                Box::pin({
+                    let stored = stored.clone();
+                    async {
+                        if let Some(stored) = stored {
+                            if *stored.read().await == *x_loop.read().await {
+                                Some(stored)
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                })
+            })
+            .next()
+            .await;
+
+        if let Some(x_loop) = found {
+            log::debug!(target: "store", "found duplicate {x_loop:?}.");
+            self.x_loop_free_list.lock().await.push(_index);
+            x_loop.clone()
+        } else {
+            log::debug!(target: "store", "interring {x_loop:?}.");
+            self.x_loop.write().await[_index] = Some(x_loop.clone());
+            x_loop
+        }
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub async fn exhume_x_loop(&self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        match self.x_loop.read().await.get(*id) {
+            Some(x_loop) => x_loop.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub async fn exorcise_x_loop(&mut self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        log::debug!(target: "store", "exorcising x_loop slot: {id}.");
+        let result = self.x_loop.write().await[*id].take();
+        self.x_loop_free_list.lock().await.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    #[inline]
+    pub async fn iter_x_loop(&self) -> impl stream::Stream<Item = Arc<RwLock<XLoop>>> + '_ {
+        let len = self.x_loop.read().await.len();
+        stream::iter((0..len)).filter_map(move |i| async move {
+            if self.x_loop.read().await[i].is_some() {
+                self.x_loop.read().await[i].clone()
+            } else {
+                None
+            }
+        })
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     #[inline]
@@ -10633,6 +11083,93 @@ impl ObjectStore {
         })
     }
 
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    #[inline]
+    pub async fn inter_x_while<F>(&mut self, x_while: F) -> Arc<RwLock<XWhile>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XWhile>>,
+    {
+        let _index = if let Some(_index) = self.x_while_free_list.lock().await.pop() {
+            log::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_while.read().await.len();
+            log::trace!(target: "store", "allocating block {_index}.");
+            self.x_while.write().await.push(None);
+            _index
+        };
+
+        let x_while = x_while(_index);
+
+        let iter = self.x_while.read().await;
+        let iter = iter.iter();
+        let iter = stream::iter(iter);
+        let found = iter
+            .filter_map(|stored| {
+                Box::pin({
+                    let stored = stored.clone();
+                    async {
+                        if let Some(stored) = stored {
+                            if *stored.read().await == *x_while.read().await {
+                                Some(stored)
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                })
+            })
+            .next()
+            .await;
+
+        if let Some(x_while) = found {
+            log::debug!(target: "store", "found duplicate {x_while:?}.");
+            self.x_while_free_list.lock().await.push(_index);
+            x_while.clone()
+        } else {
+            log::debug!(target: "store", "interring {x_while:?}.");
+            self.x_while.write().await[_index] = Some(x_while.clone());
+            x_while
+        }
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub async fn exhume_x_while(&self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        match self.x_while.read().await.get(*id) {
+            Some(x_while) => x_while.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub async fn exorcise_x_while(&mut self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        log::debug!(target: "store", "exorcising x_while slot: {id}.");
+        let result = self.x_while.write().await[*id].take();
+        self.x_while_free_list.lock().await.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    #[inline]
+    pub async fn iter_x_while(&self) -> impl stream::Stream<Item = Arc<RwLock<XWhile>>> + '_ {
+        let len = self.x_while.read().await.len();
+        stream::iter((0..len)).filter_map(move |i| async move {
+            if self.x_while.read().await[i].is_some() {
+                self.x_while.read().await[i].clone()
+            } else {
+                None
+            }
+        })
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_async-object-store-persistence"}}}
@@ -10763,6 +11300,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Break.
+        {
+            let path = path.join("x_break");
+            fs::create_dir_all(&path)?;
+            for x_break in &*self.x_break.read().await {
+                if let Some(x_break) = x_break {
+                    let path = path.join(format!("{}.json", x_break.read().await.id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &(x_break.read().await).clone())?;
+                }
+            }
+        }
+
         // Persist Call.
         {
             let path = path.join("call");
@@ -10791,6 +11342,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Continue.
+        {
+            let path = path.join("x_continue");
+            fs::create_dir_all(&path)?;
+            for x_continue in &*self.x_continue.read().await {
+                if let Some(x_continue) = x_continue {
+                    let path = path.join(format!("{}.json", x_continue.read().await.id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &(x_continue.read().await).clone())?;
+                }
+            }
+        }
+
         // Persist Data Structure.
         {
             let path = path.join("data_structure");
@@ -11308,6 +11873,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Loop.
+        {
+            let path = path.join("x_loop");
+            fs::create_dir_all(&path)?;
+            for x_loop in &*self.x_loop.read().await {
+                if let Some(x_loop) = x_loop {
+                    let path = path.join(format!("{}.json", x_loop.read().await.id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &(x_loop.read().await).clone())?;
+                }
+            }
+        }
+
         // Persist Macro.
         {
             let path = path.join("x_macro");
@@ -11811,6 +12390,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist While.
+        {
+            let path = path.join("x_while");
+            fs::create_dir_all(&path)?;
+            for x_while in &*self.x_while.read().await {
+                if let Some(x_while) = x_while {
+                    let path = path.join(format!("{}.json", x_while.read().await.id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &(x_while.read().await).clone())?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -11970,6 +12563,27 @@ impl ObjectStore {
             }
         }
 
+        // Load Break. Stores persisted before there were breaks don't have any.
+        {
+            let path = path.join("x_break");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_break: Arc<RwLock<XBreak>> =
+                        serde_json::from_reader(reader).map(|a| Arc::new(RwLock::new(a)))?;
+                    store
+                        .x_break
+                        .write()
+                        .await
+                        .insert(x_break.read().await.id, Some(x_break.clone()));
+                }
+            }
+        }
+
         // Load Call.
         {
             let path = path.join("call");
@@ -12008,6 +12622,28 @@ impl ObjectStore {
             }
         }
 
+        // Load Continue. Stores persisted before there were continues don't have
+        // any.
+        {
+            let path = path.join("x_continue");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_continue: Arc<RwLock<XContinue>> =
+                        serde_json::from_reader(reader).map(|a| Arc::new(RwLock::new(a)))?;
+                    store
+                        .x_continue
+                        .write()
+                        .await
+                        .insert(x_continue.read().await.id, Some(x_continue.clone()));
+                }
+            }
+        }
+
         // Load Data Structure.
         {
             let path = path.join("data_structure");
@@ -12639,6 +13275,27 @@ impl ObjectStore {
             }
         }
 
+        // Load Loop. Stores persisted before there were loops don't have any.
+        {
+            let path = path.join("x_loop");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_loop: Arc<RwLock<XLoop>> =
+                        serde_json::from_reader(reader).map(|a| Arc::new(RwLock::new(a)))?;
+                    store
+                        .x_loop
+                        .write()
+                        .await
+                        .insert(x_loop.read().await.id, Some(x_loop.clone()));
+                }
+            }
+        }
+
         // Load Macro.
         {
             let path = path.join("x_macro");
@@ -13271,6 +13928,28 @@ impl ObjectStore {
             }
         }
 
+        // Load While. Stores persisted before there were while loops don't have
+        // any.
+        {
+            let path = path.join("x_while");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_while: Arc<RwLock<XWhile>> =
+                        serde_json::from_reader(reader).map(|a| Arc::new(RwLock::new(a)))?;
+                    store
+                        .x_while
+                        .write()
+                        .await
+                        .insert(x_while.read().await.id, Some(x_while.clone()));
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
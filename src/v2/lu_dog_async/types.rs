@@ -2,10 +2,17 @@
 // {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"v2::lu_dog_async-module-definition-file"}}}
 pub mod a_wait;
 pub mod addition;
+pub mod addition_assignment;
 pub mod and;
 pub mod argument;
 pub mod assignment;
 pub mod binary;
+pub mod bitwise_and;
+pub mod bitwise_and_assignment;
+pub mod bitwise_or;
+pub mod bitwise_or_assignment;
+pub mod bitwise_xor;
+pub mod bitwise_xor_assignment;
 pub mod block;
 pub mod body;
 pub mod boolean_literal;
@@ -15,6 +22,7 @@ pub mod char;
 pub mod comparison;
 pub mod data_structure;
 pub mod division;
+pub mod division_assignment;
 pub mod dwarf_source_file;
 pub mod empty;
 pub mod empty_expression;
@@ -60,6 +68,7 @@ pub mod local_variable;
 pub mod macro_call;
 pub mod method_call;
 pub mod multiplication;
+pub mod multiplication_assignment;
 pub mod named_field_expression;
 pub mod negation;
 pub mod not;
@@ -72,7 +81,13 @@ pub mod path_element;
 pub mod pattern;
 pub mod range;
 pub mod range_expression;
+pub mod remainder;
+pub mod remainder_assignment;
 pub mod result_statement;
+pub mod shift_left;
+pub mod shift_left_assignment;
+pub mod shift_right;
+pub mod shift_right_assignment;
 pub mod span;
 pub mod statement;
 pub mod static_method_call;
@@ -81,6 +96,7 @@ pub mod struct_expression;
 pub mod struct_field;
 pub mod struct_generic;
 pub mod subtraction;
+pub mod subtraction_assignment;
 pub mod task;
 pub mod to;
 pub mod to_inclusive;
@@ -95,9 +111,12 @@ pub mod value_type;
 pub mod variable;
 pub mod variable_expression;
 pub mod woog_struct;
+pub mod x_break;
+pub mod x_continue;
 pub mod x_debugger;
 pub mod x_future;
 pub mod x_if;
+pub mod x_loop;
 pub mod x_macro;
 pub mod x_match;
 pub mod x_path;
@@ -105,11 +124,14 @@ pub mod x_plugin;
 pub mod x_print;
 pub mod x_return;
 pub mod x_value;
+pub mod x_while;
 pub mod z_object_store;
 
 pub use crate::v2::lu_dog_async::a_wait::AWait;
 pub use crate::v2::lu_dog_async::addition::Addition;
 pub use crate::v2::lu_dog_async::addition::ADDITION;
+pub use crate::v2::lu_dog_async::addition_assignment::AdditionAssignment;
+pub use crate::v2::lu_dog_async::addition_assignment::ADDITION_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::and::And;
 pub use crate::v2::lu_dog_async::and::AND;
 pub use crate::v2::lu_dog_async::argument::Argument;
@@ -117,6 +139,18 @@ pub use crate::v2::lu_dog_async::assignment::Assignment;
 pub use crate::v2::lu_dog_async::assignment::ASSIGNMENT;
 pub use crate::v2::lu_dog_async::binary::Binary;
 pub use crate::v2::lu_dog_async::binary::BinaryEnum;
+pub use crate::v2::lu_dog_async::bitwise_and::BitwiseAnd;
+pub use crate::v2::lu_dog_async::bitwise_and::BITWISE_AND;
+pub use crate::v2::lu_dog_async::bitwise_and_assignment::BitwiseAndAssignment;
+pub use crate::v2::lu_dog_async::bitwise_and_assignment::BITWISE_AND_ASSIGNMENT;
+pub use crate::v2::lu_dog_async::bitwise_or::BitwiseOr;
+pub use crate::v2::lu_dog_async::bitwise_or::BITWISE_OR;
+pub use crate::v2::lu_dog_async::bitwise_or_assignment::BitwiseOrAssignment;
+pub use crate::v2::lu_dog_async::bitwise_or_assignment::BITWISE_OR_ASSIGNMENT;
+pub use crate::v2::lu_dog_async::bitwise_xor::BitwiseXor;
+pub use crate::v2::lu_dog_async::bitwise_xor::BITWISE_XOR;
+pub use crate::v2::lu_dog_async::bitwise_xor_assignment::BitwiseXorAssignment;
+pub use crate::v2::lu_dog_async::bitwise_xor_assignment::BITWISE_XOR_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::block::Block;
 pub use crate::v2::lu_dog_async::body::Body;
 pub use crate::v2::lu_dog_async::body::BodyEnum;
@@ -134,6 +168,8 @@ pub use crate::v2::lu_dog_async::data_structure::DataStructure;
 pub use crate::v2::lu_dog_async::data_structure::DataStructureEnum;
 pub use crate::v2::lu_dog_async::division::Division;
 pub use crate::v2::lu_dog_async::division::DIVISION;
+pub use crate::v2::lu_dog_async::division_assignment::DivisionAssignment;
+pub use crate::v2::lu_dog_async::division_assignment::DIVISION_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::dwarf_source_file::DwarfSourceFile;
 pub use crate::v2::lu_dog_async::empty::Empty;
 pub use crate::v2::lu_dog_async::empty::EMPTY;
@@ -199,6 +235,8 @@ pub use crate::v2::lu_dog_async::macro_call::MACRO_CALL;
 pub use crate::v2::lu_dog_async::method_call::MethodCall;
 pub use crate::v2::lu_dog_async::multiplication::Multiplication;
 pub use crate::v2::lu_dog_async::multiplication::MULTIPLICATION;
+pub use crate::v2::lu_dog_async::multiplication_assignment::MultiplicationAssignment;
+pub use crate::v2::lu_dog_async::multiplication_assignment::MULTIPLICATION_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::named_field_expression::NamedFieldExpression;
 pub use crate::v2::lu_dog_async::negation::Negation;
 pub use crate::v2::lu_dog_async::negation::NEGATION;
@@ -218,7 +256,19 @@ pub use crate::v2::lu_dog_async::range::Range;
 pub use crate::v2::lu_dog_async::range::RANGE;
 pub use crate::v2::lu_dog_async::range_expression::RangeExpression;
 pub use crate::v2::lu_dog_async::range_expression::RangeExpressionEnum;
+pub use crate::v2::lu_dog_async::remainder::Remainder;
+pub use crate::v2::lu_dog_async::remainder::REMAINDER;
+pub use crate::v2::lu_dog_async::remainder_assignment::RemainderAssignment;
+pub use crate::v2::lu_dog_async::remainder_assignment::REMAINDER_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::result_statement::ResultStatement;
+pub use crate::v2::lu_dog_async::shift_left::ShiftLeft;
+pub use crate::v2::lu_dog_async::shift_left::SHIFT_LEFT;
+pub use crate::v2::lu_dog_async::shift_left_assignment::ShiftLeftAssignment;
+pub use crate::v2::lu_dog_async::shift_left_assignment::SHIFT_LEFT_ASSIGNMENT;
+pub use crate::v2::lu_dog_async::shift_right::ShiftRight;
+pub use crate::v2::lu_dog_async::shift_right::SHIFT_RIGHT;
+pub use crate::v2::lu_dog_async::shift_right_assignment::ShiftRightAssignment;
+pub use crate::v2::lu_dog_async::shift_right_assignment::SHIFT_RIGHT_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::span::Span;
 pub use crate::v2::lu_dog_async::statement::Statement;
 pub use crate::v2::lu_dog_async::statement::StatementEnum;
@@ -229,6 +279,8 @@ pub use crate::v2::lu_dog_async::struct_field::StructField;
 pub use crate::v2::lu_dog_async::struct_generic::StructGeneric;
 pub use crate::v2::lu_dog_async::subtraction::Subtraction;
 pub use crate::v2::lu_dog_async::subtraction::SUBTRACTION;
+pub use crate::v2::lu_dog_async::subtraction_assignment::SubtractionAssignment;
+pub use crate::v2::lu_dog_async::subtraction_assignment::SUBTRACTION_ASSIGNMENT;
 pub use crate::v2::lu_dog_async::task::Task;
 pub use crate::v2::lu_dog_async::task::TASK;
 pub use crate::v2::lu_dog_async::to::To;
@@ -251,10 +303,13 @@ pub use crate::v2::lu_dog_async::variable::Variable;
 pub use crate::v2::lu_dog_async::variable::VariableEnum;
 pub use crate::v2::lu_dog_async::variable_expression::VariableExpression;
 pub use crate::v2::lu_dog_async::woog_struct::WoogStruct;
+pub use crate::v2::lu_dog_async::x_break::XBreak;
+pub use crate::v2::lu_dog_async::x_continue::XContinue;
 pub use crate::v2::lu_dog_async::x_debugger::XDebugger;
 pub use crate::v2::lu_dog_async::x_debugger::X_DEBUGGER;
 pub use crate::v2::lu_dog_async::x_future::XFuture;
 pub use crate::v2::lu_dog_async::x_if::XIf;
+pub use crate::v2::lu_dog_async::x_loop::XLoop;
 pub use crate::v2::lu_dog_async::x_macro::XMacro;
 pub use crate::v2::lu_dog_async::x_match::XMatch;
 pub use crate::v2::lu_dog_async::x_path::XPath;
@@ -263,5 +318,6 @@ pub use crate::v2::lu_dog_async::x_print::XPrint;
 pub use crate::v2::lu_dog_async::x_return::XReturn;
 pub use crate::v2::lu_dog_async::x_value::XValue;
 pub use crate::v2::lu_dog_async::x_value::XValueEnum;
+pub use crate::v2::lu_dog_async::x_while::XWhile;
 pub use crate::v2::lu_dog_async::z_object_store::ZObjectStore;
 // {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_and_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and_assignment-const-documentation"}}}
+/// Compound assignment with the bitwise and operator: `&=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_and_assignment-const-definition"}}}
+pub const BITWISE_AND_ASSIGNMENT: Uuid = uuid!["de2faf59-f975-54ba-b54d-d3fe9d1fff26"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BitwiseAndAssignment;
+
+impl BitwiseAndAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_AND_ASSIGNMENT
+    }
+}
+
+impl Default for BitwiseAndAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_or_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or_assignment-const-documentation"}}}
+/// Compound assignment with the bitwise or operator: `|=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_or_assignment-const-definition"}}}
+pub const BITWISE_OR_ASSIGNMENT: Uuid = uuid!["5da2b556-80ff-5ab8-927b-2ecf2c3272e9"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BitwiseOrAssignment;
+
+impl BitwiseOrAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_OR_ASSIGNMENT
+    }
+}
+
+impl Default for BitwiseOrAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
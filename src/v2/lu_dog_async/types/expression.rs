@@ -28,14 +28,18 @@ use crate::v2::lu_dog_async::types::result_statement::ResultStatement;
 use crate::v2::lu_dog_async::types::struct_expression::StructExpression;
 use crate::v2::lu_dog_async::types::type_cast::TypeCast;
 use crate::v2::lu_dog_async::types::variable_expression::VariableExpression;
+use crate::v2::lu_dog_async::types::x_break::XBreak;
+use crate::v2::lu_dog_async::types::x_continue::XContinue;
 use crate::v2::lu_dog_async::types::x_debugger::X_DEBUGGER;
 use crate::v2::lu_dog_async::types::x_if::XIf;
+use crate::v2::lu_dog_async::types::x_loop::XLoop;
 use crate::v2::lu_dog_async::types::x_match::XMatch;
 use crate::v2::lu_dog_async::types::x_path::XPath;
 use crate::v2::lu_dog_async::types::x_print::XPrint;
 use crate::v2::lu_dog_async::types::x_return::XReturn;
 use crate::v2::lu_dog_async::types::x_value::XValue;
 use crate::v2::lu_dog_async::types::x_value::XValueEnum;
+use crate::v2::lu_dog_async::types::x_while::XWhile;
 use serde::{Deserialize, Serialize};
 
 use crate::v2::lu_dog_async::store::ObjectStore as LuDogAsyncStore;
@@ -60,7 +64,9 @@ pub struct Expression {
 pub enum ExpressionEnum {
     AWait(usize),
     Block(usize),
+    XBreak(usize),
     Call(usize),
+    XContinue(usize),
     XDebugger(Uuid),
     EmptyExpression(Uuid),
     FieldAccess(usize),
@@ -73,6 +79,7 @@ pub enum ExpressionEnum {
     ListElement(usize),
     ListExpression(usize),
     Literal(usize),
+    XLoop(usize),
     XMatch(usize),
     Operator(usize),
     XPath(usize),
@@ -82,6 +89,7 @@ pub enum ExpressionEnum {
     StructExpression(usize),
     TypeCast(usize),
     VariableExpression(usize),
+    XWhile(usize),
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-implementation"}}}
@@ -107,6 +115,26 @@ impl Expression {
             .await
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_break"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub async fn new_x_break(
+        bogus: bool,
+        subtype: &Arc<RwLock<XBreak>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<Expression>> {
+        let s_id = subtype.read().await.id;
+        let subtype = subtype.read().await.id;
+        store
+            .inter_expression(|id| {
+                Arc::new(RwLock::new(Expression {
+                    bogus: bogus,
+                    subtype: ExpressionEnum::XBreak(subtype),
+                    id,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_call"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_block"}}}
     /// Inter a new Expression in the store, and return it's `id`.
@@ -128,6 +156,26 @@ impl Expression {
             .await
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_continue"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub async fn new_x_continue(
+        bogus: bool,
+        subtype: &Arc<RwLock<XContinue>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<Expression>> {
+        let s_id = subtype.read().await.id;
+        let subtype = subtype.read().await.id;
+        store
+            .inter_expression(|id| {
+                Arc::new(RwLock::new(Expression {
+                    bogus: bogus,
+                    subtype: ExpressionEnum::XContinue(subtype),
+                    id,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_debugger"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_call"}}}
     /// Inter a new Expression in the store, and return it's `id`.
@@ -385,6 +433,26 @@ impl Expression {
             .await
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_loop"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub async fn new_x_loop(
+        bogus: bool,
+        subtype: &Arc<RwLock<XLoop>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<Expression>> {
+        let s_id = subtype.read().await.id;
+        let subtype = subtype.read().await.id;
+        store
+            .inter_expression(|id| {
+                Arc::new(RwLock::new(Expression {
+                    bogus: bogus,
+                    subtype: ExpressionEnum::XLoop(subtype),
+                    id,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_match"}}}
     /// Inter a new Expression in the store, and return it's `id`.
     pub async fn new_x_match(
@@ -569,6 +637,26 @@ impl Expression {
             .await
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_while"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub async fn new_x_while(
+        bogus: bool,
+        subtype: &Arc<RwLock<XWhile>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<Expression>> {
+        let s_id = subtype.read().await.id;
+        let subtype = subtype.read().await.id;
+        store
+            .inter_expression(|id| {
+                Arc::new(RwLock::new(Expression {
+                    bogus: bogus,
+                    subtype: ExpressionEnum::XWhile(subtype),
+                    id,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-argument"}}}
     /// Navigate to [`Argument`] across R37(1-M)
     pub async fn r37_argument<'a>(
@@ -601,6 +689,24 @@ impl Expression {
             .await
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_Mc-to-x_break"}}}
+    /// Navigate to [`XBreak`] across R124(1-Mc)
+    pub async fn r124_x_break<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<XBreak>>> + '_ {
+        store
+            .iter_x_break()
+            .await
+            .filter_map(move |x_break| async move {
+                if x_break.read().await.expression == Some(self.id) {
+                    Some(x_break.clone())
+                } else {
+                    None
+                }
+            })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_Mc-to-call"}}}
     /// Navigate to [`Call`] across R29(1-Mc)
     pub async fn r29_call<'a>(
@@ -813,6 +919,21 @@ impl Expression {
             })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_loop"}}}
+    /// Navigate to [`XLoop`] across R123(1-M)
+    pub async fn r123_x_loop<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<XLoop>>> + '_ {
+        store.iter_x_loop().await.filter_map(|x_loop| async {
+            if x_loop.read().await.block == self.id {
+                Some(x_loop)
+            } else {
+                None
+            }
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_match"}}}
     /// Navigate to [`XMatch`] across R91(1-M)
     pub async fn r91_x_match<'a>(
@@ -979,6 +1100,36 @@ impl Expression {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_while"}}}
+    /// Navigate to [`XWhile`] across R122(1-M)
+    pub async fn r122_x_while<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<XWhile>>> + '_ {
+        store.iter_x_while().await.filter_map(|x_while| async {
+            if x_while.read().await.test == self.id {
+                Some(x_while)
+            } else {
+                None
+            }
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_while"}}}
+    /// Navigate to [`XWhile`] across R121(1-M)
+    pub async fn r121_x_while<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<XWhile>>> + '_ {
+        store.iter_x_while().await.filter_map(|x_while| async {
+            if x_while.read().await.block == self.id {
+                Some(x_while)
+            } else {
+                None
+            }
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-assoc-many-to-pattern"}}}
     /// Navigate to [`Pattern`] across R87(1-M)
     pub async fn r87_pattern<'a>(
@@ -0,0 +1,98 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_break-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-use-statements"}}}
+use async_std::sync::Arc;
+use async_std::sync::RwLock;
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+
+use crate::v2::lu_dog_async::types::expression::Expression;
+use crate::v2::lu_dog_async::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_async::store::ObjectStore as LuDogAsyncStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-documentation"}}}
+/// The Break Expression
+///
+/// Leaves the innermost loop, or the loop with the label, optionally with a value.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XBreak {
+    pub id: usize,
+    pub label: String,
+    /// R124: [`XBreak`] 'exits with' [`Expression`]
+    pub expression: Option<usize>,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-implementation"}}}
+impl XBreak {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-new"}}}
+    /// Inter a new 'Break' in the store, and return it's `id`.
+    pub async fn new(
+        label: String,
+        expression: Option<&Arc<RwLock<Expression>>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<XBreak>> {
+        let expression = match expression {
+            Some(expression) => Some(expression.read().await.id),
+            None => None,
+        };
+        store
+            .inter_x_break(|id| {
+                Arc::new(RwLock::new(XBreak {
+                    id,
+                    label: label.to_owned(),
+                    expression,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-nav-forward-cond-to-expression"}}}
+    /// Navigate to [`Expression`] across R124(1-*c)
+    pub async fn r124_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<Expression>>> + '_ {
+        match self.expression {
+            Some(ref expression) => {
+                stream::iter(vec![store.exhume_expression(expression).await.unwrap()].into_iter())
+            }
+            None => stream::iter(vec![].into_iter()),
+        }
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub async fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        store
+            .iter_expression()
+            .await
+            .filter_map(|expression| async move {
+                if let ExpressionEnum::XBreak(id) = expression.read().await.subtype {
+                    Some(expression.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-implementation"}}}
+impl PartialEq for XBreak {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.expression == other.expression
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
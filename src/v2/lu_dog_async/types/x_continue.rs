@@ -0,0 +1,73 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_continue-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-use-statements"}}}
+use async_std::sync::Arc;
+use async_std::sync::RwLock;
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+
+use crate::v2::lu_dog_async::types::expression::Expression;
+use crate::v2::lu_dog_async::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_async::store::ObjectStore as LuDogAsyncStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-documentation"}}}
+/// The Continue Expression
+///
+/// Skips to the next iteration of the innermost loop, or of the loop with the label.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XContinue {
+    pub id: usize,
+    pub label: String,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-implementation"}}}
+impl XContinue {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-impl-new"}}}
+    /// Inter a new 'Continue' in the store, and return it's `id`.
+    pub async fn new(label: String, store: &mut LuDogAsyncStore) -> Arc<RwLock<XContinue>> {
+        store
+            .inter_x_continue(|id| {
+                Arc::new(RwLock::new(XContinue {
+                    id,
+                    label: label.to_owned(),
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub async fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        store
+            .iter_expression()
+            .await
+            .filter_map(|expression| async move {
+                if let ExpressionEnum::XContinue(id) = expression.read().await.subtype {
+                    Some(expression.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-implementation"}}}
+impl PartialEq for XContinue {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,89 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_loop-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-use-statements"}}}
+use async_std::sync::Arc;
+use async_std::sync::RwLock;
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+
+use crate::v2::lu_dog_async::types::expression::Expression;
+use crate::v2::lu_dog_async::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_async::store::ObjectStore as LuDogAsyncStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-documentation"}}}
+/// The Loop Expression
+///
+/// An expression that evaluates BLOCK until it's broken out of. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XLoop {
+    pub id: usize,
+    pub label: String,
+    /// R123: [`XLoop`] 'executes a' [`Expression`]
+    pub block: usize,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-implementation"}}}
+impl XLoop {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-new"}}}
+    /// Inter a new 'Loop' in the store, and return it's `id`.
+    pub async fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<XLoop>> {
+        let block = block.read().await.id;
+        store
+            .inter_x_loop(|id| {
+                Arc::new(RwLock::new(XLoop {
+                    id,
+                    label: label.to_owned(),
+                    block,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R123(1-*)
+    pub async fn r123_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<Expression>>> + '_ {
+        stream::iter(vec![store.exhume_expression(&self.block).await.unwrap()].into_iter())
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub async fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        store
+            .iter_expression()
+            .await
+            .filter_map(|expression| async move {
+                if let ExpressionEnum::XLoop(id) = expression.read().await.subtype {
+                    Some(expression.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-implementation"}}}
+impl PartialEq for XLoop {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.block == other.block
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,103 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_while-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-use-statements"}}}
+use async_std::sync::Arc;
+use async_std::sync::RwLock;
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+
+use crate::v2::lu_dog_async::types::expression::Expression;
+use crate::v2::lu_dog_async::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_async::store::ObjectStore as LuDogAsyncStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-documentation"}}}
+/// The While Expression
+///
+/// An expression that evaluates BLOCK for as long as TEST is true. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XWhile {
+    pub id: usize,
+    pub label: String,
+    /// R121: [`XWhile`] 'executes a' [`Expression`]
+    pub block: usize,
+    /// R122: [`XWhile`] 'loops while' [`Expression`]
+    pub test: usize,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl XWhile {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-new"}}}
+    /// Inter a new 'While' in the store, and return it's `id`.
+    pub async fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        test: &Arc<RwLock<Expression>>,
+        store: &mut LuDogAsyncStore,
+    ) -> Arc<RwLock<XWhile>> {
+        let block = block.read().await.id;
+        let test = test.read().await.id;
+        store
+            .inter_x_while(|id| {
+                Arc::new(RwLock::new(XWhile {
+                    id,
+                    label: label.to_owned(),
+                    block,
+                    test,
+                }))
+            })
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R121(1-*)
+    pub async fn r121_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<Expression>>> + '_ {
+        stream::iter(vec![store.exhume_expression(&self.block).await.unwrap()].into_iter())
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-test"}}}
+    /// Navigate to [`Expression`] across R122(1-*)
+    pub async fn r122_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> impl futures::Stream<Item = Arc<RwLock<Expression>>> + '_ {
+        stream::iter(vec![store.exhume_expression(&self.test).await.unwrap()].into_iter())
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub async fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogAsyncStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        store
+            .iter_expression()
+            .await
+            .filter_map(|expression| async move {
+                if let ExpressionEnum::XWhile(id) = expression.read().await.subtype {
+                    Some(expression.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+            .await
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl PartialEq for XWhile {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.block == other.block && self.test == other.test
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
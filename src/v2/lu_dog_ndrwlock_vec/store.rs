@@ -14,9 +14,11 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`CharLiteral`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -55,6 +57,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`Map`]
 //! * [`MapElement`]
@@ -92,6 +95,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_ndrwlock_vec-object-store-definition"}}}
 use no_deadlocks::RwLock;
 use std::sync::Arc;
@@ -114,11 +118,15 @@ use crate::v2::lu_dog_ndrwlock_vec::types::{
     PathElement, Pattern, RangeExpression, ResultStatement, Span, Statement, StaticMethodCall,
     StringBit, StringLiteral, StructExpression, StructField, StructGeneric, TupleField, TypeCast,
     Unary, Unit, UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct,
-    XFuture, XIf, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore, ADDITION,
-    AND, ANY_LIST, ASSIGNMENT, CHAR, DIVISION, EMPTY, EMPTY_EXPRESSION, EQUAL, FALSE_LITERAL, FROM,
-    FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT, LESS_THAN,
-    LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, NEGATION, NOT, NOT_EQUAL, OR, RANGE,
-    SUBTRACTION, TASK, TO, TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
+    XBreak, XContinue, XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn,
+    XValue, XWhile, ZObjectStore, ADDITION, ADDITION_ASSIGNMENT, AND, ANY_LIST, ASSIGNMENT,
+    BITWISE_AND, BITWISE_AND_ASSIGNMENT, BITWISE_OR, BITWISE_OR_ASSIGNMENT, BITWISE_XOR,
+    BITWISE_XOR_ASSIGNMENT, CHAR, DIVISION, DIVISION_ASSIGNMENT, EMPTY, EMPTY_EXPRESSION, EQUAL,
+    FALSE_LITERAL, FROM, FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT,
+    LESS_THAN, LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, MULTIPLICATION_ASSIGNMENT, NEGATION,
+    NOT, NOT_EQUAL, OR, RANGE, REMAINDER, REMAINDER_ASSIGNMENT, SHIFT_LEFT, SHIFT_LEFT_ASSIGNMENT,
+    SHIFT_RIGHT, SHIFT_RIGHT_ASSIGNMENT, SUBTRACTION, SUBTRACTION_ASSIGNMENT, TASK, TO,
+    TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
 };
 
 #[derive(Debug)]
@@ -142,8 +150,11 @@ pub struct ObjectStore {
     boolean_literal: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanLiteral>>>>>>,
     boolean_literal_dirty: bool,
     boolean_operator_free_list: std::sync::Mutex<Vec<usize>>,
+    x_break_free_list: std::sync::Mutex<Vec<usize>>,
     boolean_operator: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanOperator>>>>>>,
+    x_break: Arc<RwLock<Vec<Option<Arc<RwLock<XBreak>>>>>>,
     boolean_operator_dirty: bool,
+    x_break_dirty: bool,
     call_free_list: std::sync::Mutex<Vec<usize>>,
     call: Arc<RwLock<Vec<Option<Arc<RwLock<Call>>>>>>,
     call_dirty: bool,
@@ -151,8 +162,11 @@ pub struct ObjectStore {
     char_literal: Arc<RwLock<Vec<Option<Arc<RwLock<CharLiteral>>>>>>,
     char_literal_dirty: bool,
     comparison_free_list: std::sync::Mutex<Vec<usize>>,
+    x_continue_free_list: std::sync::Mutex<Vec<usize>>,
     comparison: Arc<RwLock<Vec<Option<Arc<RwLock<Comparison>>>>>>,
+    x_continue: Arc<RwLock<Vec<Option<Arc<RwLock<XContinue>>>>>>,
     comparison_dirty: bool,
+    x_continue_dirty: bool,
     data_structure_free_list: std::sync::Mutex<Vec<usize>>,
     data_structure: Arc<RwLock<Vec<Option<Arc<RwLock<DataStructure>>>>>>,
     data_structure_dirty: bool,
@@ -268,8 +282,11 @@ pub struct ObjectStore {
     literal: Arc<RwLock<Vec<Option<Arc<RwLock<Literal>>>>>>,
     literal_dirty: bool,
     local_variable_free_list: std::sync::Mutex<Vec<usize>>,
+    x_loop_free_list: std::sync::Mutex<Vec<usize>>,
     local_variable: Arc<RwLock<Vec<Option<Arc<RwLock<LocalVariable>>>>>>,
+    x_loop: Arc<RwLock<Vec<Option<Arc<RwLock<XLoop>>>>>>,
     local_variable_dirty: bool,
+    x_loop_dirty: bool,
     x_macro_free_list: std::sync::Mutex<Vec<usize>>,
     x_macro: Arc<RwLock<Vec<Option<Arc<RwLock<XMacro>>>>>>,
     x_macro_dirty: bool,
@@ -382,8 +399,11 @@ pub struct ObjectStore {
     variable: Arc<RwLock<Vec<Option<Arc<RwLock<Variable>>>>>>,
     variable_dirty: bool,
     variable_expression_free_list: std::sync::Mutex<Vec<usize>>,
+    x_while_free_list: std::sync::Mutex<Vec<usize>>,
     variable_expression: Arc<RwLock<Vec<Option<Arc<RwLock<VariableExpression>>>>>>,
+    x_while: Arc<RwLock<Vec<Option<Arc<RwLock<XWhile>>>>>>,
     variable_expression_dirty: bool,
+    x_while_dirty: bool,
 }
 
 impl Clone for ObjectStore {
@@ -412,8 +432,11 @@ impl Clone for ObjectStore {
             boolean_operator_free_list: Mutex::new(
                 self.boolean_operator_free_list.lock().unwrap().clone(),
             ),
+            x_break_free_list: Mutex::new(self.x_break_free_list.lock().unwrap().clone()),
             boolean_operator: Arc::new(RwLock::new(self.boolean_operator.read().unwrap().clone())),
+            x_break: Arc::new(RwLock::new(self.x_break.read().unwrap().clone())),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: Mutex::new(self.call_free_list.lock().unwrap().clone()),
             call: Arc::new(RwLock::new(self.call.read().unwrap().clone())),
             call_dirty: false,
@@ -421,8 +444,11 @@ impl Clone for ObjectStore {
             char_literal: Arc::new(RwLock::new(self.char_literal.read().unwrap().clone())),
             char_literal_dirty: false,
             comparison_free_list: Mutex::new(self.comparison_free_list.lock().unwrap().clone()),
+            x_continue_free_list: Mutex::new(self.x_continue_free_list.lock().unwrap().clone()),
             comparison: Arc::new(RwLock::new(self.comparison.read().unwrap().clone())),
+            x_continue: Arc::new(RwLock::new(self.x_continue.read().unwrap().clone())),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: Mutex::new(
                 self.data_structure_free_list.lock().unwrap().clone(),
             ),
@@ -591,8 +617,11 @@ impl Clone for ObjectStore {
             local_variable_free_list: Mutex::new(
                 self.local_variable_free_list.lock().unwrap().clone(),
             ),
+            x_loop_free_list: Mutex::new(self.x_loop_free_list.lock().unwrap().clone()),
             local_variable: Arc::new(RwLock::new(self.local_variable.read().unwrap().clone())),
+            x_loop: Arc::new(RwLock::new(self.x_loop.read().unwrap().clone())),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: Mutex::new(self.x_macro_free_list.lock().unwrap().clone()),
             x_macro: Arc::new(RwLock::new(self.x_macro.read().unwrap().clone())),
             x_macro_dirty: false,
@@ -743,10 +772,13 @@ impl Clone for ObjectStore {
             variable_expression_free_list: Mutex::new(
                 self.variable_expression_free_list.lock().unwrap().clone(),
             ),
+            x_while_free_list: Mutex::new(self.x_while_free_list.lock().unwrap().clone()),
             variable_expression: Arc::new(RwLock::new(
                 self.variable_expression.read().unwrap().clone(),
             )),
+            x_while: Arc::new(RwLock::new(self.x_while.read().unwrap().clone())),
             variable_expression_dirty: false,
+            x_while_dirty: false,
         }
     }
 }
@@ -772,8 +804,11 @@ impl ObjectStore {
             boolean_literal: Arc::new(RwLock::new(Vec::new())),
             boolean_literal_dirty: false,
             boolean_operator_free_list: std::sync::Mutex::new(Vec::new()),
+            x_break_free_list: std::sync::Mutex::new(Vec::new()),
             boolean_operator: Arc::new(RwLock::new(Vec::new())),
+            x_break: Arc::new(RwLock::new(Vec::new())),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: std::sync::Mutex::new(Vec::new()),
             call: Arc::new(RwLock::new(Vec::new())),
             call_dirty: false,
@@ -781,8 +816,11 @@ impl ObjectStore {
             char_literal: Arc::new(RwLock::new(Vec::new())),
             char_literal_dirty: false,
             comparison_free_list: std::sync::Mutex::new(Vec::new()),
+            x_continue_free_list: std::sync::Mutex::new(Vec::new()),
             comparison: Arc::new(RwLock::new(Vec::new())),
+            x_continue: Arc::new(RwLock::new(Vec::new())),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: std::sync::Mutex::new(Vec::new()),
             data_structure: Arc::new(RwLock::new(Vec::new())),
             data_structure_dirty: false,
@@ -898,8 +936,11 @@ impl ObjectStore {
             literal: Arc::new(RwLock::new(Vec::new())),
             literal_dirty: false,
             local_variable_free_list: std::sync::Mutex::new(Vec::new()),
+            x_loop_free_list: std::sync::Mutex::new(Vec::new()),
             local_variable: Arc::new(RwLock::new(Vec::new())),
+            x_loop: Arc::new(RwLock::new(Vec::new())),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: std::sync::Mutex::new(Vec::new()),
             x_macro: Arc::new(RwLock::new(Vec::new())),
             x_macro_dirty: false,
@@ -1012,8 +1053,11 @@ impl ObjectStore {
             variable: Arc::new(RwLock::new(Vec::new())),
             variable_dirty: false,
             variable_expression_free_list: std::sync::Mutex::new(Vec::new()),
+            x_while_free_list: std::sync::Mutex::new(Vec::new()),
             variable_expression: Arc::new(RwLock::new(Vec::new())),
+            x_while: Arc::new(RwLock::new(Vec::new())),
             variable_expression_dirty: false,
+            x_while_dirty: false,
         };
 
         // Initialize Singleton Subtypes
@@ -1277,6 +1321,42 @@ impl ObjectStore {
             });
         }
 
+        if other.x_break_dirty {
+            other.x_break.read().unwrap().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_break, if it's not there add it to x_break.
+                    if self
+                        .x_break
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read().unwrap() == *x.read().unwrap()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_break.read().unwrap().len();
+                        // if x.read().unwrap().id != _index_ {
+                        //     x.write().unwrap().id = _index_;
+                        // }
+                        self.inter_x_break(|id| -> Arc<RwLock<XBreak>> {
+                            if x.read().unwrap().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().unwrap().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.call_dirty {
             other.call.read().unwrap().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -1385,6 +1465,42 @@ impl ObjectStore {
             });
         }
 
+        if other.x_continue_dirty {
+            other.x_continue.read().unwrap().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_continue, if it's not there add it to x_continue.
+                    if self
+                        .x_continue
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read().unwrap() == *x.read().unwrap()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_continue.read().unwrap().len();
+                        // if x.read().unwrap().id != _index_ {
+                        //     x.write().unwrap().id = _index_;
+                        // }
+                        self.inter_x_continue(|id| -> Arc<RwLock<XContinue>> {
+                            if x.read().unwrap().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().unwrap().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.data_structure_dirty {
             other.data_structure.read().unwrap().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -2796,6 +2912,42 @@ impl ObjectStore {
             });
         }
 
+        if other.x_loop_dirty {
+            other.x_loop.read().unwrap().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_loop, if it's not there add it to x_loop.
+                    if self
+                        .x_loop
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read().unwrap() == *x.read().unwrap()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_loop.read().unwrap().len();
+                        // if x.read().unwrap().id != _index_ {
+                        //     x.write().unwrap().id = _index_;
+                        // }
+                        self.inter_x_loop(|id| -> Arc<RwLock<XLoop>> {
+                            if x.read().unwrap().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().unwrap().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.x_macro_dirty {
             other.x_macro.read().unwrap().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -4158,6 +4310,41 @@ impl ObjectStore {
                     }
                 });
         }
+        if other.x_while_dirty {
+            other.x_while.read().unwrap().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_while, if it's not there add it to x_while.
+                    if self
+                        .x_while
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read().unwrap() == *x.read().unwrap()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_while.read().unwrap().len();
+                        // if x.read().unwrap().id != _index_ {
+                        //     x.write().unwrap().id = _index_;
+                        // }
+                        self.inter_x_while(|id| -> Arc<RwLock<XWhile>> {
+                            if x.read().unwrap().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().unwrap().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
     }
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_ndrwlock_vec-object-store-methods"}}}
     /// Inter (insert) [`Argument`] into the store.
@@ -4711,6 +4898,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_break<F>(&mut self, x_break: F) -> Arc<RwLock<XBreak>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XBreak>>,
+    {
+        let _index = if let Some(_index) = self.x_break_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_break.read().unwrap().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_break.write().unwrap().push(None);
+            _index
+        };
+
+        let x_break = x_break(_index);
+
+        let found = if let Some(x_break) = self.x_break.read().unwrap().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read().unwrap() == *x_break.read().unwrap()
+            } else {
+                false
+            }
+        }) {
+            x_break.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_break) = found {
+            tracing::debug!(target: "store", "found duplicate {x_break:?}.");
+            self.x_break_free_list.lock().unwrap().push(_index);
+            x_break.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_break:?}.");
+            self.x_break.write().unwrap()[_index] = Some(x_break.clone());
+            self.x_break_dirty = true;
+            x_break
+        }
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_break(&self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        match self.x_break.read().unwrap().get(*id) {
+            Some(x_break) => x_break.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_break(&mut self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        tracing::debug!(target: "store", "exorcising x_break slot: {id}.");
+        let result = self.x_break.write().unwrap()[*id].take();
+        self.x_break_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    #[inline]
+    pub fn iter_x_break(&self) -> impl Iterator<Item = Arc<RwLock<XBreak>>> + '_ {
+        let len = self.x_break.read().unwrap().len();
+        (0..len)
+            .filter(|i| self.x_break.read().unwrap()[*i].is_some())
+            .map(move |i| {
+                self.x_break.read().unwrap()[i]
+                    .as_ref()
+                    .map(|x_break| x_break.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     #[inline]
@@ -4947,6 +5212,85 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_continue<F>(&mut self, x_continue: F) -> Arc<RwLock<XContinue>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XContinue>>,
+    {
+        let _index = if let Some(_index) = self.x_continue_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_continue.read().unwrap().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_continue.write().unwrap().push(None);
+            _index
+        };
+
+        let x_continue = x_continue(_index);
+
+        let found = if let Some(x_continue) =
+            self.x_continue.read().unwrap().iter().find(|stored| {
+                if let Some(stored) = stored {
+                    *stored.read().unwrap() == *x_continue.read().unwrap()
+                } else {
+                    false
+                }
+            }) {
+            x_continue.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_continue) = found {
+            tracing::debug!(target: "store", "found duplicate {x_continue:?}.");
+            self.x_continue_free_list.lock().unwrap().push(_index);
+            x_continue.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_continue:?}.");
+            self.x_continue.write().unwrap()[_index] = Some(x_continue.clone());
+            self.x_continue_dirty = true;
+            x_continue
+        }
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_continue(&self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        match self.x_continue.read().unwrap().get(*id) {
+            Some(x_continue) => x_continue.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_continue(&mut self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        tracing::debug!(target: "store", "exorcising x_continue slot: {id}.");
+        let result = self.x_continue.write().unwrap()[*id].take();
+        self.x_continue_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    #[inline]
+    pub fn iter_x_continue(&self) -> impl Iterator<Item = Arc<RwLock<XContinue>>> + '_ {
+        let len = self.x_continue.read().unwrap().len();
+        (0..len)
+            .filter(|i| self.x_continue.read().unwrap()[*i].is_some())
+            .map(move |i| {
+                self.x_continue.read().unwrap()[i]
+                    .as_ref()
+                    .map(|x_continue| x_continue.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     #[inline]
@@ -8120,6 +8464,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_loop<F>(&mut self, x_loop: F) -> Arc<RwLock<XLoop>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XLoop>>,
+    {
+        let _index = if let Some(_index) = self.x_loop_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_loop.read().unwrap().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_loop.write().unwrap().push(None);
+            _index
+        };
+
+        let x_loop = x_loop(_index);
+
+        let found = if let Some(x_loop) = self.x_loop.read().unwrap().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read().unwrap() == *x_loop.read().unwrap()
+            } else {
+                false
+            }
+        }) {
+            x_loop.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_loop) = found {
+            tracing::debug!(target: "store", "found duplicate {x_loop:?}.");
+            self.x_loop_free_list.lock().unwrap().push(_index);
+            x_loop.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_loop:?}.");
+            self.x_loop.write().unwrap()[_index] = Some(x_loop.clone());
+            self.x_loop_dirty = true;
+            x_loop
+        }
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_loop(&self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        match self.x_loop.read().unwrap().get(*id) {
+            Some(x_loop) => x_loop.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_loop(&mut self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        tracing::debug!(target: "store", "exorcising x_loop slot: {id}.");
+        let result = self.x_loop.write().unwrap()[*id].take();
+        self.x_loop_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    #[inline]
+    pub fn iter_x_loop(&self) -> impl Iterator<Item = Arc<RwLock<XLoop>>> + '_ {
+        let len = self.x_loop.read().unwrap().len();
+        (0..len)
+            .filter(|i| self.x_loop.read().unwrap()[*i].is_some())
+            .map(move |i| {
+                self.x_loop.read().unwrap()[i]
+                    .as_ref()
+                    .map(|x_loop| x_loop.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     #[inline]
@@ -11179,6 +11601,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_while<F>(&mut self, x_while: F) -> Arc<RwLock<XWhile>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XWhile>>,
+    {
+        let _index = if let Some(_index) = self.x_while_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_while.read().unwrap().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_while.write().unwrap().push(None);
+            _index
+        };
+
+        let x_while = x_while(_index);
+
+        let found = if let Some(x_while) = self.x_while.read().unwrap().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read().unwrap() == *x_while.read().unwrap()
+            } else {
+                false
+            }
+        }) {
+            x_while.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_while) = found {
+            tracing::debug!(target: "store", "found duplicate {x_while:?}.");
+            self.x_while_free_list.lock().unwrap().push(_index);
+            x_while.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_while:?}.");
+            self.x_while.write().unwrap()[_index] = Some(x_while.clone());
+            self.x_while_dirty = true;
+            x_while
+        }
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_while(&self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        match self.x_while.read().unwrap().get(*id) {
+            Some(x_while) => x_while.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_while(&mut self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        tracing::debug!(target: "store", "exorcising x_while slot: {id}.");
+        let result = self.x_while.write().unwrap()[*id].take();
+        self.x_while_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    #[inline]
+    pub fn iter_x_while(&self) -> impl Iterator<Item = Arc<RwLock<XWhile>>> + '_ {
+        let len = self.x_while.read().unwrap().len();
+        (0..len)
+            .filter(|i| self.x_while.read().unwrap()[*i].is_some())
+            .map(move |i| {
+                self.x_while.read().unwrap()[i]
+                    .as_ref()
+                    .map(|x_while| x_while.clone())
+                    .unwrap()
+            })
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -0,0 +1,87 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_break-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-use-statements"}}}
+use no_deadlocks::RwLock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::Expression;
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_ndrwlock_vec::store::ObjectStore as LuDogNdrwlockVecStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-documentation"}}}
+/// The Break Expression
+///
+/// Leaves the innermost loop, or the loop with the label, optionally with a value.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XBreak {
+    pub id: usize,
+    pub label: String,
+    /// R124: [`XBreak`] 'exits with' [`Expression`]
+    pub expression: Option<usize>,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-implementation"}}}
+impl XBreak {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-new"}}}
+    /// Inter a new 'Break' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        expression: Option<&Arc<RwLock<Expression>>>,
+        store: &mut LuDogNdrwlockVecStore,
+    ) -> Arc<RwLock<XBreak>> {
+        store.inter_x_break(|id| {
+            Arc::new(RwLock::new(XBreak {
+                id,
+                label: label.to_owned(),
+                expression: expression.map(|expression| expression.read().unwrap().id),
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-struct-impl-nav-forward-cond-to-expression"}}}
+    /// Navigate to [`Expression`] across R124(1-*c)
+    pub fn r124_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        match self.expression {
+            Some(ref expression) => vec![store.exhume_expression(&expression).unwrap()],
+            None => Vec::new(),
+        }
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XBreak(id) = expression.read().unwrap().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_break-implementation"}}}
+impl PartialEq for XBreak {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.expression == other.expression
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,83 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_loop-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-use-statements"}}}
+use no_deadlocks::RwLock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::Expression;
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_ndrwlock_vec::store::ObjectStore as LuDogNdrwlockVecStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-documentation"}}}
+/// The Loop Expression
+///
+/// An expression that evaluates BLOCK until it's broken out of. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XLoop {
+    pub id: usize,
+    pub label: String,
+    /// R123: [`XLoop`] 'executes a' [`Expression`]
+    pub block: usize,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-implementation"}}}
+impl XLoop {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-new"}}}
+    /// Inter a new 'Loop' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        store: &mut LuDogNdrwlockVecStore,
+    ) -> Arc<RwLock<XLoop>> {
+        store.inter_x_loop(|id| {
+            Arc::new(RwLock::new(XLoop {
+                id,
+                label: label.to_owned(),
+                block: block.read().unwrap().id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R123(1-*)
+    pub fn r123_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.block).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XLoop(id) = expression.read().unwrap().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-implementation"}}}
+impl PartialEq for XLoop {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.block == other.block
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,96 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_while-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-use-statements"}}}
+use no_deadlocks::RwLock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::Expression;
+use crate::v2::lu_dog_ndrwlock_vec::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_ndrwlock_vec::store::ObjectStore as LuDogNdrwlockVecStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-documentation"}}}
+/// The While Expression
+///
+/// An expression that evaluates BLOCK for as long as TEST is true. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XWhile {
+    pub id: usize,
+    pub label: String,
+    /// R121: [`XWhile`] 'executes a' [`Expression`]
+    pub block: usize,
+    /// R122: [`XWhile`] 'loops while' [`Expression`]
+    pub test: usize,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl XWhile {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-new"}}}
+    /// Inter a new 'While' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        test: &Arc<RwLock<Expression>>,
+        store: &mut LuDogNdrwlockVecStore,
+    ) -> Arc<RwLock<XWhile>> {
+        store.inter_x_while(|id| {
+            Arc::new(RwLock::new(XWhile {
+                id,
+                label: label.to_owned(),
+                block: block.read().unwrap().id,
+                test: test.read().unwrap().id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R121(1-*)
+    pub fn r121_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.block).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-test"}}}
+    /// Navigate to [`Expression`] across R122(1-*)
+    pub fn r122_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.test).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogNdrwlockVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XWhile(id) = expression.read().unwrap().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl PartialEq for XWhile {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.block == other.block && self.test == other.test
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
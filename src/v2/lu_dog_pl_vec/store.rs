@@ -14,9 +14,11 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`CharLiteral`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -55,6 +57,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`Map`]
 //! * [`MapElement`]
@@ -92,6 +95,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_pl_vec-object-store-definition"}}}
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -119,11 +123,15 @@ use crate::v2::lu_dog_pl_vec::types::{
     PathElement, Pattern, RangeExpression, ResultStatement, Span, Statement, StaticMethodCall,
     StringBit, StringLiteral, StructExpression, StructField, StructGeneric, TupleField, TypeCast,
     Unary, Unit, UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct,
-    XFuture, XIf, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore, ADDITION,
-    AND, ANY_LIST, ASSIGNMENT, CHAR, DIVISION, EMPTY, EMPTY_EXPRESSION, EQUAL, FALSE_LITERAL, FROM,
-    FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT, LESS_THAN,
-    LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, NEGATION, NOT, NOT_EQUAL, OR, RANGE,
-    SUBTRACTION, TASK, TO, TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
+    XBreak, XContinue, XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn,
+    XValue, XWhile, ZObjectStore, ADDITION, ADDITION_ASSIGNMENT, AND, ANY_LIST, ASSIGNMENT,
+    BITWISE_AND, BITWISE_AND_ASSIGNMENT, BITWISE_OR, BITWISE_OR_ASSIGNMENT, BITWISE_XOR,
+    BITWISE_XOR_ASSIGNMENT, CHAR, DIVISION, DIVISION_ASSIGNMENT, EMPTY, EMPTY_EXPRESSION, EQUAL,
+    FALSE_LITERAL, FROM, FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT,
+    LESS_THAN, LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, MULTIPLICATION_ASSIGNMENT, NEGATION,
+    NOT, NOT_EQUAL, OR, RANGE, REMAINDER, REMAINDER_ASSIGNMENT, SHIFT_LEFT, SHIFT_LEFT_ASSIGNMENT,
+    SHIFT_RIGHT, SHIFT_RIGHT_ASSIGNMENT, SUBTRACTION, SUBTRACTION_ASSIGNMENT, TASK, TO,
+    TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -147,8 +155,11 @@ pub struct ObjectStore {
     boolean_literal: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanLiteral>>>>>>,
     boolean_literal_dirty: bool,
     boolean_operator_free_list: std::sync::Mutex<Vec<usize>>,
+    x_break_free_list: std::sync::Mutex<Vec<usize>>,
     boolean_operator: Arc<RwLock<Vec<Option<Arc<RwLock<BooleanOperator>>>>>>,
+    x_break: Arc<RwLock<Vec<Option<Arc<RwLock<XBreak>>>>>>,
     boolean_operator_dirty: bool,
+    x_break_dirty: bool,
     call_free_list: std::sync::Mutex<Vec<usize>>,
     call: Arc<RwLock<Vec<Option<Arc<RwLock<Call>>>>>>,
     call_dirty: bool,
@@ -156,8 +167,11 @@ pub struct ObjectStore {
     char_literal: Arc<RwLock<Vec<Option<Arc<RwLock<CharLiteral>>>>>>,
     char_literal_dirty: bool,
     comparison_free_list: std::sync::Mutex<Vec<usize>>,
+    x_continue_free_list: std::sync::Mutex<Vec<usize>>,
     comparison: Arc<RwLock<Vec<Option<Arc<RwLock<Comparison>>>>>>,
+    x_continue: Arc<RwLock<Vec<Option<Arc<RwLock<XContinue>>>>>>,
     comparison_dirty: bool,
+    x_continue_dirty: bool,
     data_structure_free_list: std::sync::Mutex<Vec<usize>>,
     data_structure: Arc<RwLock<Vec<Option<Arc<RwLock<DataStructure>>>>>>,
     data_structure_dirty: bool,
@@ -273,8 +287,11 @@ pub struct ObjectStore {
     literal: Arc<RwLock<Vec<Option<Arc<RwLock<Literal>>>>>>,
     literal_dirty: bool,
     local_variable_free_list: std::sync::Mutex<Vec<usize>>,
+    x_loop_free_list: std::sync::Mutex<Vec<usize>>,
     local_variable: Arc<RwLock<Vec<Option<Arc<RwLock<LocalVariable>>>>>>,
+    x_loop: Arc<RwLock<Vec<Option<Arc<RwLock<XLoop>>>>>>,
     local_variable_dirty: bool,
+    x_loop_dirty: bool,
     x_macro_free_list: std::sync::Mutex<Vec<usize>>,
     x_macro: Arc<RwLock<Vec<Option<Arc<RwLock<XMacro>>>>>>,
     x_macro_dirty: bool,
@@ -387,8 +404,11 @@ pub struct ObjectStore {
     variable: Arc<RwLock<Vec<Option<Arc<RwLock<Variable>>>>>>,
     variable_dirty: bool,
     variable_expression_free_list: std::sync::Mutex<Vec<usize>>,
+    x_while_free_list: std::sync::Mutex<Vec<usize>>,
     variable_expression: Arc<RwLock<Vec<Option<Arc<RwLock<VariableExpression>>>>>>,
+    x_while: Arc<RwLock<Vec<Option<Arc<RwLock<XWhile>>>>>>,
     variable_expression_dirty: bool,
+    x_while_dirty: bool,
 }
 
 impl Clone for ObjectStore {
@@ -417,8 +437,11 @@ impl Clone for ObjectStore {
             boolean_operator_free_list: Mutex::new(
                 self.boolean_operator_free_list.lock().unwrap().clone(),
             ),
+            x_break_free_list: Mutex::new(self.x_break_free_list.lock().unwrap().clone()),
             boolean_operator: Arc::new(RwLock::new(self.boolean_operator.read().unwrap().clone())),
+            x_break: Arc::new(RwLock::new(self.x_break.read().unwrap().clone())),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: Mutex::new(self.call_free_list.lock().unwrap().clone()),
             call: Arc::new(RwLock::new(self.call.read().unwrap().clone())),
             call_dirty: false,
@@ -426,8 +449,11 @@ impl Clone for ObjectStore {
             char_literal: Arc::new(RwLock::new(self.char_literal.read().unwrap().clone())),
             char_literal_dirty: false,
             comparison_free_list: Mutex::new(self.comparison_free_list.lock().unwrap().clone()),
+            x_continue_free_list: Mutex::new(self.x_continue_free_list.lock().unwrap().clone()),
             comparison: Arc::new(RwLock::new(self.comparison.read().unwrap().clone())),
+            x_continue: Arc::new(RwLock::new(self.x_continue.read().unwrap().clone())),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: Mutex::new(
                 self.data_structure_free_list.lock().unwrap().clone(),
             ),
@@ -596,8 +622,11 @@ impl Clone for ObjectStore {
             local_variable_free_list: Mutex::new(
                 self.local_variable_free_list.lock().unwrap().clone(),
             ),
+            x_loop_free_list: Mutex::new(self.x_loop_free_list.lock().unwrap().clone()),
             local_variable: Arc::new(RwLock::new(self.local_variable.read().unwrap().clone())),
+            x_loop: Arc::new(RwLock::new(self.x_loop.read().unwrap().clone())),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: Mutex::new(self.x_macro_free_list.lock().unwrap().clone()),
             x_macro: Arc::new(RwLock::new(self.x_macro.read().unwrap().clone())),
             x_macro_dirty: false,
@@ -748,10 +777,13 @@ impl Clone for ObjectStore {
             variable_expression_free_list: Mutex::new(
                 self.variable_expression_free_list.lock().unwrap().clone(),
             ),
+            x_while_free_list: Mutex::new(self.x_while_free_list.lock().unwrap().clone()),
             variable_expression: Arc::new(RwLock::new(
                 self.variable_expression.read().unwrap().clone(),
             )),
+            x_while: Arc::new(RwLock::new(self.x_while.read().unwrap().clone())),
             variable_expression_dirty: false,
+            x_while_dirty: false,
         }
     }
 }
@@ -777,8 +809,11 @@ impl ObjectStore {
             boolean_literal: Arc::new(RwLock::new(Vec::new())),
             boolean_literal_dirty: false,
             boolean_operator_free_list: std::sync::Mutex::new(Vec::new()),
+            x_break_free_list: std::sync::Mutex::new(Vec::new()),
             boolean_operator: Arc::new(RwLock::new(Vec::new())),
+            x_break: Arc::new(RwLock::new(Vec::new())),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: std::sync::Mutex::new(Vec::new()),
             call: Arc::new(RwLock::new(Vec::new())),
             call_dirty: false,
@@ -786,8 +821,11 @@ impl ObjectStore {
             char_literal: Arc::new(RwLock::new(Vec::new())),
             char_literal_dirty: false,
             comparison_free_list: std::sync::Mutex::new(Vec::new()),
+            x_continue_free_list: std::sync::Mutex::new(Vec::new()),
             comparison: Arc::new(RwLock::new(Vec::new())),
+            x_continue: Arc::new(RwLock::new(Vec::new())),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: std::sync::Mutex::new(Vec::new()),
             data_structure: Arc::new(RwLock::new(Vec::new())),
             data_structure_dirty: false,
@@ -903,8 +941,11 @@ impl ObjectStore {
             literal: Arc::new(RwLock::new(Vec::new())),
             literal_dirty: false,
             local_variable_free_list: std::sync::Mutex::new(Vec::new()),
+            x_loop_free_list: std::sync::Mutex::new(Vec::new()),
             local_variable: Arc::new(RwLock::new(Vec::new())),
+            x_loop: Arc::new(RwLock::new(Vec::new())),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: std::sync::Mutex::new(Vec::new()),
             x_macro: Arc::new(RwLock::new(Vec::new())),
             x_macro_dirty: false,
@@ -1017,8 +1058,11 @@ impl ObjectStore {
             variable: Arc::new(RwLock::new(Vec::new())),
             variable_dirty: false,
             variable_expression_free_list: std::sync::Mutex::new(Vec::new()),
+            x_while_free_list: std::sync::Mutex::new(Vec::new()),
             variable_expression: Arc::new(RwLock::new(Vec::new())),
+            x_while: Arc::new(RwLock::new(Vec::new())),
             variable_expression_dirty: false,
+            x_while_dirty: false,
         };
 
         // Initialize Singleton Subtypes
@@ -1275,6 +1319,41 @@ impl ObjectStore {
             });
         }
 
+        if other.x_break_dirty {
+            other.x_break.read().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_break, if it's not there add it to x_break.
+                    if self
+                        .x_break
+                        .read()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read() == *x.read()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_break.read().len();
+                        // if x.read().id != _index_ {
+                        //     x.write().id = _index_;
+                        // }
+                        self.inter_x_break(|id| -> Arc<RwLock<XBreak>> {
+                            if x.read().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.call_dirty {
             other.call.read().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -1380,6 +1459,41 @@ impl ObjectStore {
             });
         }
 
+        if other.x_continue_dirty {
+            other.x_continue.read().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_continue, if it's not there add it to x_continue.
+                    if self
+                        .x_continue
+                        .read()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read() == *x.read()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_continue.read().len();
+                        // if x.read().id != _index_ {
+                        //     x.write().id = _index_;
+                        // }
+                        self.inter_x_continue(|id| -> Arc<RwLock<XContinue>> {
+                            if x.read().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.data_structure_dirty {
             other.data_structure.read().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -2712,6 +2826,41 @@ impl ObjectStore {
             });
         }
 
+        if other.x_loop_dirty {
+            other.x_loop.read().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_loop, if it's not there add it to x_loop.
+                    if self
+                        .x_loop
+                        .read()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read() == *x.read()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_loop.read().len();
+                        // if x.read().id != _index_ {
+                        //     x.write().id = _index_;
+                        // }
+                        self.inter_x_loop(|id| -> Arc<RwLock<XLoop>> {
+                            if x.read().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().id = id;
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.x_macro_dirty {
             other.x_macro.read().iter().for_each(|x| {
                 if let Some(x) = x {
@@ -4004,6 +4153,40 @@ impl ObjectStore {
                                 x.write().id = id;
                             }
 
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+        if other.x_while_dirty {
+            other.x_while.read().iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_while, if it's not there add it to x_while.
+                    if self
+                        .x_while
+                        .read()
+                        .iter()
+                        .find(|&y| {
+                            if let Some(y) = y {
+                                *y.read() == *x.read()
+                            } else {
+                                false
+                            }
+                        })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_while.read().len();
+                        // if x.read().id != _index_ {
+                        //     x.write().id = _index_;
+                        // }
+                        self.inter_x_while(|id| -> Arc<RwLock<XWhile>> {
+                            if x.read().id != id {
+                                dbg!(x, id);
+                                // panic!("id mismatch");
+                                x.write().id = id;
+                            }
+
                             x.clone()
                         });
                     }
@@ -4563,6 +4746,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_break<F>(&mut self, x_break: F) -> Arc<RwLock<XBreak>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XBreak>>,
+    {
+        let _index = if let Some(_index) = self.x_break_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_break.read().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_break.write().push(None);
+            _index
+        };
+
+        let x_break = x_break(_index);
+
+        let found = if let Some(x_break) = self.x_break.read().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read() == *x_break.read()
+            } else {
+                false
+            }
+        }) {
+            x_break.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_break) = found {
+            tracing::debug!(target: "store", "found duplicate {x_break:?}.");
+            self.x_break_free_list.lock().unwrap().push(_index);
+            x_break.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_break:?}.");
+            self.x_break.write()[_index] = Some(x_break.clone());
+            self.x_break_dirty = true;
+            x_break
+        }
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_break(&self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        match self.x_break.read().get(*id) {
+            Some(x_break) => x_break.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_break(&mut self, id: &usize) -> Option<Arc<RwLock<XBreak>>> {
+        tracing::debug!(target: "store", "exorcising x_break slot: {id}.");
+        let result = self.x_break.write()[*id].take();
+        self.x_break_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    #[inline]
+    pub fn iter_x_break(&self) -> impl Iterator<Item = Arc<RwLock<XBreak>>> + '_ {
+        let len = self.x_break.read().len();
+        (0..len)
+            .filter(|i| self.x_break.read()[*i].is_some())
+            .map(move |i| {
+                self.x_break.read()[i]
+                    .as_ref()
+                    .map(|x_break| x_break.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     #[inline]
@@ -4797,6 +5058,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_continue<F>(&mut self, x_continue: F) -> Arc<RwLock<XContinue>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XContinue>>,
+    {
+        let _index = if let Some(_index) = self.x_continue_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_continue.read().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_continue.write().push(None);
+            _index
+        };
+
+        let x_continue = x_continue(_index);
+
+        let found = if let Some(x_continue) = self.x_continue.read().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read() == *x_continue.read()
+            } else {
+                false
+            }
+        }) {
+            x_continue.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_continue) = found {
+            tracing::debug!(target: "store", "found duplicate {x_continue:?}.");
+            self.x_continue_free_list.lock().unwrap().push(_index);
+            x_continue.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_continue:?}.");
+            self.x_continue.write()[_index] = Some(x_continue.clone());
+            self.x_continue_dirty = true;
+            x_continue
+        }
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_continue(&self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        match self.x_continue.read().get(*id) {
+            Some(x_continue) => x_continue.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_continue(&mut self, id: &usize) -> Option<Arc<RwLock<XContinue>>> {
+        tracing::debug!(target: "store", "exorcising x_continue slot: {id}.");
+        let result = self.x_continue.write()[*id].take();
+        self.x_continue_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    #[inline]
+    pub fn iter_x_continue(&self) -> impl Iterator<Item = Arc<RwLock<XContinue>>> + '_ {
+        let len = self.x_continue.read().len();
+        (0..len)
+            .filter(|i| self.x_continue.read()[*i].is_some())
+            .map(move |i| {
+                self.x_continue.read()[i]
+                    .as_ref()
+                    .map(|x_continue| x_continue.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     #[inline]
@@ -7917,6 +8256,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_loop<F>(&mut self, x_loop: F) -> Arc<RwLock<XLoop>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XLoop>>,
+    {
+        let _index = if let Some(_index) = self.x_loop_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_loop.read().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_loop.write().push(None);
+            _index
+        };
+
+        let x_loop = x_loop(_index);
+
+        let found = if let Some(x_loop) = self.x_loop.read().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read() == *x_loop.read()
+            } else {
+                false
+            }
+        }) {
+            x_loop.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_loop) = found {
+            tracing::debug!(target: "store", "found duplicate {x_loop:?}.");
+            self.x_loop_free_list.lock().unwrap().push(_index);
+            x_loop.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_loop:?}.");
+            self.x_loop.write()[_index] = Some(x_loop.clone());
+            self.x_loop_dirty = true;
+            x_loop
+        }
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_loop(&self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        match self.x_loop.read().get(*id) {
+            Some(x_loop) => x_loop.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_loop(&mut self, id: &usize) -> Option<Arc<RwLock<XLoop>>> {
+        tracing::debug!(target: "store", "exorcising x_loop slot: {id}.");
+        let result = self.x_loop.write()[*id].take();
+        self.x_loop_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    #[inline]
+    pub fn iter_x_loop(&self) -> impl Iterator<Item = Arc<RwLock<XLoop>>> + '_ {
+        let len = self.x_loop.read().len();
+        (0..len)
+            .filter(|i| self.x_loop.read()[*i].is_some())
+            .map(move |i| {
+                self.x_loop.read()[i]
+                    .as_ref()
+                    .map(|x_loop| x_loop.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     #[inline]
@@ -10931,6 +11348,84 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_while<F>(&mut self, x_while: F) -> Arc<RwLock<XWhile>>
+    where
+        F: Fn(usize) -> Arc<RwLock<XWhile>>,
+    {
+        let _index = if let Some(_index) = self.x_while_free_list.lock().unwrap().pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_while.read().len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_while.write().push(None);
+            _index
+        };
+
+        let x_while = x_while(_index);
+
+        let found = if let Some(x_while) = self.x_while.read().iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.read() == *x_while.read()
+            } else {
+                false
+            }
+        }) {
+            x_while.clone()
+        } else {
+            None
+        };
+
+        if let Some(x_while) = found {
+            tracing::debug!(target: "store", "found duplicate {x_while:?}.");
+            self.x_while_free_list.lock().unwrap().push(_index);
+            x_while.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_while:?}.");
+            self.x_while.write()[_index] = Some(x_while.clone());
+            self.x_while_dirty = true;
+            x_while
+        }
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_while(&self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        match self.x_while.read().get(*id) {
+            Some(x_while) => x_while.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_while(&mut self, id: &usize) -> Option<Arc<RwLock<XWhile>>> {
+        tracing::debug!(target: "store", "exorcising x_while slot: {id}.");
+        let result = self.x_while.write()[*id].take();
+        self.x_while_free_list.lock().unwrap().push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    #[inline]
+    pub fn iter_x_while(&self) -> impl Iterator<Item = Arc<RwLock<XWhile>>> + '_ {
+        let len = self.x_while.read().len();
+        (0..len)
+            .filter(|i| self.x_while.read()[*i].is_some())
+            .map(move |i| {
+                self.x_while.read()[i]
+                    .as_ref()
+                    .map(|x_while| x_while.clone())
+                    .unwrap()
+            })
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_pl_vec-object-store-persistence"}}}
@@ -11055,6 +11550,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Break.
+        {
+            let path = path.join("x_break");
+            fs::create_dir_all(&path)?;
+            for x_break in &*self.x_break.read() {
+                if let Some(x_break) = x_break {
+                    let path = path.join(format!("{}.json", x_break.read().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_break)?;
+                }
+            }
+        }
+
         // Persist Call.
         {
             let path = path.join("call");
@@ -11097,6 +11606,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Continue.
+        {
+            let path = path.join("x_continue");
+            fs::create_dir_all(&path)?;
+            for x_continue in &*self.x_continue.read() {
+                if let Some(x_continue) = x_continue {
+                    let path = path.join(format!("{}.json", x_continue.read().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_continue)?;
+                }
+            }
+        }
+
         // Persist Data Structure.
         {
             let path = path.join("data_structure");
@@ -11629,6 +12152,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Loop.
+        {
+            let path = path.join("x_loop");
+            fs::create_dir_all(&path)?;
+            for x_loop in &*self.x_loop.read() {
+                if let Some(x_loop) = x_loop {
+                    let path = path.join(format!("{}.json", x_loop.read().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_loop)?;
+                }
+            }
+        }
+
         // Persist Macro.
         {
             let path = path.join("x_macro");
@@ -12147,6 +12684,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist While.
+        {
+            let path = path.join("x_while");
+            fs::create_dir_all(&path)?;
+            for x_while in &*self.x_while.read() {
+                if let Some(x_while) = x_while {
+                    let path = path.join(format!("{}.json", x_while.read().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_while)?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -12294,6 +12845,25 @@ impl ObjectStore {
             }
         }
 
+        // Load Break. Stores persisted before there were breaks don't have any.
+        {
+            let path = path.join("x_break");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_break: Arc<RwLock<XBreak>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_break
+                        .write()
+                        .insert(x_break.read().id, Some(x_break.clone()));
+                }
+            }
+        }
+
         // Load Call.
         {
             let path = path.join("call");
@@ -12345,6 +12915,26 @@ impl ObjectStore {
             }
         }
 
+        // Load Continue. Stores persisted before there were continues don't have
+        // any.
+        {
+            let path = path.join("x_continue");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_continue: Arc<RwLock<XContinue>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_continue
+                        .write()
+                        .insert(x_continue.read().id, Some(x_continue.clone()));
+                }
+            }
+        }
+
         // Load Data Structure.
         {
             let path = path.join("data_structure");
@@ -13012,6 +13602,25 @@ impl ObjectStore {
             }
         }
 
+        // Load Loop. Stores persisted before there were loops don't have any.
+        {
+            let path = path.join("x_loop");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_loop: Arc<RwLock<XLoop>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_loop
+                        .write()
+                        .insert(x_loop.read().id, Some(x_loop.clone()));
+                }
+            }
+        }
+
         // Load Macro.
         {
             let path = path.join("x_macro");
@@ -13657,6 +14266,26 @@ impl ObjectStore {
             }
         }
 
+        // Load While. Stores persisted before there were while loops don't have
+        // any.
+        {
+            let path = path.join("x_while");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_while: Arc<RwLock<XWhile>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_while
+                        .write()
+                        .insert(x_while.read().id, Some(x_while.clone()));
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -0,0 +1,68 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_continue-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-use-statements"}}}
+use parking_lot::RwLock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_pl_vec::types::expression::Expression;
+use crate::v2::lu_dog_pl_vec::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_pl_vec::store::ObjectStore as LuDogPlVecStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-documentation"}}}
+/// The Continue Expression
+///
+/// Skips to the next iteration of the innermost loop, or of the loop with the label.
+/// The label is empty when there isn't one.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct XContinue {
+    pub id: usize,
+    pub label: String,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-implementation"}}}
+impl XContinue {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-struct-impl-new"}}}
+    /// Inter a new 'Continue' in the store, and return it's `id`.
+    pub fn new(label: String, store: &mut LuDogPlVecStore) -> Arc<RwLock<XContinue>> {
+        store.inter_x_continue(|id| {
+            Arc::new(RwLock::new(XContinue {
+                id,
+                label: label.to_owned(),
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogPlVecStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XContinue(id) = expression.read().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_continue-implementation"}}}
+impl PartialEq for XContinue {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
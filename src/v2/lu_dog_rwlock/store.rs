@@ -14,9 +14,11 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`CharLiteral`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -55,6 +57,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`Map`]
 //! * [`MapElement`]
@@ -92,6 +95,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_rwlock-object-store-definition"}}}
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -117,7 +121,8 @@ use crate::v2::lu_dog_rwlock::types::{
     PathElement, Pattern, RangeExpression, ResultStatement, Span, Statement, StaticMethodCall,
     StringBit, StringLiteral, StructExpression, StructField, StructGeneric, TupleField, TypeCast,
     Unary, Unit, UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct,
-    XFuture, XIf, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore,
+    XBreak, XContinue, XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn,
+    XValue, XWhile, ZObjectStore,
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -129,9 +134,11 @@ pub struct ObjectStore {
     body: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Body>>>>>,
     boolean_literal: Arc<RwLock<HashMap<Uuid, Arc<RwLock<BooleanLiteral>>>>>,
     boolean_operator: Arc<RwLock<HashMap<Uuid, Arc<RwLock<BooleanOperator>>>>>,
+    x_break: Arc<RwLock<HashMap<Uuid, Arc<RwLock<XBreak>>>>>,
     call: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Call>>>>>,
     char_literal: Arc<RwLock<HashMap<Uuid, Arc<RwLock<CharLiteral>>>>>,
     comparison: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Comparison>>>>>,
+    x_continue: Arc<RwLock<HashMap<Uuid, Arc<RwLock<XContinue>>>>>,
     data_structure: Arc<RwLock<HashMap<Uuid, Arc<RwLock<DataStructure>>>>>,
     dwarf_source_file: Arc<RwLock<HashMap<Uuid, Arc<RwLock<DwarfSourceFile>>>>>,
     enum_field: Arc<RwLock<HashMap<Uuid, Arc<RwLock<EnumField>>>>>,
@@ -173,6 +180,7 @@ pub struct ObjectStore {
     list_expression: Arc<RwLock<HashMap<Uuid, Arc<RwLock<ListExpression>>>>>,
     literal: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Literal>>>>>,
     local_variable: Arc<RwLock<HashMap<Uuid, Arc<RwLock<LocalVariable>>>>>,
+    x_loop: Arc<RwLock<HashMap<Uuid, Arc<RwLock<XLoop>>>>>,
     x_macro: Arc<RwLock<HashMap<Uuid, Arc<RwLock<XMacro>>>>>,
     map: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Map>>>>>,
     map_element: Arc<RwLock<HashMap<Uuid, Arc<RwLock<MapElement>>>>>,
@@ -213,6 +221,7 @@ pub struct ObjectStore {
     value_type: Arc<RwLock<HashMap<Uuid, Arc<RwLock<ValueType>>>>>,
     variable: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Variable>>>>>,
     variable_expression: Arc<RwLock<HashMap<Uuid, Arc<RwLock<VariableExpression>>>>>,
+    x_while: Arc<RwLock<HashMap<Uuid, Arc<RwLock<XWhile>>>>>,
 }
 
 impl Clone for ObjectStore {
@@ -225,9 +234,11 @@ impl Clone for ObjectStore {
             body: Arc::new(RwLock::new(self.body.read().unwrap().clone())),
             boolean_literal: Arc::new(RwLock::new(self.boolean_literal.read().unwrap().clone())),
             boolean_operator: Arc::new(RwLock::new(self.boolean_operator.read().unwrap().clone())),
+            x_break: Arc::new(RwLock::new(self.x_break.read().unwrap().clone())),
             call: Arc::new(RwLock::new(self.call.read().unwrap().clone())),
             char_literal: Arc::new(RwLock::new(self.char_literal.read().unwrap().clone())),
             comparison: Arc::new(RwLock::new(self.comparison.read().unwrap().clone())),
+            x_continue: Arc::new(RwLock::new(self.x_continue.read().unwrap().clone())),
             data_structure: Arc::new(RwLock::new(self.data_structure.read().unwrap().clone())),
             dwarf_source_file: Arc::new(RwLock::new(
                 self.dwarf_source_file.read().unwrap().clone(),
@@ -283,6 +294,7 @@ impl Clone for ObjectStore {
             list_expression: Arc::new(RwLock::new(self.list_expression.read().unwrap().clone())),
             literal: Arc::new(RwLock::new(self.literal.read().unwrap().clone())),
             local_variable: Arc::new(RwLock::new(self.local_variable.read().unwrap().clone())),
+            x_loop: Arc::new(RwLock::new(self.x_loop.read().unwrap().clone())),
             x_macro: Arc::new(RwLock::new(self.x_macro.read().unwrap().clone())),
             map: Arc::new(RwLock::new(self.map.read().unwrap().clone())),
             map_element: Arc::new(RwLock::new(self.map_element.read().unwrap().clone())),
@@ -333,6 +345,7 @@ impl Clone for ObjectStore {
             variable_expression: Arc::new(RwLock::new(
                 self.variable_expression.read().unwrap().clone(),
             )),
+            x_while: Arc::new(RwLock::new(self.x_while.read().unwrap().clone())),
         }
     }
 }
@@ -346,9 +359,11 @@ impl ObjectStore {
             body: Arc::new(RwLock::new(HashMap::default())),
             boolean_literal: Arc::new(RwLock::new(HashMap::default())),
             boolean_operator: Arc::new(RwLock::new(HashMap::default())),
+            x_break: Arc::new(RwLock::new(HashMap::default())),
             call: Arc::new(RwLock::new(HashMap::default())),
             char_literal: Arc::new(RwLock::new(HashMap::default())),
             comparison: Arc::new(RwLock::new(HashMap::default())),
+            x_continue: Arc::new(RwLock::new(HashMap::default())),
             data_structure: Arc::new(RwLock::new(HashMap::default())),
             dwarf_source_file: Arc::new(RwLock::new(HashMap::default())),
             enum_field: Arc::new(RwLock::new(HashMap::default())),
@@ -390,6 +405,7 @@ impl ObjectStore {
             list_expression: Arc::new(RwLock::new(HashMap::default())),
             literal: Arc::new(RwLock::new(HashMap::default())),
             local_variable: Arc::new(RwLock::new(HashMap::default())),
+            x_loop: Arc::new(RwLock::new(HashMap::default())),
             x_macro: Arc::new(RwLock::new(HashMap::default())),
             map: Arc::new(RwLock::new(HashMap::default())),
             map_element: Arc::new(RwLock::new(HashMap::default())),
@@ -430,6 +446,7 @@ impl ObjectStore {
             value_type: Arc::new(RwLock::new(HashMap::default())),
             variable: Arc::new(RwLock::new(HashMap::default())),
             variable_expression: Arc::new(RwLock::new(HashMap::default())),
+            x_while: Arc::new(RwLock::new(HashMap::default())),
         };
 
         // Initialize Singleton Subtypes
@@ -497,6 +514,14 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_break.write().unwrap().extend(
+            other
+                .x_break
+                .read()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (*k, v.clone())),
+        );
         self.call.write().unwrap().extend(
             other
                 .call
@@ -521,6 +546,14 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_continue.write().unwrap().extend(
+            other
+                .x_continue
+                .read()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (*k, v.clone())),
+        );
         self.data_structure.write().unwrap().extend(
             other
                 .data_structure
@@ -849,6 +882,14 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_loop.write().unwrap().extend(
+            other
+                .x_loop
+                .read()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (*k, v.clone())),
+        );
         self.x_macro.write().unwrap().extend(
             other
                 .x_macro
@@ -1169,6 +1210,14 @@ impl ObjectStore {
                 .iter()
                 .map(|(k, v)| (*k, v.clone())),
         );
+        self.x_while.write().unwrap().extend(
+            other
+                .x_while
+                .read()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (*k, v.clone())),
+        );
     }
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_rwlock-object-store-methods"}}}
     /// Inter (insert) [`Argument`] into the store.
@@ -1463,6 +1512,50 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    pub fn inter_x_break(&mut self, x_break: Arc<RwLock<XBreak>>) {
+        let read = x_break.read().unwrap();
+        self.x_break
+            .write()
+            .unwrap()
+            .insert(read.id, x_break.clone());
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    pub fn exhume_x_break(&self, id: &Uuid) -> Option<Arc<RwLock<XBreak>>> {
+        self.x_break
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|x_break| x_break.clone())
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    pub fn exorcise_x_break(&mut self, id: &Uuid) -> Option<Arc<RwLock<XBreak>>> {
+        self.x_break
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|x_break| x_break.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    pub fn iter_x_break(&self) -> impl Iterator<Item = Arc<RwLock<XBreak>>> + '_ {
+        let values: Vec<Arc<RwLock<XBreak>>> = self
+            .x_break
+            .read()
+            .unwrap()
+            .values()
+            .map(|x_break| x_break.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     pub fn inter_call(&mut self, call: Arc<RwLock<Call>>) {
@@ -1588,6 +1681,50 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    pub fn inter_x_continue(&mut self, x_continue: Arc<RwLock<XContinue>>) {
+        let read = x_continue.read().unwrap();
+        self.x_continue
+            .write()
+            .unwrap()
+            .insert(read.id, x_continue.clone());
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    pub fn exhume_x_continue(&self, id: &Uuid) -> Option<Arc<RwLock<XContinue>>> {
+        self.x_continue
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|x_continue| x_continue.clone())
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    pub fn exorcise_x_continue(&mut self, id: &Uuid) -> Option<Arc<RwLock<XContinue>>> {
+        self.x_continue
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|x_continue| x_continue.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    pub fn iter_x_continue(&self) -> impl Iterator<Item = Arc<RwLock<XContinue>>> + '_ {
+        let values: Vec<Arc<RwLock<XContinue>>> = self
+            .x_continue
+            .read()
+            .unwrap()
+            .values()
+            .map(|x_continue| x_continue.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     pub fn inter_data_structure(&mut self, data_structure: Arc<RwLock<DataStructure>>) {
@@ -3328,6 +3465,47 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    pub fn inter_x_loop(&mut self, x_loop: Arc<RwLock<XLoop>>) {
+        let read = x_loop.read().unwrap();
+        self.x_loop.write().unwrap().insert(read.id, x_loop.clone());
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    pub fn exhume_x_loop(&self, id: &Uuid) -> Option<Arc<RwLock<XLoop>>> {
+        self.x_loop
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|x_loop| x_loop.clone())
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    pub fn exorcise_x_loop(&mut self, id: &Uuid) -> Option<Arc<RwLock<XLoop>>> {
+        self.x_loop
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|x_loop| x_loop.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    pub fn iter_x_loop(&self) -> impl Iterator<Item = Arc<RwLock<XLoop>>> + '_ {
+        let values: Vec<Arc<RwLock<XLoop>>> = self
+            .x_loop
+            .read()
+            .unwrap()
+            .values()
+            .map(|x_loop| x_loop.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     pub fn inter_x_macro(&mut self, x_macro: Arc<RwLock<XMacro>>) {
@@ -5007,6 +5185,50 @@ impl ObjectStore {
         (0..len).map(move |i| values[i].clone())
     }
 
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    pub fn inter_x_while(&mut self, x_while: Arc<RwLock<XWhile>>) {
+        let read = x_while.read().unwrap();
+        self.x_while
+            .write()
+            .unwrap()
+            .insert(read.id, x_while.clone());
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    pub fn exhume_x_while(&self, id: &Uuid) -> Option<Arc<RwLock<XWhile>>> {
+        self.x_while
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|x_while| x_while.clone())
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    pub fn exorcise_x_while(&mut self, id: &Uuid) -> Option<Arc<RwLock<XWhile>>> {
+        self.x_while
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|x_while| x_while.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    pub fn iter_x_while(&self) -> impl Iterator<Item = Arc<RwLock<XWhile>>> + '_ {
+        let values: Vec<Arc<RwLock<XWhile>>> = self
+            .x_while
+            .read()
+            .unwrap()
+            .values()
+            .map(|x_while| x_while.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_rwlock-object-store-persistence"}}}
@@ -5117,6 +5339,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Break.
+        {
+            let path = path.join("x_break");
+            fs::create_dir_all(&path)?;
+            for x_break in self.x_break.read().unwrap().values() {
+                let path = path.join(format!("{}.json", x_break.read().unwrap().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_break)?;
+            }
+        }
+
         // Persist Call.
         {
             let path = path.join("call");
@@ -5153,6 +5387,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Continue.
+        {
+            let path = path.join("x_continue");
+            fs::create_dir_all(&path)?;
+            for x_continue in self.x_continue.read().unwrap().values() {
+                let path = path.join(format!("{}.json", x_continue.read().unwrap().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_continue)?;
+            }
+        }
+
         // Persist Data Structure.
         {
             let path = path.join("data_structure");
@@ -5612,6 +5858,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Loop.
+        {
+            let path = path.join("x_loop");
+            fs::create_dir_all(&path)?;
+            for x_loop in self.x_loop.read().unwrap().values() {
+                let path = path.join(format!("{}.json", x_loop.read().unwrap().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_loop)?;
+            }
+        }
+
         // Persist Macro.
         {
             let path = path.join("x_macro");
@@ -6062,6 +6320,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist While.
+        {
+            let path = path.join("x_while");
+            fs::create_dir_all(&path)?;
+            for x_while in self.x_while.read().unwrap().values() {
+                let path = path.join(format!("{}.json", x_while.read().unwrap().id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &x_while)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -6215,6 +6485,26 @@ impl ObjectStore {
             }
         }
 
+        // Load Break. Stores persisted before there were breaks don't have any.
+        {
+            let path = path.join("x_break");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_break: Arc<RwLock<XBreak>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_break
+                        .write()
+                        .unwrap()
+                        .insert(x_break.read().unwrap().id, x_break.clone());
+                }
+            }
+        }
+
         // Load Call.
         {
             let path = path.join("call");
@@ -6269,6 +6559,27 @@ impl ObjectStore {
             }
         }
 
+        // Load Continue. Stores persisted before there were continues don't have
+        // any.
+        {
+            let path = path.join("x_continue");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_continue: Arc<RwLock<XContinue>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_continue
+                        .write()
+                        .unwrap()
+                        .insert(x_continue.read().unwrap().id, x_continue.clone());
+                }
+            }
+        }
+
         // Load Data Structure.
         {
             let path = path.join("data_structure");
@@ -6966,6 +7277,26 @@ impl ObjectStore {
             }
         }
 
+        // Load Loop. Stores persisted before there were loops don't have any.
+        {
+            let path = path.join("x_loop");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_loop: Arc<RwLock<XLoop>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_loop
+                        .write()
+                        .unwrap()
+                        .insert(x_loop.read().unwrap().id, x_loop.clone());
+                }
+            }
+        }
+
         // Load Macro.
         {
             let path = path.join("x_macro");
@@ -7644,6 +7975,27 @@ impl ObjectStore {
             }
         }
 
+        // Load While. Stores persisted before there were while loops don't have
+        // any.
+        {
+            let path = path.join("x_while");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_while: Arc<RwLock<XWhile>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_while
+                        .write()
+                        .unwrap()
+                        .insert(x_while.read().unwrap().id, x_while.clone());
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"division_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"division_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"division_assignment-const-documentation"}}}
+/// Compound assignment with the division operator: `/=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"division_assignment-const-definition"}}}
+pub const DIVISION_ASSIGNMENT: Uuid = uuid!["94bbe96c-cea4-56d0-9107-7e746ec82b53"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DivisionAssignment;
+
+impl DivisionAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        DIVISION_ASSIGNMENT
+    }
+}
+
+impl Default for DivisionAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -14,7 +14,7 @@ use crate::v2::lu_dog_rwlock::store::ObjectStore as LuDogRwlockStore;
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"for_loop-struct-documentation"}}}
 /// A For Loop Expression
 ///
-/// An expression that matches for IDENT in EXPRESSION BLOCK.
+/// An expression that matches for IDENT in EXPRESSION BLOCK. The label is empty when the loop isn't labeled.
 ///
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"for_loop-struct-definition"}}}
@@ -22,6 +22,9 @@ use crate::v2::lu_dog_rwlock::store::ObjectStore as LuDogRwlockStore;
 pub struct ForLoop {
     pub id: Uuid,
     pub ident: String,
+    /// Loops persisted before there were labels don't have one.
+    #[serde(default)]
+    pub label: String,
     /// R43: [`ForLoop`] 'executes a' [`Expression`]
     pub block: Uuid,
     /// R42: [`ForLoop`] 'iterates over an' [`Expression`]
@@ -34,6 +37,7 @@ impl ForLoop {
     /// Inter a new 'For Loop' in the store, and return it's `id`.
     pub fn new(
         ident: String,
+        label: String,
         block: &Arc<RwLock<Expression>>,
         expression: &Arc<RwLock<Expression>>,
         store: &mut LuDogRwlockStore,
@@ -42,6 +46,7 @@ impl ForLoop {
         let new = Arc::new(RwLock::new(ForLoop {
             id,
             ident,
+            label,
             block: block.read().unwrap().id,
             expression: expression.read().unwrap().id,
         }));
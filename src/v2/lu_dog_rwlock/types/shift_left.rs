@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"shift_left-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left-const-documentation"}}}
+/// The left shift operator: `<<`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left-const-definition"}}}
+pub const SHIFT_LEFT: Uuid = uuid!["ecadcc0c-246e-55b6-bac6-0dac4bb28b24"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ShiftLeft;
+
+impl ShiftLeft {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        SHIFT_LEFT
+    }
+}
+
+impl Default for ShiftLeft {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,77 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_loop-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_rwlock::types::expression::Expression;
+use crate::v2::lu_dog_rwlock::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_rwlock::store::ObjectStore as LuDogRwlockStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-documentation"}}}
+/// The Loop Expression
+///
+/// An expression that evaluates BLOCK until it's broken out of. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct XLoop {
+    pub id: Uuid,
+    pub label: String,
+    /// R123: [`XLoop`] 'executes a' [`Expression`]
+    pub block: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-implementation"}}}
+impl XLoop {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-new"}}}
+    /// Inter a new 'Loop' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        store: &mut LuDogRwlockStore,
+    ) -> Arc<RwLock<XLoop>> {
+        let id = Uuid::new_v4();
+        let new = Arc::new(RwLock::new(XLoop {
+            id,
+            label,
+            block: block.read().unwrap().id,
+        }));
+        store.inter_x_loop(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R123(1-*)
+    pub fn r123_expression<'a>(
+        &'a self,
+        store: &'a LuDogRwlockStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.block).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_loop-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogRwlockStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XLoop(id) = expression.read().unwrap().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
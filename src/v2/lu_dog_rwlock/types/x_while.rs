@@ -0,0 +1,90 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"x_while-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::lu_dog_rwlock::types::expression::Expression;
+use crate::v2::lu_dog_rwlock::types::expression::ExpressionEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::lu_dog_rwlock::store::ObjectStore as LuDogRwlockStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-documentation"}}}
+/// The While Expression
+///
+/// An expression that evaluates BLOCK for as long as TEST is true. The label is empty when the loop isn't labeled.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct XWhile {
+    pub id: Uuid,
+    pub label: String,
+    /// R121: [`XWhile`] 'executes a' [`Expression`]
+    pub block: Uuid,
+    /// R122: [`XWhile`] 'loops while' [`Expression`]
+    pub test: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-implementation"}}}
+impl XWhile {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-new"}}}
+    /// Inter a new 'While' in the store, and return it's `id`.
+    pub fn new(
+        label: String,
+        block: &Arc<RwLock<Expression>>,
+        test: &Arc<RwLock<Expression>>,
+        store: &mut LuDogRwlockStore,
+    ) -> Arc<RwLock<XWhile>> {
+        let id = Uuid::new_v4();
+        let new = Arc::new(RwLock::new(XWhile {
+            id,
+            label,
+            block: block.read().unwrap().id,
+            test: test.read().unwrap().id,
+        }));
+        store.inter_x_while(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-block"}}}
+    /// Navigate to [`Expression`] across R121(1-*)
+    pub fn r121_expression<'a>(
+        &'a self,
+        store: &'a LuDogRwlockStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.block).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-struct-impl-nav-forward-to-test"}}}
+    /// Navigate to [`Expression`] across R122(1-*)
+    pub fn r122_expression<'a>(
+        &'a self,
+        store: &'a LuDogRwlockStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store.exhume_expression(&self.test).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"x_while-impl-nav-subtype-to-supertype-expression"}}}
+    // Navigate to [`Expression`] across R15(isa)
+    pub fn r15_expression<'a>(
+        &'a self,
+        store: &'a LuDogRwlockStore,
+    ) -> Vec<Arc<RwLock<Expression>>> {
+        vec![store
+            .iter_expression()
+            .find(|expression| {
+                if let ExpressionEnum::XWhile(id) = expression.read().unwrap().subtype {
+                    id == self.id
+                } else {
+                    false
+                }
+            })
+            .unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
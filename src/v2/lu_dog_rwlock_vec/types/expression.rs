@@ -30,14 +30,18 @@ use crate::v2::lu_dog_rwlock_vec::types::result_statement::ResultStatement;
 use crate::v2::lu_dog_rwlock_vec::types::struct_expression::StructExpression;
 use crate::v2::lu_dog_rwlock_vec::types::type_cast::TypeCast;
 use crate::v2::lu_dog_rwlock_vec::types::variable_expression::VariableExpression;
+use crate::v2::lu_dog_rwlock_vec::types::x_break::XBreak;
+use crate::v2::lu_dog_rwlock_vec::types::x_continue::XContinue;
 use crate::v2::lu_dog_rwlock_vec::types::x_debugger::X_DEBUGGER;
 use crate::v2::lu_dog_rwlock_vec::types::x_if::XIf;
+use crate::v2::lu_dog_rwlock_vec::types::x_loop::XLoop;
 use crate::v2::lu_dog_rwlock_vec::types::x_match::XMatch;
 use crate::v2::lu_dog_rwlock_vec::types::x_path::XPath;
 use crate::v2::lu_dog_rwlock_vec::types::x_print::XPrint;
 use crate::v2::lu_dog_rwlock_vec::types::x_return::XReturn;
 use crate::v2::lu_dog_rwlock_vec::types::x_value::XValue;
 use crate::v2::lu_dog_rwlock_vec::types::x_value::XValueEnum;
+use crate::v2::lu_dog_rwlock_vec::types::x_while::XWhile;
 use serde::{Deserialize, Serialize};
 
 use crate::v2::lu_dog_rwlock_vec::store::ObjectStore as LuDogRwlockVecStore;
@@ -62,7 +66,9 @@ pub struct Expression {
 pub enum ExpressionEnum {
     AWait(usize),
     Block(usize),
+    XBreak(usize),
     Call(usize),
+    XContinue(usize),
     XDebugger(Uuid),
     EmptyExpression(Uuid),
     FieldAccess(usize),
@@ -76,6 +82,7 @@ pub enum ExpressionEnum {
     ListElement(usize),
     ListExpression(usize),
     Literal(usize),
+    XLoop(usize),
     XMatch(usize),
     Operator(usize),
     XPath(usize),
@@ -85,6 +92,7 @@ pub enum ExpressionEnum {
     StructExpression(usize),
     TypeCast(usize),
     VariableExpression(usize),
+    XWhile(usize),
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-implementation"}}}
@@ -123,6 +131,22 @@ impl Expression {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_break"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub fn new_x_break(
+        bogus: bool,
+        subtype: &Arc<RwLock<XBreak>>,
+        store: &mut LuDogRwlockVecStore,
+    ) -> Arc<RwLock<Expression>> {
+        store.inter_expression(|id| {
+            Arc::new(RwLock::new(Expression {
+                bogus: bogus,
+                subtype: ExpressionEnum::XBreak(subtype.read().unwrap().id), // b
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_call"}}}
     /// Inter a new Expression in the store, and return it's `id`.
     pub fn new_call(
@@ -139,6 +163,22 @@ impl Expression {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_continue"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub fn new_x_continue(
+        bogus: bool,
+        subtype: &Arc<RwLock<XContinue>>,
+        store: &mut LuDogRwlockVecStore,
+    ) -> Arc<RwLock<Expression>> {
+        store.inter_expression(|id| {
+            Arc::new(RwLock::new(Expression {
+                bogus: bogus,
+                subtype: ExpressionEnum::XContinue(subtype.read().unwrap().id), // b
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_debugger"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_debugger"}}}
     /// Inter a new Expression in the store, and return it's `id`.
@@ -346,6 +386,22 @@ impl Expression {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_loop"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub fn new_x_loop(
+        bogus: bool,
+        subtype: &Arc<RwLock<XLoop>>,
+        store: &mut LuDogRwlockVecStore,
+    ) -> Arc<RwLock<Expression>> {
+        store.inter_expression(|id| {
+            Arc::new(RwLock::new(Expression {
+                bogus: bogus,
+                subtype: ExpressionEnum::XLoop(subtype.read().unwrap().id), // b
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_match"}}}
     /// Inter a new Expression in the store, and return it's `id`.
     pub fn new_x_match(
@@ -495,6 +551,22 @@ impl Expression {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-new_x_while"}}}
+    /// Inter a new Expression in the store, and return it's `id`.
+    pub fn new_x_while(
+        bogus: bool,
+        subtype: &Arc<RwLock<XWhile>>,
+        store: &mut LuDogRwlockVecStore,
+    ) -> Arc<RwLock<Expression>> {
+        store.inter_expression(|id| {
+            Arc::new(RwLock::new(Expression {
+                bogus: bogus,
+                subtype: ExpressionEnum::XWhile(subtype.read().unwrap().id), // b
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-argument"}}}
     /// Navigate to [`Argument`] across R37(1-M)
     pub fn r37_argument<'a>(
@@ -521,6 +593,15 @@ impl Expression {
         }
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_Mc-to-x_break"}}}
+    /// Navigate to [`XBreak`] across R124(1-Mc)
+    pub fn r124_x_break<'a>(&'a self, store: &'a LuDogRwlockVecStore) -> Vec<Arc<RwLock<XBreak>>> {
+        store
+            .iter_x_break()
+            .filter(|x_break| x_break.read().unwrap().expression == Some(self.id))
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_Mc-to-call"}}}
     /// Navigate to [`Call`] across R29(1-Mc)
     pub fn r29_call<'a>(&'a self, store: &'a LuDogRwlockVecStore) -> Vec<Arc<RwLock<Call>>> {
@@ -682,6 +763,15 @@ impl Expression {
             .collect()
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_loop"}}}
+    /// Navigate to [`XLoop`] across R123(1-M)
+    pub fn r123_x_loop<'a>(&'a self, store: &'a LuDogRwlockVecStore) -> Vec<Arc<RwLock<XLoop>>> {
+        store
+            .iter_x_loop()
+            .filter(|x_loop| x_loop.read().unwrap().block == self.id)
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-operator"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-map_element"}}}
     /// Navigate to [`MapElement`] across R118(1-M)
@@ -822,6 +912,24 @@ impl Expression {
             .collect()
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_while"}}}
+    /// Navigate to [`XWhile`] across R122(1-M)
+    pub fn r122_x_while<'a>(&'a self, store: &'a LuDogRwlockVecStore) -> Vec<Arc<RwLock<XWhile>>> {
+        store
+            .iter_x_while()
+            .filter(|x_while| x_while.read().unwrap().test == self.id)
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-1_M-to-x_while"}}}
+    /// Navigate to [`XWhile`] across R121(1-M)
+    pub fn r121_x_while<'a>(&'a self, store: &'a LuDogRwlockVecStore) -> Vec<Arc<RwLock<XWhile>>> {
+        store
+            .iter_x_while()
+            .filter(|x_while| x_while.read().unwrap().block == self.id)
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-assoc-many-to-pattern"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"expression-struct-impl-nav-backward-assoc-one-cond-to-pattern"}}}
     /// Navigate to [`Pattern`] across R87(1-1c)
@@ -14,7 +14,7 @@ use crate::v2::lu_dog_rwlock_vec::store::ObjectStore as LuDogRwlockVecStore;
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"for_loop-struct-documentation"}}}
 /// A For Loop Expression
 ///
-/// An expression that matches for IDENT in EXPRESSION BLOCK.
+/// An expression that matches for IDENT in EXPRESSION BLOCK. The label is empty when the loop isn't labeled.
 ///
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"for_loop-struct-definition"}}}
@@ -22,6 +22,9 @@ use crate::v2::lu_dog_rwlock_vec::store::ObjectStore as LuDogRwlockVecStore;
 pub struct ForLoop {
     pub id: usize,
     pub ident: String,
+    /// Loops persisted before there were labels don't have one.
+    #[serde(default)]
+    pub label: String,
     /// R43: [`ForLoop`] 'executes a' [`Expression`]
     pub block: usize,
     /// R42: [`ForLoop`] 'iterates over an' [`Expression`]
@@ -34,6 +37,7 @@ impl ForLoop {
     /// Inter a new 'For Loop' in the store, and return it's `id`.
     pub fn new(
         ident: String,
+        label: String,
         block: &Arc<RwLock<Expression>>,
         expression: &Arc<RwLock<Expression>>,
         store: &mut LuDogRwlockVecStore,
@@ -42,6 +46,7 @@ impl ForLoop {
             Arc::new(RwLock::new(ForLoop {
                 id,
                 ident: ident.to_owned(),
+                label: label.to_owned(),
                 block: block.read().unwrap().id,
                 expression: expression.read().unwrap().id,
             }))
@@ -90,6 +95,7 @@ impl ForLoop {
 impl PartialEq for ForLoop {
     fn eq(&self, other: &Self) -> bool {
         self.ident == other.ident
+            && self.label == other.label
             && self.block == other.block
             && self.expression == other.expression
     }
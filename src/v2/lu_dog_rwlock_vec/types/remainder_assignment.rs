@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"remainder_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"remainder_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"remainder_assignment-const-documentation"}}}
+/// Compound assignment with the remainder operator: `%=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"remainder_assignment-const-definition"}}}
+pub const REMAINDER_ASSIGNMENT: Uuid = uuid!["483b128d-b0df-54b0-9554-042906ae64c8"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RemainderAssignment;
+
+impl RemainderAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        REMAINDER_ASSIGNMENT
+    }
+}
+
+impl Default for RemainderAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -6,10 +6,57 @@ use uuid::{uuid, Uuid};
 pub mod store;
 pub mod types;
 
-pub use store::ObjectStore;
+pub use store::{ObjectStore, Resolution};
 pub use types::*;
 
 // lu_dog
 pub const UUID_NS: Uuid = uuid!("718a3c7b-8124-5b12-bf4f-6ff6274db22c");
 
 pub const MODEL: &[u8] = include_bytes!("../../models/lu_dog.bin");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    #[test]
+    fn test_load_old_layout() {
+        let mut store = ObjectStore::new();
+        let int = IntegerLiteral::new(3, &mut store);
+        let literal = Literal::new_integer_literal(false, &int, &mut store);
+        let three = Expression::new_literal(false, &literal, &mut store);
+        ForLoop::new(
+            "i".to_owned(),
+            "outer".to_owned(),
+            &three,
+            &three,
+            &mut store,
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("lu-dog-store-{}-old-layout", std::process::id()));
+        store.persist(&path).unwrap();
+        // Stores persisted before there were loops other than `for` don't have
+        // these, and their for loops don't have labels.
+        let lu_dog = path.join("lu_dog.json");
+        for dir in ["x_break", "x_continue", "x_loop", "x_while"] {
+            fs::remove_dir_all(lu_dog.join(dir)).unwrap();
+        }
+        for entry in fs::read_dir(lu_dog.join("for_loop")).unwrap() {
+            let path = entry.unwrap().path();
+            let mut for_loop: serde_json::Value =
+                serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+            for_loop.as_object_mut().unwrap().remove("label");
+            fs::write(&path, for_loop.to_string()).unwrap();
+        }
+        let loaded = ObjectStore::load(&path).unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        let for_loop = loaded.iter_for_loop().next().unwrap();
+        assert_eq!(for_loop.borrow().ident, "i");
+        assert_eq!(for_loop.borrow().label, "");
+        assert_eq!(loaded.iter_x_while().count(), 0);
+        assert_eq!(loaded.iter_x_break().count(), 0);
+    }
+}
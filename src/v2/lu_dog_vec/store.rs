@@ -14,9 +14,11 @@
 //! * [`Body`]
 //! * [`BooleanLiteral`]
 //! * [`BooleanOperator`]
+//! * [`XBreak`]
 //! * [`Call`]
 //! * [`CharLiteral`]
 //! * [`Comparison`]
+//! * [`XContinue`]
 //! * [`DataStructure`]
 //! * [`DwarfSourceFile`]
 //! * [`EnumField`]
@@ -55,6 +57,7 @@
 //! * [`ListExpression`]
 //! * [`Literal`]
 //! * [`LocalVariable`]
+//! * [`XLoop`]
 //! * [`XMacro`]
 //! * [`Map`]
 //! * [`MapElement`]
@@ -92,6 +95,7 @@
 //! * [`ValueType`]
 //! * [`Variable`]
 //! * [`VariableExpression`]
+//! * [`XWhile`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_vec-object-store-definition"}}}
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -118,13 +122,28 @@ use crate::v2::lu_dog_vec::types::{
     PathElement, Pattern, RangeExpression, ResultStatement, Span, Statement, StaticMethodCall,
     StringBit, StringLiteral, StructExpression, StructField, StructGeneric, TupleField, TypeCast,
     Unary, Unit, UnnamedFieldExpression, ValueType, Variable, VariableExpression, WoogStruct,
-    XFuture, XIf, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn, XValue, ZObjectStore, ADDITION,
-    AND, ANY_LIST, ASSIGNMENT, CHAR, DIVISION, EMPTY, EMPTY_EXPRESSION, EQUAL, FALSE_LITERAL, FROM,
-    FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT, LESS_THAN,
-    LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, NEGATION, NOT, NOT_EQUAL, OR, RANGE,
-    SUBTRACTION, TASK, TO, TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
+    XBreak, XContinue, XFuture, XIf, XLoop, XMacro, XMatch, XPath, XPlugin, XPrint, XReturn,
+    XValue, XWhile, ZObjectStore, ADDITION, ADDITION_ASSIGNMENT, AND, ANY_LIST, ASSIGNMENT,
+    BITWISE_AND, BITWISE_AND_ASSIGNMENT, BITWISE_OR, BITWISE_OR_ASSIGNMENT, BITWISE_XOR,
+    BITWISE_XOR_ASSIGNMENT, CHAR, DIVISION, DIVISION_ASSIGNMENT, EMPTY, EMPTY_EXPRESSION, EQUAL,
+    FALSE_LITERAL, FROM, FULL, GREATER_THAN, GREATER_THAN_OR_EQUAL, INCLUSIVE, ITEM_STATEMENT,
+    LESS_THAN, LESS_THAN_OR_EQUAL, MACRO_CALL, MULTIPLICATION, MULTIPLICATION_ASSIGNMENT, NEGATION,
+    NOT, NOT_EQUAL, OR, RANGE, REMAINDER, REMAINDER_ASSIGNMENT, SHIFT_LEFT, SHIFT_LEFT_ASSIGNMENT,
+    SHIFT_RIGHT, SHIFT_RIGHT_ASSIGNMENT, SUBTRACTION, SUBTRACTION_ASSIGNMENT, TASK, TO,
+    TO_INCLUSIVE, TRUE_LITERAL, UNKNOWN, X_DEBUGGER,
 };
 
+/// What a name resolves to
+///
+/// These are recorded by name resolution. Each is the id of the definition.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Resolution {
+    EnumField(usize),
+    Field(usize),
+    Function(usize),
+    Variable(usize),
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ObjectStore {
     argument_free_list: Vec<usize>,
@@ -146,8 +165,11 @@ pub struct ObjectStore {
     boolean_literal: Vec<Option<Rc<RefCell<BooleanLiteral>>>>,
     boolean_literal_dirty: bool,
     boolean_operator_free_list: Vec<usize>,
+    x_break_free_list: Vec<usize>,
     boolean_operator: Vec<Option<Rc<RefCell<BooleanOperator>>>>,
+    x_break: Vec<Option<Rc<RefCell<XBreak>>>>,
     boolean_operator_dirty: bool,
+    x_break_dirty: bool,
     call_free_list: Vec<usize>,
     call: Vec<Option<Rc<RefCell<Call>>>>,
     call_dirty: bool,
@@ -155,8 +177,11 @@ pub struct ObjectStore {
     char_literal: Vec<Option<Rc<RefCell<CharLiteral>>>>,
     char_literal_dirty: bool,
     comparison_free_list: Vec<usize>,
+    x_continue_free_list: Vec<usize>,
     comparison: Vec<Option<Rc<RefCell<Comparison>>>>,
+    x_continue: Vec<Option<Rc<RefCell<XContinue>>>>,
     comparison_dirty: bool,
+    x_continue_dirty: bool,
     data_structure_free_list: Vec<usize>,
     data_structure: Vec<Option<Rc<RefCell<DataStructure>>>>,
     data_structure_dirty: bool,
@@ -272,8 +297,11 @@ pub struct ObjectStore {
     literal: Vec<Option<Rc<RefCell<Literal>>>>,
     literal_dirty: bool,
     local_variable_free_list: Vec<usize>,
+    x_loop_free_list: Vec<usize>,
     local_variable: Vec<Option<Rc<RefCell<LocalVariable>>>>,
+    x_loop: Vec<Option<Rc<RefCell<XLoop>>>>,
     local_variable_dirty: bool,
+    x_loop_dirty: bool,
     x_macro_free_list: Vec<usize>,
     x_macro: Vec<Option<Rc<RefCell<XMacro>>>>,
     x_macro_dirty: bool,
@@ -386,8 +414,12 @@ pub struct ObjectStore {
     variable: Vec<Option<Rc<RefCell<Variable>>>>,
     variable_dirty: bool,
     variable_expression_free_list: Vec<usize>,
+    x_while_free_list: Vec<usize>,
     variable_expression: Vec<Option<Rc<RefCell<VariableExpression>>>>,
+    x_while: Vec<Option<Rc<RefCell<XWhile>>>>,
     variable_expression_dirty: bool,
+    x_while_dirty: bool,
+    resolution: HashMap<(usize, usize), Resolution>,
 }
 
 impl Clone for ObjectStore {
@@ -412,8 +444,11 @@ impl Clone for ObjectStore {
             boolean_literal: self.boolean_literal.clone(),
             boolean_literal_dirty: false,
             boolean_operator_free_list: self.boolean_operator_free_list.clone(),
+            x_break_free_list: self.x_break_free_list.clone(),
             boolean_operator: self.boolean_operator.clone(),
+            x_break: self.x_break.clone(),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: self.call_free_list.clone(),
             call: self.call.clone(),
             call_dirty: false,
@@ -421,8 +456,11 @@ impl Clone for ObjectStore {
             char_literal: self.char_literal.clone(),
             char_literal_dirty: false,
             comparison_free_list: self.comparison_free_list.clone(),
+            x_continue_free_list: self.x_continue_free_list.clone(),
             comparison: self.comparison.clone(),
+            x_continue: self.x_continue.clone(),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: self.data_structure_free_list.clone(),
             data_structure: self.data_structure.clone(),
             data_structure_dirty: false,
@@ -538,8 +576,11 @@ impl Clone for ObjectStore {
             literal: self.literal.clone(),
             literal_dirty: false,
             local_variable_free_list: self.local_variable_free_list.clone(),
+            x_loop_free_list: self.x_loop_free_list.clone(),
             local_variable: self.local_variable.clone(),
+            x_loop: self.x_loop.clone(),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: self.x_macro_free_list.clone(),
             x_macro: self.x_macro.clone(),
             x_macro_dirty: false,
@@ -652,8 +693,12 @@ impl Clone for ObjectStore {
             variable: self.variable.clone(),
             variable_dirty: false,
             variable_expression_free_list: self.variable_expression_free_list.clone(),
+            x_while_free_list: self.x_while_free_list.clone(),
             variable_expression: self.variable_expression.clone(),
+            x_while: self.x_while.clone(),
             variable_expression_dirty: false,
+            x_while_dirty: false,
+            resolution: self.resolution.clone(),
         }
     }
 }
@@ -679,8 +724,11 @@ impl ObjectStore {
             boolean_literal: Vec::new(),
             boolean_literal_dirty: false,
             boolean_operator_free_list: Vec::new(),
+            x_break_free_list: Vec::new(),
             boolean_operator: Vec::new(),
+            x_break: Vec::new(),
             boolean_operator_dirty: false,
+            x_break_dirty: false,
             call_free_list: Vec::new(),
             call: Vec::new(),
             call_dirty: false,
@@ -688,8 +736,11 @@ impl ObjectStore {
             char_literal: Vec::new(),
             char_literal_dirty: false,
             comparison_free_list: Vec::new(),
+            x_continue_free_list: Vec::new(),
             comparison: Vec::new(),
+            x_continue: Vec::new(),
             comparison_dirty: false,
+            x_continue_dirty: false,
             data_structure_free_list: Vec::new(),
             data_structure: Vec::new(),
             data_structure_dirty: false,
@@ -805,8 +856,11 @@ impl ObjectStore {
             literal: Vec::new(),
             literal_dirty: false,
             local_variable_free_list: Vec::new(),
+            x_loop_free_list: Vec::new(),
             local_variable: Vec::new(),
+            x_loop: Vec::new(),
             local_variable_dirty: false,
+            x_loop_dirty: false,
             x_macro_free_list: Vec::new(),
             x_macro: Vec::new(),
             x_macro_dirty: false,
@@ -919,8 +973,12 @@ impl ObjectStore {
             variable: Vec::new(),
             variable_dirty: false,
             variable_expression_free_list: Vec::new(),
+            x_while_free_list: Vec::new(),
             variable_expression: Vec::new(),
+            x_while: Vec::new(),
             variable_expression_dirty: false,
+            x_while_dirty: false,
+            resolution: HashMap::default(),
         };
 
         // Initialize Singleton Subtypes
@@ -1121,6 +1179,33 @@ impl ObjectStore {
             });
         }
 
+        if other.x_break_dirty {
+            other.x_break.iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_break, if it's not there add it to x_break.
+                    if self
+                        .x_break
+                        .borrow()
+                        .iter()
+                        .find(|&y| if let Some(y) = y { *y == *x } else { false })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_break.borrow().len();
+                        // if x.borrow().id != _index_ {
+                        //     x.borrow_mut().id = _index_;
+                        // }
+                        self.inter_x_break(|id| -> Rc<RefCell<XBreak>> {
+                            if x.borrow().id != id {
+                                panic!("id mismatch");
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.call_dirty {
             other.call.iter().for_each(|x| {
                 if let Some(x) = x {
@@ -1202,6 +1287,33 @@ impl ObjectStore {
             });
         }
 
+        if other.x_continue_dirty {
+            other.x_continue.iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_continue, if it's not there add it to x_continue.
+                    if self
+                        .x_continue
+                        .borrow()
+                        .iter()
+                        .find(|&y| if let Some(y) = y { *y == *x } else { false })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_continue.borrow().len();
+                        // if x.borrow().id != _index_ {
+                        //     x.borrow_mut().id = _index_;
+                        // }
+                        self.inter_x_continue(|id| -> Rc<RefCell<XContinue>> {
+                            if x.borrow().id != id {
+                                panic!("id mismatch");
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.data_structure_dirty {
             other.data_structure.iter().for_each(|x| {
                 if let Some(x) = x {
@@ -2230,6 +2342,33 @@ impl ObjectStore {
             });
         }
 
+        if other.x_loop_dirty {
+            other.x_loop.iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_loop, if it's not there add it to x_loop.
+                    if self
+                        .x_loop
+                        .borrow()
+                        .iter()
+                        .find(|&y| if let Some(y) = y { *y == *x } else { false })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_loop.borrow().len();
+                        // if x.borrow().id != _index_ {
+                        //     x.borrow_mut().id = _index_;
+                        // }
+                        self.inter_x_loop(|id| -> Rc<RefCell<XLoop>> {
+                            if x.borrow().id != id {
+                                panic!("id mismatch");
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
         if other.x_macro_dirty {
             other.x_macro.iter().for_each(|x| {
                 if let Some(x) = x {
@@ -3232,6 +3371,36 @@ impl ObjectStore {
                 }
             });
         }
+
+        if other.x_while_dirty {
+            other.x_while.iter().for_each(|x| {
+                if let Some(x) = x {
+                    // Look for other in x_while, if it's not there add it to x_while.
+                    if self
+                        .x_while
+                        .borrow()
+                        .iter()
+                        .find(|&y| if let Some(y) = y { *y == *x } else { false })
+                        .is_none()
+                    {
+                        // let _index_ = self.x_while.borrow().len();
+                        // if x.borrow().id != _index_ {
+                        //     x.borrow_mut().id = _index_;
+                        // }
+                        self.inter_x_while(|id| -> Rc<RefCell<XWhile>> {
+                            if x.borrow().id != id {
+                                panic!("id mismatch");
+                            }
+
+                            x.clone()
+                        });
+                    }
+                }
+            });
+        }
+
+        self.resolution
+            .extend(other.resolution.iter().map(|(key, value)| (*key, *value)));
     }
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_vec-object-store-methods"}}}
     /// Inter (insert) [`Argument`] into the store.
@@ -3724,6 +3893,77 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XBreak`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_break<F>(&mut self, x_break: F) -> Rc<RefCell<XBreak>>
+    where
+        F: Fn(usize) -> Rc<RefCell<XBreak>>,
+    {
+        let _index = if let Some(_index) = self.x_break_free_list.pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_break.len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_break.push(None);
+            _index
+        };
+
+        let x_break = x_break(_index);
+
+        if let Some(Some(x_break)) = self.x_break.iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.borrow() == *x_break.borrow()
+            } else {
+                false
+            }
+        }) {
+            tracing::debug!(target: "store", "found duplicate {x_break:?}.");
+            self.x_break_free_list.push(_index);
+            x_break.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_break:?}.");
+            self.x_break[_index] = Some(x_break.clone());
+            x_break
+        }
+    }
+
+    /// Exhume (get) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_break(&self, id: &usize) -> Option<Rc<RefCell<XBreak>>> {
+        match self.x_break.get(*id) {
+            Some(x_break) => x_break.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XBreak`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_break(&mut self, id: &usize) -> Option<Rc<RefCell<XBreak>>> {
+        tracing::debug!(target: "store", "exorcising x_break slot: {id}.");
+        let result = self.x_break[*id].take();
+        self.x_break_free_list.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XBreak>`.
+    ///
+    #[inline]
+    pub fn iter_x_break(&self) -> impl Iterator<Item = Rc<RefCell<XBreak>>> + '_ {
+        let len = self.x_break.len();
+        (0..len)
+            .filter(|i| self.x_break[*i].is_some())
+            .map(move |i| {
+                self.x_break[i]
+                    .as_ref()
+                    .map(|x_break| x_break.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`Call`] into the store.
     ///
     #[inline]
@@ -3932,6 +4172,77 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XContinue`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_continue<F>(&mut self, x_continue: F) -> Rc<RefCell<XContinue>>
+    where
+        F: Fn(usize) -> Rc<RefCell<XContinue>>,
+    {
+        let _index = if let Some(_index) = self.x_continue_free_list.pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_continue.len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_continue.push(None);
+            _index
+        };
+
+        let x_continue = x_continue(_index);
+
+        if let Some(Some(x_continue)) = self.x_continue.iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.borrow() == *x_continue.borrow()
+            } else {
+                false
+            }
+        }) {
+            tracing::debug!(target: "store", "found duplicate {x_continue:?}.");
+            self.x_continue_free_list.push(_index);
+            x_continue.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_continue:?}.");
+            self.x_continue[_index] = Some(x_continue.clone());
+            x_continue
+        }
+    }
+
+    /// Exhume (get) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_continue(&self, id: &usize) -> Option<Rc<RefCell<XContinue>>> {
+        match self.x_continue.get(*id) {
+            Some(x_continue) => x_continue.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XContinue`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_continue(&mut self, id: &usize) -> Option<Rc<RefCell<XContinue>>> {
+        tracing::debug!(target: "store", "exorcising x_continue slot: {id}.");
+        let result = self.x_continue[*id].take();
+        self.x_continue_free_list.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XContinue>`.
+    ///
+    #[inline]
+    pub fn iter_x_continue(&self) -> impl Iterator<Item = Rc<RefCell<XContinue>>> + '_ {
+        let len = self.x_continue.len();
+        (0..len)
+            .filter(|i| self.x_continue[*i].is_some())
+            .map(move |i| {
+                self.x_continue[i]
+                    .as_ref()
+                    .map(|x_continue| x_continue.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`DataStructure`] into the store.
     ///
     #[inline]
@@ -6712,6 +7023,77 @@ impl ObjectStore {
             })
     }
 
+    /// Inter (insert) [`XLoop`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_loop<F>(&mut self, x_loop: F) -> Rc<RefCell<XLoop>>
+    where
+        F: Fn(usize) -> Rc<RefCell<XLoop>>,
+    {
+        let _index = if let Some(_index) = self.x_loop_free_list.pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_loop.len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_loop.push(None);
+            _index
+        };
+
+        let x_loop = x_loop(_index);
+
+        if let Some(Some(x_loop)) = self.x_loop.iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.borrow() == *x_loop.borrow()
+            } else {
+                false
+            }
+        }) {
+            tracing::debug!(target: "store", "found duplicate {x_loop:?}.");
+            self.x_loop_free_list.push(_index);
+            x_loop.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_loop:?}.");
+            self.x_loop[_index] = Some(x_loop.clone());
+            x_loop
+        }
+    }
+
+    /// Exhume (get) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_loop(&self, id: &usize) -> Option<Rc<RefCell<XLoop>>> {
+        match self.x_loop.get(*id) {
+            Some(x_loop) => x_loop.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XLoop`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_loop(&mut self, id: &usize) -> Option<Rc<RefCell<XLoop>>> {
+        tracing::debug!(target: "store", "exorcising x_loop slot: {id}.");
+        let result = self.x_loop[*id].take();
+        self.x_loop_free_list.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XLoop>`.
+    ///
+    #[inline]
+    pub fn iter_x_loop(&self) -> impl Iterator<Item = Rc<RefCell<XLoop>>> + '_ {
+        let len = self.x_loop.len();
+        (0..len)
+            .filter(|i| self.x_loop[*i].is_some())
+            .map(move |i| {
+                self.x_loop[i]
+                    .as_ref()
+                    .map(|x_loop| x_loop.clone())
+                    .unwrap()
+            })
+    }
+
     /// Inter (insert) [`XMacro`] into the store.
     ///
     #[inline]
@@ -9417,6 +9799,111 @@ impl ObjectStore {
             })
     }
 
+    /// Record what a name resolves to
+    ///
+    /// The name is an expression, like a variable or a call. Expressions that
+    /// look the same are shared, and they may resolve differently in different
+    /// places, so resolutions are per block.
+    #[inline]
+    pub fn inter_resolution(&mut self, block: usize, expression: usize, resolution: Resolution) {
+        self.resolution.insert((block, expression), resolution);
+    }
+
+    /// Exhume what a name in a block resolves to.
+    ///
+    #[inline]
+    pub fn exhume_resolution(&self, block: &usize, expression: &usize) -> Option<Resolution> {
+        self.resolution.get(&(*block, *expression)).copied()
+    }
+
+    /// Get an iterator over the resolutions, as block, expression, and
+    /// resolution.
+    ///
+    #[inline]
+    pub fn iter_resolution(&self) -> impl Iterator<Item = (usize, usize, Resolution)> + '_ {
+        self.resolution
+            .iter()
+            .map(|((block, expression), resolution)| (*block, *expression, *resolution))
+    }
+
+    /// Forget all of the resolutions.
+    ///
+    #[inline]
+    pub fn clear_resolution(&mut self) {
+        self.resolution.clear();
+    }
+
+    /// Inter (insert) [`XWhile`] into the store.
+    ///
+    #[inline]
+    pub fn inter_x_while<F>(&mut self, x_while: F) -> Rc<RefCell<XWhile>>
+    where
+        F: Fn(usize) -> Rc<RefCell<XWhile>>,
+    {
+        let _index = if let Some(_index) = self.x_while_free_list.pop() {
+            tracing::trace!(target: "store", "recycling block {_index}.");
+            _index
+        } else {
+            let _index = self.x_while.len();
+            tracing::trace!(target: "store", "allocating block {_index}.");
+            self.x_while.push(None);
+            _index
+        };
+
+        let x_while = x_while(_index);
+
+        if let Some(Some(x_while)) = self.x_while.iter().find(|stored| {
+            if let Some(stored) = stored {
+                *stored.borrow() == *x_while.borrow()
+            } else {
+                false
+            }
+        }) {
+            tracing::debug!(target: "store", "found duplicate {x_while:?}.");
+            self.x_while_free_list.push(_index);
+            x_while.clone()
+        } else {
+            tracing::debug!(target: "store", "interring {x_while:?}.");
+            self.x_while[_index] = Some(x_while.clone());
+            x_while
+        }
+    }
+
+    /// Exhume (get) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exhume_x_while(&self, id: &usize) -> Option<Rc<RefCell<XWhile>>> {
+        match self.x_while.get(*id) {
+            Some(x_while) => x_while.clone(),
+            None => None,
+        }
+    }
+
+    /// Exorcise (remove) [`XWhile`] from the store.
+    ///
+    #[inline]
+    pub fn exorcise_x_while(&mut self, id: &usize) -> Option<Rc<RefCell<XWhile>>> {
+        tracing::debug!(target: "store", "exorcising x_while slot: {id}.");
+        let result = self.x_while[*id].take();
+        self.x_while_free_list.push(*id);
+        result
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, XWhile>`.
+    ///
+    #[inline]
+    pub fn iter_x_while(&self) -> impl Iterator<Item = Rc<RefCell<XWhile>>> + '_ {
+        let len = self.x_while.len();
+        (0..len)
+            .filter(|i| self.x_while[*i].is_some())
+            .map(move |i| {
+                self.x_while[i]
+                    .as_ref()
+                    .map(|x_while| x_while.clone())
+                    .unwrap()
+            })
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::lu_dog_vec-object-store-persistence"}}}
@@ -9541,6 +10028,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Break.
+        {
+            let path = path.join("x_break");
+            fs::create_dir_all(&path)?;
+            for x_break in &self.x_break {
+                if let Some(x_break) = x_break {
+                    let path = path.join(format!("{}.json", x_break.borrow().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_break)?;
+                }
+            }
+        }
+
         // Persist Call.
         {
             let path = path.join("call");
@@ -9583,6 +10084,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Continue.
+        {
+            let path = path.join("x_continue");
+            fs::create_dir_all(&path)?;
+            for x_continue in &self.x_continue {
+                if let Some(x_continue) = x_continue {
+                    let path = path.join(format!("{}.json", x_continue.borrow().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_continue)?;
+                }
+            }
+        }
+
         // Persist Data Structure.
         {
             let path = path.join("data_structure");
@@ -10115,6 +10630,20 @@ impl ObjectStore {
             }
         }
 
+        // Persist Loop.
+        {
+            let path = path.join("x_loop");
+            fs::create_dir_all(&path)?;
+            for x_loop in &self.x_loop {
+                if let Some(x_loop) = x_loop {
+                    let path = path.join(format!("{}.json", x_loop.borrow().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_loop)?;
+                }
+            }
+        }
+
         // Persist Macro.
         {
             let path = path.join("x_macro");
@@ -10633,6 +11162,30 @@ impl ObjectStore {
             }
         }
 
+        // Persist While.
+        {
+            let path = path.join("x_while");
+            fs::create_dir_all(&path)?;
+            for x_while in &self.x_while {
+                if let Some(x_while) = x_while {
+                    let path = path.join(format!("{}.json", x_while.borrow().id));
+                    let file = fs::File::create(path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &x_while)?;
+                }
+            }
+        }
+
+        // Persist Resolution.
+        {
+            let path = path.join("resolution.json");
+            let file = fs::File::create(path)?;
+            let mut writer = io::BufWriter::new(file);
+            let mut resolution: Vec<_> = self.iter_resolution().collect();
+            resolution.sort_by_key(|(block, expression, _)| (*block, *expression));
+            serde_json::to_writer_pretty(&mut writer, &resolution)?;
+        }
+
         Ok(())
     }
 
@@ -10769,6 +11322,24 @@ impl ObjectStore {
             }
         }
 
+        // Load Break. Stores persisted before there were breaks don't have any.
+        {
+            let path = path.join("x_break");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_break: Rc<RefCell<XBreak>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_break
+                        .insert(x_break.borrow().id, Some(x_break.clone()));
+                }
+            }
+        }
+
         // Load Call.
         {
             let path = path.join("call");
@@ -10815,6 +11386,25 @@ impl ObjectStore {
             }
         }
 
+        // Load Continue. Stores persisted before there were continues don't have
+        // any.
+        {
+            let path = path.join("x_continue");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_continue: Rc<RefCell<XContinue>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_continue
+                        .insert(x_continue.borrow().id, Some(x_continue.clone()));
+                }
+            }
+        }
+
         // Load Data Structure.
         {
             let path = path.join("data_structure");
@@ -11439,6 +12029,24 @@ impl ObjectStore {
             }
         }
 
+        // Load Loop. Stores persisted before there were loops don't have any.
+        {
+            let path = path.join("x_loop");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_loop: Rc<RefCell<XLoop>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_loop
+                        .insert(x_loop.borrow().id, Some(x_loop.clone()));
+                }
+            }
+        }
+
         // Load Macro.
         {
             let path = path.join("x_macro");
@@ -12046,6 +12654,39 @@ impl ObjectStore {
             }
         }
 
+        // Load While. Stores persisted before there were while loops don't have
+        // any.
+        {
+            let path = path.join("x_while");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let x_while: Rc<RefCell<XWhile>> = serde_json::from_reader(reader)?;
+                    store
+                        .x_while
+                        .insert(x_while.borrow().id, Some(x_while.clone()));
+                }
+            }
+        }
+
+        // Load Resolution. Stores persisted before there was name resolution
+        // don't have any.
+        {
+            let path = path.join("resolution.json");
+            if path.exists() {
+                let file = fs::File::open(path)?;
+                let reader = io::BufReader::new(file);
+                let resolution: Vec<(usize, usize, Resolution)> = serde_json::from_reader(reader)?;
+                for (block, expression, resolution) in resolution {
+                    store.inter_resolution(block, expression, resolution);
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
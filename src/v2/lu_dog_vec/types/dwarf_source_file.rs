@@ -1,6 +1,6 @@
 // {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"dwarf_source_file-struct-definition-file"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"dwarf_source_file-use-statements"}}}
-use std::cell::RefCell;
+use std::cell::{OnceCell, RefCell};
 use std::rc::Rc;
 use uuid::Uuid;
 
@@ -18,23 +18,45 @@ use crate::v2::lu_dog_vec::store::ObjectStore as LuDogVecStore;
 ///  that will be parsed. This allows us to do better error reporting in the interpreter. We
 ///  may also be able to do something about displaying compiled functions, maybe.
 ///
+/// A store may hold many source files, each with it's own path. Offsets into the
+///  source are bytes, and they are mapped to one-based lines and columns, with
+///  columns counted in characters, by way of an index of the lines that is built
+///  the first time that it's needed.
+///
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"dwarf_source_file-struct-definition"}}}
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DwarfSourceFile {
     pub id: usize,
+    /// The name of the file, for display
+    #[serde(default)]
+    pub name: String,
+    /// Where the file came from, if it came from a file
+    #[serde(default)]
+    pub path: String,
     pub source: String,
+    /// The byte offset of the start of each line
+    #[serde(skip)]
+    lines: OnceCell<Vec<usize>>,
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"dwarf_source_file-implementation"}}}
 impl DwarfSourceFile {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"dwarf_source_file-struct-impl-new"}}}
     /// Inter a new 'Dwarf Source File' in the store, and return it's `id`.
-    pub fn new(source: String, store: &mut LuDogVecStore) -> Rc<RefCell<DwarfSourceFile>> {
+    pub fn new(
+        name: String,
+        path: String,
+        source: String,
+        store: &mut LuDogVecStore,
+    ) -> Rc<RefCell<DwarfSourceFile>> {
         store.inter_dwarf_source_file(|id| {
             Rc::new(RefCell::new(DwarfSourceFile {
                 id,
+                name: name.to_owned(),
+                path: path.to_owned(),
                 source: source.to_owned(),
+                lines: OnceCell::new(),
             }))
         })
     }
@@ -57,12 +79,74 @@ impl DwarfSourceFile {
             .collect()
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+    /// Replace the source, as when the file is edited
+    ///
+    /// Don't assign to `source` directly, as the line index wouldn't know.
+    pub fn set_source(&mut self, source: String) {
+        self.source = source;
+        self.lines = OnceCell::new();
+    }
+
+    fn line_starts(&self) -> &[usize] {
+        self.lines.get_or_init(|| {
+            std::iter::once(0)
+                .chain(self.source.match_indices('\n').map(|(index, _)| index + 1))
+                .collect()
+        })
+    }
+
+    /// The number of lines in the source
+    pub fn line_count(&self) -> usize {
+        self.line_starts().len()
+    }
+
+    /// The text of a line, by it's one-based number, without the line ending
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let starts = self.line_starts();
+        let start = *starts.get(line.checked_sub(1)?)?;
+        let end = starts.get(line).map_or(self.source.len(), |next| next - 1);
+        let text = &self.source[start..end];
+
+        Some(text.strip_suffix('\r').unwrap_or(text))
+    }
+
+    /// The one-based line and column of a byte offset
+    ///
+    /// Offsets past the end are taken to be at the end, and offsets inside of
+    /// a character are taken to be at the start of it.
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let starts = self.line_starts();
+        let line = starts.partition_point(|start| *start <= offset);
+        let column = self.source[starts[line - 1]..offset].chars().count() + 1;
+
+        (line, column)
+    }
+
+    /// The byte offset of a one-based line and column
+    ///
+    /// Columns past the end of the line are taken to be at the end of it.
+    pub fn offset(&self, line: usize, column: usize) -> Option<usize> {
+        let start = *self.line_starts().get(line.checked_sub(1)?)?;
+        let text = self.line(line)?;
+        let within = text
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(text.len(), |(index, _)| index);
+
+        Some(start + within)
+    }
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"dwarf_source_file-implementation"}}}
 impl PartialEq for DwarfSourceFile {
     fn eq(&self, other: &Self) -> bool {
-        self.source == other.source
+        self.path == other.path && self.source == other.source
     }
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"shift_left_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left_assignment-const-documentation"}}}
+/// Compound assignment with the shift left operator: `<<=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"shift_left_assignment-const-definition"}}}
+pub const SHIFT_LEFT_ASSIGNMENT: Uuid = uuid!["ce5d7b1b-43c5-5350-af1b-b952b83b4e4c"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShiftLeftAssignment;
+
+impl ShiftLeftAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        SHIFT_LEFT_ASSIGNMENT
+    }
+}
+
+impl Default for ShiftLeftAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
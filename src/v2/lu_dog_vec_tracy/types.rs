@@ -2,11 +2,18 @@
 // {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"v2::lu_dog_vec_tracy-module-definition-file"}}}
 pub mod a_wait;
 pub mod addition;
+pub mod addition_assignment;
 pub mod and;
 pub mod any_list;
 pub mod argument;
 pub mod assignment;
 pub mod binary;
+pub mod bitwise_and;
+pub mod bitwise_and_assignment;
+pub mod bitwise_or;
+pub mod bitwise_or_assignment;
+pub mod bitwise_xor;
+pub mod bitwise_xor_assignment;
 pub mod block;
 pub mod body;
 pub mod boolean_literal;
@@ -17,6 +24,7 @@ pub mod char_literal;
 pub mod comparison;
 pub mod data_structure;
 pub mod division;
+pub mod division_assignment;
 pub mod dwarf_source_file;
 pub mod empty;
 pub mod empty_expression;
@@ -70,6 +78,7 @@ pub mod map_element;
 pub mod map_expression;
 pub mod method_call;
 pub mod multiplication;
+pub mod multiplication_assignment;
 pub mod named_field_expression;
 pub mod negation;
 pub mod not;
@@ -82,7 +91,13 @@ pub mod path_element;
 pub mod pattern;
 pub mod range;
 pub mod range_expression;
+pub mod remainder;
+pub mod remainder_assignment;
 pub mod result_statement;
+pub mod shift_left;
+pub mod shift_left_assignment;
+pub mod shift_right;
+pub mod shift_right_assignment;
 pub mod span;
 pub mod statement;
 pub mod static_method_call;
@@ -92,6 +107,7 @@ pub mod struct_expression;
 pub mod struct_field;
 pub mod struct_generic;
 pub mod subtraction;
+pub mod subtraction_assignment;
 pub mod task;
 pub mod to;
 pub mod to_inclusive;
@@ -106,9 +122,12 @@ pub mod value_type;
 pub mod variable;
 pub mod variable_expression;
 pub mod woog_struct;
+pub mod x_break;
+pub mod x_continue;
 pub mod x_debugger;
 pub mod x_future;
 pub mod x_if;
+pub mod x_loop;
 pub mod x_macro;
 pub mod x_match;
 pub mod x_path;
@@ -116,11 +135,14 @@ pub mod x_plugin;
 pub mod x_print;
 pub mod x_return;
 pub mod x_value;
+pub mod x_while;
 pub mod z_object_store;
 
 pub use crate::v2::lu_dog_vec_tracy::a_wait::AWait;
 pub use crate::v2::lu_dog_vec_tracy::addition::Addition;
 pub use crate::v2::lu_dog_vec_tracy::addition::ADDITION;
+pub use crate::v2::lu_dog_vec_tracy::addition_assignment::AdditionAssignment;
+pub use crate::v2::lu_dog_vec_tracy::addition_assignment::ADDITION_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::and::And;
 pub use crate::v2::lu_dog_vec_tracy::and::AND;
 pub use crate::v2::lu_dog_vec_tracy::any_list::AnyList;
@@ -130,6 +152,18 @@ pub use crate::v2::lu_dog_vec_tracy::assignment::Assignment;
 pub use crate::v2::lu_dog_vec_tracy::assignment::ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::binary::Binary;
 pub use crate::v2::lu_dog_vec_tracy::binary::BinaryEnum;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_and::BitwiseAnd;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_and::BITWISE_AND;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_and_assignment::BitwiseAndAssignment;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_and_assignment::BITWISE_AND_ASSIGNMENT;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_or::BitwiseOr;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_or::BITWISE_OR;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_or_assignment::BitwiseOrAssignment;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_or_assignment::BITWISE_OR_ASSIGNMENT;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_xor::BitwiseXor;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_xor::BITWISE_XOR;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_xor_assignment::BitwiseXorAssignment;
+pub use crate::v2::lu_dog_vec_tracy::bitwise_xor_assignment::BITWISE_XOR_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::block::Block;
 pub use crate::v2::lu_dog_vec_tracy::body::Body;
 pub use crate::v2::lu_dog_vec_tracy::body::BodyEnum;
@@ -148,6 +182,8 @@ pub use crate::v2::lu_dog_vec_tracy::data_structure::DataStructure;
 pub use crate::v2::lu_dog_vec_tracy::data_structure::DataStructureEnum;
 pub use crate::v2::lu_dog_vec_tracy::division::Division;
 pub use crate::v2::lu_dog_vec_tracy::division::DIVISION;
+pub use crate::v2::lu_dog_vec_tracy::division_assignment::DivisionAssignment;
+pub use crate::v2::lu_dog_vec_tracy::division_assignment::DIVISION_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::dwarf_source_file::DwarfSourceFile;
 pub use crate::v2::lu_dog_vec_tracy::empty::Empty;
 pub use crate::v2::lu_dog_vec_tracy::empty::EMPTY;
@@ -222,6 +258,8 @@ pub use crate::v2::lu_dog_vec_tracy::map_expression::MapExpression;
 pub use crate::v2::lu_dog_vec_tracy::method_call::MethodCall;
 pub use crate::v2::lu_dog_vec_tracy::multiplication::Multiplication;
 pub use crate::v2::lu_dog_vec_tracy::multiplication::MULTIPLICATION;
+pub use crate::v2::lu_dog_vec_tracy::multiplication_assignment::MultiplicationAssignment;
+pub use crate::v2::lu_dog_vec_tracy::multiplication_assignment::MULTIPLICATION_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::named_field_expression::NamedFieldExpression;
 pub use crate::v2::lu_dog_vec_tracy::negation::Negation;
 pub use crate::v2::lu_dog_vec_tracy::negation::NEGATION;
@@ -241,7 +279,19 @@ pub use crate::v2::lu_dog_vec_tracy::range::Range;
 pub use crate::v2::lu_dog_vec_tracy::range::RANGE;
 pub use crate::v2::lu_dog_vec_tracy::range_expression::RangeExpression;
 pub use crate::v2::lu_dog_vec_tracy::range_expression::RangeExpressionEnum;
+pub use crate::v2::lu_dog_vec_tracy::remainder::Remainder;
+pub use crate::v2::lu_dog_vec_tracy::remainder::REMAINDER;
+pub use crate::v2::lu_dog_vec_tracy::remainder_assignment::RemainderAssignment;
+pub use crate::v2::lu_dog_vec_tracy::remainder_assignment::REMAINDER_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::result_statement::ResultStatement;
+pub use crate::v2::lu_dog_vec_tracy::shift_left::ShiftLeft;
+pub use crate::v2::lu_dog_vec_tracy::shift_left::SHIFT_LEFT;
+pub use crate::v2::lu_dog_vec_tracy::shift_left_assignment::ShiftLeftAssignment;
+pub use crate::v2::lu_dog_vec_tracy::shift_left_assignment::SHIFT_LEFT_ASSIGNMENT;
+pub use crate::v2::lu_dog_vec_tracy::shift_right::ShiftRight;
+pub use crate::v2::lu_dog_vec_tracy::shift_right::SHIFT_RIGHT;
+pub use crate::v2::lu_dog_vec_tracy::shift_right_assignment::ShiftRightAssignment;
+pub use crate::v2::lu_dog_vec_tracy::shift_right_assignment::SHIFT_RIGHT_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::span::Span;
 pub use crate::v2::lu_dog_vec_tracy::statement::Statement;
 pub use crate::v2::lu_dog_vec_tracy::statement::StatementEnum;
@@ -253,6 +303,8 @@ pub use crate::v2::lu_dog_vec_tracy::struct_field::StructField;
 pub use crate::v2::lu_dog_vec_tracy::struct_generic::StructGeneric;
 pub use crate::v2::lu_dog_vec_tracy::subtraction::Subtraction;
 pub use crate::v2::lu_dog_vec_tracy::subtraction::SUBTRACTION;
+pub use crate::v2::lu_dog_vec_tracy::subtraction_assignment::SubtractionAssignment;
+pub use crate::v2::lu_dog_vec_tracy::subtraction_assignment::SUBTRACTION_ASSIGNMENT;
 pub use crate::v2::lu_dog_vec_tracy::task::Task;
 pub use crate::v2::lu_dog_vec_tracy::task::TASK;
 pub use crate::v2::lu_dog_vec_tracy::to::To;
@@ -275,10 +327,13 @@ pub use crate::v2::lu_dog_vec_tracy::variable::Variable;
 pub use crate::v2::lu_dog_vec_tracy::variable::VariableEnum;
 pub use crate::v2::lu_dog_vec_tracy::variable_expression::VariableExpression;
 pub use crate::v2::lu_dog_vec_tracy::woog_struct::WoogStruct;
+pub use crate::v2::lu_dog_vec_tracy::x_break::XBreak;
+pub use crate::v2::lu_dog_vec_tracy::x_continue::XContinue;
 pub use crate::v2::lu_dog_vec_tracy::x_debugger::XDebugger;
 pub use crate::v2::lu_dog_vec_tracy::x_debugger::X_DEBUGGER;
 pub use crate::v2::lu_dog_vec_tracy::x_future::XFuture;
 pub use crate::v2::lu_dog_vec_tracy::x_if::XIf;
+pub use crate::v2::lu_dog_vec_tracy::x_loop::XLoop;
 pub use crate::v2::lu_dog_vec_tracy::x_macro::XMacro;
 pub use crate::v2::lu_dog_vec_tracy::x_match::XMatch;
 pub use crate::v2::lu_dog_vec_tracy::x_path::XPath;
@@ -287,5 +342,6 @@ pub use crate::v2::lu_dog_vec_tracy::x_print::XPrint;
 pub use crate::v2::lu_dog_vec_tracy::x_return::XReturn;
 pub use crate::v2::lu_dog_vec_tracy::x_value::XValue;
 pub use crate::v2::lu_dog_vec_tracy::x_value::XValueEnum;
+pub use crate::v2::lu_dog_vec_tracy::x_while::XWhile;
 pub use crate::v2::lu_dog_vec_tracy::z_object_store::ZObjectStore;
 // {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
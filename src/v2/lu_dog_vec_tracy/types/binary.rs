@@ -6,13 +6,29 @@ use tracy_client::span;
 use uuid::Uuid;
 
 use crate::v2::lu_dog_vec_tracy::types::addition::ADDITION;
+use crate::v2::lu_dog_vec_tracy::types::addition_assignment::ADDITION_ASSIGNMENT;
 use crate::v2::lu_dog_vec_tracy::types::assignment::ASSIGNMENT;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_and::BITWISE_AND;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_and_assignment::BITWISE_AND_ASSIGNMENT;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_or::BITWISE_OR;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_or_assignment::BITWISE_OR_ASSIGNMENT;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_xor::BITWISE_XOR;
+use crate::v2::lu_dog_vec_tracy::types::bitwise_xor_assignment::BITWISE_XOR_ASSIGNMENT;
 use crate::v2::lu_dog_vec_tracy::types::boolean_operator::BooleanOperator;
 use crate::v2::lu_dog_vec_tracy::types::division::DIVISION;
+use crate::v2::lu_dog_vec_tracy::types::division_assignment::DIVISION_ASSIGNMENT;
 use crate::v2::lu_dog_vec_tracy::types::multiplication::MULTIPLICATION;
+use crate::v2::lu_dog_vec_tracy::types::multiplication_assignment::MULTIPLICATION_ASSIGNMENT;
 use crate::v2::lu_dog_vec_tracy::types::operator::Operator;
 use crate::v2::lu_dog_vec_tracy::types::operator::OperatorEnum;
+use crate::v2::lu_dog_vec_tracy::types::remainder::REMAINDER;
+use crate::v2::lu_dog_vec_tracy::types::remainder_assignment::REMAINDER_ASSIGNMENT;
+use crate::v2::lu_dog_vec_tracy::types::shift_left::SHIFT_LEFT;
+use crate::v2::lu_dog_vec_tracy::types::shift_left_assignment::SHIFT_LEFT_ASSIGNMENT;
+use crate::v2::lu_dog_vec_tracy::types::shift_right::SHIFT_RIGHT;
+use crate::v2::lu_dog_vec_tracy::types::shift_right_assignment::SHIFT_RIGHT_ASSIGNMENT;
 use crate::v2::lu_dog_vec_tracy::types::subtraction::SUBTRACTION;
+use crate::v2::lu_dog_vec_tracy::types::subtraction_assignment::SUBTRACTION_ASSIGNMENT;
 use serde::{Deserialize, Serialize};
 
 use crate::v2::lu_dog_vec_tracy::store::ObjectStore as LuDogVecTracyStore;
@@ -36,11 +52,27 @@ pub struct Binary {
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum BinaryEnum {
     Addition(Uuid),
+    AdditionAssignment(Uuid),
     Assignment(Uuid),
+    BitwiseAnd(Uuid),
+    BitwiseAndAssignment(Uuid),
+    BitwiseOr(Uuid),
+    BitwiseOrAssignment(Uuid),
+    BitwiseXor(Uuid),
+    BitwiseXorAssignment(Uuid),
     BooleanOperator(usize),
     Division(Uuid),
+    DivisionAssignment(Uuid),
     Multiplication(Uuid),
+    MultiplicationAssignment(Uuid),
+    Remainder(Uuid),
+    RemainderAssignment(Uuid),
+    ShiftLeft(Uuid),
+    ShiftLeftAssignment(Uuid),
+    ShiftRight(Uuid),
+    ShiftRightAssignment(Uuid),
     Subtraction(Uuid),
+    SubtractionAssignment(Uuid),
 }
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-implementation"}}}
@@ -57,6 +89,21 @@ impl Binary {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_addition_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_addition_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::AdditionAssignment(ADDITION_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_assignment"}}}
     /// Inter a new Binary in the store, and return it's `id`.
     pub fn new_assignment(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
@@ -69,6 +116,87 @@ impl Binary {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_and"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_and(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseAnd(BITWISE_AND),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_and_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_and_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseAndAssignment(BITWISE_AND_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_or"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_or(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseOr(BITWISE_OR),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_or_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_or_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseOrAssignment(BITWISE_OR_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_xor"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_xor(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseXor(BITWISE_XOR),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_bitwise_xor_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_bitwise_xor_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::BitwiseXorAssignment(BITWISE_XOR_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_boolean_operator"}}}
     /// Inter a new Binary in the store, and return it's `id`.
     pub fn new_boolean_operator(
@@ -97,6 +225,21 @@ impl Binary {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_division_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_division_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::DivisionAssignment(DIVISION_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_multiplication"}}}
     /// Inter a new Binary in the store, and return it's `id`.
     pub fn new_multiplication(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
@@ -109,6 +252,102 @@ impl Binary {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_multiplication_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_multiplication_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::MultiplicationAssignment(MULTIPLICATION_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_remainder"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_remainder(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::Remainder(REMAINDER),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_remainder_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_remainder_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::RemainderAssignment(REMAINDER_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_shift_left"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_shift_left(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::ShiftLeft(SHIFT_LEFT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_shift_left_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_shift_left_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::ShiftLeftAssignment(SHIFT_LEFT_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_shift_right"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_shift_right(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::ShiftRight(SHIFT_RIGHT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_shift_right_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_shift_right_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::ShiftRightAssignment(SHIFT_RIGHT_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_subtraction"}}}
     /// Inter a new Binary in the store, and return it's `id`.
     pub fn new_subtraction(bogus: bool, store: &mut LuDogVecTracyStore) -> Rc<RefCell<Binary>> {
@@ -121,6 +360,21 @@ impl Binary {
         })
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-struct-impl-new_subtraction_assignment"}}}
+    /// Inter a new Binary in the store, and return it's `id`.
+    pub fn new_subtraction_assignment(
+        bogus: bool,
+        store: &mut LuDogVecTracyStore,
+    ) -> Rc<RefCell<Binary>> {
+        store.inter_binary(|id| {
+            Rc::new(RefCell::new(Binary {
+                bogus: bogus,
+                subtype: BinaryEnum::SubtractionAssignment(SUBTRACTION_ASSIGNMENT),
+                id,
+            }))
+        })
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"binary-impl-nav-subtype-to-supertype-operator"}}}
     // Navigate to [`Operator`] across R47(isa)
     pub fn r47_operator<'a>(&'a self, store: &'a LuDogVecTracyStore) -> Vec<Rc<RefCell<Operator>>> {
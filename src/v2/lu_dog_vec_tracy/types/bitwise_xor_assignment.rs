@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"bitwise_xor_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor_assignment-const-documentation"}}}
+/// Compound assignment with the bitwise xor operator: `^=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"bitwise_xor_assignment-const-definition"}}}
+pub const BITWISE_XOR_ASSIGNMENT: Uuid = uuid!["d46441b9-9f62-55c5-88ef-b1ff283866d1"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BitwiseXorAssignment;
+
+impl BitwiseXorAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        BITWISE_XOR_ASSIGNMENT
+    }
+}
+
+impl Default for BitwiseXorAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,33 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"multiplication_assignment-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"multiplication_assignment-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"multiplication_assignment-const-documentation"}}}
+/// Compound assignment with the multiplication operator: `*=`.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"multiplication_assignment-const-definition"}}}
+pub const MULTIPLICATION_ASSIGNMENT: Uuid = uuid!["6966d34c-620f-530c-9569-371f9cc84b49"];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MultiplicationAssignment;
+
+impl MultiplicationAssignment {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        MULTIPLICATION_ASSIGNMENT
+    }
+}
+
+impl Default for MultiplicationAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
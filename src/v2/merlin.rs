@@ -2,7 +2,11 @@
 //!
 use uuid::{uuid, Uuid};
 
+#[cfg(feature = "sarzak-rwlock")]
+pub mod draw;
 pub mod from;
+pub mod geometry;
+pub mod route;
 pub mod store;
 pub mod types;
 
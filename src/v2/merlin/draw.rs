@@ -0,0 +1,69 @@
+//! Relationship Drawing Helpers
+//!
+//! Relationships are drawn in three places: when importing a v1 drawing, when
+//! the [`ModelBuilder`][crate::v2::domain::builder::ModelBuilder] lays out a
+//! new domain, and when [`sync`][crate::v2::domain::sync] draws a line that's
+//! gone missing. They all dress the ends of a line the same way, with these.
+use std::sync::{Arc, RwLock};
+
+use crate::v2::{
+    merlin::{
+        store::ObjectStore as MerlinStore,
+        types::{Anchor, Glyph, Line, RelationshipPhrase},
+    },
+    sarzak::types::Cardinality,
+};
+
+/// The glyph at the end of a line, given the cardinality of that end
+///
+pub fn cardinality_glyph(
+    card: &Arc<RwLock<Cardinality>>,
+    line: &Arc<RwLock<Line>>,
+    merlin: &mut MerlinStore,
+) -> Arc<RwLock<Glyph>> {
+    match *card.read().unwrap() {
+        Cardinality::One(_) => Glyph::new_one(line, merlin),
+        Cardinality::Many(_) => Glyph::new_many(line, merlin),
+    }
+}
+
+/// Label an anchor with a phrase, at `x` and `y`
+///
+/// Empty phrases aren't worth drawing, so they are skipped.
+pub fn phrase(
+    text: &str,
+    x: i64,
+    y: i64,
+    line: &Arc<RwLock<Line>>,
+    anchor: &Arc<RwLock<Anchor>>,
+    merlin: &mut MerlinStore,
+) -> Option<Arc<RwLock<RelationshipPhrase>>> {
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(RelationshipPhrase::new(
+        text.to_owned(),
+        x,
+        y,
+        line,
+        anchor,
+        merlin,
+    ))
+}
+
+/// Label an anchor with a phrase, where the line leaves the box
+///
+pub fn anchor_phrase(
+    text: &str,
+    line: &Arc<RwLock<Line>>,
+    anchor: &Arc<RwLock<Anchor>>,
+    merlin: &mut MerlinStore,
+) -> Option<Arc<RwLock<RelationshipPhrase>>> {
+    let (x, y) = {
+        let anchor = anchor.read().unwrap();
+        (anchor.x_offset, anchor.y_offset)
+    };
+
+    phrase(text, x, y, line, anchor, merlin)
+}
@@ -10,6 +10,8 @@
 //! the generated code will need to be manually edited.
 use std::sync::{Arc, RwLock};
 
+use uuid::Uuid;
+
 // {"magic":"","directive":{"Start":{"directive":"ignore-gen","tag":"v2::drawing-from-impl-file"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-gen","tag":"v2::drawing-from-impl-definition"}}}
 use crate::v2::merlin::types::{
@@ -18,11 +20,14 @@ use crate::v2::merlin::types::{
 };
 use crate::v2::merlin::ObjectStore;
 
-use crate::v2::drawing::types::{Edge as FromEdge, ObjectUi, Point as FromPoint};
+use crate::v2::drawing::types::{
+    Anchor as FromAnchor, Edge as FromEdge, ObjectUi, Point as FromPoint,
+};
 use crate::v2::drawing::ObjectStore as DrawingStore;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "sarzak-rwlock")] {
+        use crate::v2::merlin::draw::{cardinality_glyph, phrase};
         use crate::v2::sarzak::types::Cardinality;
         use crate::v2::sarzak::ObjectStore as SarzakStore;
     } else if #[cfg(feature = "sarzak-single")] {
@@ -50,7 +55,13 @@ cfg_if::cfg_if! {
 /// these. The offset attribute is calculated, and requires the object to do so.
 ///
 /// Well darn. There isn't a good way to get an object from an anchor, nor an
-/// anchor from an object -- not in the drawing domain anyway.
+/// anchor from an object -- not in the drawing domain anyway. So we go looking
+/// for the box whose edge the anchor sits on.
+///
+/// Isa relationships get a `ZSuper` glyph on the supertype, and a `Sub` glyph on
+/// each subtype. Associatives are drawn with a segment between the formalizing
+/// objects, and a second segment from the associative object to the first. The
+/// referrer and referent descriptions become relationship phrases.
 ///
 #[cfg(feature = "sarzak-rwlock")]
 impl From<(&DrawingStore, &SarzakStore)> for ObjectStore {
@@ -85,115 +96,231 @@ impl From<(&DrawingStore, &SarzakStore)> for ObjectStore {
                 &mut merlin,
             );
 
-            let from_anchor = &bui.read().unwrap().r7_anchor(drawing)[0];
-
             // Our relationships are jacked up, so we have to do the dumb thing.
             // Not that relationship navigation is any better, depending on the
             // direction.
+            let from_anchor = &bui.read().unwrap().r7_anchor(drawing)[0];
             let referrer = &binary.read().unwrap().r6_referrer(sarzak)[0];
             let from_obj = &referrer.read().unwrap().r17_object(sarzak)[0];
-            let from_obj_ui = drawing
-                .iter_object_ui()
-                .find(|oui| oui.read().unwrap().object_id == from_obj.read().unwrap().id)
-                .unwrap();
-
-            // Get what we need to build the offset to which the line connects.
-            let point = &from_anchor.read().unwrap().r4_point(drawing)[0];
-            let edge = &from_anchor.read().unwrap().r3_edge(drawing)[0];
-            let (x, y) = get_anchor_offset(&point, &edge);
-
-            // Sort out how far along the edge the arrow should be drawn.
-            let origin = &from_obj_ui.read().unwrap().r13_point(drawing)[0];
-            let offset = get_anchor_line_offset(&edge, &point, &from_obj_ui, &origin);
+            let from_obj_ui = find_object_ui(from_anchor, &[from_obj.read().unwrap().id], drawing);
 
-            // Sort out the glyph.
             let card = &referrer.read().unwrap().r9_cardinality(sarzak)[0];
-            let glyph = match *card.read().unwrap() {
-                Cardinality::One(_) => Glyph::new_one(&line, &mut merlin),
-                Cardinality::Many(_) => Glyph::new_many(&line, &mut merlin),
-            };
+            let glyph = cardinality_glyph(card, &line, &mut merlin);
+            let (anchor, _) = make_anchor(
+                from_anchor,
+                &from_obj_ui,
+                &glyph,
+                &line,
+                &line_seg,
+                drawing,
+                &mut merlin,
+            );
+            make_phrase(
+                &referrer.read().unwrap().description,
+                from_anchor,
+                &line,
+                &anchor,
+                drawing,
+                &mut merlin,
+            );
 
-            // Get the box.
-            let x_box = merlin
-                .exhume_x_box(&from_obj_ui.read().unwrap().id)
-                .unwrap()
-                .clone();
+            let to_anchor = &bui.read().unwrap().r8_anchor(drawing)[0];
+            let referent = &binary.read().unwrap().r5_referent(sarzak)[0];
+            let to_obj = &referent.read().unwrap().r16_object(sarzak)[0];
+            let to_obj_ui = find_object_ui(to_anchor, &[to_obj.read().unwrap().id], drawing);
 
-            // Create the anchor.
-            let from_anchor = Anchor::new(
-                offset,
-                x,
-                y,
-                &Arc::new(RwLock::new(XyzzyEdge(&edge, &merlin).into())),
+            let card = &referent.read().unwrap().r8_cardinality(sarzak)[0];
+            let glyph = cardinality_glyph(card, &line, &mut merlin);
+            let (anchor, _) = make_anchor(
+                to_anchor,
+                &to_obj_ui,
                 &glyph,
-                &x_box,
                 &line,
+                &line_seg,
+                drawing,
                 &mut merlin,
             );
-
-            // Create the from point
-            let point = Point::new_anchor(
-                point.read().unwrap().x,
-                point.read().unwrap().y,
-                &from_anchor,
+            make_phrase(
+                &referent.read().unwrap().description,
+                to_anchor,
+                &line,
+                &anchor,
+                drawing,
                 &mut merlin,
             );
+        }
 
-            // Create the "line segment point"
-            LineSegmentPoint::new(&line_seg, &point, &mut merlin);
+        // An isa is drawn as a single line, with a segment from the supertype
+        // to each of the subtypes. The supertype anchor, and it's point, are
+        // shared by all of the segments.
+        for iui in drawing.iter_isa_ui() {
+            let isa = &iui.read().unwrap().r11_isa(sarzak)[0];
+            let rel = &isa.read().unwrap().r4_relationship(sarzak)[0];
 
-            let to_anchor = &bui.read().unwrap().r8_anchor(drawing)[0];
-            let referent = &binary.read().unwrap().r5_referent(sarzak)[0];
-            let from_obj = &referent.read().unwrap().r16_object(sarzak)[0];
-            let from_obj_ui = drawing
-                .iter_object_ui()
-                .find(|oui| oui.read().unwrap().object_id == from_obj.read().unwrap().id)
-                .unwrap();
+            let line = Line::new(&rel.read().unwrap(), &mut merlin);
+            let first_seg = LineSegment::new(&line, &mut merlin);
 
-            // Get what we need to build the offset to which the line connects.
-            let point = &to_anchor.read().unwrap().r4_point(drawing)[0];
-            let edge = &to_anchor.read().unwrap().r3_edge(drawing)[0];
-            let (x, y) = get_anchor_offset(&point, &edge);
+            let bisection = Bisection::new(0.5, &first_seg, &mut merlin);
+            let _name = RelationshipName::new(
+                format!("R{}", isa.read().unwrap().number),
+                0,
+                0,
+                &line,
+                &bisection,
+                &mut merlin,
+            );
 
-            // Sort out how far along the edge the arrow should be drawn.
-            let origin = &from_obj_ui.read().unwrap().r13_point(drawing)[0];
-            let offset = get_anchor_line_offset(&edge, &point, &from_obj_ui, &origin);
+            let super_anchor = &iui.read().unwrap().r9_anchor(drawing)[0];
+            let supertype = &isa.read().unwrap().r13_supertype(sarzak)[0];
+            let super_obj = &supertype.read().unwrap().r14_object(sarzak)[0];
+            let super_obj_ui =
+                find_object_ui(super_anchor, &[super_obj.read().unwrap().id], drawing);
 
-            // Sort out the glyph.
-            let card = &referent.read().unwrap().r8_cardinality(sarzak)[0];
-            let glyph = match *card.read().unwrap() {
-                Cardinality::One(_) => Glyph::new_one(&line, &mut merlin),
-                Cardinality::Many(_) => Glyph::new_many(&line, &mut merlin),
-            };
+            let glyph = Glyph::new_z_super(&line, &mut merlin);
+            let (_, super_point) = make_anchor(
+                super_anchor,
+                &super_obj_ui,
+                &glyph,
+                &line,
+                &first_seg,
+                drawing,
+                &mut merlin,
+            );
 
-            // Get the box.
-            let x_box = merlin
-                .exhume_x_box(&from_obj_ui.read().unwrap().id)
+            // Subtype anchors aren't related to the subtypes, so we sort out which
+            // is which by looking at where they are drawn.
+            let subtypes = isa
+                .read()
                 .unwrap()
-                .clone();
+                .r27_subtype(sarzak)
+                .iter()
+                .map(|sub| sub.read().unwrap().r15_object(sarzak)[0].read().unwrap().id)
+                .collect::<Vec<_>>();
+
+            // R10 is an associative relationship, and the navigation only
+            // returns the first instance. So we do it by hand.
+            let isa_ui_id = iui.read().unwrap().id;
+            let sub_anchors = drawing
+                .iter_subtype_anchors()
+                .filter(|sa| sa.read().unwrap().isaui_id == isa_ui_id)
+                .collect::<Vec<_>>();
+
+            for (n, sa) in sub_anchors.iter().enumerate() {
+                let sub_anchor = &sa.read().unwrap().r10_anchor(drawing)[0];
+                let sub_obj_ui = find_object_ui(sub_anchor, &subtypes, drawing);
+
+                let line_seg = if n == 0 {
+                    first_seg.clone()
+                } else {
+                    let line_seg = LineSegment::new(&line, &mut merlin);
+                    LineSegmentPoint::new(&line_seg, &super_point, &mut merlin);
+                    line_seg
+                };
+
+                let glyph = Glyph::new_sub(&line, &mut merlin);
+                make_anchor(
+                    sub_anchor,
+                    &sub_obj_ui,
+                    &glyph,
+                    &line,
+                    &line_seg,
+                    drawing,
+                    &mut merlin,
+                );
+            }
+        }
 
-            // Create the anchor.
-            let to_anchor = Anchor::new(
-                offset,
-                x,
-                y,
-                &Arc::new(RwLock::new(XyzzyEdge(&edge, &merlin).into())),
-                &glyph,
-                &x_box,
+        // An associative is drawn as a line between the two formalizing objects,
+        // and a second line segment from the associative object to a point on the
+        // first.
+        for aui in drawing.iter_associative_ui() {
+            let assoc = &aui.read().unwrap().r20_associative(sarzak)[0];
+            let rel = &assoc.read().unwrap().r4_relationship(sarzak)[0];
+
+            let line = Line::new(&rel.read().unwrap(), &mut merlin);
+            let line_seg = LineSegment::new(&line, &mut merlin);
+
+            let bisection = Bisection::new(0.5, &line_seg, &mut merlin);
+            let _name = RelationshipName::new(
+                format!("R{}", assoc.read().unwrap().number),
+                0,
+                0,
                 &line,
+                &bisection,
                 &mut merlin,
             );
 
-            // Create the to point
-            let point = Point::new_anchor(
-                point.read().unwrap().x,
-                point.read().unwrap().y,
-                &to_anchor,
+            let referents = assoc
+                .read()
+                .unwrap()
+                .r22_an_associative_referent(sarzak)
+                .iter()
+                .map(|aar| aar.read().unwrap().r22_associative_referent(sarzak)[0].clone())
+                .collect::<Vec<_>>();
+            let objects = referents
+                .iter()
+                .map(|referent| referent.read().unwrap().obj_id)
+                .collect::<Vec<_>>();
+
+            for anchor in [
+                &aui.read().unwrap().r14_anchor(drawing)[0],
+                &aui.read().unwrap().r15_anchor(drawing)[0],
+            ] {
+                let obj_ui = find_object_ui(anchor, &objects, drawing);
+                let referent = referents
+                    .iter()
+                    .find(|r| r.read().unwrap().obj_id == obj_ui.read().unwrap().object_id)
+                    .unwrap();
+
+                let card = &referent.read().unwrap().r88_cardinality(sarzak)[0];
+                let glyph = cardinality_glyph(card, &line, &mut merlin);
+                let (merlin_anchor, _) = make_anchor(
+                    anchor,
+                    &obj_ui,
+                    &glyph,
+                    &line,
+                    &line_seg,
+                    drawing,
+                    &mut merlin,
+                );
+                make_phrase(
+                    &referent.read().unwrap().description,
+                    anchor,
+                    &line,
+                    &merlin_anchor,
+                    drawing,
+                    &mut merlin,
+                );
+            }
+
+            // Now the line from the associative object.
+            let middle = &aui.read().unwrap().r16_anchor(drawing)[0];
+            let referrer = &assoc.read().unwrap().r21_associative_referrer(sarzak)[0];
+            let assoc_obj = &referrer.read().unwrap().r26_object(sarzak)[0];
+            let assoc_obj_ui = find_object_ui(middle, &[assoc_obj.read().unwrap().id], drawing);
+
+            let middle_seg = LineSegment::new(&line, &mut merlin);
+            let card = &referrer.read().unwrap().r89_cardinality(sarzak)[0];
+            let glyph = cardinality_glyph(card, &line, &mut merlin);
+            make_anchor(
+                middle,
+                &assoc_obj_ui,
+                &glyph,
+                &line,
+                &middle_seg,
+                drawing,
                 &mut merlin,
             );
 
-            // Create the "line segment point"
-            LineSegmentPoint::new(&line_seg, &point, &mut merlin);
+            // The point where the segments meet lies on the first segment.
+            let from = &aui.read().unwrap().r17_point(drawing)[0];
+            let point = Point::new_bisection(
+                from.read().unwrap().x,
+                from.read().unwrap().y,
+                &bisection,
+                &mut merlin,
+            );
+            LineSegmentPoint::new(&middle_seg, &point, &mut merlin);
         }
 
         merlin
@@ -349,6 +476,116 @@ impl From<(&DrawingStore, &SarzakStore)> for ObjectStore {
     }
 }
 
+/// Find the box, among those drawn for `candidates`, to which `anchor` is attached
+///
+/// The drawing domain doesn't relate an anchor to the object it's attached to.
+/// Instead we pick the box with the anchor's edge closest to the anchor's point.
+fn find_object_ui(
+    anchor: &Arc<RwLock<FromAnchor>>,
+    candidates: &[Uuid],
+    drawing: &DrawingStore,
+) -> Arc<RwLock<ObjectUi>> {
+    let point = &anchor.read().unwrap().r4_point(drawing)[0];
+    let edge = &anchor.read().unwrap().r3_edge(drawing)[0];
+    let (x, y) = (point.read().unwrap().x, point.read().unwrap().y);
+
+    drawing
+        .iter_object_ui()
+        .filter(|oui| candidates.contains(&oui.read().unwrap().object_id))
+        .min_by_key(|oui| {
+            let oui = oui.read().unwrap();
+            let origin = &oui.r13_point(drawing)[0];
+            let (left, top) = (origin.read().unwrap().x, origin.read().unwrap().y);
+            let (right, bottom) = (left + oui.width, top + oui.height);
+
+            let ((x0, y0), (x1, y1)) = match *edge.read().unwrap() {
+                FromEdge::Top(_) => ((left, top), (right, top)),
+                FromEdge::Right(_) => ((right, top), (right, bottom)),
+                FromEdge::Bottom(_) => ((left, bottom), (right, bottom)),
+                FromEdge::Left(_) => ((left, top), (left, bottom)),
+            };
+
+            let dx = (x0 - x).max(x - x1).max(0);
+            let dy = (y0 - y).max(y - y1).max(0);
+            dx * dx + dy * dy
+        })
+        .expect("anchor is not attached to any of the candidate objects")
+}
+
+/// Create the merlin anchor, and it's point on `line_seg`, for a drawing anchor
+///
+fn make_anchor(
+    anchor: &Arc<RwLock<FromAnchor>>,
+    obj_ui: &Arc<RwLock<ObjectUi>>,
+    glyph: &Arc<RwLock<Glyph>>,
+    line: &Arc<RwLock<Line>>,
+    line_seg: &Arc<RwLock<LineSegment>>,
+    drawing: &DrawingStore,
+    merlin: &mut ObjectStore,
+) -> (Arc<RwLock<Anchor>>, Arc<RwLock<Point>>) {
+    // Get what we need to build the offset to which the line connects.
+    let point = &anchor.read().unwrap().r4_point(drawing)[0];
+    let edge = &anchor.read().unwrap().r3_edge(drawing)[0];
+    let (x, y) = get_anchor_offset(&point, &edge);
+
+    // Sort out how far along the edge the arrow should be drawn.
+    let origin = &obj_ui.read().unwrap().r13_point(drawing)[0];
+    let offset = get_anchor_line_offset(&edge, &point, &obj_ui, &origin);
+
+    // Get the box.
+    let x_box = merlin
+        .exhume_x_box(&obj_ui.read().unwrap().id)
+        .unwrap()
+        .clone();
+
+    // Create the anchor.
+    let anchor = Anchor::new(
+        offset,
+        x,
+        y,
+        &Arc::new(RwLock::new(XyzzyEdge(&edge, &merlin).into())),
+        glyph,
+        &x_box,
+        line,
+        merlin,
+    );
+
+    // Create the point
+    let point = Point::new_anchor(
+        point.read().unwrap().x,
+        point.read().unwrap().y,
+        &anchor,
+        merlin,
+    );
+
+    // Create the "line segment point"
+    LineSegmentPoint::new(line_seg, &point, merlin);
+
+    (anchor, point)
+}
+
+/// Create a relationship phrase, positioned by the drawing anchor's phrase offset
+///
+/// Empty phrases aren't worth drawing, so they are skipped.
+#[cfg(feature = "sarzak-rwlock")]
+fn make_phrase(
+    text: &str,
+    from: &Arc<RwLock<FromAnchor>>,
+    line: &Arc<RwLock<Line>>,
+    anchor: &Arc<RwLock<Anchor>>,
+    drawing: &DrawingStore,
+    merlin: &mut ObjectStore,
+) {
+    let point = &from.read().unwrap().r4_point(drawing)[0];
+    let offset = &from.read().unwrap().r5_point(drawing)[0];
+    let (x, y) = (
+        point.read().unwrap().x + offset.read().unwrap().x,
+        point.read().unwrap().y + offset.read().unwrap().y,
+    );
+
+    phrase(text, x, y, line, anchor, merlin);
+}
+
 fn get_anchor_offset(point: &Arc<RwLock<FromPoint>>, edge: &Arc<RwLock<FromEdge>>) -> (i64, i64) {
     let (x, y) = (point.read().unwrap().x, point.read().unwrap().y);
 
@@ -360,6 +597,10 @@ fn get_anchor_offset(point: &Arc<RwLock<FromPoint>>, edge: &Arc<RwLock<FromEdge>
     }
 }
 
+/// How far along it's edge the anchor is, as a fraction of the edge length
+///
+/// This is what [`anchor_location`][crate::v2::merlin::geometry::anchor_location]
+/// expects.
 fn get_anchor_line_offset(
     edge: &Arc<RwLock<FromEdge>>,
     anchor: &Arc<RwLock<FromPoint>>,
@@ -370,9 +611,17 @@ fn get_anchor_line_offset(
     let (obj_x, obj_y) = (origin.read().unwrap().x, origin.read().unwrap().y);
     let (width, height) = (obj.read().unwrap().width, obj.read().unwrap().height);
 
+    let fraction = |v: i64, length: i64| {
+        if length == 0 {
+            0.5
+        } else {
+            (v as f64 / length as f64).clamp(0.0, 1.0)
+        }
+    };
+
     match *edge.read().unwrap() {
-        FromEdge::Top(_) | FromEdge::Bottom(_) => width as f64 / (x + width - obj_x) as f64,
-        FromEdge::Left(_) | FromEdge::Right(_) => height as f64 / (y + height - obj_y) as f64,
+        FromEdge::Top(_) | FromEdge::Bottom(_) => fraction(x - obj_x, width),
+        FromEdge::Left(_) | FromEdge::Right(_) => fraction(y - obj_y, height),
     }
 }
 
@@ -413,6 +662,10 @@ mod tests {
     use tracy_client::Client;
 
     use crate::domain::DomainBuilder;
+    use crate::v2::merlin::{
+        geometry::anchor_location,
+        types::{Edge, GlyphEnum},
+    };
 
     #[test]
     fn test_from_drawing() {
@@ -427,6 +680,125 @@ mod tests {
 
         dbg!(&drawing.merlin());
     }
+
+    #[test]
+    fn test_from_sarzak() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let domain = DomainBuilder::new()
+            .cuckoo_model("models/sarzak.json")
+            .unwrap()
+            .build_v2()
+            .unwrap();
+
+        let sarzak = domain.sarzak();
+        let merlin = domain.merlin();
+
+        // One line, and one name, per relationship.
+        assert_eq!(
+            merlin.iter_line().count(),
+            sarzak.iter_relationship().count()
+        );
+        assert_eq!(
+            merlin.iter_relationship_name().count(),
+            sarzak.iter_relationship().count()
+        );
+        for line in merlin.iter_line() {
+            assert!(sarzak
+                .exhume_relationship(&line.read().unwrap().relationship)
+                .is_some());
+        }
+
+        let isas = sarzak.iter_isa().count();
+        let subtypes = sarzak.iter_subtype().count();
+        let binaries = sarzak.iter_binary().count();
+        let associatives = sarzak.iter_associative().count();
+        assert!(isas > 0);
+        assert!(associatives > 0);
+
+        let glyphs = merlin
+            .iter_glyph()
+            .map(|g| g.read().unwrap().subtype.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            glyphs
+                .iter()
+                .filter(|g| matches!(g, GlyphEnum::ZSuper(_)))
+                .count(),
+            isas
+        );
+        assert_eq!(
+            glyphs
+                .iter()
+                .filter(|g| matches!(g, GlyphEnum::Sub(_)))
+                .count(),
+            subtypes
+        );
+
+        // Every end of every line is anchored to a box.
+        assert_eq!(
+            merlin.iter_anchor().count(),
+            2 * binaries + isas + subtypes + 3 * associatives
+        );
+        assert_eq!(merlin.iter_glyph().count(), merlin.iter_anchor().count());
+
+        // Every non-empty description becomes a phrase.
+        let phrases = sarzak
+            .iter_referrer()
+            .filter(|r| !r.read().unwrap().description.is_empty())
+            .count()
+            + sarzak
+                .iter_referent()
+                .filter(|r| !r.read().unwrap().description.is_empty())
+                .count()
+            + sarzak
+                .iter_associative_referent()
+                .filter(|r| !r.read().unwrap().description.is_empty())
+                .count();
+        assert!(phrases > 0);
+        assert_eq!(merlin.iter_relationship_phrase().count(), phrases);
+
+        // Each subtype's anchor is on the box for that subtype.
+        for anchor in merlin.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            let glyph = anchor.r10_glyph(merlin)[0].read().unwrap().subtype.clone();
+            if !matches!(glyph, GlyphEnum::Sub(_)) {
+                continue;
+            }
+
+            let object = anchor.r3_x_box(merlin)[0].read().unwrap().object;
+            let line = &anchor.r3_line(merlin)[0];
+            let isa = sarzak
+                .exhume_isa(&line.read().unwrap().relationship)
+                .unwrap();
+            let subtypes = isa.read().unwrap().r27_subtype(sarzak);
+            assert!(subtypes
+                .iter()
+                .any(|sub| sub.read().unwrap().obj_id == object));
+        }
+
+        // Anchors are where their offset says they are, and the line leaves
+        // the box straight out from there.
+        for anchor in merlin.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            assert!((0.0..=1.0).contains(&anchor.offset));
+
+            let point = &anchor.r6_point(merlin)[0];
+            let (x, y) = {
+                let point = point.read().unwrap();
+                (point.x, point.y)
+            };
+            assert_eq!(anchor_location(&anchor, merlin), (x, y));
+
+            let (ox, oy) = match *anchor.r9_edge(merlin)[0].read().unwrap() {
+                Edge::Top(_) => (x, y - 40),
+                Edge::Bottom(_) => (x, y + 40),
+                Edge::Left(_) => (x - 40, y),
+                Edge::Right(_) => (x + 40, y),
+            };
+            assert_eq!((anchor.x_offset, anchor.y_offset), (ox, oy));
+        }
+    }
 }
 
 // {"magic":"","directive":{"End":{"directive":"ignore-gen"}}}
@@ -0,0 +1,683 @@
+//! Diagram Geometry
+//!
+//! Geometric queries over the merlin store, for editors and the like: where
+//! things are, what's under the mouse, and what's inside of a selection
+//! rectangle.
+//!
+//! The free functions compute positions from the model. An [`Anchor`]'s
+//! `offset` is the fraction of the way along it's edge, from the left or top,
+//! at which the line attaches. It's `x_offset` and `y_offset` are the absolute
+//! position of the end of the leader, just outside of the box.
+//!
+//! [`SpatialIndex`] buckets boxes, line segments, and relationship text into a
+//! uniform grid, so that hit testing doesn't have to look at everything. The
+//! store doesn't tell anyone when it changes, so the index remembers where it
+//! put each thing, and [`SpatialIndex::refresh`] moves whatever has moved since.
+//! Queries always check the candidates against the store, so a stale index
+//! never returns something that isn't there.
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use crate::v2::merlin::{
+    store::ObjectStore as MerlinStore,
+    types::{
+        Anchor, Edge, Line, LineSegment, Point, PointEnum, RelationshipName, XBox, BOTTOM, LEFT,
+        RIGHT, TOP,
+    },
+};
+
+/// Size of a spatial index grid cell
+const CELL: i64 = 200;
+/// How close a point needs to be to a line to hit it
+const TOLERANCE: f64 = 4.0;
+/// Approximate dimensions of a character of relationship text
+const CHAR_WIDTH: i64 = 7;
+const TEXT_HEIGHT: i64 = 14;
+
+/// An axis aligned rectangle
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Rect {
+    pub fn new(x: i64, y: i64, width: i64, height: i64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The smallest rectangle containing both points
+    ///
+    pub fn from_points((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> Self {
+        Self::new(x1.min(x2), y1.min(y2), (x1 - x2).abs(), (y1 - y2).abs())
+    }
+
+    pub fn right(&self) -> i64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> i64 {
+        self.y + self.height
+    }
+
+    pub fn center(&self) -> (i64, i64) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    /// Is the point inside, or on the edge of, the rectangle?
+    ///
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= self.x && x <= self.right() && y >= self.y && y <= self.bottom()
+    }
+
+    /// Do the rectangles overlap, or touch?
+    ///
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x <= other.right()
+            && other.x <= self.right()
+            && self.y <= other.bottom()
+            && other.y <= self.bottom()
+    }
+
+    pub fn union(&self, other: &Rect) -> Rect {
+        let (x, y) = (self.x.min(other.x), self.y.min(other.y));
+        Rect::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    /// Grow the rectangle by `by` in every direction
+    ///
+    pub fn inflate(&self, by: i64) -> Rect {
+        Rect::new(
+            self.x - by,
+            self.y - by,
+            self.width + 2 * by,
+            self.height + 2 * by,
+        )
+    }
+}
+
+impl From<&XBox> for Rect {
+    fn from(x_box: &XBox) -> Self {
+        Rect::new(x_box.x, x_box.y, x_box.width, x_box.height)
+    }
+}
+
+/// Something in the diagram
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Hit {
+    XBox(Uuid),
+    Line(Uuid),
+    RelationshipName(Uuid),
+    RelationshipPhrase(Uuid),
+}
+
+/// The bounding box of the whole diagram
+///
+/// Returns `None` if there's nothing in the store to draw.
+pub fn bounds(store: &MerlinStore) -> Option<Rect> {
+    let boxes = store.iter_x_box().map(|b| Rect::from(&*b.read().unwrap()));
+    let points = store.iter_point().map(|p| {
+        let p = p.read().unwrap();
+        Rect::new(p.x, p.y, 0, 0)
+    });
+    let names = store
+        .iter_relationship_name()
+        .map(|n| name_rect(&n.read().unwrap(), store));
+    let phrases = store.iter_relationship_phrase().map(|p| {
+        let p = p.read().unwrap();
+        text_rect(&p.text, p.x, p.y)
+    });
+
+    boxes
+        .chain(points)
+        .chain(names)
+        .chain(phrases)
+        .reduce(|a, b| a.union(&b))
+}
+
+/// The absolute position at which an anchor attaches to it's box
+///
+pub fn anchor_location(anchor: &Anchor, store: &MerlinStore) -> (i64, i64) {
+    let x_box = &anchor.r3_x_box(store)[0];
+    let x_box = x_box.read().unwrap();
+    let edge = *anchor.r9_edge(store)[0].read().unwrap();
+
+    position_on_edge(&x_box, &edge, anchor.offset)
+}
+
+/// The position `offset` of the way along an edge of a box
+///
+/// The position is rounded, rather than truncated, so that an offset computed
+/// from a position gets the same position back.
+pub fn position_on_edge(x_box: &XBox, edge: &Edge, offset: f64) -> (i64, i64) {
+    let along = |length: i64| (length as f64 * offset).round() as i64;
+
+    match edge {
+        Edge::Top(_) => (x_box.x + along(x_box.width), x_box.y),
+        Edge::Bottom(_) => (x_box.x + along(x_box.width), x_box.y + x_box.height),
+        Edge::Left(_) => (x_box.x, x_box.y + along(x_box.height)),
+        Edge::Right(_) => (x_box.x + x_box.width, x_box.y + along(x_box.height)),
+    }
+}
+
+/// The nearest place on the boundary of a box to attach an anchor
+///
+/// Returns the edge, the offset along it, and the position.
+pub fn nearest_on_edge(x_box: &XBox, x: i64, y: i64) -> (Edge, f64, (i64, i64)) {
+    let clamp = |v: i64, low: i64, high: i64| v.max(low).min(high);
+    let (left, top) = (x_box.x, x_box.y);
+    let (right, bottom) = (left + x_box.width, top + x_box.height);
+    let (cx, cy) = (clamp(x, left, right), clamp(y, top, bottom));
+
+    let candidates = [
+        (Edge::Top(TOP), (cx, top)),
+        (Edge::Bottom(BOTTOM), (cx, bottom)),
+        (Edge::Left(LEFT), (left, cy)),
+        (Edge::Right(RIGHT), (right, cy)),
+    ];
+    let (edge, (px, py)) = candidates
+        .into_iter()
+        .min_by_key(|(_, (px, py))| (px - x).pow(2) + (py - y).pow(2))
+        .unwrap();
+
+    let fraction = |v: i64, length: i64| {
+        if length == 0 {
+            0.5
+        } else {
+            v as f64 / length as f64
+        }
+    };
+    let offset = match edge {
+        Edge::Top(_) | Edge::Bottom(_) => fraction(px - left, x_box.width),
+        Edge::Left(_) | Edge::Right(_) => fraction(py - top, x_box.height),
+    };
+
+    (edge, offset, (px, py))
+}
+
+/// The segments that make up a line
+///
+/// A line has a segment for each leg, but the generated `r4_line_segment`
+/// only ever returns the first one.
+pub fn line_segments(line: &Line, store: &MerlinStore) -> Vec<Arc<RwLock<LineSegment>>> {
+    store
+        .iter_line_segment()
+        .filter(|segment| segment.read().unwrap().line == line.id)
+        .collect()
+}
+
+/// The points of a segment, in drawing order
+///
+/// The order of the points isn't captured by the model. Anchors go at the
+/// ends, and we walk from the first one, nearest point first.
+pub fn segment_points(segment: &Arc<RwLock<LineSegment>>, store: &MerlinStore) -> Vec<(i64, i64)> {
+    let points = segment
+        .read()
+        .unwrap()
+        .r5_line_segment_point(store)
+        .iter()
+        .map(|lsp| tagged_point(&lsp.read().unwrap().r5_point(store)[0]))
+        .collect();
+
+    order_points(points)
+}
+
+/// The points of every segment, in drawing order
+///
+/// This walks the line segment points once, rather than once per segment, as
+/// [`segment_points`] would.
+fn all_segment_points(store: &MerlinStore) -> HashMap<Uuid, Vec<(i64, i64)>> {
+    let mut points: HashMap<Uuid, Vec<_>> = HashMap::new();
+    for lsp in store.iter_line_segment_point() {
+        let lsp = lsp.read().unwrap();
+        if let Some(point) = store.exhume_point(&lsp.point) {
+            points
+                .entry(lsp.segment)
+                .or_default()
+                .push(tagged_point(&point));
+        }
+    }
+
+    points
+        .into_iter()
+        .map(|(segment, points)| (segment, order_points(points)))
+        .collect()
+}
+
+/// A point, and whether or not it's an anchor
+///
+fn tagged_point(point: &Arc<RwLock<Point>>) -> (bool, (i64, i64)) {
+    let point = point.read().unwrap();
+    (
+        matches!(point.subtype, PointEnum::Anchor(_)),
+        (point.x, point.y),
+    )
+}
+
+fn order_points(mut points: Vec<(bool, (i64, i64))>) -> Vec<(i64, i64)> {
+    // Anchors sort to the front.
+    points.sort_by_key(|(anchor, xy)| (!anchor, *xy));
+
+    let mut result = Vec::with_capacity(points.len());
+    if points.is_empty() {
+        return result;
+    }
+
+    let (_, mut current) = points.remove(0);
+    result.push(current);
+    while !points.is_empty() {
+        let (n, _) = points
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, (x, y)))| {
+                (x - current.0) * (x - current.0) + (y - current.1) * (y - current.1)
+            })
+            .unwrap();
+        let (_, next) = points.remove(n);
+        result.push(next);
+        current = next;
+    }
+
+    result
+}
+
+/// Find the point `fraction` of the way along a polyline
+///
+pub fn point_along(points: &[(i64, i64)], fraction: f64) -> (f64, f64) {
+    let lengths = points
+        .windows(2)
+        .map(|w| {
+            let (dx, dy) = ((w[1].0 - w[0].0) as f64, (w[1].1 - w[0].1) as f64);
+            (dx * dx + dy * dy).sqrt()
+        })
+        .collect::<Vec<_>>();
+    let mut remaining = lengths.iter().sum::<f64>() * fraction;
+
+    for (w, length) in points.windows(2).zip(lengths) {
+        if remaining <= length && length > 0.0 {
+            let t = remaining / length;
+            return (
+                w[0].0 as f64 + (w[1].0 - w[0].0) as f64 * t,
+                w[0].1 as f64 + (w[1].1 - w[0].1) as f64 * t,
+            );
+        }
+        remaining -= length;
+    }
+
+    points
+        .last()
+        .map(|(x, y)| (*x as f64, *y as f64))
+        .unwrap_or_default()
+}
+
+/// The absolute position of a relationship name
+///
+/// The name is positioned relative to it's bisection.
+pub fn name_position(name: &RelationshipName, store: &MerlinStore) -> (i64, i64) {
+    let bisection = &name.r15_bisection(store)[0];
+    let bisection = bisection.read().unwrap();
+    let segment = &bisection.r14_line_segment(store)[0];
+    let (x, y) = point_along(&segment_points(segment, store), bisection.offset);
+
+    (x as i64 + name.x, y as i64 + name.y)
+}
+
+fn name_rect(name: &RelationshipName, store: &MerlinStore) -> Rect {
+    let (x, y) = name_position(name, store);
+    text_rect(&name.text, x, y)
+}
+
+/// [`name_rect`], with the segment points already in hand
+///
+fn name_rect_among(
+    name: &RelationshipName,
+    points: &HashMap<Uuid, Vec<(i64, i64)>>,
+    store: &MerlinStore,
+) -> Rect {
+    let bisection = &name.r15_bisection(store)[0];
+    let bisection = bisection.read().unwrap();
+    let (x, y) = points
+        .get(&bisection.segment)
+        .map(|points| point_along(points, bisection.offset))
+        .unwrap_or_default();
+
+    text_rect(&name.text, x as i64 + name.x, y as i64 + name.y)
+}
+
+/// Text is centered on `x`, with it's baseline at `y`
+///
+fn text_rect(text: &str, x: i64, y: i64) -> Rect {
+    let width = text.chars().count() as i64 * CHAR_WIDTH;
+    Rect::new(x - width / 2, y - TEXT_HEIGHT, width, TEXT_HEIGHT)
+}
+
+fn distance_to_segment((px, py): (i64, i64), (ax, ay): (i64, i64), (bx, by): (i64, i64)) -> f64 {
+    let (px, py, ax, ay, bx, by) = (
+        px as f64, py as f64, ax as f64, ay as f64, bx as f64, by as f64,
+    );
+    let (dx, dy) = (bx - ax, by - ay);
+    let length = dx * dx + dy * dy;
+    let t = if length == 0.0 {
+        0.0
+    } else {
+        (((px - ax) * dx + (py - ay) * dy) / length).clamp(0.0, 1.0)
+    };
+    let (cx, cy) = (ax + t * dx, ay + t * dy);
+
+    ((px - cx).powi(2) + (py - cy).powi(2)).sqrt()
+}
+
+/// The things that the index keeps track of
+///
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+enum Item {
+    XBox(Uuid),
+    Segment(Uuid),
+    Name(Uuid),
+    Phrase(Uuid),
+}
+
+/// A grid based spatial index over a merlin store
+///
+/// Call [`refresh`][Self::refresh] after changing the store.
+#[derive(Clone, Debug, Default)]
+pub struct SpatialIndex {
+    cells: HashMap<(i64, i64), HashSet<Item>>,
+    items: HashMap<Item, Rect>,
+}
+
+impl SpatialIndex {
+    /// Index everything in the store
+    ///
+    pub fn new(store: &MerlinStore) -> Self {
+        let mut index = Self::default();
+        index.refresh(store);
+
+        index
+    }
+
+    /// Bring the index up to date with the store
+    ///
+    /// Only the things that were added, removed, or moved are re-indexed.
+    /// Returns `true` if anything changed.
+    pub fn refresh(&mut self, store: &MerlinStore) -> bool {
+        let current = current(store);
+        let mut changed = false;
+
+        let gone = self
+            .items
+            .keys()
+            .filter(|item| !current.contains_key(item))
+            .cloned()
+            .collect::<Vec<_>>();
+        for item in gone {
+            self.remove(&item);
+            changed = true;
+        }
+
+        for (item, rect) in current {
+            if self.items.get(&item) != Some(&rect) {
+                self.remove(&item);
+                self.insert(item, rect);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// The topmost thing under the point
+    ///
+    /// Text is above lines, which are above boxes.
+    pub fn hit(&self, x: i64, y: i64, store: &MerlinStore) -> Option<Hit> {
+        let mut hits = self.hits(x, y, store);
+        hits.sort_by_key(|hit| match hit {
+            Hit::RelationshipName(_) | Hit::RelationshipPhrase(_) => 0,
+            Hit::Line(_) => 1,
+            Hit::XBox(_) => 2,
+        });
+
+        hits.into_iter().next()
+    }
+
+    /// Everything under the point
+    ///
+    pub fn hits(&self, x: i64, y: i64, store: &MerlinStore) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let candidates = match self.cells.get(&cell(x, y)) {
+            Some(candidates) => candidates,
+            None => return hits,
+        };
+
+        for item in candidates {
+            let hit = match *item {
+                Item::XBox(id) => store.exhume_x_box(&id).and_then(|b| {
+                    Rect::from(&*b.read().unwrap())
+                        .contains(x, y)
+                        .then_some(Hit::XBox(id))
+                }),
+                Item::Segment(id) => store.exhume_line_segment(&id).and_then(|s| {
+                    let points = segment_points(&s, store);
+                    points
+                        .windows(2)
+                        .any(|w| distance_to_segment((x, y), w[0], w[1]) <= TOLERANCE)
+                        .then(|| Hit::Line(s.read().unwrap().line))
+                }),
+                Item::Name(id) => store.exhume_relationship_name(&id).and_then(|n| {
+                    name_rect(&n.read().unwrap(), store)
+                        .contains(x, y)
+                        .then_some(Hit::RelationshipName(id))
+                }),
+                Item::Phrase(id) => store.exhume_relationship_phrase(&id).and_then(|p| {
+                    let p = p.read().unwrap();
+                    text_rect(&p.text, p.x, p.y)
+                        .contains(x, y)
+                        .then_some(Hit::RelationshipPhrase(id))
+                }),
+            };
+
+            if let Some(hit) = hit {
+                if !hits.contains(&hit) {
+                    hits.push(hit);
+                }
+            }
+        }
+
+        hits
+    }
+
+    /// The boxes that intersect the rectangle
+    ///
+    pub fn boxes_in(&self, rect: &Rect, store: &MerlinStore) -> Vec<Uuid> {
+        let mut boxes = cells(rect)
+            .filter_map(|c| self.cells.get(&c))
+            .flatten()
+            .filter_map(|item| match item {
+                Item::XBox(id) => Some(*id),
+                _ => None,
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|id| {
+                store
+                    .exhume_x_box(id)
+                    .map(|b| Rect::from(&*b.read().unwrap()).intersects(rect))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+        boxes.sort();
+
+        boxes
+    }
+
+    fn insert(&mut self, item: Item, rect: Rect) {
+        for c in cells(&rect) {
+            self.cells.entry(c).or_default().insert(item);
+        }
+        self.items.insert(item, rect);
+    }
+
+    fn remove(&mut self, item: &Item) {
+        if let Some(rect) = self.items.remove(item) {
+            for c in cells(&rect) {
+                if let Some(cell) = self.cells.get_mut(&c) {
+                    cell.remove(item);
+                    if cell.is_empty() {
+                        self.cells.remove(&c);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The extents of everything in the store, as the index sees them
+///
+fn current(store: &MerlinStore) -> HashMap<Item, Rect> {
+    let mut items = HashMap::new();
+    let slop = TOLERANCE.ceil() as i64;
+
+    for x_box in store.iter_x_box() {
+        let x_box = x_box.read().unwrap();
+        items.insert(Item::XBox(x_box.id), Rect::from(&*x_box));
+    }
+    let points = all_segment_points(store);
+    for segment in store.iter_line_segment() {
+        let segment = segment.read().unwrap();
+        let points = points
+            .get(&segment.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if let Some(first) = points.first() {
+            let rect = points
+                .iter()
+                .map(|p| Rect::new(p.0, p.1, 0, 0))
+                .fold(Rect::new(first.0, first.1, 0, 0), |a, b| a.union(&b));
+            items.insert(Item::Segment(segment.id), rect.inflate(slop));
+        }
+    }
+    for name in store.iter_relationship_name() {
+        let name = name.read().unwrap();
+        items.insert(Item::Name(name.id), name_rect_among(&name, &points, store));
+    }
+    for phrase in store.iter_relationship_phrase() {
+        let phrase = phrase.read().unwrap();
+        items.insert(
+            Item::Phrase(phrase.id),
+            text_rect(&phrase.text, phrase.x, phrase.y),
+        );
+    }
+
+    items
+}
+
+fn cell(x: i64, y: i64) -> (i64, i64) {
+    (x.div_euclid(CELL), y.div_euclid(CELL))
+}
+
+fn cells(rect: &Rect) -> impl Iterator<Item = (i64, i64)> {
+    let (x0, y0) = cell(rect.x, rect.y);
+    let (x1, y1) = cell(rect.right(), rect.bottom());
+
+    (x0..=x1).flat_map(move |x| (y0..=y1).map(move |y| (x, y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::{ModelBuilder, Multiplicity::*},
+        merlin::route::move_box,
+    };
+
+    #[test]
+    fn test_spatial_index() {
+        let mut domain = ModelBuilder::new("pets", "")
+            .object("Dog")
+            .object("Owner")
+            .binary(1, "Dog", Many, "Owner", One, "is owned by")
+            .build()
+            .unwrap();
+        let store = domain.merlin_mut();
+        let mut index = SpatialIndex::new(store);
+
+        let x_box = store.iter_x_box().next().unwrap();
+        let (id, rect) = {
+            let x_box = x_box.read().unwrap();
+            (x_box.id, Rect::from(&*x_box))
+        };
+        let (cx, cy) = rect.center();
+        assert_eq!(index.hit(cx, cy, store), Some(Hit::XBox(id)));
+        assert_eq!(index.hit(-1000, -1000, store), None);
+
+        let line = store.iter_line().next().unwrap();
+        let line = line.read().unwrap().id;
+        let segment = store.iter_line_segment().next().unwrap();
+        let points = segment_points(&segment, store);
+        let (x, y) = point_along(&points, 0.4);
+        assert_eq!(index.hit(x as i64, y as i64, store), Some(Hit::Line(line)));
+
+        let name = store.iter_relationship_name().next().unwrap();
+        let (nx, ny) = name_position(&name.read().unwrap(), store);
+        assert_eq!(
+            index.hit(nx, ny - 2, store),
+            Some(Hit::RelationshipName(name.read().unwrap().id))
+        );
+
+        // Indexing everything at once agrees with looking things up one by one.
+        let points = all_segment_points(store);
+        for segment in store.iter_line_segment() {
+            let segment = segment.read().unwrap();
+            assert_eq!(
+                points.get(&segment.id).cloned().unwrap_or_default(),
+                segment_points(&store.exhume_line_segment(&segment.id).unwrap(), store)
+            );
+        }
+        for name in store.iter_relationship_name() {
+            let name = name.read().unwrap();
+            assert_eq!(index.items[&Item::Name(name.id)], name_rect(&name, store));
+        }
+
+        let all = bounds(store).unwrap();
+        assert!(all.intersects(&rect));
+        assert_eq!(index.boxes_in(&all, store).len(), 2);
+        assert_eq!(index.boxes_in(&rect, store), vec![id]);
+
+        // Anchors are where their offset says they are.
+        for anchor in store.iter_anchor() {
+            let anchor = anchor.read().unwrap();
+            let point = &anchor.r6_point(store)[0];
+            let point = point.read().unwrap();
+            assert_eq!(anchor_location(&anchor, store), (point.x, point.y));
+        }
+
+        // Follow a box when it moves.
+        move_box(&id, rect.x + 1000, rect.y + 1000, store);
+        assert!(!index.hits(cx, cy, store).contains(&Hit::XBox(id)));
+        assert!(index.refresh(store));
+        assert_eq!(index.hit(cx + 1000, cy + 1000, store), Some(Hit::XBox(id)));
+        assert!(!index.refresh(store));
+
+        let (edge, offset, (px, py)) = nearest_on_edge(&x_box.read().unwrap(), cx + 1000, -50);
+        assert_eq!(edge, Edge::Top(TOP));
+        assert_eq!(offset, 0.5);
+        assert_eq!((px, py), (cx + 1000, rect.y + 1000));
+    }
+}
@@ -0,0 +1,623 @@
+//! Orthogonal Line Routing
+//!
+//! Lines start out straight: a single [`LineSegment`] between each pair of
+//! points. This module replaces them with routes made of horizontal and
+//! vertical segments that go around the boxes, joined by `Inflection` points.
+//!
+//! A line is routed in pieces, which we call branches. A branch runs between
+//! two points that aren't inflections, e.g., from an anchor on one box to an
+//! anchor on another, or from the associative object's anchor to the bisection
+//! point on the line that it hangs off of. Routing a branch replaces all of
+//! it's segments and inflections. Any [`Bisection`] on the old segments is
+//! moved to the longest of the new ones.
+//!
+//! The route itself is the cheapest path through a sparse grid, built from
+//! the edges of the boxes, that doesn't cross any of them. Bends cost extra, so
+//! routes are as straight as they can be. Lines leave and enter boxes
+//! perpendicular to the edge to which they are anchored.
+//!
+//! [`move_box`] moves a box along with it's anchors, and then re-routes the
+//! lines attached to it.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use crate::v2::merlin::{
+    store::ObjectStore as MerlinStore,
+    types::{Bisection, Edge, LineSegment, LineSegmentPoint, Point, PointEnum},
+};
+
+/// Distance between a box and a line running alongside it
+const CLEARANCE: i64 = 20;
+/// The cost of a bend, in terms of line length
+const BEND: i64 = 60;
+
+/// Route every line in the store
+///
+pub fn route(store: &mut MerlinStore) {
+    let lines = store
+        .iter_line()
+        .map(|l| l.read().unwrap().id)
+        .collect::<Vec<_>>();
+
+    for line in lines {
+        route_line(&line, store);
+    }
+}
+
+/// Route a single line
+///
+pub fn route_line(line: &Uuid, store: &mut MerlinStore) {
+    let mut branches = branches(line, store);
+
+    // Branches that end at a bisection point go last, so that the point is
+    // where it belongs before we route to it.
+    branches
+        .sort_by_key(|b| is_bisection(&b.start, store) as u8 + is_bisection(&b.end, store) as u8);
+
+    let router = Router::new(store);
+    for branch in branches {
+        let from = end(&branch.start, store);
+        let to = end(&branch.end, store);
+        let path = router.path(&from, &to);
+
+        replace(line, &branch, &path, store);
+    }
+}
+
+/// Move a box, and re-route the lines attached to it
+///
+/// The box's anchors, and their relationship phrases, move along with it.
+pub fn move_box(id: &Uuid, x: i64, y: i64, store: &mut MerlinStore) {
+    let x_box = match store.exhume_x_box(id) {
+        Some(x_box) => x_box,
+        None => return,
+    };
+    let (dx, dy) = {
+        let mut x_box = x_box.write().unwrap();
+        let delta = (x - x_box.x, y - x_box.y);
+        x_box.x = x;
+        x_box.y = y;
+        delta
+    };
+
+    let mut lines = HashSet::new();
+    for anchor in x_box.read().unwrap().r3_anchor(store) {
+        let mut anchor = anchor.write().unwrap();
+        anchor.x_offset += dx;
+        anchor.y_offset += dy;
+
+        let point = &anchor.r6_point(store)[0];
+        let mut point = point.write().unwrap();
+        point.x += dx;
+        point.y += dy;
+
+        for phrase in anchor.r13c_relationship_phrase(store) {
+            let mut phrase = phrase.write().unwrap();
+            phrase.x += dx;
+            phrase.y += dy;
+        }
+
+        lines.insert(anchor.line);
+    }
+
+    // Order doesn't matter, but let's be deterministic about it.
+    let mut lines = lines.into_iter().collect::<Vec<_>>();
+    lines.sort();
+    for line in lines {
+        route_line(&line, store);
+    }
+}
+
+/// A run of segments, between two points that aren't inflections
+///
+struct Branch {
+    start: Uuid,
+    end: Uuid,
+    segments: Vec<Uuid>,
+    inflections: Vec<Uuid>,
+}
+
+/// Find the branches of a line
+///
+fn branches(line: &Uuid, store: &MerlinStore) -> Vec<Branch> {
+    let segments = store
+        .iter_line_segment()
+        .filter(|s| s.read().unwrap().line == *line)
+        .map(|s| s.read().unwrap().id)
+        .collect::<HashSet<_>>();
+
+    // The points at either end of each segment, and the segments at each point
+    let mut ends: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut at: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for lsp in store.iter_line_segment_point() {
+        let lsp = lsp.read().unwrap();
+        if segments.contains(&lsp.segment) {
+            ends.entry(lsp.segment).or_default().push(lsp.point);
+            at.entry(lsp.point).or_default().push(lsp.segment);
+        }
+    }
+
+    let inflection = |point: &Uuid| {
+        store
+            .exhume_point(point)
+            .map(|p| matches!(p.read().unwrap().subtype, PointEnum::Inflection(_)))
+            .unwrap_or(false)
+    };
+
+    let mut starts = at.keys().filter(|p| !inflection(p)).collect::<Vec<_>>();
+    starts.sort();
+
+    let mut visited = HashSet::new();
+    let mut branches = Vec::new();
+    for start in starts {
+        for first in &at[start] {
+            if visited.contains(first) {
+                continue;
+            }
+
+            let mut branch = Branch {
+                start: *start,
+                end: *start,
+                segments: Vec::new(),
+                inflections: Vec::new(),
+            };
+            let (mut point, mut segment) = (*start, *first);
+            loop {
+                visited.insert(segment);
+                branch.segments.push(segment);
+
+                let next = ends
+                    .get(&segment)
+                    .and_then(|ends| ends.iter().find(|p| **p != point).cloned());
+                let next = match next {
+                    Some(next) => next,
+                    // A degenerate segment, with fewer than two points.
+                    None => break,
+                };
+                point = next;
+                branch.end = point;
+
+                if !inflection(&point) {
+                    break;
+                }
+                branch.inflections.push(point);
+                match at[&point].iter().find(|s| !visited.contains(*s)) {
+                    Some(s) => segment = *s,
+                    None => break,
+                }
+            }
+
+            if branch.start != branch.end {
+                branches.push(branch);
+            }
+        }
+    }
+
+    branches
+}
+
+fn is_bisection(point: &Uuid, store: &MerlinStore) -> bool {
+    store
+        .exhume_point(point)
+        .map(|p| matches!(p.read().unwrap().subtype, PointEnum::Bisection(_)))
+        .unwrap_or(false)
+}
+
+/// One end of a route
+///
+/// Anchors have a direction, pointing away from their box. Other points don't
+/// care which way the line leaves them.
+struct End {
+    x: i64,
+    y: i64,
+    out: Option<(i64, i64)>,
+}
+
+impl End {
+    /// The point just outside of the box, where the line turns
+    ///
+    fn stub(&self) -> (i64, i64) {
+        match self.out {
+            Some((dx, dy)) => (self.x + dx * CLEARANCE, self.y + dy * CLEARANCE),
+            None => (self.x, self.y),
+        }
+    }
+}
+
+fn end(point: &Uuid, store: &MerlinStore) -> End {
+    let point = store.exhume_point(point).unwrap();
+    let point = point.read().unwrap();
+
+    let out = match point.subtype {
+        PointEnum::Anchor(id) => store.exhume_anchor(&id).map(|anchor| {
+            match *anchor.read().unwrap().r9_edge(store)[0].read().unwrap() {
+                Edge::Top(_) => (0, -1),
+                Edge::Bottom(_) => (0, 1),
+                Edge::Left(_) => (-1, 0),
+                Edge::Right(_) => (1, 0),
+            }
+        }),
+        _ => None,
+    };
+
+    End {
+        x: point.x,
+        y: point.y,
+        out,
+    }
+}
+
+/// Replace the segments of a branch with those of a new path
+///
+fn replace(line: &Uuid, branch: &Branch, path: &[(i64, i64)], store: &mut MerlinStore) {
+    let bisections = store
+        .iter_bisection()
+        .filter(|b| branch.segments.contains(&b.read().unwrap().segment))
+        .collect::<Vec<_>>();
+
+    let doomed = store
+        .iter_line_segment_point()
+        .filter(|lsp| branch.segments.contains(&lsp.read().unwrap().segment))
+        .map(|lsp| lsp.read().unwrap().id)
+        .collect::<Vec<_>>();
+    for lsp in doomed {
+        store.exorcise_line_segment_point(&lsp);
+    }
+    for segment in &branch.segments {
+        store.exorcise_line_segment(segment);
+    }
+    for point in &branch.inflections {
+        store.exorcise_point(point);
+    }
+
+    let line = store.exhume_line(line).unwrap();
+    let mut points = vec![store.exhume_point(&branch.start).unwrap()];
+    for (x, y) in path.iter().skip(1).take(path.len().saturating_sub(2)) {
+        points.push(Point::new_inflection(*x, *y, store));
+    }
+    points.push(store.exhume_point(&branch.end).unwrap());
+
+    let mut longest = (-1, None);
+    for pair in points.windows(2) {
+        let segment = LineSegment::new(&line, store);
+        LineSegmentPoint::new(&segment, &pair[0], store);
+        LineSegmentPoint::new(&segment, &pair[1], store);
+
+        let length = {
+            let (a, b) = (pair[0].read().unwrap(), pair[1].read().unwrap());
+            (a.x - b.x).abs() + (a.y - b.y).abs()
+        };
+        if length > longest.0 {
+            longest = (length, Some((segment, pair[0].clone(), pair[1].clone())));
+        }
+    }
+
+    if let (_, Some((segment, a, b))) = longest {
+        for bisection in bisections {
+            move_bisection(&bisection, &segment, &a, &b, store);
+        }
+    }
+}
+
+/// Put a bisection on a new segment, and move it's point, if it has one
+///
+fn move_bisection(
+    bisection: &Arc<RwLock<Bisection>>,
+    segment: &Arc<RwLock<LineSegment>>,
+    a: &Arc<RwLock<Point>>,
+    b: &Arc<RwLock<Point>>,
+    store: &MerlinStore,
+) {
+    let mut bisection = bisection.write().unwrap();
+    bisection.segment = segment.read().unwrap().id;
+
+    let (x, y) = {
+        let (a, b) = (a.read().unwrap(), b.read().unwrap());
+        (
+            a.x + ((b.x - a.x) as f64 * bisection.offset) as i64,
+            a.y + ((b.y - a.y) as f64 * bisection.offset) as i64,
+        )
+    };
+
+    for point in store.iter_point() {
+        let mut point = point.write().unwrap();
+        if point.subtype == PointEnum::Bisection(bisection.id) {
+            point.x = x;
+            point.y = y;
+        }
+    }
+}
+
+/// Obstacle avoiding router
+///
+/// The obstacles are the boxes, padded by half of the clearance.
+struct Router {
+    obstacles: Vec<(i64, i64, i64, i64)>,
+}
+
+impl Router {
+    fn new(store: &MerlinStore) -> Self {
+        let pad = CLEARANCE / 2;
+        let obstacles = store
+            .iter_x_box()
+            .map(|b| {
+                let b = b.read().unwrap();
+                (
+                    b.x - pad,
+                    b.y - pad,
+                    b.x + b.width + pad,
+                    b.y + b.height + pad,
+                )
+            })
+            .collect();
+
+        Self { obstacles }
+    }
+
+    fn inside(&self, (x, y): (i64, i64)) -> bool {
+        self.obstacles
+            .iter()
+            .any(|&(x0, y0, x1, y1)| x0 < x && x < x1 && y0 < y && y < y1)
+    }
+
+    fn blocked(&self, (ax, ay): (i64, i64), (bx, by): (i64, i64)) -> bool {
+        let (left, right) = (ax.min(bx), ax.max(bx));
+        let (top, bottom) = (ay.min(by), ay.max(by));
+
+        self.obstacles.iter().any(|&(x0, y0, x1, y1)| {
+            if ay == by {
+                y0 < ay && ay < y1 && left < x1 && right > x0
+            } else {
+                x0 < ax && ax < x1 && top < y1 && bottom > y0
+            }
+        })
+    }
+
+    /// Find a path between two ends
+    ///
+    /// The path includes both ends, and has no redundant points. If there's no
+    /// way around the obstacles, the path is just a straight line.
+    fn path(&self, from: &End, to: &End) -> Vec<(i64, i64)> {
+        let (start, goal) = (from.stub(), to.stub());
+
+        let mut xs = vec![start.0, goal.0];
+        let mut ys = vec![start.1, goal.1];
+        for &(x0, y0, x1, y1) in &self.obstacles {
+            let pad = CLEARANCE / 2;
+            xs.extend([x0 - pad, x1 + pad]);
+            ys.extend([y0 - pad, y1 + pad]);
+        }
+        xs.sort();
+        xs.dedup();
+        ys.sort();
+        ys.dedup();
+
+        let (width, height) = (xs.len(), ys.len());
+        let node = |i: usize, j: usize| j * width + i;
+        let position = |n: usize| (xs[n % width], ys[n / width]);
+        let index = |(x, y): (i64, i64)| {
+            let i = xs.binary_search(&x).unwrap();
+            let j = ys.binary_search(&y).unwrap();
+            node(i, j)
+        };
+
+        let valid = (0..width * height)
+            .map(|n| !self.inside(position(n)))
+            .collect::<Vec<_>>();
+
+        // Directions: right, left, down, up. The start has no direction, unless
+        // it's leaving a box.
+        const NONE: usize = 4;
+        let step = |n: usize, dir: usize| -> Option<usize> {
+            let (i, j) = (n % width, n / width);
+            match dir {
+                0 if i + 1 < width => Some(node(i + 1, j)),
+                1 if i > 0 => Some(node(i - 1, j)),
+                2 if j + 1 < height => Some(node(i, j + 1)),
+                3 if j > 0 => Some(node(i, j - 1)),
+                _ => None,
+            }
+        };
+        let direction = |(dx, dy): (i64, i64)| match (dx.signum(), dy.signum()) {
+            (1, _) => 0,
+            (-1, _) => 1,
+            (_, 1) => 2,
+            _ => 3,
+        };
+
+        let first = from.out.map(direction).unwrap_or(NONE);
+        // The direction of the last leg, from the stub to the box
+        let last = to.out.map(|(dx, dy)| direction((-dx, -dy)));
+
+        let (start, goal) = (index(start), index(goal));
+        let mut cost = vec![i64::MAX; width * height * 5];
+        let mut prev = vec![usize::MAX; width * height * 5];
+        let mut heap = BinaryHeap::new();
+
+        cost[start * 5 + first] = 0;
+        heap.push(Reverse((0, start * 5 + first)));
+        while let Some(Reverse((c, state))) = heap.pop() {
+            if c > cost[state] {
+                continue;
+            }
+            let (n, dir) = (state / 5, state % 5);
+            if n == goal {
+                continue;
+            }
+
+            for next_dir in 0..4 {
+                let next = match step(n, next_dir) {
+                    Some(next) if valid[next] => next,
+                    _ => continue,
+                };
+                let (a, b) = (position(n), position(next));
+                if self.blocked(a, b) {
+                    continue;
+                }
+
+                let mut next_cost = c + (a.0 - b.0).abs() + (a.1 - b.1).abs();
+                if dir != NONE && dir != next_dir {
+                    next_cost += BEND;
+                }
+                // Doubling back is never useful.
+                if dir != NONE && dir ^ 1 == next_dir {
+                    continue;
+                }
+
+                let next_state = next * 5 + next_dir;
+                if next_cost < cost[next_state] {
+                    cost[next_state] = next_cost;
+                    prev[next_state] = state;
+                    heap.push(Reverse((next_cost, next_state)));
+                }
+            }
+        }
+
+        let best = (0..5)
+            .map(|dir| goal * 5 + dir)
+            .filter(|&state| cost[state] != i64::MAX)
+            .min_by_key(|&state| match last {
+                Some(last) if state % 5 != last && state % 5 != NONE => cost[state] + BEND,
+                _ => cost[state],
+            });
+
+        let mut path = vec![(from.x, from.y)];
+        match best {
+            Some(mut state) => {
+                let mut middle = vec![position(state / 5)];
+                while prev[state] != usize::MAX {
+                    state = prev[state];
+                    middle.push(position(state / 5));
+                }
+                middle.reverse();
+                path.extend(middle);
+            }
+            // No way through, so just go straight there.
+            None => {}
+        }
+        path.push((to.x, to.y));
+
+        simplify(path)
+    }
+}
+
+/// Remove repeated points, and points in the middle of a straight run
+///
+fn simplify(path: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    let mut result: Vec<(i64, i64)> = Vec::with_capacity(path.len());
+    for point in path {
+        if result.last() == Some(&point) {
+            continue;
+        }
+        if result.len() >= 2 {
+            let (a, b) = (result[result.len() - 2], result[result.len() - 1]);
+            if (a.0 == b.0 && b.0 == point.0) || (a.1 == b.1 && b.1 == point.1) {
+                result.pop();
+            }
+        }
+        result.push(point);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::domain::builder::{ModelBuilder, Multiplicity::*};
+
+    fn segments(store: &MerlinStore) -> Vec<((i64, i64), (i64, i64))> {
+        store
+            .iter_line_segment()
+            .map(|s| {
+                let points = s
+                    .read()
+                    .unwrap()
+                    .r5_line_segment_point(store)
+                    .iter()
+                    .map(|lsp| {
+                        let p = lsp.read().unwrap().r5_point(store)[0].clone();
+                        let p = p.read().unwrap();
+                        (p.x, p.y)
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(points.len(), 2);
+                (points[0], points[1])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_route() {
+        let mut domain = ModelBuilder::new("pets", "")
+            .object("Animal")
+            .object("Cat")
+            .object("Dog")
+            .object("Owner")
+            .object("Vet")
+            .object("Visit")
+            .isa(1, "Animal", &["Cat", "Dog"])
+            .binary(2, "Cat", Many, "Owner", One, "is owned by")
+            .associative(3, "Visit", "Dog", Many, "Vet", Many)
+            .build()
+            .unwrap();
+        let store = domain.merlin_mut();
+
+        route(store);
+
+        // Every segment is horizontal or vertical, and doesn't cut through a box.
+        let router = Router::new(store);
+        for (a, b) in segments(store) {
+            assert!(a.0 == b.0 || a.1 == b.1, "{:?} -> {:?}", a, b);
+        }
+        for segment in store.iter_line_segment() {
+            let points = segment
+                .read()
+                .unwrap()
+                .r5_line_segment_point(store)
+                .iter()
+                .map(|lsp| lsp.read().unwrap().r5_point(store)[0].clone())
+                .collect::<Vec<_>>();
+            let (a, b) = (points[0].read().unwrap(), points[1].read().unwrap());
+            // Segments touching an anchor start on the edge of a box.
+            if matches!(a.subtype, PointEnum::Inflection(_))
+                && matches!(b.subtype, PointEnum::Inflection(_))
+            {
+                assert!(!router.blocked((a.x, a.y), (b.x, b.y)));
+            }
+        }
+
+        // Every bisection is on a segment of it's line.
+        for bisection in store.iter_bisection() {
+            assert!(store
+                .exhume_line_segment(&bisection.read().unwrap().segment)
+                .is_some());
+        }
+
+        // Moving a box re-routes it's lines, and they stay orthogonal.
+        let owner = store
+            .iter_x_box()
+            .max_by_key(|b| b.read().unwrap().x)
+            .unwrap();
+        let (id, x, y) = {
+            let owner = owner.read().unwrap();
+            (owner.id, owner.x, owner.y)
+        };
+        move_box(&id, x + 200, y + 300, store);
+
+        for (a, b) in segments(store) {
+            assert!(a.0 == b.0 || a.1 == b.1, "{:?} -> {:?}", a, b);
+        }
+        for anchor in owner.read().unwrap().r3_anchor(store) {
+            let point = &anchor.read().unwrap().r6_point(store)[0];
+            let point = point.read().unwrap();
+            let owner = owner.read().unwrap();
+            assert!(point.x >= owner.x && point.x <= owner.x + owner.width);
+            assert!(point.y >= owner.y && point.y <= owner.y + owner.height);
+        }
+    }
+}
@@ -49,6 +49,10 @@ pub struct ObjectStore {
     point: Arc<RwLock<HashMap<Uuid, Arc<RwLock<Point>>>>>,
     relationship_name: Arc<RwLock<HashMap<Uuid, Arc<RwLock<RelationshipName>>>>>,
     relationship_phrase: Arc<RwLock<HashMap<Uuid, Arc<RwLock<RelationshipPhrase>>>>>,
+    #[serde(skip)]
+    namespace: Option<Uuid>,
+    #[serde(skip)]
+    sequence: u64,
 }
 
 impl ObjectStore {
@@ -65,6 +69,8 @@ impl ObjectStore {
             point: Arc::new(RwLock::new(HashMap::default())),
             relationship_name: Arc::new(RwLock::new(HashMap::default())),
             relationship_phrase: Arc::new(RwLock::new(HashMap::default())),
+            namespace: None,
+            sequence: 0,
         };
 
         // Initialize Singleton Subtypes
@@ -79,6 +85,86 @@ impl ObjectStore {
         store
     }
 
+    /// Inter every instance in `other` into this store
+    ///
+    /// Instances that are in both stores end up as they are in `other`.
+    pub fn merge(&mut self, other: &ObjectStore) {
+        for instance in other.iter_anchor() {
+            self.inter_anchor(instance);
+        }
+        for instance in other.iter_bisection() {
+            self.inter_bisection(instance);
+        }
+        for instance in other.iter_x_box() {
+            self.inter_x_box(instance);
+        }
+        for instance in other.iter_edge() {
+            self.inter_edge(instance);
+        }
+        for instance in other.iter_glyph() {
+            self.inter_glyph(instance);
+        }
+        for instance in other.iter_line() {
+            self.inter_line(instance);
+        }
+        for instance in other.iter_line_segment() {
+            self.inter_line_segment(instance);
+        }
+        for instance in other.iter_line_segment_point() {
+            self.inter_line_segment_point(instance);
+        }
+        for instance in other.iter_point() {
+            self.inter_point(instance);
+        }
+        for instance in other.iter_relationship_name() {
+            self.inter_relationship_name(instance);
+        }
+        for instance in other.iter_relationship_phrase() {
+            self.inter_relationship_phrase(instance);
+        }
+    }
+
+    /// Set the namespace used to derive instance ids
+    ///
+    /// This works like the sarzak
+    /// [`set_namespace`][crate::v2::sarzak::store::ObjectStore::set_namespace].
+    /// Most of the drawing has a natural key: a box is keyed by it's object, a
+    /// line by it's relationship, and a point by the anchor or bisection that it
+    /// locates. The rest, e.g., line segments and glyphs, are keyed by their
+    /// owner and the order in which they were created. Drawing the same model
+    /// the same way twice thus produces the same ids.
+    ///
+    /// The namespace is not persisted.
+    pub fn set_namespace(&mut self, namespace: Option<Uuid>) {
+        self.namespace = namespace;
+        self.sequence = 0;
+    }
+
+    /// Return the namespace used to derive instance ids, if any
+    ///
+    pub fn namespace(&self) -> Option<Uuid> {
+        self.namespace
+    }
+
+    /// Return an id for a new instance with the natural key `key`
+    ///
+    /// The id is random unless the store has a namespace.
+    pub fn id_for(&self, key: &str) -> Uuid {
+        match self.namespace {
+            Some(ref namespace) => Uuid::new_v5(namespace, key.as_bytes()),
+            None => Uuid::new_v4(),
+        }
+    }
+
+    /// Return an id for a new instance that has no natural key of it's own
+    ///
+    /// `key` is qualified by a sequence number, so that successive calls with
+    /// the same key return different ids.
+    pub fn next_id_for(&mut self, key: &str) -> Uuid {
+        self.sequence += 1;
+        self.id_for(&format!("{}{}::", key, self.sequence))
+    }
+
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::merlin-object-store-methods"}}}
     /// Inter (insert) [`Anchor`] into the store.
     ///
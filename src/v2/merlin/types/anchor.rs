@@ -59,7 +59,11 @@ impl Anchor {
         line: &Arc<RwLock<Line>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<Anchor>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!(
+            "anchor::{}::{}::",
+            x_box.read().unwrap().id,
+            line.read().unwrap().id
+        ));
         let new = Arc::new(RwLock::new(Anchor {
             id,
             offset,
@@ -42,7 +42,7 @@ impl Bisection {
         segment: &Arc<RwLock<LineSegment>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<Bisection>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("bisection::{}::", segment.read().unwrap().id));
         let new = Arc::new(RwLock::new(Bisection {
             id,
             offset,
@@ -39,7 +39,7 @@ impl Glyph {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"glyph-struct-impl-new_many"}}}
     /// Inter a new Glyph in the store, and return it's `id`.
     pub fn new_many(line: &Arc<RwLock<Line>>, store: &mut MerlinStore) -> Arc<RwLock<Glyph>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("glyph::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(Glyph {
             line: line.read().unwrap().id,
             subtype: GlyphEnum::Many(MANY),
@@ -55,7 +55,7 @@ impl Glyph {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"glyph-struct-impl-new_one"}}}
     /// Inter a new Glyph in the store, and return it's `id`.
     pub fn new_one(line: &Arc<RwLock<Line>>, store: &mut MerlinStore) -> Arc<RwLock<Glyph>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("glyph::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(Glyph {
             line: line.read().unwrap().id,
             subtype: GlyphEnum::One(ONE),
@@ -71,7 +71,7 @@ impl Glyph {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"glyph-struct-impl-new_sub"}}}
     /// Inter a new Glyph in the store, and return it's `id`.
     pub fn new_sub(line: &Arc<RwLock<Line>>, store: &mut MerlinStore) -> Arc<RwLock<Glyph>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("glyph::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(Glyph {
             line: line.read().unwrap().id,
             subtype: GlyphEnum::Sub(SUB),
@@ -88,7 +88,7 @@ impl Glyph {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"glyph-struct-impl-new_z_super"}}}
     /// Inter a new Glyph in the store, and return it's `id`.
     pub fn new_z_super(line: &Arc<RwLock<Line>>, store: &mut MerlinStore) -> Arc<RwLock<Glyph>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("glyph::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(Glyph {
             line: line.read().unwrap().id,
             subtype: GlyphEnum::ZSuper(Z_SUPER),
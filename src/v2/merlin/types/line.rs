@@ -36,7 +36,7 @@ impl Line {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"line-struct-impl-new"}}}
     /// Inter a new 'Line' in the store, and return it's `id`.
     pub fn new(relationship: &Relationship, store: &mut MerlinStore) -> Arc<RwLock<Line>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("line::{}::", relationship.id()));
         let new = Arc::new(RwLock::new(Line {
             id,
             relationship: relationship.id(),
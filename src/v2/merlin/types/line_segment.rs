@@ -32,7 +32,7 @@ impl LineSegment {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"line_segment-struct-impl-new"}}}
     /// Inter a new 'Line Segment' in the store, and return it's `id`.
     pub fn new(line: &Arc<RwLock<Line>>, store: &mut MerlinStore) -> Arc<RwLock<LineSegment>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for(&format!("line_segment::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(LineSegment {
             id,
             line: line.read().unwrap().id,
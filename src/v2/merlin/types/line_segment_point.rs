@@ -30,7 +30,11 @@ impl LineSegmentPoint {
         point: &Arc<RwLock<Point>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<LineSegmentPoint>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "line_segment_point::{}::{}::",
+            segment.read().unwrap().id,
+            point.read().unwrap().id
+        ));
         let new = Arc::new(RwLock::new(LineSegmentPoint {
             id,
             segment: segment.read().unwrap().id,
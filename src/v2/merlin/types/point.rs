@@ -47,7 +47,7 @@ impl Point {
         subtype: &Arc<RwLock<Anchor>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<Point>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("point::{}::", subtype.read().unwrap().id));
         let new = Arc::new(RwLock::new(Point {
             x: x,
             y: y,
@@ -69,7 +69,7 @@ impl Point {
         subtype: &Arc<RwLock<Bisection>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<Point>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("point::{}::", subtype.read().unwrap().id));
         let new = Arc::new(RwLock::new(Point {
             x: x,
             y: y,
@@ -86,7 +86,7 @@ impl Point {
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"point-struct-impl-new_inflection"}}}
     /// Inter a new Point in the store, and return it's `id`.
     pub fn new_inflection(x: i64, y: i64, store: &mut MerlinStore) -> Arc<RwLock<Point>> {
-        let id = Uuid::new_v4();
+        let id = store.next_id_for("point::inflection::");
         let new = Arc::new(RwLock::new(Point {
             x: x,
             y: y,
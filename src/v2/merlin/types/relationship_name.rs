@@ -36,7 +36,7 @@ impl RelationshipName {
         origin: &Arc<RwLock<Bisection>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<RelationshipName>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("relationship_name::{}::", line.read().unwrap().id));
         let new = Arc::new(RwLock::new(RelationshipName {
             id,
             text,
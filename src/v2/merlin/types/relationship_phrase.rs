@@ -36,7 +36,10 @@ impl RelationshipPhrase {
         origin: &Arc<RwLock<Anchor>>,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<RelationshipPhrase>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "relationship_phrase::{}::",
+            origin.read().unwrap().id
+        ));
         let new = Arc::new(RwLock::new(RelationshipPhrase {
             id,
             text,
@@ -45,7 +45,7 @@ impl XBox {
         object: &Object,
         store: &mut MerlinStore,
     ) -> Arc<RwLock<XBox>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("x_box::{}::", object.id));
         let new = Arc::new(RwLock::new(XBox {
             height,
             id,
@@ -14,3 +14,234 @@ pub const UUID_NS: Uuid = uuid!("daccabb9-eb3a-5cde-ba7c-19a3f22ab649");
 
 pub const MODEL: &[u8] = include_bytes!("../../models/sarzak.bin");
 // pub const MODEL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "sarzak.bin"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        fs,
+        path::PathBuf,
+        sync::{Arc, RwLock},
+    };
+
+    fn scratch(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("sarzak-store-{}-{}", std::process::id(), name))
+    }
+
+    /// A store with one of each of the new types, and the [`Ty`]s that go with them.
+    fn store() -> (ObjectStore, Vec<Arc<RwLock<Ty>>>) {
+        let mut store = ObjectStore::new();
+
+        let enumeration = Enumeration::new("Colors".to_owned(), "Color".to_owned(), &mut store);
+        EnumValue::new("Red".to_owned(), 0, &enumeration, &mut store);
+        EnumValue::new("Green".to_owned(), 1, &enumeration, &mut store);
+        let enumeration = Ty::new_enumeration(&enumeration, &mut store);
+
+        let sized = SizedInteger::new(16, false, &mut store);
+        let sized = Ty::new_sized_integer(&sized, &mut store);
+
+        let list = List::new(&Ty::new_z_string(&store), &mut store);
+        let list = Ty::new_list(&list, &mut store);
+
+        let option = ZOption::new(&sized, &mut store);
+        let option = Ty::new_z_option(&option, &mut store);
+
+        let tys = vec![
+            enumeration,
+            sized,
+            list,
+            option,
+            Ty::new_duration(&store),
+            Ty::new_timestamp(&store),
+        ];
+
+        (store, tys)
+    }
+
+    #[test]
+    fn test_new_ty_variants() {
+        let (store, tys) = store();
+
+        let tys: Vec<Ty> = tys.iter().map(|ty| *ty.read().unwrap()).collect();
+        assert!(matches!(tys[0], Ty::Enumeration(_)));
+        assert!(matches!(tys[1], Ty::SizedInteger(_)));
+        assert!(matches!(tys[2], Ty::List(_)));
+        assert!(matches!(tys[3], Ty::ZOption(_)));
+        assert_eq!(tys[4], Ty::Duration(DURATION));
+        assert_eq!(tys[5], Ty::Timestamp(TIMESTAMP));
+
+        let enumeration = store.exhume_enumeration(&tys[0].id()).unwrap();
+        assert_eq!(enumeration.read().unwrap().r28_enum_value(&store).len(), 2);
+
+        let list = store.exhume_list(&tys[2].id()).unwrap();
+        assert_eq!(
+            *list.read().unwrap().r29_ty(&store)[0].read().unwrap(),
+            Ty::ZString(Z_STRING)
+        );
+
+        let option = store.exhume_z_option(&tys[3].id()).unwrap();
+        assert_eq!(
+            *option.read().unwrap().r30_ty(&store)[0].read().unwrap(),
+            tys[1]
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let (store, tys) = store();
+        let mut other = ObjectStore::new();
+        Object::new("".to_owned(), "D".to_owned(), "Dog".to_owned(), &mut other);
+
+        other.merge(&store);
+        assert!(other.exhume_object_id_by_name("Dog").is_some());
+        assert_eq!(other.iter_enum_value().count(), 2);
+        for ty in &tys {
+            let ty = *ty.read().unwrap();
+            assert_eq!(*other.exhume_ty(&ty.id()).unwrap().read().unwrap(), ty);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (store, tys) = store();
+
+        let path = scratch("round-trip");
+        store.persist(&path).unwrap();
+        let loaded = ObjectStore::load(&path).unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        for ty in &tys {
+            let ty = *ty.read().unwrap();
+            assert_eq!(*loaded.exhume_ty(&ty.id()).unwrap().read().unwrap(), ty);
+        }
+
+        let enumeration = tys[0].read().unwrap().id();
+        assert_eq!(
+            *loaded
+                .exhume_enumeration(&enumeration)
+                .unwrap()
+                .read()
+                .unwrap(),
+            *store
+                .exhume_enumeration(&enumeration)
+                .unwrap()
+                .read()
+                .unwrap()
+        );
+        assert_eq!(loaded.iter_enum_value().count(), 2);
+        for value in store.iter_enum_value() {
+            let value = value.read().unwrap();
+            assert_eq!(
+                *loaded.exhume_enum_value(&value.id).unwrap().read().unwrap(),
+                *value
+            );
+        }
+
+        let sized = tys[1].read().unwrap().id();
+        assert_eq!(
+            *loaded.exhume_sized_integer(&sized).unwrap().read().unwrap(),
+            *store.exhume_sized_integer(&sized).unwrap().read().unwrap()
+        );
+
+        let list = tys[2].read().unwrap().id();
+        assert_eq!(
+            *loaded.exhume_list(&list).unwrap().read().unwrap(),
+            *store.exhume_list(&list).unwrap().read().unwrap()
+        );
+
+        let option = tys[3].read().unwrap().id();
+        assert_eq!(
+            *loaded.exhume_z_option(&option).unwrap().read().unwrap(),
+            *store.exhume_z_option(&option).unwrap().read().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let (store, tys) = store();
+
+        let path = scratch("round-trip.bin");
+        store.persist_bincode(&path).unwrap();
+        let loaded = ObjectStore::load_bincode(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        for ty in &tys {
+            let ty = *ty.read().unwrap();
+            assert_eq!(*loaded.exhume_ty(&ty.id()).unwrap().read().unwrap(), ty);
+        }
+        assert_eq!(loaded.iter_enum_value().count(), 2);
+    }
+
+    #[test]
+    fn test_load_old_layout() {
+        let mut store = ObjectStore::new();
+        Object::new(
+            "An object".to_owned(),
+            "OB".to_owned(),
+            "Object".to_owned(),
+            &mut store,
+        );
+
+        let path = scratch("old-layout");
+        store.persist(&path).unwrap();
+        // Stores persisted before the new types were added don't have these.
+        for dir in [
+            "enum_value",
+            "enumeration",
+            "list",
+            "sized_integer",
+            "z_option",
+        ] {
+            fs::remove_dir_all(path.join("sarzak.json").join(dir)).unwrap();
+        }
+        let loaded = ObjectStore::load(&path).unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        assert!(loaded.exhume_object_id_by_name("Object").is_some());
+        assert_eq!(loaded.iter_enumeration().count(), 0);
+        assert_eq!(loaded.iter_z_option().count(), 0);
+    }
+
+    #[test]
+    fn test_models() {
+        let sarzak = ObjectStore::from_bincode(MODEL).unwrap();
+        for name in [
+            "Object",
+            "Enumeration",
+            "EnumValue",
+            "List",
+            "SizedInteger",
+            "Option",
+            "Duration",
+            "Timestamp",
+        ] {
+            assert!(sarzak.exhume_object_id_by_name(name).is_some(), "{name}");
+        }
+        assert!(sarzak.exhume_ty(&DURATION).is_some());
+        assert!(sarzak.exhume_ty(&TIMESTAMP).is_some());
+
+        // The other domains' models are sarzak stores too.
+        let merlin = ObjectStore::from_bincode(crate::v2::merlin::MODEL).unwrap();
+        assert!(merlin.exhume_object_id_by_name("Point").is_some());
+        assert!(merlin.exhume_ty(&TIMESTAMP).is_some());
+
+        #[cfg(feature = "lu-dog-vec")]
+        {
+            let lu_dog = ObjectStore::from_bincode(crate::v2::lu_dog_vec::MODEL).unwrap();
+            for name in [
+                "Block",
+                "While",
+                "Loop",
+                "Break",
+                "Continue",
+                "Remainder",
+                "ShiftRight",
+                "BitwiseXorAssignment",
+            ] {
+                assert!(lu_dog.exhume_object_id_by_name(name).is_some(), "{name}");
+            }
+            assert!(lu_dog.exhume_ty(&TIMESTAMP).is_some());
+        }
+    }
+}
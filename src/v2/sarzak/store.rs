@@ -16,17 +16,22 @@
 //! * [`Binary`]
 //! * [`Cardinality`]
 //! * [`Conditionality`]
+//! * [`EnumValue`]
+//! * [`Enumeration`]
 //! * [`Event`]
 //! * [`External`]
 //! * [`Isa`]
+//! * [`List`]
 //! * [`Object`]
 //! * [`Referent`]
 //! * [`Referrer`]
 //! * [`Relationship`]
+//! * [`SizedInteger`]
 //! * [`State`]
 //! * [`Subtype`]
 //! * [`Supertype`]
 //! * [`Ty`]
+//! * [`ZOption`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::sarzak-object-store-definition"}}}
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -44,9 +49,10 @@ use uuid::Uuid;
 
 use crate::v2::sarzak::types::{
     AcknowledgedEvent, AnAssociativeReferent, Associative, AssociativeReferent,
-    AssociativeReferrer, Attribute, Binary, Cardinality, Conditionality, Event, External, Isa,
-    Object, Referent, Referrer, Relationship, State, Subtype, Supertype, Ty, BOOLEAN, CONDITIONAL,
-    FLOAT, INTEGER, MANY, ONE, UNCONDITIONAL, Z_STRING, Z_UUID,
+    AssociativeReferrer, Attribute, Binary, Cardinality, Conditionality, EnumValue, Enumeration,
+    Event, External, Isa, List, Object, Referent, Referrer, Relationship, SizedInteger, State,
+    Subtype, Supertype, Ty, ZOption, BOOLEAN, CONDITIONAL, DURATION, FLOAT, INTEGER, MANY, ONE,
+    TIMESTAMP, UNCONDITIONAL, Z_STRING, Z_UUID,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -63,18 +69,25 @@ pub struct ObjectStore {
     binary: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Binary>>, SystemTime)>>>,
     cardinality: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Cardinality>>, SystemTime)>>>,
     conditionality: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Conditionality>>, SystemTime)>>>,
+    enum_value: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<EnumValue>>, SystemTime)>>>,
+    enumeration: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Enumeration>>, SystemTime)>>>,
     event: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Event>>, SystemTime)>>>,
     external: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<External>>, SystemTime)>>>,
     isa: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Isa>>, SystemTime)>>>,
+    list: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<List>>, SystemTime)>>>,
     object: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Object>>, SystemTime)>>>,
     object_id_by_name: Arc<RwLock<HashMap<String, (Uuid, SystemTime)>>>,
     referent: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Referent>>, SystemTime)>>>,
     referrer: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Referrer>>, SystemTime)>>>,
     relationship: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Relationship>>, SystemTime)>>>,
+    sized_integer: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<SizedInteger>>, SystemTime)>>>,
     state: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<State>>, SystemTime)>>>,
     subtype: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Subtype>>, SystemTime)>>>,
     supertype: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Supertype>>, SystemTime)>>>,
+    z_option: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<ZOption>>, SystemTime)>>>,
     ty: Arc<RwLock<HashMap<Uuid, (Arc<RwLock<Ty>>, SystemTime)>>>,
+    #[serde(skip)]
+    namespace: Option<Uuid>,
 }
 
 impl ObjectStore {
@@ -89,18 +102,24 @@ impl ObjectStore {
             binary: Arc::new(RwLock::new(HashMap::default())),
             cardinality: Arc::new(RwLock::new(HashMap::default())),
             conditionality: Arc::new(RwLock::new(HashMap::default())),
+            enum_value: Arc::new(RwLock::new(HashMap::default())),
+            enumeration: Arc::new(RwLock::new(HashMap::default())),
             event: Arc::new(RwLock::new(HashMap::default())),
             external: Arc::new(RwLock::new(HashMap::default())),
             isa: Arc::new(RwLock::new(HashMap::default())),
+            list: Arc::new(RwLock::new(HashMap::default())),
             object: Arc::new(RwLock::new(HashMap::default())),
             object_id_by_name: Arc::new(RwLock::new(HashMap::default())),
             referent: Arc::new(RwLock::new(HashMap::default())),
             referrer: Arc::new(RwLock::new(HashMap::default())),
             relationship: Arc::new(RwLock::new(HashMap::default())),
+            sized_integer: Arc::new(RwLock::new(HashMap::default())),
             state: Arc::new(RwLock::new(HashMap::default())),
             subtype: Arc::new(RwLock::new(HashMap::default())),
             supertype: Arc::new(RwLock::new(HashMap::default())),
+            z_option: Arc::new(RwLock::new(HashMap::default())),
             ty: Arc::new(RwLock::new(HashMap::default())),
+            namespace: None,
         };
 
         // Initialize Singleton Subtypes
@@ -116,14 +135,142 @@ impl ObjectStore {
             UNCONDITIONAL,
         ))));
         store.inter_ty(Arc::new(RwLock::new(Ty::Boolean(BOOLEAN))));
+        store.inter_ty(Arc::new(RwLock::new(Ty::Duration(DURATION))));
         store.inter_ty(Arc::new(RwLock::new(Ty::Float(FLOAT))));
         store.inter_ty(Arc::new(RwLock::new(Ty::Integer(INTEGER))));
+        store.inter_ty(Arc::new(RwLock::new(Ty::Timestamp(TIMESTAMP))));
         store.inter_ty(Arc::new(RwLock::new(Ty::ZString(Z_STRING))));
         store.inter_ty(Arc::new(RwLock::new(Ty::ZUuid(Z_UUID))));
 
         store
     }
 
+    /// Inter every instance in `other` into this store
+    ///
+    /// Instances that are in both stores end up as they are in `other`.
+    pub fn merge(&mut self, other: &ObjectStore) {
+        for instance in other.iter_acknowledged_event() {
+            self.inter_acknowledged_event(instance);
+        }
+        for instance in other.iter_an_associative_referent() {
+            self.inter_an_associative_referent(instance);
+        }
+        for instance in other.iter_associative() {
+            self.inter_associative(instance);
+        }
+        for instance in other.iter_associative_referent() {
+            self.inter_associative_referent(instance);
+        }
+        for instance in other.iter_associative_referrer() {
+            self.inter_associative_referrer(instance);
+        }
+        for instance in other.iter_attribute() {
+            self.inter_attribute(instance);
+        }
+        for instance in other.iter_binary() {
+            self.inter_binary(instance);
+        }
+        for instance in other.iter_cardinality() {
+            self.inter_cardinality(instance);
+        }
+        for instance in other.iter_conditionality() {
+            self.inter_conditionality(instance);
+        }
+        for instance in other.iter_enum_value() {
+            self.inter_enum_value(instance);
+        }
+        for instance in other.iter_enumeration() {
+            self.inter_enumeration(instance);
+        }
+        for instance in other.iter_event() {
+            self.inter_event(instance);
+        }
+        for instance in other.iter_external() {
+            self.inter_external(instance);
+        }
+        for instance in other.iter_isa() {
+            self.inter_isa(instance);
+        }
+        for instance in other.iter_list() {
+            self.inter_list(instance);
+        }
+        for instance in other.iter_object() {
+            self.inter_object(instance);
+        }
+        for instance in other.iter_referent() {
+            self.inter_referent(instance);
+        }
+        for instance in other.iter_referrer() {
+            self.inter_referrer(instance);
+        }
+        for instance in other.iter_relationship() {
+            self.inter_relationship(instance);
+        }
+        for instance in other.iter_sized_integer() {
+            self.inter_sized_integer(instance);
+        }
+        for instance in other.iter_state() {
+            self.inter_state(instance);
+        }
+        for instance in other.iter_subtype() {
+            self.inter_subtype(instance);
+        }
+        for instance in other.iter_supertype() {
+            self.inter_supertype(instance);
+        }
+        for instance in other.iter_z_option() {
+            self.inter_z_option(instance);
+        }
+        for instance in other.iter_ty() {
+            self.inter_ty(instance);
+        }
+    }
+
+    /// Create a store that derives instance ids from `namespace`
+    ///
+    /// See [`set_namespace`][Self::set_namespace].
+    pub fn new_with_namespace(namespace: Uuid) -> Self {
+        let mut store = Self::new();
+        store.namespace = Some(namespace);
+
+        store
+    }
+
+    /// Set the namespace used to derive instance ids
+    ///
+    /// Normally the constructors assign each new instance a random (v4) UUID.
+    /// When a namespace is set they instead derive a v5 UUID from the namespace
+    /// and the instance's natural key: an object's name, an attribute's object
+    /// and name, a relationship's number, etc. Running the same code against a
+    /// new store thus produces the same ids, and re-running it against a store
+    /// that already contains the model simply replaces each instance with an
+    /// identical copy.
+    ///
+    /// Instances without a natural key of their own, e.g., [`Referrer`] and
+    /// [`Supertype`], are re-keyed by the relationship that owns them when the
+    /// relationship is created.
+    ///
+    /// The namespace is not persisted.
+    pub fn set_namespace(&mut self, namespace: Option<Uuid>) {
+        self.namespace = namespace;
+    }
+
+    /// Return the namespace used to derive instance ids, if any
+    ///
+    pub fn namespace(&self) -> Option<Uuid> {
+        self.namespace
+    }
+
+    /// Return an id for a new instance with the natural key `key`
+    ///
+    /// The id is random unless the store has a namespace.
+    pub fn id_for(&self, key: &str) -> Uuid {
+        match self.namespace {
+            Some(ref namespace) => Uuid::new_v5(namespace, key.as_bytes()),
+            None => Uuid::new_v4(),
+        }
+    }
+
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::sarzak-object-store-methods"}}}
     /// Inter (insert) [`AcknowledgedEvent`] into the store.
     ///
@@ -670,6 +817,116 @@ impl ObjectStore {
             .unwrap_or(SystemTime::now())
     }
 
+    /// Inter (insert) [`EnumValue`] into the store.
+    ///
+    pub fn inter_enum_value(&mut self, enum_value: Arc<RwLock<EnumValue>>) {
+        let read = enum_value.read().unwrap();
+        self.enum_value
+            .write()
+            .unwrap()
+            .insert(read.id, (enum_value.clone(), SystemTime::now()));
+    }
+
+    /// Exhume (get) [`EnumValue`] from the store.
+    ///
+    pub fn exhume_enum_value(&self, id: &Uuid) -> Option<Arc<RwLock<EnumValue>>> {
+        self.enum_value
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|enum_value| enum_value.0.clone())
+    }
+
+    /// Exorcise (remove) [`EnumValue`] from the store.
+    ///
+    pub fn exorcise_enum_value(&mut self, id: &Uuid) -> Option<Arc<RwLock<EnumValue>>> {
+        self.enum_value
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|enum_value| enum_value.0.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, EnumValue>`.
+    ///
+    pub fn iter_enum_value(&self) -> impl Iterator<Item = Arc<RwLock<EnumValue>>> + '_ {
+        let values: Vec<Arc<RwLock<EnumValue>>> = self
+            .enum_value
+            .read()
+            .unwrap()
+            .values()
+            .map(|enum_value| enum_value.0.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
+    /// Get the timestamp for EnumValue.
+    ///
+    pub fn enum_value_timestamp(&self, enum_value: &EnumValue) -> SystemTime {
+        self.enum_value
+            .read()
+            .unwrap()
+            .get(&enum_value.id)
+            .map(|enum_value| enum_value.1)
+            .unwrap_or(SystemTime::now())
+    }
+
+    /// Inter (insert) [`Enumeration`] into the store.
+    ///
+    pub fn inter_enumeration(&mut self, enumeration: Arc<RwLock<Enumeration>>) {
+        let read = enumeration.read().unwrap();
+        self.enumeration
+            .write()
+            .unwrap()
+            .insert(read.id, (enumeration.clone(), SystemTime::now()));
+    }
+
+    /// Exhume (get) [`Enumeration`] from the store.
+    ///
+    pub fn exhume_enumeration(&self, id: &Uuid) -> Option<Arc<RwLock<Enumeration>>> {
+        self.enumeration
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|enumeration| enumeration.0.clone())
+    }
+
+    /// Exorcise (remove) [`Enumeration`] from the store.
+    ///
+    pub fn exorcise_enumeration(&mut self, id: &Uuid) -> Option<Arc<RwLock<Enumeration>>> {
+        self.enumeration
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|enumeration| enumeration.0.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, Enumeration>`.
+    ///
+    pub fn iter_enumeration(&self) -> impl Iterator<Item = Arc<RwLock<Enumeration>>> + '_ {
+        let values: Vec<Arc<RwLock<Enumeration>>> = self
+            .enumeration
+            .read()
+            .unwrap()
+            .values()
+            .map(|enumeration| enumeration.0.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
+    /// Get the timestamp for Enumeration.
+    ///
+    pub fn enumeration_timestamp(&self, enumeration: &Enumeration) -> SystemTime {
+        self.enumeration
+            .read()
+            .unwrap()
+            .get(&enumeration.id)
+            .map(|enumeration| enumeration.1)
+            .unwrap_or(SystemTime::now())
+    }
+
     /// Inter (insert) [`Event`] into the store.
     ///
     pub fn inter_event(&mut self, event: Arc<RwLock<Event>>) {
@@ -831,6 +1088,57 @@ impl ObjectStore {
             .unwrap_or(SystemTime::now())
     }
 
+    /// Inter (insert) [`List`] into the store.
+    ///
+    pub fn inter_list(&mut self, list: Arc<RwLock<List>>) {
+        let read = list.read().unwrap();
+        self.list
+            .write()
+            .unwrap()
+            .insert(read.id, (list.clone(), SystemTime::now()));
+    }
+
+    /// Exhume (get) [`List`] from the store.
+    ///
+    pub fn exhume_list(&self, id: &Uuid) -> Option<Arc<RwLock<List>>> {
+        self.list.read().unwrap().get(id).map(|list| list.0.clone())
+    }
+
+    /// Exorcise (remove) [`List`] from the store.
+    ///
+    pub fn exorcise_list(&mut self, id: &Uuid) -> Option<Arc<RwLock<List>>> {
+        self.list
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|list| list.0.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, List>`.
+    ///
+    pub fn iter_list(&self) -> impl Iterator<Item = Arc<RwLock<List>>> + '_ {
+        let values: Vec<Arc<RwLock<List>>> = self
+            .list
+            .read()
+            .unwrap()
+            .values()
+            .map(|list| list.0.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
+    /// Get the timestamp for List.
+    ///
+    pub fn list_timestamp(&self, list: &List) -> SystemTime {
+        self.list
+            .read()
+            .unwrap()
+            .get(&list.id)
+            .map(|list| list.1)
+            .unwrap_or(SystemTime::now())
+    }
+
     /// Inter (insert) [`Object`] into the store.
     ///
     pub fn inter_object(&mut self, object: Arc<RwLock<Object>>) {
@@ -1063,6 +1371,61 @@ impl ObjectStore {
             .unwrap_or(SystemTime::now())
     }
 
+    /// Inter (insert) [`SizedInteger`] into the store.
+    ///
+    pub fn inter_sized_integer(&mut self, sized_integer: Arc<RwLock<SizedInteger>>) {
+        let read = sized_integer.read().unwrap();
+        self.sized_integer
+            .write()
+            .unwrap()
+            .insert(read.id, (sized_integer.clone(), SystemTime::now()));
+    }
+
+    /// Exhume (get) [`SizedInteger`] from the store.
+    ///
+    pub fn exhume_sized_integer(&self, id: &Uuid) -> Option<Arc<RwLock<SizedInteger>>> {
+        self.sized_integer
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|sized_integer| sized_integer.0.clone())
+    }
+
+    /// Exorcise (remove) [`SizedInteger`] from the store.
+    ///
+    pub fn exorcise_sized_integer(&mut self, id: &Uuid) -> Option<Arc<RwLock<SizedInteger>>> {
+        self.sized_integer
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|sized_integer| sized_integer.0.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, SizedInteger>`.
+    ///
+    pub fn iter_sized_integer(&self) -> impl Iterator<Item = Arc<RwLock<SizedInteger>>> + '_ {
+        let values: Vec<Arc<RwLock<SizedInteger>>> = self
+            .sized_integer
+            .read()
+            .unwrap()
+            .values()
+            .map(|sized_integer| sized_integer.0.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
+    /// Get the timestamp for SizedInteger.
+    ///
+    pub fn sized_integer_timestamp(&self, sized_integer: &SizedInteger) -> SystemTime {
+        self.sized_integer
+            .read()
+            .unwrap()
+            .get(&sized_integer.id)
+            .map(|sized_integer| sized_integer.1)
+            .unwrap_or(SystemTime::now())
+    }
+
     /// Inter (insert) [`State`] into the store.
     ///
     pub fn inter_state(&mut self, state: Arc<RwLock<State>>) {
@@ -1228,6 +1591,61 @@ impl ObjectStore {
             .unwrap_or(SystemTime::now())
     }
 
+    /// Inter (insert) [`ZOption`] into the store.
+    ///
+    pub fn inter_z_option(&mut self, z_option: Arc<RwLock<ZOption>>) {
+        let read = z_option.read().unwrap();
+        self.z_option
+            .write()
+            .unwrap()
+            .insert(read.id, (z_option.clone(), SystemTime::now()));
+    }
+
+    /// Exhume (get) [`ZOption`] from the store.
+    ///
+    pub fn exhume_z_option(&self, id: &Uuid) -> Option<Arc<RwLock<ZOption>>> {
+        self.z_option
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|z_option| z_option.0.clone())
+    }
+
+    /// Exorcise (remove) [`ZOption`] from the store.
+    ///
+    pub fn exorcise_z_option(&mut self, id: &Uuid) -> Option<Arc<RwLock<ZOption>>> {
+        self.z_option
+            .write()
+            .unwrap()
+            .remove(id)
+            .map(|z_option| z_option.0.clone())
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, ZOption>`.
+    ///
+    pub fn iter_z_option(&self) -> impl Iterator<Item = Arc<RwLock<ZOption>>> + '_ {
+        let values: Vec<Arc<RwLock<ZOption>>> = self
+            .z_option
+            .read()
+            .unwrap()
+            .values()
+            .map(|z_option| z_option.0.clone())
+            .collect();
+        let len = values.len();
+        (0..len).map(move |i| values[i].clone())
+    }
+
+    /// Get the timestamp for ZOption.
+    ///
+    pub fn z_option_timestamp(&self, z_option: &ZOption) -> SystemTime {
+        self.z_option
+            .read()
+            .unwrap()
+            .get(&z_option.id)
+            .map(|z_option| z_option.1)
+            .unwrap_or(SystemTime::now())
+    }
+
     /// Inter (insert) [`Ty`] into the store.
     ///
     pub fn inter_ty(&mut self, ty: Arc<RwLock<Ty>>) {
@@ -1656,6 +2074,80 @@ impl ObjectStore {
             }
         }
 
+        // Persist Enum Value.
+        {
+            let path = path.join("enum_value");
+            fs::create_dir_all(&path)?;
+            for enum_value_tuple in self.enum_value.read().unwrap().values() {
+                let path = path.join(format!("{}.json", enum_value_tuple.0.read().unwrap().id));
+                if path.exists() {
+                    let file = fs::File::open(&path)?;
+                    let reader = io::BufReader::new(file);
+                    let on_disk: (Arc<RwLock<EnumValue>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    if on_disk.0.read().unwrap().to_owned()
+                        != enum_value_tuple.0.read().unwrap().to_owned()
+                    {
+                        let file = fs::File::create(path)?;
+                        let mut writer = io::BufWriter::new(file);
+                        serde_json::to_writer_pretty(&mut writer, &enum_value_tuple)?;
+                    }
+                } else {
+                    let file = fs::File::create(&path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &enum_value_tuple)?;
+                }
+            }
+            for file in fs::read_dir(&path)? {
+                let file = file?;
+                let path = file.path();
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                let id = file_name.split('.').next().unwrap();
+                if let Ok(id) = Uuid::parse_str(id) {
+                    if !self.enum_value.read().unwrap().contains_key(&id) {
+                        fs::remove_file(path)?;
+                    }
+                }
+            }
+        }
+
+        // Persist Enumeration.
+        {
+            let path = path.join("enumeration");
+            fs::create_dir_all(&path)?;
+            for enumeration_tuple in self.enumeration.read().unwrap().values() {
+                let path = path.join(format!("{}.json", enumeration_tuple.0.read().unwrap().id));
+                if path.exists() {
+                    let file = fs::File::open(&path)?;
+                    let reader = io::BufReader::new(file);
+                    let on_disk: (Arc<RwLock<Enumeration>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    if on_disk.0.read().unwrap().to_owned()
+                        != enumeration_tuple.0.read().unwrap().to_owned()
+                    {
+                        let file = fs::File::create(path)?;
+                        let mut writer = io::BufWriter::new(file);
+                        serde_json::to_writer_pretty(&mut writer, &enumeration_tuple)?;
+                    }
+                } else {
+                    let file = fs::File::create(&path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &enumeration_tuple)?;
+                }
+            }
+            for file in fs::read_dir(&path)? {
+                let file = file?;
+                let path = file.path();
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                let id = file_name.split('.').next().unwrap();
+                if let Ok(id) = Uuid::parse_str(id) {
+                    if !self.enumeration.read().unwrap().contains_key(&id) {
+                        fs::remove_file(path)?;
+                    }
+                }
+            }
+        }
+
         // Persist Event.
         {
             let path = path.join("event");
@@ -1766,6 +2258,42 @@ impl ObjectStore {
             }
         }
 
+        // Persist List.
+        {
+            let path = path.join("list");
+            fs::create_dir_all(&path)?;
+            for list_tuple in self.list.read().unwrap().values() {
+                let path = path.join(format!("{}.json", list_tuple.0.read().unwrap().id));
+                if path.exists() {
+                    let file = fs::File::open(&path)?;
+                    let reader = io::BufReader::new(file);
+                    let on_disk: (Arc<RwLock<List>>, SystemTime) = serde_json::from_reader(reader)?;
+                    if on_disk.0.read().unwrap().to_owned()
+                        != list_tuple.0.read().unwrap().to_owned()
+                    {
+                        let file = fs::File::create(path)?;
+                        let mut writer = io::BufWriter::new(file);
+                        serde_json::to_writer_pretty(&mut writer, &list_tuple)?;
+                    }
+                } else {
+                    let file = fs::File::create(&path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &list_tuple)?;
+                }
+            }
+            for file in fs::read_dir(&path)? {
+                let file = file?;
+                let path = file.path();
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                let id = file_name.split('.').next().unwrap();
+                if let Ok(id) = Uuid::parse_str(id) {
+                    if !self.list.read().unwrap().contains_key(&id) {
+                        fs::remove_file(path)?;
+                    }
+                }
+            }
+        }
+
         // Persist Object.
         {
             let path = path.join("object");
@@ -1917,6 +2445,43 @@ impl ObjectStore {
             }
         }
 
+        // Persist Sized Integer.
+        {
+            let path = path.join("sized_integer");
+            fs::create_dir_all(&path)?;
+            for sized_integer_tuple in self.sized_integer.read().unwrap().values() {
+                let path = path.join(format!("{}.json", sized_integer_tuple.0.read().unwrap().id));
+                if path.exists() {
+                    let file = fs::File::open(&path)?;
+                    let reader = io::BufReader::new(file);
+                    let on_disk: (Arc<RwLock<SizedInteger>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    if on_disk.0.read().unwrap().to_owned()
+                        != sized_integer_tuple.0.read().unwrap().to_owned()
+                    {
+                        let file = fs::File::create(path)?;
+                        let mut writer = io::BufWriter::new(file);
+                        serde_json::to_writer_pretty(&mut writer, &sized_integer_tuple)?;
+                    }
+                } else {
+                    let file = fs::File::create(&path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &sized_integer_tuple)?;
+                }
+            }
+            for file in fs::read_dir(&path)? {
+                let file = file?;
+                let path = file.path();
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                let id = file_name.split('.').next().unwrap();
+                if let Ok(id) = Uuid::parse_str(id) {
+                    if !self.sized_integer.read().unwrap().contains_key(&id) {
+                        fs::remove_file(path)?;
+                    }
+                }
+            }
+        }
+
         // Persist State.
         {
             let path = path.join("state");
@@ -2028,6 +2593,43 @@ impl ObjectStore {
             }
         }
 
+        // Persist Z Option.
+        {
+            let path = path.join("z_option");
+            fs::create_dir_all(&path)?;
+            for z_option_tuple in self.z_option.read().unwrap().values() {
+                let path = path.join(format!("{}.json", z_option_tuple.0.read().unwrap().id));
+                if path.exists() {
+                    let file = fs::File::open(&path)?;
+                    let reader = io::BufReader::new(file);
+                    let on_disk: (Arc<RwLock<ZOption>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    if on_disk.0.read().unwrap().to_owned()
+                        != z_option_tuple.0.read().unwrap().to_owned()
+                    {
+                        let file = fs::File::create(path)?;
+                        let mut writer = io::BufWriter::new(file);
+                        serde_json::to_writer_pretty(&mut writer, &z_option_tuple)?;
+                    }
+                } else {
+                    let file = fs::File::create(&path)?;
+                    let mut writer = io::BufWriter::new(file);
+                    serde_json::to_writer_pretty(&mut writer, &z_option_tuple)?;
+                }
+            }
+            for file in fs::read_dir(&path)? {
+                let file = file?;
+                let path = file.path();
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                let id = file_name.split('.').next().unwrap();
+                if let Ok(id) = Uuid::parse_str(id) {
+                    if !self.z_option.read().unwrap().contains_key(&id) {
+                        fs::remove_file(path)?;
+                    }
+                }
+            }
+        }
+
         // Persist Type.
         {
             let path = path.join("ty");
@@ -2255,6 +2857,50 @@ impl ObjectStore {
             }
         }
 
+        // Load Enum Value. Stores persisted before there were enumerations
+        // don't have any.
+        {
+            let path = path.join("enum_value");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let enum_value: (Arc<RwLock<EnumValue>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    store
+                        .enum_value
+                        .write()
+                        .unwrap()
+                        .insert(enum_value.0.read().unwrap().id, enum_value.clone());
+                }
+            }
+        }
+
+        // Load Enumeration. Stores persisted before there were enumerations
+        // don't have any.
+        {
+            let path = path.join("enumeration");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let enumeration: (Arc<RwLock<Enumeration>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    store
+                        .enumeration
+                        .write()
+                        .unwrap()
+                        .insert(enumeration.0.read().unwrap().id, enumeration.clone());
+                }
+            }
+        }
+
         // Load Event.
         {
             let path = path.join("event");
@@ -2310,6 +2956,27 @@ impl ObjectStore {
             }
         }
 
+        // Load List. Stores persisted before there were lists
+        // don't have any.
+        {
+            let path = path.join("list");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let list: (Arc<RwLock<List>>, SystemTime) = serde_json::from_reader(reader)?;
+                    store
+                        .list
+                        .write()
+                        .unwrap()
+                        .insert(list.0.read().unwrap().id, list.clone());
+                }
+            }
+        }
+
         // Load Object.
         {
             let path = path.join("object");
@@ -2389,6 +3056,28 @@ impl ObjectStore {
             }
         }
 
+        // Load Sized Integer. Stores persisted before there were sized integers
+        // don't have any.
+        {
+            let path = path.join("sized_integer");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let sized_integer: (Arc<RwLock<SizedInteger>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    store
+                        .sized_integer
+                        .write()
+                        .unwrap()
+                        .insert(sized_integer.0.read().unwrap().id, sized_integer.clone());
+                }
+            }
+        }
+
         // Load State.
         {
             let path = path.join("state");
@@ -2444,6 +3133,28 @@ impl ObjectStore {
             }
         }
 
+        // Load Z Option. Stores persisted before there were options
+        // don't have any.
+        {
+            let path = path.join("z_option");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let z_option: (Arc<RwLock<ZOption>>, SystemTime) =
+                        serde_json::from_reader(reader)?;
+                    store
+                        .z_option
+                        .write()
+                        .unwrap()
+                        .insert(z_option.0.read().unwrap().id, z_option.clone());
+                }
+            }
+        }
+
         // Load Type.
         {
             let path = path.join("ty");
@@ -14,22 +14,29 @@ pub mod boolean;
 pub mod cardinality;
 pub mod conditional;
 pub mod conditionality;
+pub mod duration;
+pub mod enum_value;
+pub mod enumeration;
 pub mod event;
 pub mod external;
 pub mod float;
 pub mod integer;
 pub mod isa;
+pub mod list;
 pub mod many;
 pub mod object;
 pub mod one;
 pub mod referent;
 pub mod referrer;
 pub mod relationship;
+pub mod sized_integer;
 pub mod state;
 pub mod subtype;
 pub mod supertype;
+pub mod timestamp;
 pub mod ty;
 pub mod unconditional;
+pub mod z_option;
 pub mod z_string;
 pub mod z_uuid;
 
@@ -46,6 +53,10 @@ pub use crate::v2::sarzak::cardinality::Cardinality;
 pub use crate::v2::sarzak::conditional::Conditional;
 pub use crate::v2::sarzak::conditional::CONDITIONAL;
 pub use crate::v2::sarzak::conditionality::Conditionality;
+pub use crate::v2::sarzak::duration::Duration;
+pub use crate::v2::sarzak::duration::DURATION;
+pub use crate::v2::sarzak::enum_value::EnumValue;
+pub use crate::v2::sarzak::enumeration::Enumeration;
 pub use crate::v2::sarzak::event::Event;
 pub use crate::v2::sarzak::external::External;
 pub use crate::v2::sarzak::float::Float;
@@ -53,6 +64,7 @@ pub use crate::v2::sarzak::float::FLOAT;
 pub use crate::v2::sarzak::integer::Integer;
 pub use crate::v2::sarzak::integer::INTEGER;
 pub use crate::v2::sarzak::isa::Isa;
+pub use crate::v2::sarzak::list::List;
 pub use crate::v2::sarzak::many::Many;
 pub use crate::v2::sarzak::many::MANY;
 pub use crate::v2::sarzak::object::Object;
@@ -61,13 +73,17 @@ pub use crate::v2::sarzak::one::ONE;
 pub use crate::v2::sarzak::referent::Referent;
 pub use crate::v2::sarzak::referrer::Referrer;
 pub use crate::v2::sarzak::relationship::Relationship;
+pub use crate::v2::sarzak::sized_integer::SizedInteger;
 pub use crate::v2::sarzak::state::State;
 pub use crate::v2::sarzak::subtype::Subtype;
 pub use crate::v2::sarzak::supertype::Supertype;
+pub use crate::v2::sarzak::timestamp::Timestamp;
+pub use crate::v2::sarzak::timestamp::TIMESTAMP;
 pub use crate::v2::sarzak::ty::Ty;
 pub use crate::v2::sarzak::unconditional::Unconditional;
 pub use crate::v2::sarzak::unconditional::UNCONDITIONAL;
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+pub use crate::v2::sarzak::z_option::ZOption;
 pub use crate::v2::sarzak::z_string::ZString;
 pub use crate::v2::sarzak::z_string::Z_STRING;
 pub use crate::v2::sarzak::z_uuid::ZUuid;
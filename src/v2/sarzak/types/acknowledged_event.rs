@@ -36,7 +36,11 @@ impl AcknowledgedEvent {
         state_id: &Arc<RwLock<State>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<AcknowledgedEvent>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "acknowledged_event::{}::{}::",
+            event_id.read().unwrap().id,
+            state_id.read().unwrap().id
+        ));
         let new = Arc::new(RwLock::new(AcknowledgedEvent {
             id,
             event_id: event_id.read().unwrap().id,
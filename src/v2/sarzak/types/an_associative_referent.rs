@@ -32,7 +32,19 @@ impl AnAssociativeReferent {
         referent: &Arc<RwLock<AssociativeReferent>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<AnAssociativeReferent>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "an_associative_referent::{}::{}::",
+            associative.read().unwrap().id,
+            referential_attribute
+        ));
+        // The associative referent has no natural key of its own, so it
+        // borrows one from this instance.
+        if store.namespace().is_some() {
+            let old = referent.read().unwrap().id;
+            store.exorcise_associative_referent(&old);
+            referent.write().unwrap().id = store.id_for(&format!("associative_referent::{}::", id));
+            store.inter_associative_referent(referent.clone());
+        }
         let new = Arc::new(RwLock::new(AnAssociativeReferent {
             id,
             referential_attribute,
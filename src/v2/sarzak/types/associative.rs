@@ -30,7 +30,15 @@ impl Associative {
         from: &Arc<RwLock<AssociativeReferrer>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Associative>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("associative::{}::", number));
+        // The associative referrer has no natural key of its own, so it
+        // borrows one from the associative that owns it.
+        if store.namespace().is_some() {
+            let old = from.read().unwrap().id;
+            store.exorcise_associative_referrer(&old);
+            from.write().unwrap().id = store.id_for(&format!("associative_referrer::{}::", id));
+            store.inter_associative_referrer(from.clone());
+        }
         let new = Arc::new(RwLock::new(Associative {
             id,
             number,
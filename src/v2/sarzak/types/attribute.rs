@@ -38,7 +38,11 @@ impl Attribute {
         ty: &Arc<RwLock<Ty>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Attribute>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "attribute::{}::{}::",
+            obj_id.read().unwrap().id,
+            name
+        ));
         let new = Arc::new(RwLock::new(Attribute {
             id,
             name,
@@ -46,7 +46,19 @@ impl Binary {
         to: &Arc<RwLock<Referent>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Binary>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("binary::{}::", number));
+        // Referrers and referents have no natural key of their own, so they
+        // borrow one from the binary that owns them.
+        if store.namespace().is_some() {
+            let old = from.read().unwrap().id;
+            store.exorcise_referrer(&old);
+            from.write().unwrap().id = store.id_for(&format!("referrer::{}::", id));
+            store.inter_referrer(from.clone());
+            let old = to.read().unwrap().id;
+            store.exorcise_referent(&old);
+            to.write().unwrap().id = store.id_for(&format!("referent::{}::", id));
+            store.inter_referent(to.clone());
+        }
         let new = Arc::new(RwLock::new(Binary {
             id,
             number,
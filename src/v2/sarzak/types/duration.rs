@@ -0,0 +1,38 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"duration-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"duration-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"duration-const-documentation"}}}
+/// The Duration Type
+///
+/// This type holds a span of time. This type is just a placeholder. It's implementation
+///  is determined downstream by the code generator.
+///
+/// ❗️{"singleton_object": true}
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"duration-const-definition"}}}
+pub const DURATION: Uuid = uuid!["5bbf00bc-6141-5c01-a692-b10a2341c8dd"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Duration;
+
+impl Duration {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        DURATION
+    }
+}
+
+impl Default for Duration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,64 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"enum_value-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::sarzak::types::enumeration::Enumeration;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak::store::ObjectStore as SarzakStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-documentation"}}}
+/// A named value of an [`Enumeration`]
+///
+/// The `value` attribute is the discriminant. Model compilers are free to ignore it.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EnumValue {
+    pub id: Uuid,
+    pub name: String,
+    pub value: i64,
+    /// R28: [`EnumValue`] 'is a member of' [`Enumeration`]
+    pub enumeration: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-implementation"}}}
+impl EnumValue {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-new"}}}
+    /// Inter a new 'Enum Value' in the store, and return it's `id`.
+    pub fn new(
+        name: String,
+        value: i64,
+        enumeration: &Arc<RwLock<Enumeration>>,
+        store: &mut SarzakStore,
+    ) -> Arc<RwLock<EnumValue>> {
+        let id = store.id_for(&format!(
+            "enum_value::{}::{}::",
+            enumeration.read().unwrap().id,
+            name
+        ));
+        let new = Arc::new(RwLock::new(EnumValue {
+            id,
+            name,
+            value,
+            enumeration: enumeration.read().unwrap().id,
+        }));
+        store.inter_enum_value(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-nav-forward-to-enumeration"}}}
+    /// Navigate to [`Enumeration`] across R28(1-*)
+    pub fn r28_enumeration<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Enumeration>>> {
+        vec![store.exhume_enumeration(&self.enumeration).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,67 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"enumeration-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::sarzak::types::enum_value::EnumValue;
+use crate::v2::sarzak::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak::store::ObjectStore as SarzakStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-documentation"}}}
+/// An Enumerated Type
+///
+/// An `Enumeration` is a type whose values are drawn from a fixed set of named values,
+///  each of which is an [`EnumValue`]. How it is realized is up to the model compiler.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Enumeration {
+    pub description: String,
+    pub id: Uuid,
+    pub name: String,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-implementation"}}}
+impl Enumeration {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-new"}}}
+    /// Inter a new 'Enumeration' in the store, and return it's `id`.
+    pub fn new(
+        description: String,
+        name: String,
+        store: &mut SarzakStore,
+    ) -> Arc<RwLock<Enumeration>> {
+        let id = store.id_for(&format!("enumeration::{}::", name));
+        let new = Arc::new(RwLock::new(Enumeration {
+            description,
+            id,
+            name,
+        }));
+        store.inter_enumeration(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-nav-backward-1_M-to-enum_value"}}}
+    /// Navigate to [`EnumValue`] across R28(1-M)
+    pub fn r28_enum_value<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<EnumValue>>> {
+        store
+            .iter_enum_value()
+            .filter(|enum_value| enum_value.read().unwrap().enumeration == self.id)
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -34,7 +34,7 @@ impl Event {
         obj_id: &Arc<RwLock<Object>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Event>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("event::{}::{}::", obj_id.read().unwrap().id, name));
         let new = Arc::new(RwLock::new(Event {
             id,
             name,
@@ -50,7 +50,7 @@ impl External {
         x_path: String,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<External>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("external::{}::{}::", name, x_path));
         let new = Arc::new(RwLock::new(External {
             ctor,
             id,
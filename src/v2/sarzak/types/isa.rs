@@ -30,7 +30,15 @@ impl Isa {
         supertype: &Arc<RwLock<Supertype>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Isa>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("isa::{}::", number));
+        // A supertype has no natural key of its own, so it borrows one from
+        // the isa that owns it.
+        if store.namespace().is_some() {
+            let old = supertype.read().unwrap().id;
+            store.exorcise_supertype(&old);
+            supertype.write().unwrap().id = store.id_for(&format!("supertype::{}::", id));
+            store.inter_supertype(supertype.clone());
+        }
         let new = Arc::new(RwLock::new(Isa {
             id,
             number,
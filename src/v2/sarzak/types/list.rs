@@ -0,0 +1,58 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"list-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::sarzak::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak::store::ObjectStore as SarzakStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-documentation"}}}
+/// A List Type
+///
+/// This is a type constructor. A `List` is an ordered collection of values, all of the same
+///  [`Ty`].
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct List {
+    pub id: Uuid,
+    /// R29: [`List`] 'contains values of' [`Ty`]
+    pub ty: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-implementation"}}}
+impl List {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-new"}}}
+    /// Inter a new 'List' in the store, and return it's `id`.
+    pub fn new(ty: &Arc<RwLock<Ty>>, store: &mut SarzakStore) -> Arc<RwLock<List>> {
+        let id = store.id_for(&format!("list::{}::", ty.read().unwrap().id()));
+        let new = Arc::new(RwLock::new(List {
+            id,
+            ty: ty.read().unwrap().id(),
+        }));
+        store.inter_list(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-nav-forward-to-ty"}}}
+    /// Navigate to [`Ty`] across R29(1-*)
+    pub fn r29_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.ty).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -49,7 +49,7 @@ impl Object {
         name: String,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Object>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("object::{}::", name));
         let new = Arc::new(RwLock::new(Object {
             description,
             id,
@@ -0,0 +1,50 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"sized_integer-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::sarzak::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak::store::ObjectStore as SarzakStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-documentation"}}}
+/// A Sized Integer Type
+///
+/// Unlike [`Integer`][crate::v2::sarzak::types::Integer], this type carries an explicit width
+///  in bits, and whether or not it is signed. This is for those times when the model needs to
+///  talk about a `u8` and not just "some integer".
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SizedInteger {
+    pub bits: i64,
+    pub id: Uuid,
+    pub signed: bool,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-implementation"}}}
+impl SizedInteger {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-impl-new"}}}
+    /// Inter a new 'Sized Integer' in the store, and return it's `id`.
+    pub fn new(bits: i64, signed: bool, store: &mut SarzakStore) -> Arc<RwLock<SizedInteger>> {
+        let id = store.id_for(&format!("sized_integer::{}::{}::", bits, signed));
+        let new = Arc::new(RwLock::new(SizedInteger { bits, id, signed }));
+        store.inter_sized_integer(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
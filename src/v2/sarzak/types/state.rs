@@ -33,7 +33,7 @@ impl State {
         obj_id: &Arc<RwLock<Object>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<State>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!("state::{}::{}::", obj_id.read().unwrap().id, name));
         let new = Arc::new(RwLock::new(State {
             id,
             name,
@@ -34,7 +34,11 @@ impl Subtype {
         obj_id: &Arc<RwLock<Object>>,
         store: &mut SarzakStore,
     ) -> Arc<RwLock<Subtype>> {
-        let id = Uuid::new_v4();
+        let id = store.id_for(&format!(
+            "subtype::{}::{}::",
+            isa.read().unwrap().id,
+            obj_id.read().unwrap().id
+        ));
         let new = Arc::new(RwLock::new(Subtype {
             id,
             isa: isa.read().unwrap().id,
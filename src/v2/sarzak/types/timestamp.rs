@@ -0,0 +1,38 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"timestamp-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"timestamp-use-statements"}}}
+use serde::{Deserialize, Serialize};
+use uuid::{uuid, Uuid};
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"timestamp-const-documentation"}}}
+/// The Timestamp Type
+///
+/// This type holds a point in time. This type is just a placeholder. It's implementation
+///  is determined downstream by the code generator.
+///
+/// ❗️{"singleton_object": true}
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"timestamp-const-definition"}}}
+pub const TIMESTAMP: Uuid = uuid!["ea67b711-707e-5c46-a72f-68da19aceb4e"];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Timestamp;
+
+impl Timestamp {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn id(&self) -> Uuid {
+        TIMESTAMP
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
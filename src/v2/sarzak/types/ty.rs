@@ -3,10 +3,16 @@
 use crate::v2::sarzak::store::ObjectStore as SarzakStore;
 use crate::v2::sarzak::types::attribute::Attribute;
 use crate::v2::sarzak::types::boolean::BOOLEAN;
+use crate::v2::sarzak::types::duration::DURATION;
+use crate::v2::sarzak::types::enumeration::Enumeration;
 use crate::v2::sarzak::types::external::External;
 use crate::v2::sarzak::types::float::FLOAT;
 use crate::v2::sarzak::types::integer::INTEGER;
+use crate::v2::sarzak::types::list::List;
 use crate::v2::sarzak::types::object::Object;
+use crate::v2::sarzak::types::sized_integer::SizedInteger;
+use crate::v2::sarzak::types::timestamp::TIMESTAMP;
+use crate::v2::sarzak::types::z_option::ZOption;
 use crate::v2::sarzak::types::z_string::Z_STRING;
 use crate::v2::sarzak::types::z_uuid::Z_UUID;
 use serde::{Deserialize, Serialize};
@@ -21,15 +27,25 @@ use uuid::Uuid;
 /// There are several values available: [Integer], [Boolean], [Float], [String], and [UUID]
 /// .
 ///
+/// In addition there are [Timestamp] and [Duration] for dealing with time, [SizedInteger]
+///  for integers of a particular width, and [Enumeration] for enumerated types. [List] and
+///  [ZOption] are type constructors: they wrap another `Ty`.
+///
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"ty-enum-definition"}}}
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Ty {
     Boolean(Uuid),
+    Duration(Uuid),
+    Enumeration(Uuid),
     External(Uuid),
     Float(Uuid),
     Integer(Uuid),
+    List(Uuid),
     Object(Uuid),
+    SizedInteger(Uuid),
+    Timestamp(Uuid),
+    ZOption(Uuid),
     ZString(Uuid),
     ZUuid(Uuid),
 }
@@ -43,6 +59,27 @@ impl Ty {
         store.exhume_ty(&BOOLEAN).unwrap()
     }
 
+    /// Create a new instance of Ty::Duration
+    pub fn new_duration(store: &SarzakStore) -> Arc<RwLock<Self>> {
+        // This is already in the store.
+        store.exhume_ty(&DURATION).unwrap()
+    }
+
+    /// Create a new instance of Ty::Enumeration
+    pub fn new_enumeration(
+        enumeration: &Arc<RwLock<Enumeration>>,
+        store: &mut SarzakStore,
+    ) -> Arc<RwLock<Self>> {
+        let id = enumeration.read().unwrap().id;
+        if let Some(enumeration) = store.exhume_ty(&id) {
+            enumeration
+        } else {
+            let new = Arc::new(RwLock::new(Self::Enumeration(id)));
+            store.inter_ty(new.clone());
+            new
+        }
+    } // wtf?
+
     /// Create a new instance of Ty::External
     pub fn new_external(
         external: &Arc<RwLock<External>>,
@@ -70,6 +107,18 @@ impl Ty {
         store.exhume_ty(&INTEGER).unwrap()
     }
 
+    /// Create a new instance of Ty::List
+    pub fn new_list(list: &Arc<RwLock<List>>, store: &mut SarzakStore) -> Arc<RwLock<Self>> {
+        let id = list.read().unwrap().id;
+        if let Some(list) = store.exhume_ty(&id) {
+            list
+        } else {
+            let new = Arc::new(RwLock::new(Self::List(id)));
+            store.inter_ty(new.clone());
+            new
+        }
+    } // wtf?
+
     /// Create a new instance of Ty::Object
     pub fn new_object(object: &Arc<RwLock<Object>>, store: &mut SarzakStore) -> Arc<RwLock<Self>> {
         let id = object.read().unwrap().id;
@@ -82,6 +131,42 @@ impl Ty {
         }
     } // wtf?
 
+    /// Create a new instance of Ty::SizedInteger
+    pub fn new_sized_integer(
+        sized_integer: &Arc<RwLock<SizedInteger>>,
+        store: &mut SarzakStore,
+    ) -> Arc<RwLock<Self>> {
+        let id = sized_integer.read().unwrap().id;
+        if let Some(sized_integer) = store.exhume_ty(&id) {
+            sized_integer
+        } else {
+            let new = Arc::new(RwLock::new(Self::SizedInteger(id)));
+            store.inter_ty(new.clone());
+            new
+        }
+    } // wtf?
+
+    /// Create a new instance of Ty::Timestamp
+    pub fn new_timestamp(store: &SarzakStore) -> Arc<RwLock<Self>> {
+        // This is already in the store.
+        store.exhume_ty(&TIMESTAMP).unwrap()
+    }
+
+    /// Create a new instance of Ty::ZOption
+    pub fn new_z_option(
+        z_option: &Arc<RwLock<ZOption>>,
+        store: &mut SarzakStore,
+    ) -> Arc<RwLock<Self>> {
+        let id = z_option.read().unwrap().id;
+        if let Some(z_option) = store.exhume_ty(&id) {
+            z_option
+        } else {
+            let new = Arc::new(RwLock::new(Self::ZOption(id)));
+            store.inter_ty(new.clone());
+            new
+        }
+    } // wtf?
+
     /// Create a new instance of Ty::ZString
     pub fn new_z_string(store: &SarzakStore) -> Arc<RwLock<Self>> {
         // This is already in the store.
@@ -99,10 +184,16 @@ impl Ty {
     pub fn id(&self) -> Uuid {
         match self {
             Self::Boolean(id) => *id,
+            Self::Duration(id) => *id,
+            Self::Enumeration(id) => *id,
             Self::External(id) => *id,
             Self::Float(id) => *id,
             Self::Integer(id) => *id,
+            Self::List(id) => *id,
             Self::Object(id) => *id,
+            Self::SizedInteger(id) => *id,
+            Self::Timestamp(id) => *id,
+            Self::ZOption(id) => *id,
             Self::ZString(id) => *id,
             Self::ZUuid(id) => *id,
         }
@@ -0,0 +1,58 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"z_option-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-use-statements"}}}
+use std::sync::Arc;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::v2::sarzak::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak::store::ObjectStore as SarzakStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-documentation"}}}
+/// An Optional Type
+///
+/// This is a type constructor. A value of type `ZOption` either holds a value of the referenced
+///  [`Ty`], or it holds nothing at all.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ZOption {
+    pub id: Uuid,
+    /// R30: [`ZOption`] 'may contain a value of' [`Ty`]
+    pub ty: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-implementation"}}}
+impl ZOption {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-new"}}}
+    /// Inter a new 'Z Option' in the store, and return it's `id`.
+    pub fn new(ty: &Arc<RwLock<Ty>>, store: &mut SarzakStore) -> Arc<RwLock<ZOption>> {
+        let id = store.id_for(&format!("z_option::{}::", ty.read().unwrap().id()));
+        let new = Arc::new(RwLock::new(ZOption {
+            id,
+            ty: ty.read().unwrap().id(),
+        }));
+        store.inter_z_option(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-nav-forward-to-ty"}}}
+    /// Navigate to [`Ty`] across R30(1-*)
+    pub fn r30_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.ty).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakStore) -> Vec<Arc<RwLock<Ty>>> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
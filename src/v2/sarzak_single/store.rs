@@ -16,17 +16,22 @@
 //! * [`Binary`]
 //! * [`Cardinality`]
 //! * [`Conditionality`]
+//! * [`EnumValue`]
+//! * [`Enumeration`]
 //! * [`Event`]
 //! * [`External`]
 //! * [`Isa`]
+//! * [`List`]
 //! * [`Object`]
 //! * [`Referent`]
 //! * [`Referrer`]
 //! * [`Relationship`]
+//! * [`SizedInteger`]
 //! * [`State`]
 //! * [`Subtype`]
 //! * [`Supertype`]
 //! * [`Ty`]
+//! * [`ZOption`]
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::sarzak_single-object-store-definition"}}}
 use std::{
     fs,
@@ -41,9 +46,10 @@ use uuid::Uuid;
 
 use crate::v2::sarzak_single::types::{
     AcknowledgedEvent, AnAssociativeReferent, Associative, AssociativeReferent,
-    AssociativeReferrer, Attribute, Binary, Cardinality, Conditionality, Event, External, Isa,
-    Object, Referent, Referrer, Relationship, State, Subtype, Supertype, Ty, BOOLEAN, CONDITIONAL,
-    FLOAT, INTEGER, MANY, ONE, UNCONDITIONAL, Z_STRING, Z_UUID,
+    AssociativeReferrer, Attribute, Binary, Cardinality, Conditionality, EnumValue, Enumeration,
+    Event, External, Isa, List, Object, Referent, Referrer, Relationship, SizedInteger, State,
+    Subtype, Supertype, Ty, ZOption, BOOLEAN, CONDITIONAL, DURATION, FLOAT, INTEGER, MANY, ONE,
+    TIMESTAMP, UNCONDITIONAL, Z_STRING, Z_UUID,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -57,18 +63,23 @@ pub struct ObjectStore {
     binary: HashMap<Uuid, Binary>,
     cardinality: HashMap<Uuid, Cardinality>,
     conditionality: HashMap<Uuid, Conditionality>,
+    enum_value: HashMap<Uuid, EnumValue>,
+    enumeration: HashMap<Uuid, Enumeration>,
     event: HashMap<Uuid, Event>,
     external: HashMap<Uuid, External>,
     isa: HashMap<Uuid, Isa>,
+    list: HashMap<Uuid, List>,
     object: HashMap<Uuid, Object>,
     object_id_by_name: HashMap<String, Uuid>,
     referent: HashMap<Uuid, Referent>,
     referrer: HashMap<Uuid, Referrer>,
     relationship: HashMap<Uuid, Relationship>,
+    sized_integer: HashMap<Uuid, SizedInteger>,
     state: HashMap<Uuid, State>,
     subtype: HashMap<Uuid, Subtype>,
     supertype: HashMap<Uuid, Supertype>,
     ty: HashMap<Uuid, Ty>,
+    z_option: HashMap<Uuid, ZOption>,
 }
 
 impl ObjectStore {
@@ -83,18 +94,23 @@ impl ObjectStore {
             binary: HashMap::default(),
             cardinality: HashMap::default(),
             conditionality: HashMap::default(),
+            enum_value: HashMap::default(),
+            enumeration: HashMap::default(),
             event: HashMap::default(),
             external: HashMap::default(),
             isa: HashMap::default(),
+            list: HashMap::default(),
             object: HashMap::default(),
             object_id_by_name: HashMap::default(),
             referent: HashMap::default(),
             referrer: HashMap::default(),
             relationship: HashMap::default(),
+            sized_integer: HashMap::default(),
             state: HashMap::default(),
             subtype: HashMap::default(),
             supertype: HashMap::default(),
             ty: HashMap::default(),
+            z_option: HashMap::default(),
         };
 
         // Initialize Singleton Subtypes
@@ -106,8 +122,10 @@ impl ObjectStore {
         store.inter_conditionality(Conditionality::Conditional(CONDITIONAL));
         store.inter_conditionality(Conditionality::Unconditional(UNCONDITIONAL));
         store.inter_ty(Ty::Boolean(BOOLEAN));
+        store.inter_ty(Ty::Duration(DURATION));
         store.inter_ty(Ty::Float(FLOAT));
         store.inter_ty(Ty::Integer(INTEGER));
+        store.inter_ty(Ty::Timestamp(TIMESTAMP));
         store.inter_ty(Ty::ZString(Z_STRING));
         store.inter_ty(Ty::ZUuid(Z_UUID));
 
@@ -339,6 +357,54 @@ impl ObjectStore {
         self.conditionality.values()
     }
 
+    /// Inter (insert) [`EnumValue`] into the store.
+    ///
+    pub fn inter_enum_value(&mut self, enum_value: EnumValue) {
+        self.enum_value.insert(enum_value.id, enum_value);
+    }
+
+    /// Exhume (get) [`EnumValue`] from the store.
+    ///
+    pub fn exhume_enum_value(&self, id: &Uuid) -> Option<&EnumValue> {
+        self.enum_value.get(id)
+    }
+
+    /// Exorcise (remove) [`EnumValue`] from the store.
+    ///
+    pub fn exorcise_enum_value(&mut self, id: &Uuid) -> Option<EnumValue> {
+        self.enum_value.remove(id)
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, EnumValue>`.
+    ///
+    pub fn iter_enum_value(&self) -> impl Iterator<Item = &EnumValue> {
+        self.enum_value.values()
+    }
+
+    /// Inter (insert) [`Enumeration`] into the store.
+    ///
+    pub fn inter_enumeration(&mut self, enumeration: Enumeration) {
+        self.enumeration.insert(enumeration.id, enumeration);
+    }
+
+    /// Exhume (get) [`Enumeration`] from the store.
+    ///
+    pub fn exhume_enumeration(&self, id: &Uuid) -> Option<&Enumeration> {
+        self.enumeration.get(id)
+    }
+
+    /// Exorcise (remove) [`Enumeration`] from the store.
+    ///
+    pub fn exorcise_enumeration(&mut self, id: &Uuid) -> Option<Enumeration> {
+        self.enumeration.remove(id)
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, Enumeration>`.
+    ///
+    pub fn iter_enumeration(&self) -> impl Iterator<Item = &Enumeration> {
+        self.enumeration.values()
+    }
+
     /// Inter (insert) [`Event`] into the store.
     ///
     pub fn inter_event(&mut self, event: Event) {
@@ -411,6 +477,30 @@ impl ObjectStore {
         self.isa.values()
     }
 
+    /// Inter (insert) [`List`] into the store.
+    ///
+    pub fn inter_list(&mut self, list: List) {
+        self.list.insert(list.id, list);
+    }
+
+    /// Exhume (get) [`List`] from the store.
+    ///
+    pub fn exhume_list(&self, id: &Uuid) -> Option<&List> {
+        self.list.get(id)
+    }
+
+    /// Exorcise (remove) [`List`] from the store.
+    ///
+    pub fn exorcise_list(&mut self, id: &Uuid) -> Option<List> {
+        self.list.remove(id)
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, List>`.
+    ///
+    pub fn iter_list(&self) -> impl Iterator<Item = &List> {
+        self.list.values()
+    }
+
     /// Inter (insert) [`Object`] into the store.
     ///
     pub fn inter_object(&mut self, object: Object) {
@@ -515,6 +605,30 @@ impl ObjectStore {
         self.relationship.values()
     }
 
+    /// Inter (insert) [`SizedInteger`] into the store.
+    ///
+    pub fn inter_sized_integer(&mut self, sized_integer: SizedInteger) {
+        self.sized_integer.insert(sized_integer.id, sized_integer);
+    }
+
+    /// Exhume (get) [`SizedInteger`] from the store.
+    ///
+    pub fn exhume_sized_integer(&self, id: &Uuid) -> Option<&SizedInteger> {
+        self.sized_integer.get(id)
+    }
+
+    /// Exorcise (remove) [`SizedInteger`] from the store.
+    ///
+    pub fn exorcise_sized_integer(&mut self, id: &Uuid) -> Option<SizedInteger> {
+        self.sized_integer.remove(id)
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, SizedInteger>`.
+    ///
+    pub fn iter_sized_integer(&self) -> impl Iterator<Item = &SizedInteger> {
+        self.sized_integer.values()
+    }
+
     /// Inter (insert) [`State`] into the store.
     ///
     pub fn inter_state(&mut self, state: State) {
@@ -611,6 +725,30 @@ impl ObjectStore {
         self.ty.values()
     }
 
+    /// Inter (insert) [`ZOption`] into the store.
+    ///
+    pub fn inter_z_option(&mut self, z_option: ZOption) {
+        self.z_option.insert(z_option.id, z_option);
+    }
+
+    /// Exhume (get) [`ZOption`] from the store.
+    ///
+    pub fn exhume_z_option(&self, id: &Uuid) -> Option<&ZOption> {
+        self.z_option.get(id)
+    }
+
+    /// Exorcise (remove) [`ZOption`] from the store.
+    ///
+    pub fn exorcise_z_option(&mut self, id: &Uuid) -> Option<ZOption> {
+        self.z_option.remove(id)
+    }
+
+    /// Get an iterator over the internal `HashMap<&Uuid, ZOption>`.
+    ///
+    pub fn iter_z_option(&self) -> impl Iterator<Item = &ZOption> {
+        self.z_option.values()
+    }
+
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 
     // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"v2::sarzak_single-object-store-persistence"}}}
@@ -745,6 +883,30 @@ impl ObjectStore {
             }
         }
 
+        // Persist Enum Value.
+        {
+            let path = path.join("enum_value");
+            fs::create_dir_all(&path)?;
+            for enum_value in self.enum_value.values() {
+                let path = path.join(format!("{}.json", enum_value.id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &enum_value)?;
+            }
+        }
+
+        // Persist Enumeration.
+        {
+            let path = path.join("enumeration");
+            fs::create_dir_all(&path)?;
+            for enumeration in self.enumeration.values() {
+                let path = path.join(format!("{}.json", enumeration.id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &enumeration)?;
+            }
+        }
+
         // Persist Event.
         {
             let path = path.join("event");
@@ -781,6 +943,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist List.
+        {
+            let path = path.join("list");
+            fs::create_dir_all(&path)?;
+            for list in self.list.values() {
+                let path = path.join(format!("{}.json", list.id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &list)?;
+            }
+        }
+
         // Persist Object.
         {
             let path = path.join("object");
@@ -829,6 +1003,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Sized Integer.
+        {
+            let path = path.join("sized_integer");
+            fs::create_dir_all(&path)?;
+            for sized_integer in self.sized_integer.values() {
+                let path = path.join(format!("{}.json", sized_integer.id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &sized_integer)?;
+            }
+        }
+
         // Persist State.
         {
             let path = path.join("state");
@@ -877,6 +1063,18 @@ impl ObjectStore {
             }
         }
 
+        // Persist Z Option.
+        {
+            let path = path.join("z_option");
+            fs::create_dir_all(&path)?;
+            for z_option in self.z_option.values() {
+                let path = path.join(format!("{}.json", z_option.id));
+                let file = fs::File::create(path)?;
+                let mut writer = io::BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, &z_option)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -1041,6 +1239,40 @@ impl ObjectStore {
             }
         }
 
+        // Load Enum Value. Stores persisted before there were enumerations
+        // don't have any.
+        {
+            let path = path.join("enum_value");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let enum_value: EnumValue = serde_json::from_reader(reader)?;
+                    store.enum_value.insert(enum_value.id, enum_value);
+                }
+            }
+        }
+
+        // Load Enumeration. Stores persisted before there were enumerations
+        // don't have any.
+        {
+            let path = path.join("enumeration");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let enumeration: Enumeration = serde_json::from_reader(reader)?;
+                    store.enumeration.insert(enumeration.id, enumeration);
+                }
+            }
+        }
+
         // Load Event.
         {
             let path = path.join("event");
@@ -1083,6 +1315,23 @@ impl ObjectStore {
             }
         }
 
+        // Load List. Stores persisted before there were lists
+        // don't have any.
+        {
+            let path = path.join("list");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let list: List = serde_json::from_reader(reader)?;
+                    store.list.insert(list.id, list);
+                }
+            }
+        }
+
         // Load Object.
         {
             let path = path.join("object");
@@ -1142,6 +1391,23 @@ impl ObjectStore {
             }
         }
 
+        // Load Sized Integer. Stores persisted before there were sized integers
+        // don't have any.
+        {
+            let path = path.join("sized_integer");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let sized_integer: SizedInteger = serde_json::from_reader(reader)?;
+                    store.sized_integer.insert(sized_integer.id, sized_integer);
+                }
+            }
+        }
+
         // Load State.
         {
             let path = path.join("state");
@@ -1198,6 +1464,23 @@ impl ObjectStore {
             }
         }
 
+        // Load Z Option. Stores persisted before there were options
+        // don't have any.
+        {
+            let path = path.join("z_option");
+            if path.exists() {
+                let entries = fs::read_dir(path)?;
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let file = fs::File::open(path)?;
+                    let reader = io::BufReader::new(file);
+                    let z_option: ZOption = serde_json::from_reader(reader)?;
+                    store.z_option.insert(z_option.id, z_option);
+                }
+            }
+        }
+
         Ok(store)
     }
     // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
@@ -13,11 +13,15 @@ pub mod boolean;
 pub mod cardinality;
 pub mod conditional;
 pub mod conditionality;
+pub mod duration;
+pub mod enum_value;
+pub mod enumeration;
 pub mod event;
 pub mod external;
 pub mod float;
 pub mod integer;
 pub mod isa;
+pub mod list;
 pub mod many;
 pub mod object;
 pub mod one;
@@ -26,11 +30,14 @@ pub mod referrer;
 pub mod relationship;
 pub mod s_string;
 pub mod s_uuid;
+pub mod sized_integer;
 pub mod state;
 pub mod subtype;
 pub mod supertype;
+pub mod timestamp;
 pub mod ty;
 pub mod unconditional;
+pub mod z_option;
 pub mod z_string;
 pub mod z_uuid;
 
@@ -47,6 +54,10 @@ pub use crate::v2::sarzak_single::cardinality::Cardinality;
 pub use crate::v2::sarzak_single::conditional::Conditional;
 pub use crate::v2::sarzak_single::conditional::CONDITIONAL;
 pub use crate::v2::sarzak_single::conditionality::Conditionality;
+pub use crate::v2::sarzak_single::duration::Duration;
+pub use crate::v2::sarzak_single::duration::DURATION;
+pub use crate::v2::sarzak_single::enum_value::EnumValue;
+pub use crate::v2::sarzak_single::enumeration::Enumeration;
 pub use crate::v2::sarzak_single::event::Event;
 pub use crate::v2::sarzak_single::external::External;
 pub use crate::v2::sarzak_single::float::Float;
@@ -54,6 +65,7 @@ pub use crate::v2::sarzak_single::float::FLOAT;
 pub use crate::v2::sarzak_single::integer::Integer;
 pub use crate::v2::sarzak_single::integer::INTEGER;
 pub use crate::v2::sarzak_single::isa::Isa;
+pub use crate::v2::sarzak_single::list::List;
 pub use crate::v2::sarzak_single::many::Many;
 pub use crate::v2::sarzak_single::many::MANY;
 pub use crate::v2::sarzak_single::object::Object;
@@ -66,12 +78,16 @@ pub use crate::v2::sarzak_single::s_string::SString;
 pub use crate::v2::sarzak_single::s_string::S_STRING;
 pub use crate::v2::sarzak_single::s_uuid::SUuid;
 pub use crate::v2::sarzak_single::s_uuid::S_UUID;
+pub use crate::v2::sarzak_single::sized_integer::SizedInteger;
 pub use crate::v2::sarzak_single::state::State;
 pub use crate::v2::sarzak_single::subtype::Subtype;
 pub use crate::v2::sarzak_single::supertype::Supertype;
+pub use crate::v2::sarzak_single::timestamp::Timestamp;
+pub use crate::v2::sarzak_single::timestamp::TIMESTAMP;
 pub use crate::v2::sarzak_single::ty::Ty;
 pub use crate::v2::sarzak_single::unconditional::Unconditional;
 pub use crate::v2::sarzak_single::unconditional::UNCONDITIONAL;
+pub use crate::v2::sarzak_single::z_option::ZOption;
 pub use crate::v2::sarzak_single::z_string::ZString;
 pub use crate::v2::sarzak_single::z_string::Z_STRING;
 pub use crate::v2::sarzak_single::z_uuid::ZUuid;
@@ -0,0 +1,58 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"enum_value-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-use-statements"}}}
+use uuid::Uuid;
+
+use crate::v2::sarzak_single::types::enumeration::Enumeration;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-documentation"}}}
+/// A named value of an [`Enumeration`]
+///
+/// The `value` attribute is the discriminant. Model compilers are free to ignore it.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EnumValue {
+    pub id: Uuid,
+    pub name: String,
+    pub value: i64,
+    /// R28: [`EnumValue`] 'is a member of' [`Enumeration`]
+    pub enumeration: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-implementation"}}}
+impl EnumValue {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-new"}}}
+    /// Inter a new 'Enum Value' in the store, and return it's `id`.
+    pub fn new(
+        name: String,
+        value: i64,
+        enumeration: &Enumeration,
+        store: &mut SarzakSingleStore,
+    ) -> EnumValue {
+        let id = Uuid::new_v4();
+        let new = EnumValue {
+            id,
+            name,
+            value,
+            enumeration: enumeration.id,
+        };
+        store.inter_enum_value(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enum_value-struct-impl-nav-forward-to-enumeration"}}}
+    /// Navigate to [`Enumeration`] across R28(1-*)
+    pub fn r28_enumeration<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Enumeration> {
+        vec![store.exhume_enumeration(&self.enumeration).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
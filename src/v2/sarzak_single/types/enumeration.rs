@@ -0,0 +1,61 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"enumeration-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-use-statements"}}}
+use uuid::Uuid;
+
+use crate::v2::sarzak_single::types::enum_value::EnumValue;
+use crate::v2::sarzak_single::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-documentation"}}}
+/// An Enumerated Type
+///
+/// An `Enumeration` is a type whose values are drawn from a fixed set of named values,
+///  each of which is an [`EnumValue`]. How it is realized is up to the model compiler.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Enumeration {
+    pub description: String,
+    pub id: Uuid,
+    pub name: String,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-implementation"}}}
+impl Enumeration {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-new"}}}
+    /// Inter a new 'Enumeration' in the store, and return it's `id`.
+    pub fn new(description: String, name: String, store: &mut SarzakSingleStore) -> Enumeration {
+        let id = Uuid::new_v4();
+        let new = Enumeration {
+            description,
+            id,
+            name,
+        };
+        store.inter_enumeration(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-struct-impl-nav-backward-1_M-to-enum_value"}}}
+    /// Navigate to [`EnumValue`] across R28(1-M)
+    pub fn r28_enum_value<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&EnumValue> {
+        store
+            .iter_enum_value()
+            .filter(|enum_value| enum_value.enumeration == self.id)
+            .collect()
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"enumeration-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
@@ -0,0 +1,53 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"list-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-use-statements"}}}
+use uuid::Uuid;
+
+use crate::v2::sarzak_single::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-documentation"}}}
+/// A List Type
+///
+/// This is a type constructor. A `List` is an ordered collection of values, all of the same
+///  [`Ty`].
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct List {
+    pub id: Uuid,
+    /// R29: [`List`] 'contains values of' [`Ty`]
+    pub ty: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-implementation"}}}
+impl List {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-new"}}}
+    /// Inter a new 'List' in the store, and return it's `id`.
+    pub fn new(ty: &Ty, store: &mut SarzakSingleStore) -> List {
+        let id = Uuid::new_v4();
+        let new = List { id, ty: ty.id() };
+        store.inter_list(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-struct-impl-nav-forward-to-ty"}}}
+    /// Navigate to [`Ty`] across R29(1-*)
+    pub fn r29_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.ty).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"list-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
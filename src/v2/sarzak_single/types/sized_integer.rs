@@ -0,0 +1,48 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"sized_integer-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-use-statements"}}}
+use uuid::Uuid;
+
+use crate::v2::sarzak_single::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-documentation"}}}
+/// A Sized Integer Type
+///
+/// Unlike [`Integer`][crate::v2::sarzak_single::types::Integer], this type carries an explicit width
+///  in bits, and whether or not it is signed. This is for those times when the model needs to
+///  talk about a `u8` and not just "some integer".
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SizedInteger {
+    pub bits: i64,
+    pub id: Uuid,
+    pub signed: bool,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-implementation"}}}
+impl SizedInteger {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-impl-new"}}}
+    /// Inter a new 'Sized Integer' in the store, and return it's `id`.
+    pub fn new(bits: i64, signed: bool, store: &mut SarzakSingleStore) -> SizedInteger {
+        let id = Uuid::new_v4();
+        let new = SizedInteger { bits, id, signed };
+        store.inter_sized_integer(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"sized_integer-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
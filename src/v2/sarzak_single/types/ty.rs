@@ -3,10 +3,16 @@
 use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
 use crate::v2::sarzak_single::types::attribute::Attribute;
 use crate::v2::sarzak_single::types::boolean::BOOLEAN;
+use crate::v2::sarzak_single::types::duration::DURATION;
+use crate::v2::sarzak_single::types::enumeration::Enumeration;
 use crate::v2::sarzak_single::types::external::External;
 use crate::v2::sarzak_single::types::float::FLOAT;
 use crate::v2::sarzak_single::types::integer::INTEGER;
+use crate::v2::sarzak_single::types::list::List;
 use crate::v2::sarzak_single::types::object::Object;
+use crate::v2::sarzak_single::types::sized_integer::SizedInteger;
+use crate::v2::sarzak_single::types::timestamp::TIMESTAMP;
+use crate::v2::sarzak_single::types::z_option::ZOption;
 use crate::v2::sarzak_single::types::z_string::Z_STRING;
 use crate::v2::sarzak_single::types::z_uuid::Z_UUID;
 use serde::{Deserialize, Serialize};
@@ -19,15 +25,25 @@ use uuid::Uuid;
 /// There are several values available: [Integer], [Boolean], [Float], [String], and [UUID]
 /// .
 ///
+/// In addition there are [Timestamp] and [Duration] for dealing with time, [SizedInteger]
+///  for integers of a particular width, and [Enumeration] for enumerated types. [List] and
+///  [ZOption] are type constructors: they wrap another `Ty`.
+///
 // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
 // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"ty-enum-definition"}}}
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Ty {
     Boolean(Uuid),
+    Duration(Uuid),
+    Enumeration(Uuid),
     External(Uuid),
     Float(Uuid),
     Integer(Uuid),
+    List(Uuid),
     Object(Uuid),
+    SizedInteger(Uuid),
+    Timestamp(Uuid),
+    ZOption(Uuid),
     ZString(Uuid),
     ZUuid(Uuid),
 }
@@ -41,6 +57,19 @@ impl Ty {
         Self::Boolean(BOOLEAN)
     }
 
+    /// Create a new instance of Ty::Duration
+    pub fn new_duration() -> Self {
+        // This is already in the store, see associated function `new` above.
+        Self::Duration(DURATION)
+    }
+
+    /// Create a new instance of Ty::Enumeration
+    pub fn new_enumeration(enumeration: &Enumeration, store: &mut SarzakSingleStore) -> Self {
+        let new = Self::Enumeration(enumeration.id);
+        store.inter_ty(new.clone());
+        new
+    } // wtf?
+
     /// Create a new instance of Ty::External
     pub fn new_external(external: &External, store: &mut SarzakSingleStore) -> Self {
         let new = Self::External(external.id);
@@ -60,6 +89,13 @@ impl Ty {
         Self::Integer(INTEGER)
     }
 
+    /// Create a new instance of Ty::List
+    pub fn new_list(list: &List, store: &mut SarzakSingleStore) -> Self {
+        let new = Self::List(list.id);
+        store.inter_ty(new.clone());
+        new
+    } // wtf?
+
     /// Create a new instance of Ty::Object
     pub fn new_object(object: &Object, store: &mut SarzakSingleStore) -> Self {
         let new = Self::Object(object.id);
@@ -67,6 +103,26 @@ impl Ty {
         new
     } // wtf?
 
+    /// Create a new instance of Ty::SizedInteger
+    pub fn new_sized_integer(sized_integer: &SizedInteger, store: &mut SarzakSingleStore) -> Self {
+        let new = Self::SizedInteger(sized_integer.id);
+        store.inter_ty(new.clone());
+        new
+    } // wtf?
+
+    /// Create a new instance of Ty::Timestamp
+    pub fn new_timestamp() -> Self {
+        // This is already in the store, see associated function `new` above.
+        Self::Timestamp(TIMESTAMP)
+    }
+
+    /// Create a new instance of Ty::ZOption
+    pub fn new_z_option(z_option: &ZOption, store: &mut SarzakSingleStore) -> Self {
+        let new = Self::ZOption(z_option.id);
+        store.inter_ty(new.clone());
+        new
+    } // wtf?
+
     /// Create a new instance of Ty::ZString
     pub fn new_z_string() -> Self {
         // This is already in the store, see associated function `new` above.
@@ -84,10 +140,16 @@ impl Ty {
     pub fn id(&self) -> Uuid {
         match self {
             Self::Boolean(id) => *id,
+            Self::Duration(id) => *id,
+            Self::Enumeration(id) => *id,
             Self::External(id) => *id,
             Self::Float(id) => *id,
             Self::Integer(id) => *id,
+            Self::List(id) => *id,
             Self::Object(id) => *id,
+            Self::SizedInteger(id) => *id,
+            Self::Timestamp(id) => *id,
+            Self::ZOption(id) => *id,
             Self::ZString(id) => *id,
             Self::ZUuid(id) => *id,
         }
@@ -0,0 +1,53 @@
+// {"magic":"","directive":{"Start":{"directive":"allow-editing","tag":"z_option-struct-definition-file"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-use-statements"}}}
+use uuid::Uuid;
+
+use crate::v2::sarzak_single::types::ty::Ty;
+use serde::{Deserialize, Serialize};
+
+use crate::v2::sarzak_single::store::ObjectStore as SarzakSingleStore;
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-documentation"}}}
+/// An Optional Type
+///
+/// This is a type constructor. A value of type `ZOption` either holds a value of the referenced
+///  [`Ty`], or it holds nothing at all.
+///
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-definition"}}}
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ZOption {
+    pub id: Uuid,
+    /// R30: [`ZOption`] 'may contain a value of' [`Ty`]
+    pub ty: Uuid,
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-implementation"}}}
+impl ZOption {
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-new"}}}
+    /// Inter a new 'Z Option' in the store, and return it's `id`.
+    pub fn new(ty: &Ty, store: &mut SarzakSingleStore) -> ZOption {
+        let id = Uuid::new_v4();
+        let new = ZOption { id, ty: ty.id() };
+        store.inter_z_option(new.clone());
+        new
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-new_"}}}
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-struct-impl-nav-forward-to-ty"}}}
+    /// Navigate to [`Ty`] across R30(1-*)
+    pub fn r30_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.ty).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+    // {"magic":"","directive":{"Start":{"directive":"ignore-orig","tag":"z_option-impl-nav-subtype-to-supertype-ty"}}}
+    // Navigate to [`Ty`] across R3(isa)
+    pub fn r3_ty<'a>(&'a self, store: &'a SarzakSingleStore) -> Vec<&Ty> {
+        vec![store.exhume_ty(&self.id).unwrap()]
+    }
+    // {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+}
+// {"magic":"","directive":{"End":{"directive":"ignore-orig"}}}
+// {"magic":"","directive":{"End":{"directive":"allow-editing"}}}
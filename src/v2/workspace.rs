@@ -0,0 +1,612 @@
+//! Multi-Domain Workspace
+//!
+//! Domains don't live in isolation. An object may be imported from another
+//! domain, an attribute may be typed by an [`External`] that lives in another
+//! domain, and lu_dog refers to objects and object stores in other domains by
+//! id and by name. A [`Workspace`] is a set of domains that may refer to each
+//! other, and it's what those references are resolved against.
+//!
+//! Domains are known by their name, e.g., `sarzak`. References to a domain may
+//! use it's module path, as in `sarzak.toml` and in imported object
+//! annotations, so `crate::v2::sarzak`, `v2::sarzak`, and `crate/v2/sarzak`
+//! all refer to the `sarzak` domain.
+//!
+//! [`Workspace::check`] looks for references that can't be resolved, and for
+//! cycles among the domains.
+//!
+//! A workspace is persisted as a directory, containing a `workspace.json`
+//! manifest, and each domain in a subdirectory of it's own.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::{fs, io};
+
+use heck::ToUpperCamelCase;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::v2::{
+    domain::Domain,
+    sarzak::types::{External, Object, Ty},
+};
+
+#[cfg(feature = "lu-dog-vec")]
+use crate::v2::lu_dog_vec::store::ObjectStore as LuDogStore;
+
+/// The marker that starts an annotation in an object's description
+const ANNOTATION: &str = "🐶";
+/// The name of the manifest file in a persisted workspace
+const MANIFEST: &str = "workspace.json";
+
+/// A reference that can't be resolved, or a cycle
+///
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Problem {
+    /// `domain` imports `import`, which isn't in the workspace
+    UnknownDomain { domain: String, import: String },
+    /// `object` in `domain` is imported from `import`, which doesn't have it
+    DanglingImport {
+        domain: String,
+        object: Uuid,
+        import: String,
+    },
+    /// `external` in `domain` names an object in `import`, which doesn't have it
+    DanglingExternal {
+        domain: String,
+        external: Uuid,
+        import: String,
+    },
+    /// The domains import each other, with the first importing the second, and
+    /// so on around
+    Cycle { domains: Vec<String> },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::UnknownDomain { domain, import } => {
+                write!(f, "`{}` imports unknown domain `{}`", domain, import)
+            }
+            Problem::DanglingImport {
+                domain,
+                object,
+                import,
+            } => write!(
+                f,
+                "object {} in `{}` is imported from `{}`, which doesn't have it",
+                object, domain, import
+            ),
+            Problem::DanglingExternal {
+                domain,
+                external,
+                import,
+            } => write!(
+                f,
+                "external {} in `{}` refers to `{}`, which doesn't have it",
+                external, domain, import
+            ),
+            Problem::Cycle { domains } => {
+                write!(
+                    f,
+                    "import cycle: {} -> {}",
+                    domains.join(" -> "),
+                    domains[0]
+                )
+            }
+        }
+    }
+}
+
+/// The contents of `workspace.json`
+///
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Manifest {
+    domains: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// A set of domains that refer to each other
+///
+#[derive(Debug, Default)]
+pub struct Workspace {
+    domains: BTreeMap<String, Domain>,
+    /// Declared imports, by domain
+    imports: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Workspace {
+    /// Create an empty workspace
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a domain to the workspace
+    ///
+    /// The domain is known by it's name. If there was already a domain by that
+    /// name it's replaced, and returned.
+    pub fn insert(&mut self, domain: Domain) -> Option<Domain> {
+        let name = domain.name().to_owned();
+        self.imports.entry(name.clone()).or_default();
+        self.domains.insert(name, domain)
+    }
+
+    /// Remove a domain from the workspace
+    ///
+    pub fn remove(&mut self, name: &str) -> Option<Domain> {
+        self.imports.remove(name);
+        self.domains.remove(name)
+    }
+
+    /// Declare that `domain` imports `import`
+    ///
+    /// This is the equivalent of `imported_domains` in `sarzak.toml`. Imports
+    /// that are implied by imported objects and externals needn't be declared.
+    pub fn import(&mut self, domain: &str, import: &str) {
+        self.imports
+            .entry(domain_name(domain).to_owned())
+            .or_default()
+            .insert(domain_name(import).to_owned());
+    }
+
+    /// Look up a domain by name, or by module path
+    ///
+    pub fn domain(&self, name: &str) -> Option<&Domain> {
+        self.domains.get(domain_name(name))
+    }
+
+    /// Look up a domain by name, or by module path
+    ///
+    pub fn domain_mut(&mut self, name: &str) -> Option<&mut Domain> {
+        self.domains.get_mut(domain_name(name))
+    }
+
+    /// Iterate over the domains, in order of name
+    ///
+    pub fn domains(&self) -> impl Iterator<Item = &Domain> + '_ {
+        self.domains.values()
+    }
+
+    /// Find an object, in whichever domain it lives
+    ///
+    /// Imported objects live in the domain that they are imported from, and
+    /// not in the domain that imports them.
+    pub fn object(&self, id: &Uuid) -> Option<(&Domain, Arc<RwLock<Object>>)> {
+        let mut found = None;
+        for domain in self.domains.values() {
+            if let Some(obj) = domain.sarzak().exhume_object(id) {
+                if imported_from(&obj.read().unwrap()).is_none() {
+                    return Some((domain, obj));
+                }
+                found.get_or_insert((domain, obj));
+            }
+        }
+
+        found
+    }
+
+    /// Resolve an imported object to the object that it's imported from
+    ///
+    /// Returns `None` if the object isn't imported, or if it can't be found.
+    pub fn resolve_import(&self, obj: &Object) -> Option<(&Domain, Arc<RwLock<Object>>)> {
+        let (import, id) = imported_from(obj)?;
+        let domain = self.domain(&import)?;
+        let obj = domain.sarzak().exhume_object(&id)?;
+
+        Some((domain, obj))
+    }
+
+    /// Resolve an external type to an object in another domain
+    ///
+    /// This works for externals whose path names a domain in the workspace, and
+    /// whose name is the name of an object in that domain.
+    pub fn resolve_external(&self, external: &External) -> Option<(&Domain, Arc<RwLock<Object>>)> {
+        let domain = self.domain(&external.x_path)?;
+        let id = domain
+            .sarzak()
+            .exhume_object_id_by_name(&external.name.to_upper_camel_case())?;
+        let obj = domain.sarzak().exhume_object(&id)?;
+
+        Some((domain, obj))
+    }
+
+    /// Check every cross-domain reference in the workspace
+    ///
+    /// An empty result means that everything resolves, and that there are no
+    /// cycles.
+    pub fn check(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+        let mut edges = self.imports.clone();
+
+        for (name, domain) in &self.domains {
+            let sarzak = domain.sarzak();
+
+            for obj in sarzak.iter_object() {
+                let obj = obj.read().unwrap();
+                let (import, id) = match imported_from(&obj) {
+                    Some(import) => import,
+                    None => continue,
+                };
+                let import = domain_name(&import).to_owned();
+                edges
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(import.clone());
+
+                if let Some(imported) = self.domains.get(&import) {
+                    if imported.sarzak().exhume_object(&id).is_none() {
+                        problems.push(Problem::DanglingImport {
+                            domain: name.clone(),
+                            object: obj.id,
+                            import,
+                        });
+                    }
+                }
+            }
+
+            for ty in sarzak.iter_ty() {
+                let external = match *ty.read().unwrap() {
+                    Ty::External(id) => sarzak.exhume_external(&id).unwrap(),
+                    _ => continue,
+                };
+                let external = external.read().unwrap();
+                let import = domain_name(&external.x_path).to_owned();
+                if !self.domains.contains_key(&import) {
+                    // Not ours, e.g., `std::time`.
+                    continue;
+                }
+
+                edges
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(import.clone());
+                if self.resolve_external(&external).is_none() {
+                    problems.push(Problem::DanglingExternal {
+                        domain: name.clone(),
+                        external: external.id,
+                        import,
+                    });
+                }
+            }
+        }
+
+        for (name, imports) in &edges {
+            for import in imports {
+                if !self.domains.contains_key(import) {
+                    problems.push(Problem::UnknownDomain {
+                        domain: name.clone(),
+                        import: import.clone(),
+                    });
+                }
+            }
+        }
+
+        problems.extend(
+            cycles(&edges)
+                .into_iter()
+                .map(|domains| Problem::Cycle { domains }),
+        );
+
+        problems.sort();
+        problems.dedup();
+        problems
+    }
+
+    /// Check the references from a lu_dog store into the workspace
+    ///
+    /// Every [`Import`] and [`WoogStruct`] that refers to an object must refer
+    /// to one in the workspace, and every [`ZObjectStore`] must name a domain
+    /// in the workspace. `domain` is the name used for the lu_dog program in
+    /// the problems reported.
+    ///
+    /// [`Import`]: crate::v2::lu_dog_vec::types::Import
+    /// [`WoogStruct`]: crate::v2::lu_dog_vec::types::WoogStruct
+    /// [`ZObjectStore`]: crate::v2::lu_dog_vec::types::ZObjectStore
+    #[cfg(feature = "lu-dog-vec")]
+    pub fn check_lu_dog(&self, domain: &str, store: &LuDogStore) -> Vec<Problem> {
+        let mut problems = Vec::new();
+
+        let objects = store
+            .iter_import()
+            .filter_map(|i| i.borrow().object.map(|o| (o, i.borrow().x_path.clone())))
+            .chain(
+                store
+                    .iter_woog_struct()
+                    .filter_map(|s| s.borrow().object.map(|o| (o, s.borrow().x_path.clone()))),
+            );
+        for (object, path) in objects {
+            if self.object(&object).is_none() {
+                problems.push(Problem::DanglingImport {
+                    domain: domain.to_owned(),
+                    object,
+                    import: path,
+                });
+            }
+        }
+
+        for object_store in store.iter_z_object_store() {
+            let import = object_store.borrow().domain.clone();
+            if self.domain(&import).is_none() {
+                problems.push(Problem::UnknownDomain {
+                    domain: domain.to_owned(),
+                    import,
+                });
+            }
+        }
+
+        problems.sort();
+        problems.dedup();
+        problems
+    }
+
+    /// Load a workspace from a directory
+    ///
+    /// See [`persist`][Self::persist].
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        let file = fs::File::open(path.join(MANIFEST))?;
+        let reader = io::BufReader::new(file);
+        let manifest: Manifest = serde_json::from_reader(reader)?;
+
+        let mut workspace = Workspace::new();
+        for (name, imports) in manifest.domains {
+            let domain = Domain::load(path.join(&name))?;
+            workspace.insert(domain);
+            workspace.imports.insert(name, imports);
+        }
+
+        Ok(workspace)
+    }
+
+    /// Persist the workspace as a directory
+    ///
+    /// Each domain is persisted in a subdirectory named after it, and the
+    /// declared imports go in the manifest.
+    pub fn persist<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        fs::create_dir_all(path)?;
+
+        for (name, domain) in &self.domains {
+            domain.persist(path.join(name))?;
+        }
+
+        let manifest = Manifest {
+            domains: self
+                .domains
+                .keys()
+                .map(|name| {
+                    let imports = self.imports.get(name).cloned().unwrap_or_default();
+                    (name.clone(), imports)
+                })
+                .collect(),
+        };
+        let file = fs::File::create(path.join(MANIFEST))?;
+        let writer = io::BufWriter::new(file);
+        serde_json::to_writer_pretty(writer, &manifest)?;
+
+        Ok(())
+    }
+}
+
+/// The name of the domain at the end of a module path
+///
+fn domain_name(path: &str) -> &str {
+    path.rsplit(|c| c == ':' || c == '/')
+        .find(|s| !s.is_empty())
+        .unwrap_or(path)
+}
+
+/// If the object is imported, the domain it's imported from, and it's id there
+///
+/// Cuckoo has no way to mark an object as imported, so it's done with an
+/// annotation at the end of the description:
+///
+/// ```text
+/// 🐶 { "imported_object": { "domain": "crate::v2::sarzak", "id": "..." }}
+/// ```
+fn imported_from(obj: &Object) -> Option<(String, Uuid)> {
+    let (_, annotation) = obj.description.split_once(ANNOTATION)?;
+    let annotation: Value = serde_json::from_str(annotation.trim()).ok()?;
+    let import = annotation.get("imported_object")?;
+    let domain = import.get("domain")?.as_str()?;
+    let id = import.get("id")?.as_str()?.parse().ok()?;
+
+    Some((domain.to_owned(), id))
+}
+
+/// Find the cycles in the import graph
+///
+/// Each cycle is reported once, starting from it's least domain.
+fn cycles(edges: &BTreeMap<String, BTreeSet<String>>) -> Vec<Vec<String>> {
+    fn visit(
+        node: &String,
+        edges: &BTreeMap<String, BTreeSet<String>>,
+        path: &mut Vec<String>,
+        done: &mut BTreeSet<String>,
+        found: &mut BTreeSet<Vec<String>>,
+    ) {
+        if let Some(start) = path.iter().position(|n| n == node) {
+            let mut cycle = path[start..].to_vec();
+            let least = cycle
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, n)| *n)
+                .map(|(i, _)| i)
+                .unwrap();
+            cycle.rotate_left(least);
+            found.insert(cycle);
+            return;
+        }
+        if done.contains(node) {
+            return;
+        }
+
+        path.push(node.clone());
+        for next in edges.get(node).into_iter().flatten() {
+            visit(next, edges, path, done, found);
+        }
+        path.pop();
+        done.insert(node.clone());
+    }
+
+    let mut found = BTreeSet::new();
+    let mut done = BTreeSet::new();
+    for node in edges.keys() {
+        visit(node, edges, &mut Vec::new(), &mut done, &mut found);
+    }
+
+    found.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::v2::{
+        domain::builder::ModelBuilder,
+        sarzak::types::{Attribute, Ty},
+    };
+
+    fn imported(obj: &Arc<RwLock<Object>>, domain: &str) -> String {
+        format!(
+            "Imported.\n\n{} {{ \"imported_object\": {{ \"domain\": \"{}\", \"id\": \"{}\" }}}}",
+            ANNOTATION,
+            domain,
+            obj.read().unwrap().id
+        )
+    }
+
+    fn workspace() -> Workspace {
+        let sarzak = ModelBuilder::new("sarzak", "")
+            .object("Object")
+            .object("Ty")
+            .build()
+            .unwrap();
+        let object = sarzak
+            .sarzak()
+            .exhume_object(&sarzak.sarzak().exhume_object_id_by_name("Object").unwrap())
+            .unwrap();
+
+        let mut merlin = ModelBuilder::new("merlin", "")
+            .object("XBox")
+            .object("Object")
+            .build()
+            .unwrap();
+        {
+            // Replace the placeholder with the imported object.
+            let store = merlin.sarzak_mut();
+            let id = store.exhume_object_id_by_name("Object").unwrap();
+            let placeholder = store.exorcise_object(&id).unwrap();
+            let mut placeholder = placeholder.read().unwrap().clone();
+            placeholder.id = object.read().unwrap().id;
+            placeholder.description = imported(&object, "crate::v2::sarzak");
+            store.inter_object(Arc::new(RwLock::new(placeholder)));
+
+            let external = External::new(
+                "new".to_owned(),
+                "Ty".to_owned(),
+                "crate::v2::sarzak".to_owned(),
+                store,
+            );
+            let ty = Ty::new_external(&external, store);
+            let x_box = store
+                .exhume_object(&store.exhume_object_id_by_name("XBox").unwrap())
+                .unwrap();
+            Attribute::new("ty".to_owned(), &x_box, &ty, store);
+        }
+
+        let mut workspace = Workspace::new();
+        workspace.insert(sarzak);
+        workspace.insert(merlin);
+        workspace.import("merlin", "crate/v2/sarzak");
+
+        workspace
+    }
+
+    #[test]
+    fn test_resolve() {
+        let workspace = workspace();
+        assert!(workspace.check().is_empty());
+        assert_eq!(
+            workspace.domain("crate::v2::sarzak").unwrap().name(),
+            "sarzak"
+        );
+
+        let merlin = workspace.domain("merlin").unwrap().sarzak();
+        let id = merlin.exhume_object_id_by_name("Object").unwrap();
+        let placeholder = merlin.exhume_object(&id).unwrap();
+        let (domain, obj) = workspace
+            .resolve_import(&placeholder.read().unwrap())
+            .unwrap();
+        assert_eq!(domain.name(), "sarzak");
+        assert_eq!(obj.read().unwrap().id, id);
+        assert_eq!(workspace.object(&id).unwrap().0.name(), "sarzak");
+
+        let external = merlin.iter_external().next().unwrap();
+        let (domain, obj) = workspace
+            .resolve_external(&external.read().unwrap())
+            .unwrap();
+        assert_eq!(domain.name(), "sarzak");
+        assert_eq!(obj.read().unwrap().name, "Ty");
+    }
+
+    #[test]
+    fn test_problems() {
+        let mut workspace = workspace();
+
+        // Remove the external's object out from under it.
+        {
+            let sarzak = workspace.domain_mut("sarzak").unwrap().sarzak_mut();
+            let id = sarzak.exhume_object_id_by_name("Ty").unwrap();
+            sarzak.exorcise_object(&id).unwrap();
+        }
+        workspace.import("sarzak", "merlin");
+        workspace.import("merlin", "woog");
+
+        let problems = workspace.check();
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems.contains(&Problem::UnknownDomain {
+            domain: "merlin".to_owned(),
+            import: "woog".to_owned()
+        }));
+        assert!(problems.contains(&Problem::Cycle {
+            domains: vec!["merlin".to_owned(), "sarzak".to_owned()]
+        }));
+        assert!(problems
+            .iter()
+            .any(|p| matches!(p, Problem::DanglingExternal { import, .. } if import == "sarzak")));
+
+        // Without sarzak there's nothing to import from.
+        workspace.remove("sarzak").unwrap();
+        assert!(workspace.check().contains(&Problem::UnknownDomain {
+            domain: "merlin".to_owned(),
+            import: "sarzak".to_owned()
+        }));
+
+        // With an empty sarzak, the imported object dangles.
+        workspace.insert(ModelBuilder::new("sarzak", "").build().unwrap());
+        assert!(workspace
+            .check()
+            .iter()
+            .any(|p| matches!(p, Problem::DanglingImport { import, .. } if import == "sarzak")));
+    }
+
+    #[test]
+    fn test_persist() {
+        let workspace = workspace();
+        let path = std::env::temp_dir().join(format!("sarzak-workspace-{}", std::process::id()));
+
+        workspace.persist(&path).unwrap();
+        let loaded = Workspace::load(&path).unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        assert_eq!(
+            loaded.domains().map(|d| d.name()).collect::<Vec<_>>(),
+            ["merlin", "sarzak"]
+        );
+        assert_eq!(loaded.imports, workspace.imports);
+        assert!(loaded.check().is_empty());
+    }
+}